@@ -109,7 +109,7 @@ fn render_map(selected_row: usize, area: Rect, buf: &mut Buffer) {
         resolution: MapResolution::High,
         color: theme.color,
     };
-    Canvas::default()
+    let canvas = Canvas::default()
         .background_color(theme.background_color)
         .block(
             Block::new()
@@ -140,8 +140,8 @@ fn render_map(selected_row: usize, area: Rect, buf: &mut Buffer) {
                     coords: &[path.1.location], // perth
                 });
             }
-        })
-        .render(area, buf);
+        });
+    Widget::render(canvas, area, buf);
 }
 
 #[derive(Debug)]
@@ -14,7 +14,7 @@ use ratatui::Frame;
 use ratatui::layout::{Constraint, Layout};
 use ratatui::style::Stylize;
 use ratatui::text::Line;
-use ratatui::widgets::{Block, Clear, Paragraph};
+use ratatui::widgets::{Block, Popup, Shadow};
 
 fn main() -> Result<()> {
     color_eyre::install()?;
@@ -52,14 +52,11 @@ fn render(frame: &mut Frame, show_popup: bool) {
     frame.render_widget(Block::bordered().title("Content").on_blue(), content);
 
     if show_popup {
-        let popup_block = Block::bordered().title("Popup");
-        let centered_area = area.centered(Constraint::Percentage(60), Constraint::Percentage(20));
-        // clears out any background in the area before rendering the popup
-        frame.render_widget(Clear, centered_area);
-        let paragraph = Paragraph::new("Lorem ipsum").block(popup_block);
-        frame.render_widget(paragraph, centered_area);
-        // another solution is to use the inner area of the block
-        // let inner_area = popup_block.inner(centered_area);
-        // frame.render_widget(your_widget, inner_area);
+        // the popup sizes itself to its content, is centered in the area and clears out any
+        // background in its area before rendering
+        let popup = Popup::new("Lorem ipsum")
+            .title("Popup")
+            .shadow(Shadow::dark_shade());
+        frame.render_widget(popup, area);
     }
 }
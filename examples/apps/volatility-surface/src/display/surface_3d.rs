@@ -113,7 +113,7 @@ impl Surface3D {
                 self.draw_peak_highlights(ctx, surface_data, n_exp, n_strike, min_vol, max_vol);
             });
 
-        canvas.render(area, buf);
+        Widget::render(canvas, area, buf);
     }
 
     /// Find the minimum and maximum volatility values in the surface data.
@@ -177,13 +177,10 @@ impl TestBackend {
     /// When the scrollback buffer is not equal, a panic occurs with a detailed error message
     /// showing the differences between the expected and actual buffers.
     pub fn assert_scrollback_empty(&self) {
-        let expected = Buffer {
-            area: Rect {
-                width: self.scrollback.area.width,
-                ..Rect::ZERO
-            },
-            content: vec![],
-        };
+        let expected = Buffer::empty(Rect {
+            width: self.scrollback.area.width,
+            ..Rect::ZERO
+        });
         self.assert_scrollback(&expected);
     }
 
@@ -470,6 +467,7 @@ mod tests {
     use itertools::Itertools as _;
 
     use super::*;
+    use crate::buffer::WidthPolicy;
 
     #[test]
     fn new() {
@@ -963,6 +961,7 @@ mod tests {
             Buffer {
                 area: Rect::new(0, 0, 10, 5),
                 content: backend.scrollback.content[0..10 * 5].to_vec(),
+                width_policy: WidthPolicy::default(),
             },
             Buffer::with_lines([
                 "         6",
@@ -978,6 +977,7 @@ mod tests {
             Buffer {
                 area: Rect::new(0, 0, 10, 5),
                 content: backend.scrollback.content[10 * 65530..10 * 65535].to_vec(),
+                width_policy: WidthPolicy::default(),
             },
             Buffer::with_lines([
                 "     65536",
@@ -6,8 +6,10 @@ mod buffer;
 mod cell;
 mod cell_width;
 mod diff;
+mod width_policy;
 
 pub use buffer::Buffer;
 pub use cell::{Cell, CellDiffOption};
 pub use cell_width::CellWidth;
 pub use diff::BufferDiff;
+pub use width_policy::{AmbiguousWidth, EmojiPresentation, WidthPolicy, ZwjSequence};
@@ -1,11 +1,12 @@
 use alloc::vec;
 use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
 use core::ops::{Index, IndexMut};
 use core::{cmp, fmt};
 
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::buffer::{BufferDiff, Cell, CellWidth};
+use crate::buffer::{BufferDiff, Cell, CellWidth, WidthPolicy};
 use crate::layout::{Position, Rect};
 use crate::style::Style;
 use crate::text::{Line, Span};
@@ -62,7 +63,7 @@ use crate::text::{Line, Span};
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Default, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Buffer {
     /// The area represented by this buffer
@@ -70,6 +71,25 @@ pub struct Buffer {
     /// The content of the buffer. The length of this Vec should always be equal to area.width *
     /// area.height
     pub content: Vec<Cell>,
+    // Determines the width of the graphemes written to the buffer. It is a setting of the buffer
+    // rather than part of its content, so it is not compared, hashed or serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) width_policy: WidthPolicy,
+}
+
+impl PartialEq for Buffer {
+    fn eq(&self, other: &Self) -> bool {
+        self.area == other.area && self.content == other.content
+    }
+}
+
+impl Eq for Buffer {}
+
+impl Hash for Buffer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.area.hash(state);
+        self.content.hash(state);
+    }
 }
 
 impl Buffer {
@@ -80,14 +100,23 @@ impl Buffer {
     }
 
     /// Returns a Buffer with all cells initialized with the attributes of the given Cell
+    ///
+    /// The buffer uses the [active](WidthPolicy::active) width policy.
     #[must_use]
     pub fn filled(area: Rect, cell: Cell) -> Self {
         let size = area.area() as usize;
         let content = vec![cell; size];
-        Self { area, content }
+        Self {
+            area,
+            content,
+            width_policy: WidthPolicy::active(),
+        }
     }
 
     /// Returns a Buffer containing the given lines
+    ///
+    /// The lines describe the content of the cells, so unlike [`Buffer::set_line`], bidirectional
+    /// text is not reordered.
     #[must_use]
     pub fn with_lines<'a, Iter>(lines: Iter) -> Self
     where
@@ -99,7 +128,12 @@ impl Buffer {
         let width = lines.iter().map(Line::width).max().unwrap_or_default() as u16;
         let mut buffer = Self::empty(Rect::new(0, 0, width, height));
         for (y, line) in lines.iter().enumerate() {
-            buffer.set_line(0, y as u16, line, width);
+            let mut x = 0;
+            for span in line {
+                let style = line.style.patch(span.style);
+                let max_width = usize::from(width.saturating_sub(x));
+                (x, _) = buffer.set_stringn(x, y as u16, &span.content, max_width, style);
+            }
         }
         buffer
     }
@@ -114,6 +148,22 @@ impl Buffer {
         &self.area
     }
 
+    /// Returns the [`WidthPolicy`] that determines the width of the graphemes written to the
+    /// buffer
+    pub const fn width_policy(&self) -> WidthPolicy {
+        self.width_policy
+    }
+
+    /// Sets the [`WidthPolicy`] that determines the width of the graphemes written to the buffer
+    ///
+    /// Graphemes whose width differs from their Unicode width are written with a
+    /// [`CellDiffOption::ForcedWidth`], so that the buffer is diffed using the same width. The
+    /// policy is a setting of the buffer rather than part of its content, so buffers with the same
+    /// cells are equal regardless of their policy.
+    pub const fn set_width_policy(&mut self, width_policy: WidthPolicy) {
+        self.width_policy = width_policy;
+    }
+
     /// Returns a reference to the [`Cell`] at the given coordinates
     ///
     /// Callers should use [`Buffer[]`](Self::index) or [`Buffer::cell`] instead of this method.
@@ -347,9 +397,10 @@ impl Buffer {
     {
         let max_width = max_width.try_into().unwrap_or(u16::MAX);
         let mut remaining_width = self.area.right().saturating_sub(x).min(max_width);
+        let width_policy = self.width_policy;
         let graphemes = UnicodeSegmentation::graphemes(string.as_ref(), true)
             .filter(|symbol| !symbol.contains(char::is_control))
-            .map(|symbol| (symbol, symbol.cell_width()))
+            .map(|symbol| (symbol, width_policy.width(symbol)))
             .filter(|(_symbol, width)| *width > 0)
             .map_while(|(symbol, width)| {
                 remaining_width = remaining_width.checked_sub(width)?;
@@ -357,7 +408,10 @@ impl Buffer {
             });
         let style = style.into();
         for (symbol, width) in graphemes {
-            self[(x, y)].set_symbol(symbol).set_style(style);
+            self[(x, y)]
+                .set_symbol(symbol)
+                .set_style(style)
+                .set_width(width);
             let next_symbol = x + width;
             x += 1;
             // Reset following cells if multi-width (they would be hidden by the grapheme),
@@ -370,10 +424,31 @@ impl Buffer {
     }
 
     /// Print a line, starting at the position (x, y)
+    ///
+    /// If the line is wider than `max_width`, it is shortened according to its
+    /// [`Line::truncation`], or cut at `max_width` if no truncation is set. Bidirectional text is
+    /// printed in visual order.
     pub fn set_line(&mut self, x: u16, y: u16, line: &Line<'_>, max_width: u16) -> (u16, u16) {
+        // ASCII text is always displayed from left to right, which is the common case
+        let direction = if line.spans.iter().all(|span| span.content.is_ascii()) {
+            None
+        } else {
+            Some(line.direction())
+        };
+        let truncated;
+        let line = if line.width() > usize::from(max_width) {
+            // cut the line in logical order, as the end of bidirectional text is not always
+            // displayed on the right
+            truncated = line.truncated(usize::from(max_width));
+            &truncated
+        } else {
+            line
+        };
+        let reordered = direction.and_then(|direction| direction.reorder_spans(&line.spans));
+        let spans = reordered.as_ref().unwrap_or(&line.spans);
         let mut remaining_width = max_width;
         let mut x = x;
-        for span in line {
+        for span in spans {
             if remaining_width == 0 {
                 break;
             }
@@ -576,6 +651,9 @@ impl fmt::Debug for Buffer {
     ///   modifier: Modifier::BOLD }` only showing a value when there is a change in style.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_fmt(format_args!("Buffer {{\n    area: {:?}", self.area))?;
+        if self.width_policy != WidthPolicy::default() {
+            f.write_fmt(format_args!(",\n    width_policy: {:?}", self.width_policy))?;
+        }
 
         if self.area.is_empty() {
             return f.write_str("\n}");
@@ -656,6 +734,7 @@ mod tests {
     use super::*;
     use crate::buffer::CellDiffOption;
     use crate::style::{Color, Modifier, Stylize};
+    use crate::text::Truncation;
 
     #[test]
     fn debug_empty_buffer() {
@@ -666,6 +745,15 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn debug_width_policy() {
+        let mut buffer = Buffer::empty(Rect::ZERO);
+        buffer.set_width_policy(WidthPolicy::CJK);
+        let result = format!("{buffer:?}");
+        let expected = "Buffer {\n    area: Rect { x: 0, y: 0, width: 0, height: 0 },\n    width_policy: WidthPolicy { ambiguous: Wide, emoji_presentation: Wide, zwj_sequence: Joined }\n}";
+        assert_eq!(result, expected);
+    }
+
     #[cfg(feature = "underline-color")]
     #[test]
     fn debug_grapheme_override() {
@@ -914,6 +1002,48 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(["コン "]));
     }
 
+    #[test]
+    fn set_string_with_width_policy() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        buffer.set_width_policy(WidthPolicy::WCWIDTH);
+        buffer.set_string(0, 0, "❤️abc", Style::default());
+        let symbols = buffer.content.iter().map(Cell::symbol).collect::<Vec<_>>();
+        assert_eq!(symbols, ["❤️", "a", "b", "c"]);
+        assert_eq!(buffer.content[0].cell_width(), 1);
+
+        buffer.set_width_policy(WidthPolicy::CJK);
+        buffer.set_string(0, 0, "①abc", Style::default());
+        let symbols = buffer.content.iter().map(Cell::symbol).collect::<Vec<_>>();
+        assert_eq!(symbols, ["①", " ", "a", "b"]);
+        assert_eq!(buffer.content[0].cell_width(), 2);
+
+        // a forced width is removed when the cell is overwritten
+        buffer.set_string(0, 0, "x", Style::default());
+        assert_eq!(buffer.content[0].diff_option, CellDiffOption::None);
+    }
+
+    #[test]
+    fn width_policy_is_not_compared() {
+        let buffer = Buffer::with_lines(["abc"]);
+        let mut other = buffer.clone();
+        other.set_width_policy(WidthPolicy::CJK);
+        assert_eq!(buffer, other);
+    }
+
+    #[test]
+    fn diff_with_width_policy() {
+        let prev = Buffer::empty(Rect::new(0, 0, 3, 1));
+        let mut next = Buffer::empty(Rect::new(0, 0, 3, 1));
+        next.set_width_policy(WidthPolicy::WCWIDTH);
+        next.set_string(0, 0, "❤️ab", Style::default());
+        let diff = prev
+            .diff(&next)
+            .into_iter()
+            .map(|(x, _, cell)| (x, cell.symbol()))
+            .collect::<Vec<_>>();
+        assert_eq!(diff, [(0, "❤️"), (1, "a"), (2, "b")]);
+    }
+
     #[test]
     fn set_string_halfwidth_katakana_with_dakuten() {
         let area = Rect::new(0, 0, 5, 1);
@@ -1011,6 +1141,40 @@ mod tests {
         assert_eq!(small_one_line_buffer, expected_buffer);
     }
 
+    #[rstest]
+    #[case::fits("12345", "12345")]
+    #[case::end("123456", "1234…")]
+    fn set_line_with_truncation(
+        mut small_one_line_buffer: Buffer,
+        #[case] content: &str,
+        #[case] expected: &str,
+    ) {
+        let line = Line::raw(content).truncation(Truncation::end());
+        small_one_line_buffer.set_line(0, 0, &line, 5);
+
+        let mut expected_buffer = Buffer::empty(small_one_line_buffer.area);
+        expected_buffer.set_string(0, 0, expected, Style::default());
+        assert_eq!(small_one_line_buffer, expected_buffer);
+    }
+
+    #[rstest]
+    #[case::ltr_with_rtl("ab אב", "ab בא")]
+    #[case::rtl("אבגד", "דגבא ")]
+    #[case::rtl_overflow("אבגדהו", "הדגבא")]
+    #[case::ltr_overflow("ab אבגד", "ab בא")]
+    fn set_line_bidi(
+        mut small_one_line_buffer: Buffer,
+        #[case] content: &str,
+        #[case] expected: &str,
+    ) {
+        let line = Line::raw(content);
+        small_one_line_buffer.set_line(0, 0, &line, 5);
+
+        let mut expected_buffer = Buffer::empty(small_one_line_buffer.area);
+        expected_buffer.set_string(0, 0, expected, Style::default());
+        assert_eq!(small_one_line_buffer, expected_buffer);
+    }
+
     #[rstest]
     #[case::empty("", "     ")]
     #[case::one("1", "1    ")]
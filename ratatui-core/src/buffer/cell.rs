@@ -166,6 +166,24 @@ impl Cell {
         self
     }
 
+    /// Sets the width of the symbol of the cell, as measured by a
+    /// [`WidthPolicy`](crate::buffer::WidthPolicy).
+    ///
+    /// A width that differs from the Unicode width of the symbol is forced with
+    /// [`CellDiffOption::ForcedWidth`], and a width forced by a previous symbol is dropped.
+    pub(crate) fn set_width(&mut self, width: u16) -> &mut Self {
+        match NonZeroU16::new(width) {
+            Some(width) if width.get() != self.symbol().cell_width() => {
+                self.diff_option = CellDiffOption::ForcedWidth(width);
+            }
+            _ if matches!(self.diff_option, CellDiffOption::ForcedWidth(_)) => {
+                self.diff_option = CellDiffOption::None;
+            }
+            _ => {}
+        }
+        self
+    }
+
     /// Sets the symbol of the cell to a single character.
     pub fn set_char(&mut self, ch: char) -> &mut Self {
         let mut buf = [0; 4];
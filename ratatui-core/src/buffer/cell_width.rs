@@ -1,4 +1,4 @@
-use unicode_width::UnicodeWidthStr;
+use crate::buffer::WidthPolicy;
 
 /// Halfwidth Katakana Voiced Sound Mark (dakuten).
 const HALFWIDTH_KATAKANA_VOICED_SOUND_MARK: char = '\u{FF9E}';
@@ -12,10 +12,12 @@ const HALFWIDTH_KATAKANA_SEMI_VOICED_SOUND_MARK: char = '\u{FF9F}';
 ///
 /// - **`str`**: width is derived from [`UnicodeWidthStr`], with a fast path for single-byte ASCII
 ///   characters and a terminal-compatibility adjustment for halfwidth katakana dakuten/handakuten
-///   (`U+FF9E`/`U+FF9F`).
+///   (`U+FF9E`/`U+FF9F`). This is the width given by [`WidthPolicy::UNICODE`].
 /// - **[`Cell`](super::Cell)**: returns the
 ///   [`CellDiffOption::ForcedWidth`](super::CellDiffOption::ForcedWidth) when set, otherwise falls
 ///   back to the width of the cell's symbol.
+///
+/// [`UnicodeWidthStr`]: unicode_width::UnicodeWidthStr
 pub trait CellWidth {
     /// Returns the display width in terminal cells.
     fn cell_width(&self) -> u16;
@@ -32,16 +34,7 @@ impl CellWidth for str {
     /// caller is responsible for providing valid content. Single-byte control
     /// characters that slip through will be reported as width 1.
     fn cell_width(&self) -> u16 {
-        if self.len() == 1 {
-            debug_assert!(
-                !self.as_bytes()[0].is_ascii_control(),
-                "control character passed to cell_width without filtering"
-            );
-            1
-        } else {
-            let width = self.width() as u16;
-            width.saturating_add(count_halfwidth_sound_marks(self))
-        }
+        WidthPolicy::UNICODE.width(self)
     }
 }
 
@@ -63,7 +56,7 @@ impl CellWidth for str {
 ///   Half-width Katakana and (han)dakuten should not overlap
 /// - [Unicode L2/19-039](https://www.unicode.org/L2/L2019/19039-grapheme-break.pdf): Grapheme break
 ///   property for U+FF9E and U+FF9F
-fn count_halfwidth_sound_marks(s: &str) -> u16 {
+pub(super) fn count_halfwidth_sound_marks(s: &str) -> u16 {
     s.chars()
         .filter(|c| {
             matches!(
@@ -0,0 +1,327 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::buffer::cell_width::count_halfwidth_sound_marks;
+
+/// Zero Width Joiner, which joins emoji into a single glyph (e.g. 👩‍💻).
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+/// Variation Selector-16, which requests the emoji presentation of a character (e.g. ❤️).
+const EMOJI_PRESENTATION_SELECTOR: char = '\u{FE0F}';
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static ACTIVE_WIDTH_POLICY: core::cell::Cell<WidthPolicy> =
+        const { core::cell::Cell::new(WidthPolicy::UNICODE) };
+}
+
+/// Restores the active width policy when dropped.
+#[cfg(feature = "std")]
+struct ActivePolicyGuard(WidthPolicy);
+
+#[cfg(feature = "std")]
+impl Drop for ActivePolicyGuard {
+    fn drop(&mut self) {
+        ACTIVE_WIDTH_POLICY.set(self.0);
+    }
+}
+
+/// Determines how many cells a grapheme occupies in the terminal.
+///
+/// Terminals disagree about the width of some graphemes, which can misalign the content of a
+/// [`Buffer`](super::Buffer) when the width Ratatui computes differs from the width the terminal
+/// uses to draw a grapheme. The most common differences are:
+///
+/// - East Asian Ambiguous characters (such as `±` or `①`) which are wide in terminals configured
+///   for CJK locales.
+/// - Emoji presentation sequences (such as `❤️`, a heart followed by U+FE0F), which some terminals
+///   draw with the width of the base character.
+/// - Emoji ZWJ sequences (such as `👩‍💻`), which some terminals draw as their separate emoji.
+///
+/// [`WidthPolicy::UNICODE`] is the default and follows the Unicode specification. The other
+/// presets match common terminal behaviors, and the fields can be combined to match a specific
+/// terminal.
+///
+/// The policy of a buffer is set with [`Buffer::set_width_policy`], and the width of text measured
+/// outside of a buffer (such as [`Line::width`] or the widths used to truncate and align text) uses
+/// the [active](WidthPolicy::active) policy. [`Terminal::set_width_policy`] sets both for the
+/// frames drawn by a terminal. The active policy is stored per thread, so it requires the `std`
+/// feature: without it, text outside of a buffer is always measured with
+/// [`WidthPolicy::UNICODE`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::buffer::{AmbiguousWidth, WidthPolicy};
+///
+/// assert_eq!(WidthPolicy::UNICODE.width("❤️"), 2);
+/// assert_eq!(WidthPolicy::WCWIDTH.width("❤️"), 1);
+/// assert_eq!(WidthPolicy::CJK.width("①"), 2);
+///
+/// let policy = WidthPolicy {
+///     ambiguous: AmbiguousWidth::Wide,
+///     ..WidthPolicy::WCWIDTH
+/// };
+/// assert_eq!(policy.width("👩‍💻"), 4);
+/// ```
+///
+/// [`Buffer::set_width_policy`]: super::Buffer::set_width_policy
+/// [`Line::width`]: crate::text::Line::width
+/// [`Terminal::set_width_policy`]: crate::terminal::Terminal::set_width_policy
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WidthPolicy {
+    /// The width of East Asian Ambiguous characters.
+    pub ambiguous: AmbiguousWidth,
+    /// The width of emoji presentation sequences.
+    pub emoji_presentation: EmojiPresentation,
+    /// The width of emoji ZWJ sequences.
+    pub zwj_sequence: ZwjSequence,
+}
+
+/// The width of East Asian Ambiguous characters.
+///
+/// See [`WidthPolicy::ambiguous`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AmbiguousWidth {
+    /// Ambiguous characters occupy one cell.
+    #[default]
+    Narrow,
+    /// Ambiguous characters occupy two cells, as in terminals configured for CJK locales.
+    Wide,
+}
+
+/// The width of emoji presentation sequences (a character followed by U+FE0F).
+///
+/// See [`WidthPolicy::emoji_presentation`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EmojiPresentation {
+    /// The sequence occupies two cells like other emoji.
+    #[default]
+    Wide,
+    /// The variation selector is ignored and the sequence is as wide as its base character.
+    BaseWidth,
+}
+
+/// The width of emoji ZWJ sequences (emoji joined by U+200D).
+///
+/// See [`WidthPolicy::zwj_sequence`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ZwjSequence {
+    /// The sequence is drawn as a single emoji.
+    #[default]
+    Joined,
+    /// The sequence is drawn as the separate emoji it is made of.
+    Split,
+}
+
+impl WidthPolicy {
+    /// Widths as specified by Unicode.
+    ///
+    /// This matches terminals with up-to-date Unicode support and is the default policy.
+    pub const UNICODE: Self = Self {
+        ambiguous: AmbiguousWidth::Narrow,
+        emoji_presentation: EmojiPresentation::Wide,
+        zwj_sequence: ZwjSequence::Joined,
+    };
+
+    /// Widths computed one code point at a time, like the C library `wcwidth` function.
+    ///
+    /// This matches terminals and multiplexers that do not handle grapheme clusters, which ignore
+    /// variation selectors and draw ZWJ sequences as separate emoji.
+    pub const WCWIDTH: Self = Self {
+        ambiguous: AmbiguousWidth::Narrow,
+        emoji_presentation: EmojiPresentation::BaseWidth,
+        zwj_sequence: ZwjSequence::Split,
+    };
+
+    /// Widths as specified by Unicode, with wide East Asian Ambiguous characters.
+    ///
+    /// This matches terminals configured for CJK locales.
+    pub const CJK: Self = Self {
+        ambiguous: AmbiguousWidth::Wide,
+        emoji_presentation: EmojiPresentation::Wide,
+        zwj_sequence: ZwjSequence::Joined,
+    };
+
+    /// Makes this policy the active policy.
+    ///
+    /// The active policy measures text that is not written to a [`Buffer`](super::Buffer), such as
+    /// [`Line::width`](crate::text::Line::width), so that widgets align and truncate text with the
+    /// widths used by the terminal. New buffers also use the active policy. The active policy is
+    /// stored per thread, and [`Terminal::draw`](crate::terminal::Terminal::draw) sets it to the
+    /// policy of the terminal while drawing a frame.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::buffer::WidthPolicy;
+    /// use ratatui_core::text::Line;
+    ///
+    /// WidthPolicy::CJK.set_active();
+    /// assert_eq!(Line::from("±1").width(), 3);
+    ///
+    /// WidthPolicy::UNICODE.set_active();
+    /// assert_eq!(Line::from("±1").width(), 2);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn set_active(self) {
+        ACTIVE_WIDTH_POLICY.set(self);
+    }
+
+    /// Returns the active policy.
+    ///
+    /// This is [`WidthPolicy::UNICODE`] unless another policy is [set](WidthPolicy::set_active).
+    /// Without the `std` feature there is no active policy and this always returns
+    /// [`WidthPolicy::UNICODE`].
+    #[cfg_attr(not(feature = "std"), expect(clippy::missing_const_for_fn))]
+    pub fn active() -> Self {
+        #[cfg(feature = "std")]
+        {
+            ACTIVE_WIDTH_POLICY.get()
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Self::UNICODE
+        }
+    }
+
+    /// Calls `f` with this policy as the active policy, then restores the previous active policy.
+    ///
+    /// The previous policy is restored even if `f` panics. Without the `std` feature there is no
+    /// active policy and this only calls `f`.
+    #[cfg_attr(not(feature = "std"), expect(clippy::unused_self))]
+    pub(crate) fn with_active<R>(self, f: impl FnOnce() -> R) -> R {
+        #[cfg(feature = "std")]
+        let _guard = ActivePolicyGuard(ACTIVE_WIDTH_POLICY.replace(self));
+        f()
+    }
+
+    /// Returns the width of a string in terminal cells, measured one grapheme at a time.
+    ///
+    /// Control characters are not rendered and have no width.
+    pub fn str_width(self, s: &str) -> usize {
+        if self == Self::UNICODE {
+            return s.width();
+        }
+        s.graphemes(true)
+            .filter(|grapheme| !grapheme.contains(char::is_control))
+            .map(|grapheme| usize::from(self.width(grapheme)))
+            .sum()
+    }
+
+    /// Returns the width of a grapheme in terminal cells.
+    ///
+    /// The width of halfwidth katakana sound marks is adjusted in the same way as
+    /// [`CellWidth`](super::CellWidth), regardless of the policy.
+    pub fn width(self, grapheme: &str) -> u16 {
+        if grapheme.len() == 1 {
+            debug_assert!(
+                !grapheme.as_bytes()[0].is_ascii_control(),
+                "control character passed to cell_width without filtering"
+            );
+            return 1;
+        }
+        if self.zwj_sequence == ZwjSequence::Split && grapheme.contains(ZERO_WIDTH_JOINER) {
+            return grapheme
+                .split(ZERO_WIDTH_JOINER)
+                .map(|part| self.width(part))
+                .fold(0, u16::saturating_add);
+        }
+        if self.emoji_presentation == EmojiPresentation::BaseWidth
+            && grapheme.contains(EMOJI_PRESENTATION_SELECTOR)
+        {
+            return grapheme
+                .split(EMOJI_PRESENTATION_SELECTOR)
+                .map(|part| self.unicode_width(part))
+                .fold(0, u16::saturating_add);
+        }
+        self.unicode_width(grapheme)
+    }
+
+    /// Returns the Unicode width of a string, with the width of ambiguous characters set by the
+    /// policy.
+    fn unicode_width(self, s: &str) -> u16 {
+        let width = match self.ambiguous {
+            AmbiguousWidth::Narrow => s.width(),
+            AmbiguousWidth::Wide => s.width_cjk(),
+        };
+        u16::try_from(width)
+            .unwrap_or(u16::MAX)
+            .saturating_add(count_halfwidth_sound_marks(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::buffer::CellWidth;
+
+    #[rstest]
+    #[case::ascii("a", 1, 1, 1)]
+    #[case::cjk("あ", 2, 2, 2)]
+    #[case::ambiguous("①", 1, 1, 2)]
+    #[case::ambiguous_sign("±", 1, 1, 2)]
+    #[case::emoji("😀", 2, 2, 2)]
+    #[case::emoji_presentation("❤\u{FE0F}", 2, 1, 2)]
+    #[case::keycap("1\u{FE0F}\u{20E3}", 2, 1, 2)]
+    #[case::zwj_sequence("👩\u{200D}💻", 2, 4, 2)]
+    #[case::zwj_with_presentation("🏳\u{FE0F}\u{200D}🌈", 2, 3, 2)]
+    #[case::halfwidth_dakuten("ｶﾞ", 2, 2, 2)]
+    #[case::zero_width("\u{200B}", 0, 0, 0)]
+    fn presets(
+        #[case] grapheme: &str,
+        #[case] unicode: u16,
+        #[case] wcwidth: u16,
+        #[case] cjk: u16,
+    ) {
+        assert_eq!(WidthPolicy::UNICODE.width(grapheme), unicode, "unicode");
+        assert_eq!(WidthPolicy::WCWIDTH.width(grapheme), wcwidth, "wcwidth");
+        assert_eq!(WidthPolicy::CJK.width(grapheme), cjk, "cjk");
+    }
+
+    #[rstest]
+    #[case::unicode(WidthPolicy::UNICODE, 8)]
+    #[case::wcwidth(WidthPolicy::WCWIDTH, 7)]
+    #[case::cjk(WidthPolicy::CJK, 9)]
+    fn str_width(#[case] policy: WidthPolicy, #[case] expected: usize) {
+        assert_eq!(policy.str_width("±ab❤\u{FE0F}あ\u{200B}c"), expected);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn active_policy() {
+        assert_eq!(WidthPolicy::active(), WidthPolicy::UNICODE);
+        WidthPolicy::WCWIDTH.set_active();
+        assert_eq!(WidthPolicy::active(), WidthPolicy::WCWIDTH);
+        WidthPolicy::UNICODE.set_active();
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_active_restores_policy_after_panic() {
+        let result = std::panic::catch_unwind(|| {
+            WidthPolicy::CJK.with_active(|| panic!("draw failed"));
+        });
+        assert!(result.is_err());
+        assert_eq!(WidthPolicy::active(), WidthPolicy::UNICODE);
+    }
+
+    #[rstest]
+    #[case("a")]
+    #[case("あ")]
+    #[case("❤\u{FE0F}")]
+    #[case("👩\u{200D}💻")]
+    #[case("ｶﾞ")]
+    fn default_matches_cell_width(#[case] grapheme: &str) {
+        assert_eq!(WidthPolicy::default(), WidthPolicy::UNICODE);
+        assert_eq!(
+            WidthPolicy::default().width(grapheme),
+            grapheme.cell_width()
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! Backend independent representation of keyboard input.
+//!
+//! Each backend reads input using its own types. Widgets that handle input, such as text inputs,
+//! use the types in this module instead, so that they work the same with every backend. The
+//! backend crates provide conversions from their key types, for example `KeyEvent::from_crossterm`
+//! in `ratatui-crossterm` or `KeyEvent::from_termion` in `ratatui-termion`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use ratatui_core::input::{KeyCode, KeyEvent, KeyModifiers};
+//!
+//! let key = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL);
+//! assert!(key.modifiers.contains(KeyModifiers::CONTROL));
+//!
+//! assert_eq!(
+//!     KeyEvent::from('a'),
+//!     KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE)
+//! );
+//! assert_eq!(KeyEvent::from(KeyCode::Enter).code, KeyCode::Enter);
+//! ```
+
+use bitflags::bitflags;
+
+/// A key press, made of a [`KeyCode`] and the [`KeyModifiers`] held while it was pressed.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyEvent {
+    /// The key that was pressed.
+    pub code: KeyCode,
+    /// The modifiers held while the key was pressed.
+    pub modifiers: KeyModifiers,
+}
+
+/// A key on the keyboard.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum KeyCode {
+    /// A character key. Shifted characters are reported as their shifted value, e.g. `'A'`.
+    Char(char),
+    /// Backspace key.
+    Backspace,
+    /// Delete key.
+    Delete,
+    /// Insert key.
+    Insert,
+    /// Enter key.
+    Enter,
+    /// Escape key.
+    Esc,
+    /// Tab key.
+    Tab,
+    /// Shift + Tab key, reported separately by most terminals.
+    BackTab,
+    /// Left arrow key.
+    Left,
+    /// Right arrow key.
+    Right,
+    /// Up arrow key.
+    Up,
+    /// Down arrow key.
+    Down,
+    /// Home key.
+    Home,
+    /// End key.
+    End,
+    /// Page up key.
+    PageUp,
+    /// Page down key.
+    PageDown,
+    /// A function key, e.g. `F(1)` for F1.
+    F(u8),
+    /// A key that is not represented by the other variants, such as media or modifier keys.
+    Unidentified,
+}
+
+bitflags! {
+    /// The modifier keys held while a key was pressed.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+    pub struct KeyModifiers: u8 {
+        const SHIFT   = 0b0000_0001;
+        const CONTROL = 0b0000_0010;
+        const ALT     = 0b0000_0100;
+        const SUPER   = 0b0000_1000;
+    }
+}
+
+impl KeyModifiers {
+    /// No modifiers.
+    pub const NONE: Self = Self::empty();
+}
+
+impl KeyEvent {
+    /// Creates a new key event.
+    pub const fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    /// Returns `true` if the control key was held, ignoring the other modifiers.
+    pub const fn is_ctrl(&self) -> bool {
+        self.modifiers.contains(KeyModifiers::CONTROL)
+    }
+
+    /// Returns `true` if the alt key was held, ignoring the other modifiers.
+    pub const fn is_alt(&self) -> bool {
+        self.modifiers.contains(KeyModifiers::ALT)
+    }
+
+    /// Returns `true` if the shift key was held, ignoring the other modifiers.
+    pub const fn is_shift(&self) -> bool {
+        self.modifiers.contains(KeyModifiers::SHIFT)
+    }
+}
+
+impl From<KeyCode> for KeyEvent {
+    fn from(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::NONE)
+    }
+}
+
+impl From<char> for KeyEvent {
+    fn from(c: char) -> Self {
+        Self::new(KeyCode::Char(c), KeyModifiers::NONE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modifiers() {
+        let key = KeyEvent::new(KeyCode::Left, KeyModifiers::CONTROL | KeyModifiers::SHIFT);
+        assert!(key.is_ctrl());
+        assert!(key.is_shift());
+        assert!(!key.is_alt());
+        assert_eq!(KeyModifiers::default(), KeyModifiers::NONE);
+    }
+}
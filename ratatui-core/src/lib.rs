@@ -80,6 +80,7 @@ extern crate std;
 
 pub mod backend;
 pub mod buffer;
+pub mod input;
 pub mod layout;
 pub mod style;
 pub mod symbols;
@@ -74,6 +74,7 @@ use bitflags::bitflags;
 pub use color::{Color, ParseColorError};
 use stylize::ColorDebugKind;
 pub use stylize::{Styled, Stylize};
+pub use theme::Theme;
 
 #[cfg(feature = "anstyle")]
 mod anstyle;
@@ -83,6 +84,7 @@ pub mod palette;
 mod palette_conversion;
 #[macro_use]
 mod stylize;
+mod theme;
 
 bitflags! {
     /// Modifier changes the way a piece of text is displayed.
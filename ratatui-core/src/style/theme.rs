@@ -0,0 +1,401 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+use crate::style::palette::{material, tailwind};
+use crate::style::{Color, Modifier, Style};
+
+/// A set of [`Style`]s identified by semantic, dot separated keys.
+///
+/// A [`Theme`] maps semantic keys such as `list.highlight`, `block.border.focused` or `chart.axis`
+/// to the [`Style`] used to draw that part of the user interface. Keys are dot separated paths and
+/// inherit the style of their parents, so `block.border.focused` is the style of `block`, patched
+/// with `block.border`, patched with `block.border.focused`.
+///
+/// Themes can be built in code, derived from the [`tailwind`] and [`material`] palettes, or loaded
+/// from a configuration file with the `serde` feature. The built-in widgets take their styles from
+/// a theme with their `theme` method, e.g. `List::new(items).theme(&theme)`, which sets the styles
+/// of the widget from the keys below in place of [`Style::default()`]. A theme is switched at
+/// runtime by passing another theme to the widgets of the next frame.
+///
+/// # Keys used by the built-in widgets
+///
+/// | Key | Widget |
+/// |-----|--------|
+/// | `block`, `block.border`, `block.title` | `Block` |
+/// | `paragraph` | `Paragraph` |
+/// | `popup`, `popup.button`, `popup.button.selected` | `Popup` |
+/// | `list`, `list.highlight`, `list.selection` | `List` |
+/// | `table`, `table.row.highlight`, `table.column.highlight`, `table.cell.highlight`, `table.selection`, `table.gridline` | `Table` |
+/// | `tabs`, `tabs.highlight` | `Tabs` |
+/// | `tree`, `tree.highlight`, `tree.guide` | `Tree` |
+/// | `menu`, `menu.highlight`, `menu.disabled`, `menu.accelerator` | `Menu` |
+/// | `gauge`, `gauge.bar` | `Gauge` |
+/// | `line_gauge`, `line_gauge.filled`, `line_gauge.unfilled` | `LineGauge` |
+/// | `chart`, `chart.axis`, `chart.crosshair`, `chart.tooltip` | `Chart`, `Axis` |
+/// | `sparkline`, `sparkline.annotation` | `Sparkline` |
+/// | `heatmap`, `heatmap.label` | `Heatmap` |
+/// | `piechart` | `PieChart` |
+/// | `barchart`, `barchart.bar`, `barchart.value`, `barchart.label` | `BarChart` |
+/// | `text_input`, `text_input.selection`, `text_input.placeholder` | `TextInput` |
+/// | `text_area`, `text_area.selection`, `text_area.search`, `text_area.line_number` | `TextArea` |
+///
+/// Other keys, such as `block.border.focused`, are not used by the widgets themselves but can be
+/// looked up by applications with [`Theme::style`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::style::{Color, Style, Theme};
+///
+/// let theme = Theme::new()
+///     .with_style("block", Style::new().fg(Color::White))
+///     .with_style("block.border", Style::new().fg(Color::Gray))
+///     .with_style("block.border.focused", Style::new().fg(Color::Yellow));
+///
+/// assert_eq!(
+///     theme.style("block.border.focused"),
+///     Style::new().fg(Color::Yellow)
+/// );
+/// // keys that are not in the theme inherit the style of their parent
+/// assert_eq!(theme.style("block.title"), Style::new().fg(Color::White));
+/// ```
+///
+/// With the `serde` feature, a theme serializes as a map from keys to styles, so it can be loaded
+/// from a configuration file:
+///
+/// ```json
+/// {
+///     "list": { "fg": "White" },
+///     "list.highlight": { "fg": "Black", "bg": "Yellow", "add_modifier": "BOLD" }
+/// }
+/// ```
+///
+/// [`tailwind`]: crate::style::palette::tailwind
+/// [`material`]: crate::style::palette::material
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Theme {
+    styles: BTreeMap<String, Style>,
+}
+
+/// The colors a preset theme is built from.
+struct Roles {
+    background: Color,
+    surface: Color,
+    text: Color,
+    muted: Color,
+    accent: Color,
+    on_accent: Color,
+}
+
+impl Theme {
+    /// Creates an empty theme.
+    ///
+    /// Every key of an empty theme resolves to [`Style::default()`].
+    pub const fn new() -> Self {
+        Self {
+            styles: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the style of a key and returns the modified theme.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_style<K: Into<String>, S: Into<Style>>(mut self, key: K, style: S) -> Self {
+        self.set_style(key, style);
+        self
+    }
+
+    /// Sets the style of a key, replacing any previous style.
+    pub fn set_style<K: Into<String>, S: Into<Style>>(&mut self, key: K, style: S) {
+        self.styles.insert(key.into(), style.into());
+    }
+
+    /// Returns the style set for exactly this key, without inheritance.
+    pub fn get(&self, key: &str) -> Option<Style> {
+        self.styles.get(key).copied()
+    }
+
+    /// Returns the resolved style of a key.
+    ///
+    /// The style is built by patching the style of each parent of the key, from the outermost to
+    /// the key itself. Keys that are not in the theme do not change the style, so a key that is
+    /// missing entirely resolves to [`Style::default()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Style, Stylize, Theme};
+    ///
+    /// let theme = Theme::new()
+    ///     .with_style("list", Style::new().white().on_black())
+    ///     .with_style("list.highlight", Style::new().yellow().bold());
+    ///
+    /// assert_eq!(
+    ///     theme.style("list.highlight"),
+    ///     Style::new().yellow().on_black().bold()
+    /// );
+    /// assert_eq!(theme.style("table"), Style::new());
+    /// ```
+    #[expect(clippy::string_slice)] // Is safe as the index is the position of a '.'
+    pub fn style(&self, key: &str) -> Style {
+        key.match_indices('.')
+            .map(|(index, _)| &key[..index])
+            .chain([key])
+            .filter_map(|key| self.get(key))
+            .fold(Style::new(), Style::patch)
+    }
+
+    /// Returns an iterator over the keys and styles of the theme, ordered by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Style)> {
+        self.styles
+            .iter()
+            .map(|(key, style)| (key.as_str(), *style))
+    }
+
+    /// Patches the styles of this theme with the styles of another theme.
+    ///
+    /// This is useful to derive a theme from one of the presets. Styles of keys that are in both
+    /// themes are patched with [`Style::patch`], and keys that are only in `other` are added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Style, Stylize, Theme};
+    ///
+    /// let overrides = Theme::new().with_style("list.highlight", Style::new().underlined());
+    /// let theme = Theme::tailwind_dark().patch(overrides);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn patch(mut self, other: Self) -> Self {
+        for (key, style) in other.styles {
+            let patched = self.get(&key).unwrap_or_default().patch(style);
+            self.styles.insert(key, patched);
+        }
+        self
+    }
+
+    /// A dark theme using the [`tailwind`] palette.
+    pub fn tailwind_dark() -> Self {
+        Self::from_roles(&Roles {
+            background: tailwind::SLATE.c950,
+            surface: tailwind::SLATE.c800,
+            text: tailwind::SLATE.c200,
+            muted: tailwind::SLATE.c500,
+            accent: tailwind::BLUE.c400,
+            on_accent: tailwind::SLATE.c950,
+        })
+    }
+
+    /// A light theme using the [`tailwind`] palette.
+    pub fn tailwind_light() -> Self {
+        Self::from_roles(&Roles {
+            background: tailwind::SLATE.c50,
+            surface: tailwind::SLATE.c200,
+            text: tailwind::SLATE.c900,
+            muted: tailwind::SLATE.c500,
+            accent: tailwind::BLUE.c600,
+            on_accent: tailwind::WHITE,
+        })
+    }
+
+    /// A dark theme using the [`material`] palette.
+    pub fn material_dark() -> Self {
+        Self::from_roles(&Roles {
+            background: material::GRAY.c900,
+            surface: material::GRAY.c800,
+            text: material::GRAY.c100,
+            muted: material::GRAY.c500,
+            accent: material::TEAL.a200,
+            on_accent: material::BLACK,
+        })
+    }
+
+    /// A light theme using the [`material`] palette.
+    pub fn material_light() -> Self {
+        Self::from_roles(&Roles {
+            background: material::GRAY.c50,
+            surface: material::GRAY.c300,
+            text: material::GRAY.c900,
+            muted: material::GRAY.c600,
+            accent: material::INDIGO.c500,
+            on_accent: material::WHITE,
+        })
+    }
+
+    fn from_roles(roles: &Roles) -> Self {
+        let highlight = Style::new()
+            .fg(roles.on_accent)
+            .bg(roles.accent)
+            .add_modifier(Modifier::BOLD);
+        let accent = Style::new().fg(roles.accent);
+        let muted = Style::new().fg(roles.muted);
+        Self::new()
+            .with_style("block", Style::new().fg(roles.text).bg(roles.background))
+            .with_style("block.border", muted)
+            .with_style("block.border.focused", accent)
+            .with_style("block.title", Modifier::BOLD)
+            .with_style("paragraph", Style::new().fg(roles.text))
+            .with_style("popup", Style::new().fg(roles.text).bg(roles.surface))
+            .with_style("popup.button", accent)
+            .with_style("popup.button.selected", highlight)
+            .with_style("list", Style::new().fg(roles.text))
+            .with_style("list.highlight", highlight)
+            .with_style("list.selection", accent.add_modifier(Modifier::BOLD))
+            .with_style("table", Style::new().fg(roles.text))
+            .with_style("table.row.highlight", highlight)
+            .with_style("table.column.highlight", accent)
+            .with_style("table.cell.highlight", Modifier::REVERSED)
+            .with_style("table.selection", accent.add_modifier(Modifier::BOLD))
+            .with_style("table.gridline", muted)
+            .with_style("tabs", muted)
+            .with_style("tabs.highlight", accent.add_modifier(Modifier::BOLD))
+            .with_style("tree", Style::new().fg(roles.text))
+            .with_style("tree.highlight", highlight)
+            .with_style("tree.guide", muted)
+            .with_style("menu", Style::new().fg(roles.text).bg(roles.surface))
+            .with_style("menu.highlight", highlight)
+            .with_style("menu.disabled", muted)
+            .with_style("menu.accelerator", muted)
+            .with_style("gauge", Style::new().fg(roles.text))
+            .with_style("gauge.bar", accent.bg(roles.surface))
+            .with_style("line_gauge", Style::new().fg(roles.text))
+            .with_style("line_gauge.filled", accent)
+            .with_style("line_gauge.unfilled", Style::new().fg(roles.surface))
+            .with_style("chart", Style::new().fg(roles.text))
+            .with_style("chart.axis", muted)
+            .with_style("chart.crosshair", muted)
+            .with_style(
+                "chart.tooltip",
+                Style::new().fg(roles.text).bg(roles.surface),
+            )
+            .with_style("sparkline", accent)
+            .with_style("sparkline.annotation", muted)
+            .with_style("heatmap", Style::new().fg(roles.text))
+            .with_style("heatmap.label", muted)
+            .with_style("piechart", Style::new().fg(roles.text))
+            .with_style("barchart", Style::new().fg(roles.text))
+            .with_style("barchart.bar", accent)
+            .with_style(
+                "barchart.value",
+                Style::new().fg(roles.on_accent).bg(roles.accent),
+            )
+            .with_style("barchart.label", muted)
+            .with_style("text_input", Style::new().fg(roles.text))
+            .with_style("text_input.selection", highlight)
+            .with_style("text_input.placeholder", muted)
+            .with_style("text_area", Style::new().fg(roles.text))
+            .with_style("text_area.selection", highlight)
+            .with_style("text_area.search", Style::new().bg(roles.surface))
+            .with_style("text_area.line_number", muted)
+    }
+}
+
+impl<K: Into<String>, S: Into<Style>> FromIterator<(K, S)> for Theme {
+    fn from_iter<T: IntoIterator<Item = (K, S)>>(iter: T) -> Self {
+        let mut theme = Self::new();
+        theme.extend(iter);
+        theme
+    }
+}
+
+impl<K: Into<String>, S: Into<Style>> Extend<(K, S)> for Theme {
+    fn extend<T: IntoIterator<Item = (K, S)>>(&mut self, iter: T) {
+        for (key, style) in iter {
+            self.set_style(key, style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn theme() -> Theme {
+        Theme::new()
+            .with_style("block", Style::new().white().on_black())
+            .with_style("block.border", Style::new().gray())
+            .with_style("block.border.focused", Style::new().yellow().bold())
+    }
+
+    #[rstest]
+    #[case::exact("block", Style::new().white().on_black())]
+    #[case::child("block.border", Style::new().gray().on_black())]
+    #[case::grandchild("block.border.focused", Style::new().yellow().on_black().bold())]
+    #[case::missing_child("block.title", Style::new().white().on_black())]
+    #[case::missing_grandchild("block.border.unfocused", Style::new().gray().on_black())]
+    #[case::missing("list", Style::new())]
+    #[case::prefix_is_not_parent("blocks", Style::new())]
+    fn style(#[case] key: &str, #[case] expected: Style) {
+        assert_eq!(theme().style(key), expected);
+    }
+
+    #[test]
+    fn get_does_not_inherit() {
+        let theme = theme();
+        assert_eq!(theme.get("block.border"), Some(Style::new().gray()));
+        assert_eq!(theme.get("block.title"), None);
+    }
+
+    #[test]
+    fn patch() {
+        let theme = theme().patch(
+            Theme::new()
+                .with_style("block.border", Style::new().italic())
+                .with_style("list", Style::new().red()),
+        );
+        assert_eq!(
+            theme.get("block.border"),
+            Some(Style::new().gray().italic())
+        );
+        assert_eq!(theme.get("list"), Some(Style::new().red()));
+        assert_eq!(theme.get("block"), Some(Style::new().white().on_black()));
+    }
+
+    #[test]
+    fn from_iter() {
+        let theme = Theme::from_iter([("a", Style::new().red()), ("a.b", Style::new().blue())]);
+        assert_eq!(
+            theme.iter().collect::<alloc::vec::Vec<_>>(),
+            [("a", Style::new().red()), ("a.b", Style::new().blue())]
+        );
+    }
+
+    #[rstest]
+    #[case::tailwind_dark(Theme::tailwind_dark())]
+    #[case::tailwind_light(Theme::tailwind_light())]
+    #[case::material_dark(Theme::material_dark())]
+    #[case::material_light(Theme::material_light())]
+    fn presets(#[case] theme: Theme) {
+        for key in [
+            "block",
+            "list.highlight",
+            "block.border.focused",
+            "chart.axis",
+        ] {
+            assert_ne!(theme.style(key), Style::new(), "{key}");
+        }
+        assert_ne!(theme.style("list"), theme.style("list.highlight"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let theme = theme();
+        let json = serde_json::to_value(&theme).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "block": { "fg": "White", "bg": "Black" },
+                "block.border": { "fg": "Gray" },
+                "block.border.focused": { "fg": "Yellow", "add_modifier": "BOLD" },
+            })
+        );
+        let deserialized: Theme = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized, theme);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::backend::{Backend, ClearType};
-use crate::buffer::{Buffer, Cell};
+use crate::buffer::{Buffer, Cell, WidthPolicy};
 use crate::layout::{Position, Rect};
 use crate::terminal::{Frame, Terminal, Viewport};
 
@@ -73,6 +73,49 @@ impl<B: Backend> Terminal<B> {
         &mut self.buffers[self.current]
     }
 
+    /// Returns the [`WidthPolicy`] used to measure the graphemes rendered to the terminal.
+    ///
+    /// See [`Terminal::set_width_policy`].
+    pub const fn width_policy(&self) -> WidthPolicy {
+        self.buffers[self.current].width_policy()
+    }
+
+    /// Sets the [`WidthPolicy`] used to measure the graphemes rendered to the terminal.
+    ///
+    /// Terminals disagree about the width of emoji and East Asian Ambiguous characters. Using a
+    /// policy that matches the terminal keeps the content that follows such graphemes aligned.
+    /// The policy applies to the buffers of every following frame, and to the buffers passed to
+    /// [`Terminal::insert_before`]. It is also the [active](WidthPolicy::active) policy while a
+    /// frame is drawn, so that widgets measure text with the same widths.
+    ///
+    /// The active policy requires the `std` feature. Without it, the policy only applies to the
+    /// buffers, and widgets measure text with [`WidthPolicy::UNICODE`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # mod ratatui {
+    /// #     pub use ratatui_core::backend;
+    /// #     pub use ratatui_core::buffer;
+    /// #     pub use ratatui_core::terminal::Terminal;
+    /// # }
+    /// use ratatui::Terminal;
+    /// use ratatui::backend::TestBackend;
+    /// use ratatui::buffer::WidthPolicy;
+    ///
+    /// let mut terminal = Terminal::new(TestBackend::new(10, 1))?;
+    /// terminal.set_width_policy(WidthPolicy::WCWIDTH);
+    /// terminal.draw(|frame| frame.render_widget("❤️ ok", frame.area()))?;
+    /// // the heart is drawn in a single cell
+    /// assert_eq!(terminal.backend().buffer()[(2, 0)].symbol(), "o");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_width_policy(&mut self, width_policy: WidthPolicy) {
+        for buffer in &mut self.buffers {
+            buffer.set_width_policy(width_policy);
+        }
+    }
+
     /// Applies the current buffer diff to the backend's active display surface.
     ///
     /// This compares the current buffer with the previous buffer and passes only the changed cells
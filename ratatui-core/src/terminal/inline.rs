@@ -139,7 +139,8 @@ impl<B: Backend> Terminal<B> {
             height,
         };
         let mut buffer = Buffer::empty(area);
-        draw_fn(&mut buffer);
+        buffer.set_width_policy(self.width_policy());
+        self.width_policy().with_active(|| draw_fn(&mut buffer));
         let mut buffer = buffer.content.as_slice();
 
         // Use i32 variables so we don't have worry about overflowed u16s when adding, or about
@@ -237,7 +238,8 @@ impl<B: Backend> Terminal<B> {
             height,
         };
         let mut buffer = Buffer::empty(area);
-        draw_fn(&mut buffer);
+        buffer.set_width_policy(self.width_policy());
+        self.width_policy().with_active(|| draw_fn(&mut buffer));
         let mut buffer = buffer.content.as_slice();
 
         // Handle the special case where the viewport takes up the whole screen.
@@ -336,6 +338,7 @@ impl<B: Backend> Terminal<B> {
             let new = Buffer {
                 area,
                 content: to_draw.to_vec(),
+                width_policy: self.width_policy(),
             };
             self.backend.draw(old.diff_iter(&new))?;
             self.backend.flush()?;
@@ -195,9 +195,13 @@ impl<B: Backend> Terminal<B> {
         // and the terminal (if growing), which may OOB.
         self.autoresize()?;
 
+        // widgets measure text with the width policy of the terminal while rendering the frame
+        let width_policy = self.width_policy();
         let mut frame = self.get_frame();
 
-        render_callback(&mut frame).map_err(Into::into)?;
+        width_policy
+            .with_active(|| render_callback(&mut frame))
+            .map_err(Into::into)?;
 
         let cursor_position = frame.cursor_position;
 
@@ -48,6 +48,9 @@
 //! ]);
 //! ```
 
+mod bidi;
+pub use bidi::TextDirection;
+
 mod grapheme;
 pub use grapheme::StyledGrapheme;
 
@@ -62,3 +65,6 @@ pub use span::{Span, ToSpan};
 
 mod text;
 pub use text::{Text, ToText};
+
+mod truncation;
+pub use truncation::Truncation;
@@ -0,0 +1,335 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use unicode_bidi::{BidiClass, Level, ParagraphBidiInfo, bidi_class};
+
+use crate::layout::Alignment;
+use crate::style::Style;
+use crate::text::{Span, StyledGrapheme};
+
+/// The direction in which a line of text is read.
+///
+/// Lines are rendered using the [Unicode Bidirectional Algorithm] so that text mixing
+/// left-to-right scripts (such as Latin) and right-to-left scripts (such as Arabic or Hebrew) is
+/// displayed in visual order. The base direction of a line is detected from its first strong
+/// character (see [`TextDirection::detect`]) and decides how the left-to-right and right-to-left
+/// runs of the line are ordered.
+///
+/// The alignment of right-to-left lines is mirrored (see [`TextDirection::mirror`]), so a
+/// right-to-left line that is not aligned explicitly starts at the right edge of the area it is
+/// rendered in.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::layout::Alignment;
+/// use ratatui_core::text::TextDirection;
+///
+/// assert_eq!(TextDirection::detect("Hello"), TextDirection::LeftToRight);
+/// assert_eq!(TextDirection::detect("שלום"), TextDirection::RightToLeft);
+/// assert_eq!(
+///     TextDirection::detect("123 שלום"),
+///     TextDirection::RightToLeft
+/// );
+/// assert_eq!(
+///     TextDirection::RightToLeft.mirror(Alignment::Left),
+///     Alignment::Right
+/// );
+/// ```
+///
+/// [Unicode Bidirectional Algorithm]: https://www.unicode.org/reports/tr9/
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TextDirection {
+    /// The text is read from left to right.
+    #[default]
+    LeftToRight,
+    /// The text is read from right to left.
+    RightToLeft,
+}
+
+impl TextDirection {
+    /// Detects the base direction of a paragraph of text.
+    ///
+    /// The direction is that of the first strong left-to-right or right-to-left character in the
+    /// text. Text without strong characters (e.g. digits or punctuation only) is left-to-right.
+    pub fn detect(text: &str) -> Self {
+        Self::from_chars(text.chars())
+    }
+
+    /// Detects the base direction of a paragraph of text from its characters.
+    pub(crate) fn from_chars<I: IntoIterator<Item = char>>(chars: I) -> Self {
+        for c in chars {
+            match bidi_class(c) {
+                BidiClass::L => return Self::LeftToRight,
+                BidiClass::R | BidiClass::AL => return Self::RightToLeft,
+                _ => {}
+            }
+        }
+        Self::LeftToRight
+    }
+
+    /// Mirrors an alignment for text with this direction.
+    ///
+    /// Left and right alignment are swapped for right-to-left text, so that they align to where
+    /// the text starts and ends. Centered text and left-to-right text are left unchanged.
+    #[must_use]
+    pub const fn mirror(self, alignment: Alignment) -> Alignment {
+        match (self, alignment) {
+            (Self::RightToLeft, Alignment::Left) => Alignment::Right,
+            (Self::RightToLeft, Alignment::Right) => Alignment::Left,
+            (_, alignment) => alignment,
+        }
+    }
+
+    /// Reorders the graphemes of a line from logical order to visual order.
+    ///
+    /// `self` is the base direction of the paragraph that the line belongs to. Graphemes in
+    /// right-to-left runs are reversed and mirrored characters such as brackets are replaced by
+    /// their counterpart, as terminals display cells from left to right. The graphemes are
+    /// borrowed when the line does not need to be reordered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::Style;
+    /// use ratatui_core::text::{StyledGrapheme, TextDirection};
+    ///
+    /// let graphemes = ["a", " ", "ב", "ג"].map(|g| StyledGrapheme::new(g, Style::new()));
+    /// let reordered = TextDirection::LeftToRight.reorder(&graphemes);
+    /// let symbols: Vec<_> = reordered.iter().map(|g| g.symbol).collect();
+    /// assert_eq!(symbols, ["a", " ", "ג", "ב"]);
+    /// ```
+    pub fn reorder<'g, 'a>(
+        self,
+        graphemes: &'g [StyledGrapheme<'a>],
+    ) -> Cow<'g, [StyledGrapheme<'a>]> {
+        let Some(runs) = self.visual_runs(graphemes.iter().map(|g| g.symbol)) else {
+            return Cow::Borrowed(graphemes);
+        };
+        let mut reordered = Vec::with_capacity(graphemes.len());
+        for run in runs {
+            let graphemes = &graphemes[run.graphemes];
+            if run.rtl {
+                reordered.extend(graphemes.iter().rev().map(|g| StyledGrapheme {
+                    symbol: mirrored(g.symbol),
+                    style: g.style,
+                }));
+            } else {
+                reordered.extend_from_slice(graphemes);
+            }
+        }
+        Cow::Owned(reordered)
+    }
+
+    /// Reorders the spans of a line from logical order to visual order.
+    ///
+    /// Returns `None` when the spans are displayed in logical order. Otherwise consecutive
+    /// graphemes with the same style are merged into one span.
+    pub(crate) fn reorder_spans(self, spans: &[Span]) -> Option<Vec<Span<'static>>> {
+        if !spans.iter().any(|span| needs_reordering(&span.content)) {
+            return None;
+        }
+        let graphemes = spans
+            .iter()
+            .flat_map(|span| span.styled_graphemes(Style::new()))
+            .collect::<Vec<_>>();
+        let mut reordered: Vec<Span<'static>> = Vec::new();
+        for grapheme in self.reorder(&graphemes).iter() {
+            match reordered.last_mut() {
+                Some(span) if span.style == grapheme.style => {
+                    span.content.to_mut().push_str(grapheme.symbol);
+                }
+                _ => reordered.push(Span::styled(String::from(grapheme.symbol), grapheme.style)),
+            }
+        }
+        Some(reordered)
+    }
+
+    /// Splits a line into runs of graphemes with the same direction, in visual order.
+    ///
+    /// Returns `None` when the line is displayed in logical order.
+    pub(crate) fn visual_runs<'g, I>(self, symbols: I) -> Option<Vec<VisualRun>>
+    where
+        I: Iterator<Item = &'g str> + Clone,
+    {
+        if self == Self::LeftToRight && !symbols.clone().any(needs_reordering) {
+            return None;
+        }
+        let mut text = String::new();
+        let mut starts = Vec::new();
+        for symbol in symbols {
+            starts.push(text.len());
+            text.push_str(symbol);
+        }
+        if text.is_empty() {
+            return None;
+        }
+        let level = match self {
+            Self::LeftToRight => Level::ltr(),
+            Self::RightToLeft => Level::rtl(),
+        };
+        let info = ParagraphBidiInfo::new(&text, Some(level));
+        let (levels, runs) = info.visual_runs(0..text.len());
+        let runs = runs
+            .into_iter()
+            .map(|run| VisualRun {
+                graphemes: starts.partition_point(|&start| start < run.start)
+                    ..starts.partition_point(|&start| start < run.end),
+                rtl: levels[run.start].is_rtl(),
+            })
+            .collect();
+        Some(runs)
+    }
+}
+
+/// A run of graphemes of a line that are displayed in the same direction.
+pub(crate) struct VisualRun {
+    /// The logical indices of the graphemes in the run.
+    pub graphemes: Range<usize>,
+    /// Whether the graphemes of the run are displayed from right to left.
+    pub rtl: bool,
+}
+
+/// Returns true if the text may change the order in which a line is displayed.
+fn needs_reordering(text: &str) -> bool {
+    text.chars().any(|c| {
+        matches!(
+            bidi_class(c),
+            BidiClass::R
+                | BidiClass::AL
+                | BidiClass::RLE
+                | BidiClass::RLO
+                | BidiClass::RLI
+                | BidiClass::FSI
+        )
+    })
+}
+
+/// Returns the mirrored counterpart of paired punctuation displayed from right to left.
+fn mirrored(symbol: &str) -> &str {
+    match symbol {
+        "(" => ")",
+        ")" => "(",
+        "[" => "]",
+        "]" => "[",
+        "{" => "}",
+        "}" => "{",
+        "<" => ">",
+        ">" => "<",
+        "«" => "»",
+        "»" => "«",
+        "‹" => "›",
+        "›" => "‹",
+        _ => symbol,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::Style;
+
+    fn reorder(direction: TextDirection, text: &str) -> String {
+        use unicode_segmentation::UnicodeSegmentation;
+        let graphemes = text
+            .graphemes(true)
+            .map(|g| StyledGrapheme::new(g, Style::new()))
+            .collect::<Vec<_>>();
+        direction
+            .reorder(&graphemes)
+            .iter()
+            .map(|g| g.symbol)
+            .collect()
+    }
+
+    #[rstest]
+    #[case::empty("", TextDirection::LeftToRight)]
+    #[case::latin("Hello", TextDirection::LeftToRight)]
+    #[case::hebrew("שלום", TextDirection::RightToLeft)]
+    #[case::arabic("مرحبا", TextDirection::RightToLeft)]
+    #[case::neutral_first("123 - שלום", TextDirection::RightToLeft)]
+    #[case::latin_first("Hi שלום", TextDirection::LeftToRight)]
+    #[case::neutral_only("123 - 456", TextDirection::LeftToRight)]
+    fn detect(#[case] text: &str, #[case] expected: TextDirection) {
+        assert_eq!(TextDirection::detect(text), expected);
+    }
+
+    #[rstest]
+    #[case::ltr_left(TextDirection::LeftToRight, Alignment::Left, Alignment::Left)]
+    #[case::ltr_right(TextDirection::LeftToRight, Alignment::Right, Alignment::Right)]
+    #[case::rtl_left(TextDirection::RightToLeft, Alignment::Left, Alignment::Right)]
+    #[case::rtl_center(TextDirection::RightToLeft, Alignment::Center, Alignment::Center)]
+    #[case::rtl_right(TextDirection::RightToLeft, Alignment::Right, Alignment::Left)]
+    fn mirror(
+        #[case] direction: TextDirection,
+        #[case] alignment: Alignment,
+        #[case] expected: Alignment,
+    ) {
+        assert_eq!(direction.mirror(alignment), expected);
+    }
+
+    #[rstest]
+    #[case::ltr_only(TextDirection::LeftToRight, "Hello, world!", "Hello, world!")]
+    #[case::rtl_only(TextDirection::RightToLeft, "אבג", "גבא")]
+    #[case::rtl_in_ltr(TextDirection::LeftToRight, "abc אבג def", "abc גבא def")]
+    #[case::ltr_in_rtl(TextDirection::RightToLeft, "אבג abc דהו", "והד abc גבא")]
+    #[case::numbers_in_rtl(TextDirection::RightToLeft, "אבג 123", "123 גבא")]
+    #[case::brackets(TextDirection::RightToLeft, "אב (גד)", "(דג) בא")]
+    #[case::forced_rtl(TextDirection::RightToLeft, "abc", "abc")]
+    #[case::empty(TextDirection::RightToLeft, "", "")]
+    fn reorder_graphemes(
+        #[case] direction: TextDirection,
+        #[case] text: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(reorder(direction, text), expected);
+    }
+
+    #[test]
+    fn reorder_keeps_combining_marks() {
+        // shin with a shin dot and a qamats is a single grapheme
+        assert_eq!(reorder(TextDirection::RightToLeft, "שָׁלוֹם"), "םוֹלשָׁ");
+    }
+
+    #[test]
+    fn reorder_spans() {
+        use crate::style::Stylize;
+
+        let spans = [Span::raw("abc ").red(), Span::raw("אבג").blue()];
+        assert_eq!(
+            TextDirection::LeftToRight.reorder_spans(&spans),
+            Some(vec![Span::raw("abc ").red(), Span::raw("גבא").blue()])
+        );
+        let spans = [Span::raw("abc")];
+        assert_eq!(TextDirection::LeftToRight.reorder_spans(&spans), None);
+    }
+
+    #[test]
+    fn visual_runs() {
+        let symbols = ["a", " ", "א", "ב", " ", "c"];
+        let runs = TextDirection::LeftToRight
+            .visual_runs(symbols.iter().copied())
+            .unwrap();
+        let runs = runs
+            .iter()
+            .map(|run| (run.graphemes.clone(), run.rtl))
+            .collect::<Vec<_>>();
+        assert_eq!(runs, [(0..2, false), (2..4, true), (4..6, false)]);
+    }
+
+    #[test]
+    fn visual_runs_ltr_text_is_not_reordered() {
+        let symbols = ["a", "b", "c"];
+        assert!(
+            TextDirection::LeftToRight
+                .visual_runs(symbols.iter().copied())
+                .is_none()
+        );
+    }
+}
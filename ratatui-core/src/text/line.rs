@@ -9,10 +9,11 @@ use core::fmt;
 use unicode_truncate::UnicodeTruncateStr;
 use unicode_width::UnicodeWidthStr;
 
-use crate::buffer::Buffer;
-use crate::layout::{Alignment, Rect};
+use crate::buffer::{Buffer, CellWidth};
+use crate::layout::{Alignment, Position, Rect};
 use crate::style::{Style, Styled};
-use crate::text::{Span, StyledGrapheme, Text};
+use crate::text::bidi::VisualRun;
+use crate::text::{Span, StyledGrapheme, Text, TextDirection, Truncation};
 use crate::widgets::Widget;
 
 /// A line of text, consisting of one or more [`Span`]s.
@@ -50,6 +51,7 @@ use crate::widgets::Widget;
 /// - [`Line::left_aligned`] sets the alignment of the line to [`Alignment::Left`].
 /// - [`Line::centered`] sets the alignment of the line to [`Alignment::Center`].
 /// - [`Line::right_aligned`] sets the alignment of the line to [`Alignment::Right`].
+/// - [`Line::truncation`] sets how the line is shortened when it does not fit.
 ///
 /// # Iteration Methods
 ///
@@ -64,6 +66,7 @@ use crate::widgets::Widget;
 /// - [`Line::width`] returns the unicode width of the content held by this line.
 /// - [`Line::styled_graphemes`] returns an iterator over the graphemes held by this line.
 /// - [`Line::push_span`] adds a span to the line.
+/// - [`Line::truncated`] returns a copy of the line shortened to a given width.
 ///
 /// # Compatibility Notes
 ///
@@ -130,6 +133,43 @@ use crate::widgets::Widget;
 /// let line = Line::from("Hello world!").right_aligned();
 /// ```
 ///
+/// ## Truncating Lines
+///
+/// The line's [`Truncation`] determines how the line is shortened when it is rendered in an area
+/// that is too narrow for it. By default the line is clipped at the edge of the area. Setting a
+/// truncation policy replaces the hidden part of the line with a marker such as an ellipsis. This
+/// also applies when the line is rendered by a widget, e.g. as a `Block` title or a `List` item.
+///
+/// ```rust
+/// use ratatui_core::text::{Line, Truncation};
+///
+/// let line = Line::from("A very long title").truncation(Truncation::end());
+/// let line = Line::from("/home/user/src/main.rs").truncation(Truncation::middle());
+/// let line = Line::from("Hello world!").truncation(Truncation::End("...".into()));
+/// ```
+///
+/// ## Bidirectional Text
+///
+/// Lines that contain right-to-left scripts such as Arabic or Hebrew are displayed in visual order
+/// using the Unicode Bidirectional Algorithm. The base [`TextDirection`] of the line is detected
+/// from its first strong character (see [`Line::direction`]). The alignment of right-to-left lines
+/// is mirrored, so they are right aligned unless they are aligned explicitly.
+/// [`Line::cursor_position`] and [`Line::cursor_index`] map between grapheme indices and the
+/// columns where the graphemes are displayed.
+///
+/// ```rust
+/// use ratatui_core::buffer::Buffer;
+/// use ratatui_core::layout::Rect;
+/// use ratatui_core::text::{Line, TextDirection};
+/// use ratatui_core::widgets::Widget;
+///
+/// let line = Line::from("שלום world");
+/// assert_eq!(line.direction(), TextDirection::RightToLeft);
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+/// line.render(buf.area, &mut buf);
+/// assert_eq!(buf, Buffer::with_lines(["  world םולש"]));
+/// ```
+///
 /// ## Rendering Lines
 ///
 /// `Line` implements the [`Widget`] trait, which means it can be rendered to a [`Buffer`].
@@ -188,6 +228,9 @@ pub struct Line<'a> {
     /// The alignment of this line of text.
     pub alignment: Option<Alignment>,
 
+    /// How this line of text is shortened when it does not fit, set with [`Line::truncation`].
+    pub(crate) truncation: Option<Truncation<'a>>,
+
     /// The spans that make up this line of text.
     pub spans: Vec<Span<'a>>,
 }
@@ -211,11 +254,15 @@ impl fmt::Debug for Line<'_> {
         }
         self.style.fmt_stylize(f)?;
         match self.alignment {
-            Some(Alignment::Left) => write!(f, ".left_aligned()"),
-            Some(Alignment::Center) => write!(f, ".centered()"),
-            Some(Alignment::Right) => write!(f, ".right_aligned()"),
-            None => Ok(()),
+            Some(Alignment::Left) => write!(f, ".left_aligned()")?,
+            Some(Alignment::Center) => write!(f, ".centered()")?,
+            Some(Alignment::Right) => write!(f, ".right_aligned()")?,
+            None => (),
         }
+        if let Some(truncation) = &self.truncation {
+            write!(f, ".truncation(Truncation::{truncation:?})")?;
+        }
+        Ok(())
     }
 }
 
@@ -426,8 +473,61 @@ impl<'a> Line<'a> {
         self.alignment(Alignment::Right)
     }
 
+    /// Sets how this line of text is shortened when it is rendered in an area that is too narrow.
+    ///
+    /// Setting the truncation of a Line overrides the truncation of its parent Text. See
+    /// [`Truncation`] for the available policies.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::buffer::Buffer;
+    /// use ratatui_core::layout::Rect;
+    /// use ratatui_core::text::{Line, Truncation};
+    /// use ratatui_core::widgets::Widget;
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+    /// Line::from("Hello, world!")
+    ///     .truncation(Truncation::end())
+    ///     .render(buf.area, &mut buf);
+    /// assert_eq!(buf, Buffer::with_lines(["Hello, wo…"]));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn truncation(mut self, truncation: Truncation<'a>) -> Self {
+        self.truncation = Some(truncation);
+        self
+    }
+
+    /// Returns a copy of this line shortened to fit in `max_width` cells.
+    ///
+    /// The line's [`Truncation`] determines how the line is shortened. If it is not set, the end
+    /// of the line is cut. The line is returned unchanged if it already fits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::Stylize;
+    /// use ratatui_core::text::{Line, Truncation};
+    ///
+    /// let line = Line::from(vec!["/home/user/".blue(), "src/main.rs".green()])
+    ///     .truncation(Truncation::middle());
+    /// assert_eq!(line.truncated(14).to_string(), "/home/u…ain.rs");
+    /// ```
+    #[must_use]
+    pub fn truncated(&self, max_width: usize) -> Self {
+        let truncation = self.truncation.clone().unwrap_or_default();
+        Self {
+            style: self.style,
+            alignment: self.alignment,
+            truncation: self.truncation.clone(),
+            spans: truncation.truncate_spans(&self.spans, max_width),
+        }
+    }
+
     /// Returns the width of the underlying string.
     ///
+    /// The width is measured with the [active](crate::buffer::WidthPolicy::active) width policy.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -439,7 +539,127 @@ impl<'a> Line<'a> {
     /// ```
     #[must_use]
     pub fn width(&self) -> usize {
-        UnicodeWidthStr::width(self)
+        self.spans.iter().map(Span::width).sum()
+    }
+
+    /// Returns the base direction of the line.
+    ///
+    /// The direction is detected from the first strong character of the line, see
+    /// [`TextDirection::detect`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::{Line, TextDirection};
+    ///
+    /// assert_eq!(Line::from("Hello").direction(), TextDirection::LeftToRight);
+    /// assert_eq!(Line::from("مرحبا").direction(), TextDirection::RightToLeft);
+    /// ```
+    #[must_use]
+    pub fn direction(&self) -> TextDirection {
+        TextDirection::from_chars(self.spans.iter().flat_map(|span| span.content.chars()))
+    }
+
+    /// Returns the position of the cursor before the grapheme at `index` when the line is
+    /// rendered in `area`.
+    ///
+    /// `index` counts the graphemes returned by [`Line::styled_graphemes`] in logical (reading)
+    /// order. The cursor is displayed on the grapheme at `index`, which is where the next
+    /// character typed would appear. An index past the last grapheme places the cursor after the
+    /// end of the line: to the right of left-to-right lines and to the left of right-to-left
+    /// lines.
+    ///
+    /// The position takes the line's alignment into account, mirrored for right-to-left lines as
+    /// when rendering. The line is expected to fit in the area.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Position, Rect};
+    /// use ratatui_core::text::Line;
+    ///
+    /// let area = Rect::new(0, 0, 10, 1);
+    /// let line = Line::from("abc");
+    /// assert_eq!(line.cursor_position(area, 1), Position::new(1, 0));
+    /// assert_eq!(line.cursor_position(area, 3), Position::new(3, 0));
+    ///
+    /// // right-to-left lines are right aligned and read from right to left
+    /// let line = Line::from("אבג");
+    /// assert_eq!(line.cursor_position(area, 0), Position::new(9, 0));
+    /// assert_eq!(line.cursor_position(area, 3), Position::new(6, 0));
+    /// ```
+    #[must_use]
+    pub fn cursor_position(&self, area: Rect, index: usize) -> Position {
+        let layout = self.grapheme_layout();
+        let start = self.cursor_start(area);
+        let x = cursor_x(&layout, start, index, self.direction()).max(area.x);
+        Position::new(x, area.y)
+    }
+
+    /// Returns the index of the grapheme whose cursor position is closest to column `x` when the
+    /// line is rendered in `area`.
+    ///
+    /// This is the inverse of [`Line::cursor_position`] and can be used to move the cursor to
+    /// where the line was clicked. The result is between `0` and the number of graphemes in the
+    /// line (inclusive).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Rect;
+    /// use ratatui_core::text::Line;
+    ///
+    /// let area = Rect::new(0, 0, 10, 1);
+    /// let line = Line::from("abc אבג");
+    /// assert_eq!(line.cursor_index(area, 1), 1);
+    /// // "abc גבא" is displayed, so the first hebrew letter is in the last column
+    /// assert_eq!(line.cursor_index(area, 6), 4);
+    /// assert_eq!(line.cursor_index(area, 7), 7);
+    /// ```
+    #[must_use]
+    pub fn cursor_index(&self, area: Rect, x: u16) -> usize {
+        let layout = self.grapheme_layout();
+        let start = self.cursor_start(area);
+        let direction = self.direction();
+        (0..=layout.len())
+            .min_by_key(|&index| cursor_x(&layout, start, index, direction).abs_diff(x))
+            .unwrap_or_default()
+    }
+
+    /// Returns the column where the line starts when it is rendered in `area`.
+    fn cursor_start(&self, area: Rect) -> u16 {
+        let alignment = self.direction().mirror(self.alignment.unwrap_or_default());
+        let indent = alignment_indent(Some(alignment), self.width(), usize::from(area.width));
+        area.x
+            .saturating_add(u16::try_from(indent).unwrap_or(u16::MAX))
+    }
+
+    /// Returns where each grapheme of the line is displayed, in logical order.
+    fn grapheme_layout(&self) -> Vec<GraphemeLayout> {
+        let graphemes = self.styled_graphemes(Style::new()).collect::<Vec<_>>();
+        let runs = self
+            .direction()
+            .visual_runs(graphemes.iter().map(|g| g.symbol))
+            .unwrap_or_else(|| {
+                vec![VisualRun {
+                    graphemes: 0..graphemes.len(),
+                    rtl: false,
+                }]
+            });
+        let mut layout = vec![GraphemeLayout::default(); graphemes.len()];
+        let mut column = 0u16;
+        for run in runs {
+            let mut indices = run.graphemes.collect::<Vec<_>>();
+            if run.rtl {
+                indices.reverse();
+            }
+            for index in indices {
+                let width = graphemes[index].symbol.cell_width();
+                layout[index] = GraphemeLayout { column, width };
+                column = column.saturating_add(width);
+            }
+        }
+        layout
     }
 
     /// Returns an iterator over the graphemes held by this line.
@@ -704,18 +924,20 @@ impl Widget for Line<'_> {
 
 impl Widget for &Line<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        self.render_with_alignment(area, buf, None);
+        self.render_with_alignment(area, buf, None, None);
     }
 }
 
 impl Line<'_> {
     /// An internal implementation method for `Widget::render` that allows the parent widget to
-    /// define a default alignment, to be used if `Line::alignment` is `None`.
+    /// define a default alignment and truncation, to be used if `Line::alignment` or
+    /// `Line::truncation` is `None`.
     pub(crate) fn render_with_alignment(
         &self,
         area: Rect,
         buf: &mut Buffer,
         parent_alignment: Option<Alignment>,
+        parent_truncation: Option<&Truncation>,
     ) {
         let area = area.intersection(buf.area);
         if area.is_empty() {
@@ -729,19 +951,30 @@ impl Line<'_> {
 
         buf.set_style(area, self.style);
 
-        let alignment = self.alignment.or(parent_alignment);
+        let direction = self.direction();
+        let alignment = match (self.alignment.or(parent_alignment), direction) {
+            (alignment, TextDirection::LeftToRight) => alignment,
+            (alignment, TextDirection::RightToLeft) => {
+                Some(direction.mirror(alignment.unwrap_or_default()))
+            }
+        };
+        let truncation = self.truncation.as_ref().or(parent_truncation);
 
         let area_width = usize::from(area.width);
         let can_render_complete_line = line_width <= area_width;
-        if can_render_complete_line {
-            let indent_width = match alignment {
-                Some(Alignment::Center) => (area_width.saturating_sub(line_width)) / 2,
-                Some(Alignment::Right) => area_width.saturating_sub(line_width),
-                Some(Alignment::Left) | None => 0,
-            };
-            let indent_width = u16::try_from(indent_width).unwrap_or(u16::MAX);
-            let area = area.indent_x(indent_width);
-            render_spans(&self.spans, area, buf, 0);
+        let truncation =
+            truncation.filter(|t| !can_render_complete_line && **t != Truncation::Clip);
+        // Replace the part of the line that does not fit with the truncation marker. The truncated
+        // line may be narrower than the area when a wide grapheme did not fit.
+        let truncated = truncation.map(|t| t.truncate_spans(&self.spans, area_width));
+        let spans = truncated.as_deref().unwrap_or(&self.spans);
+        // Bidirectional text is truncated in logical order and then displayed in visual order.
+        let reordered = direction.reorder_spans(spans);
+        let spans = reordered.as_deref().map_or(spans, |reordered| reordered);
+
+        if can_render_complete_line || truncation.is_some() {
+            let line_width = spans.iter().map(Span::width).sum();
+            render_aligned_spans(spans, line_width, area, buf, alignment);
         } else {
             // There is not enough space to render the whole line. As the right side is truncated by
             // the area width, only truncate the left.
@@ -750,11 +983,58 @@ impl Line<'_> {
                 Some(Alignment::Right) => line_width.saturating_sub(area_width),
                 Some(Alignment::Left) | None => 0,
             };
-            render_spans(&self.spans, area, buf, skip_width);
+            render_spans(spans, area, buf, skip_width);
         }
     }
 }
 
+/// Where a grapheme of a line is displayed, relative to the start of the line.
+#[derive(Debug, Default, Clone, Copy)]
+struct GraphemeLayout {
+    column: u16,
+    width: u16,
+}
+
+/// Returns the column of the cursor before the grapheme at `index` of a line that starts at
+/// column `start`.
+fn cursor_x(layout: &[GraphemeLayout], start: u16, index: usize, direction: TextDirection) -> u16 {
+    match (layout.get(index), direction) {
+        (Some(grapheme), _) => start.saturating_add(grapheme.column),
+        (None, TextDirection::LeftToRight) => {
+            let width = layout.iter().map(|g| g.width).fold(0, u16::saturating_add);
+            start.saturating_add(width)
+        }
+        (None, TextDirection::RightToLeft) => start.saturating_sub(1),
+    }
+}
+
+/// Returns the indentation of a line that is aligned in an area.
+const fn alignment_indent(
+    alignment: Option<Alignment>,
+    line_width: usize,
+    area_width: usize,
+) -> usize {
+    match alignment {
+        Some(Alignment::Center) => (area_width.saturating_sub(line_width)) / 2,
+        Some(Alignment::Right) => area_width.saturating_sub(line_width),
+        Some(Alignment::Left) | None => 0,
+    }
+}
+
+/// Renders spans that fit in the area, indented according to the alignment.
+fn render_aligned_spans(
+    spans: &[Span],
+    line_width: usize,
+    area: Rect,
+    buf: &mut Buffer,
+    alignment: Option<Alignment>,
+) {
+    let indent_width = alignment_indent(alignment, line_width, usize::from(area.width));
+    let indent_width = u16::try_from(indent_width).unwrap_or(u16::MAX);
+    let area = area.indent_x(indent_width);
+    render_spans(spans, area, buf, 0);
+}
+
 /// Renders all the spans of the line that should be visible.
 fn render_spans(spans: &[Span], mut area: Rect, buf: &mut Buffer, span_skip_width: usize) {
     for (span, span_width, offset) in spans_after_width(spans, span_skip_width) {
@@ -966,6 +1246,16 @@ mod tests {
         assert_eq!(0, empty_line.width());
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn width_with_active_width_policy() {
+        use crate::buffer::WidthPolicy;
+
+        let line = Line::from("±1");
+        assert_eq!(line.width(), 2);
+        assert_eq!(WidthPolicy::CJK.with_active(|| line.width()), 3);
+    }
+
     #[test]
     fn patch_style() {
         let raw_line = Line::styled("foobar", Color::Yellow);
@@ -1109,6 +1399,7 @@ mod tests {
                 spans: vec![Span::raw("Red"), Span::raw("blue").blue()],
                 style: Style::new().red(),
                 alignment: None,
+                truncation: None,
             },
         );
     }
@@ -1121,6 +1412,7 @@ mod tests {
                 lines: vec![Line::raw("Red").red(), Line::raw("Blue").blue()],
                 style: Style::default(),
                 alignment: None,
+                truncation: None,
             }
         );
     }
@@ -1135,6 +1427,7 @@ mod tests {
                 spans: vec![Span::raw("Red"), Span::raw("Blue").blue()],
                 style: Style::new().red(),
                 alignment: None,
+                truncation: None,
             },
         );
     }
@@ -1248,6 +1541,67 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::empty(Line::default(), TextDirection::LeftToRight)]
+    #[case::ltr(Line::from("Hello"), TextDirection::LeftToRight)]
+    #[case::rtl(Line::from("שלום"), TextDirection::RightToLeft)]
+    #[case::rtl_after_neutral(Line::from(vec![Span::raw("1. "), Span::raw("שלום")]), TextDirection::RightToLeft)]
+    fn direction(#[case] line: Line, #[case] expected: TextDirection) {
+        assert_eq!(line.direction(), expected);
+    }
+
+    #[rstest]
+    #[case::ltr_start(Line::from("abc"), 0, 2)]
+    #[case::ltr_end(Line::from("abc"), 3, 5)]
+    #[case::past_end(Line::from("abc"), 10, 5)]
+    #[case::centered(Line::from("abc").centered(), 0, 5)]
+    #[case::rtl_start(Line::from("אבג"), 0, 11)]
+    #[case::rtl_last(Line::from("אבג"), 2, 9)]
+    #[case::rtl_end(Line::from("אבג"), 3, 8)]
+    #[case::rtl_in_ltr_start(Line::from("ab אב"), 3, 6)]
+    #[case::rtl_in_ltr_last(Line::from("ab אב"), 4, 5)]
+    #[case::rtl_in_ltr_end(Line::from("ab אב"), 5, 7)]
+    #[case::wide(Line::from("コンピ"), 2, 6)]
+    #[case::empty(Line::default(), 0, 2)]
+    fn cursor_position(#[case] line: Line, #[case] index: usize, #[case] expected: u16) {
+        let area = Rect::new(2, 1, 10, 1);
+        assert_eq!(
+            line.cursor_position(area, index),
+            Position::new(expected, 1)
+        );
+    }
+
+    #[test]
+    fn cursor_position_rtl_end_stays_in_area() {
+        let area = Rect::new(2, 1, 3, 1);
+        assert_eq!(
+            Line::from("אבג").cursor_position(area, 3),
+            Position::new(2, 1)
+        );
+    }
+
+    #[rstest]
+    #[case::ltr(Line::from("abc de"))]
+    #[case::rtl(Line::from("אבג דה"))]
+    #[case::ltr_with_rtl(Line::from("ab אבג de"))]
+    #[case::rtl_with_ltr(Line::from("אב abc דה"))]
+    fn cursor_index_is_inverse_of_cursor_position(#[case] line: Line) {
+        let area = Rect::new(2, 1, 12, 1);
+        for index in 0..=line.styled_graphemes(Style::new()).count() {
+            let position = line.cursor_position(area, index);
+            assert_eq!(line.cursor_index(area, position.x), index, "index {index}");
+        }
+    }
+
+    #[test]
+    fn cursor_index_outside_line() {
+        let area = Rect::new(0, 0, 10, 1);
+        let line = Line::from("abc");
+        assert_eq!(line.cursor_index(area, 8), 3);
+        let line = Line::from("אבג");
+        assert_eq!(line.cursor_index(area, 0), 3);
+    }
+
     mod widget {
         use unicode_segmentation::UnicodeSegmentation;
         use unicode_width::UnicodeWidthStr;
@@ -1315,6 +1669,71 @@ mod tests {
             assert_eq!(buf, Buffer::with_lines(["Hello     "]));
         }
 
+        #[rstest]
+        #[case::end(Truncation::end(), None, "Hello w…")]
+        #[case::start(Truncation::start(), None, "…o world")]
+        #[case::middle(Truncation::middle(), None, "Hell…rld")]
+        #[case::custom_marker(Truncation::End("..".into()), None, "Hello ..")]
+        #[case::right_aligned(Truncation::end(), Some(Alignment::Right), "Hello w…")]
+        #[case::clip_right_aligned(Truncation::Clip, Some(Alignment::Right), "lo world")]
+        fn render_truncation(
+            #[case] truncation: Truncation<'static>,
+            #[case] alignment: Option<Alignment>,
+            #[case] expected: &str,
+        ) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+            let line = Line {
+                alignment,
+                ..Line::from("Hello world")
+            };
+            line.truncation(truncation).render(buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines([expected]));
+        }
+
+        #[test]
+        fn render_truncation_wide_graphemes_is_aligned() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+            Line::from("コンピュータ")
+                .truncation(Truncation::end())
+                .right_aligned()
+                .render(buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines([" コン…"]));
+        }
+
+        #[test]
+        fn render_truncation_fits() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+            Line::from("Hello world")
+                .truncation(Truncation::end())
+                .centered()
+                .render(buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines(["Hello world "]));
+        }
+
+        #[rstest]
+        #[case::ltr_with_rtl(Line::from("ab אבג"), 8, "ab גבא  ")]
+        #[case::rtl_is_right_aligned(Line::from("אבג"), 5, "  גבא")]
+        #[case::rtl_right_is_mirrored(Line::from("אבג").right_aligned(), 5, "גבא  ")]
+        #[case::rtl_centered(Line::from("אבג").centered(), 5, " גבא ")]
+        #[case::rtl_with_ltr(Line::from("אב cd"), 7, "  cd בא")]
+        #[case::rtl_truncated(Line::from("אבגדה").truncation(Truncation::end()), 4, "…גבא")]
+        #[case::rtl_clipped(Line::from("אבגדה"), 3, "גבא")]
+        fn render_bidi(#[case] line: Line, #[case] width: u16, #[case] expected: &str) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+            line.render(buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines([expected]));
+        }
+
+        #[test]
+        fn render_bidi_keeps_styles() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+            Line::from(vec!["אב".red(), "גד".blue()]).render(buf.area, &mut buf);
+            let mut expected = Buffer::with_lines(["דגבא"]);
+            expected.set_style(Rect::new(0, 0, 2, 1), Color::Blue);
+            expected.set_style(Rect::new(2, 0, 2, 1), Color::Red);
+            assert_eq!(buf, expected);
+        }
+
         #[test]
         fn render_centered() {
             let line = hello_world().alignment(Alignment::Center);
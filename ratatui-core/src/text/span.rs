@@ -1,14 +1,14 @@
 use alloc::borrow::Cow;
-use alloc::string::ToString;
-use core::fmt;
+use alloc::string::{String, ToString};
+use core::{fmt, slice};
 
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
-use crate::buffer::{Buffer, CellWidth};
+use crate::buffer::{Buffer, WidthPolicy};
 use crate::layout::Rect;
 use crate::style::{Style, Styled};
-use crate::text::{Line, StyledGrapheme};
+use crate::text::{Line, StyledGrapheme, Truncation};
 use crate::widgets::Widget;
 
 /// Represents a part of a line that is contiguous and where all characters share the same style.
@@ -268,8 +268,44 @@ impl<'a> Span<'a> {
     }
 
     /// Returns the unicode width of the content held by this span.
+    ///
+    /// The width is measured with the [active](WidthPolicy::active) [`WidthPolicy`], so that it
+    /// matches the width of the span once rendered.
     pub fn width(&self) -> usize {
-        UnicodeWidthStr::width(self)
+        WidthPolicy::active().str_width(&self.content)
+    }
+
+    /// Returns a copy of this span shortened to fit in `max_width` cells.
+    ///
+    /// The `truncation` policy determines which part of the content is replaced by the marker. The
+    /// span is returned unchanged if it already fits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::text::{Span, Truncation};
+    ///
+    /// let span = Span::raw("/home/user/src/main.rs");
+    /// assert_eq!(
+    ///     span.truncated(10, &Truncation::end()),
+    ///     Span::raw("/home/use…")
+    /// );
+    /// assert_eq!(
+    ///     span.truncated(10, &Truncation::middle()),
+    ///     Span::raw("/home…n.rs")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn truncated(&self, max_width: usize, truncation: &Truncation) -> Self {
+        if self.width() <= max_width {
+            return self.clone();
+        }
+        let content = truncation
+            .truncate_spans(slice::from_ref(self), max_width)
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect::<String>();
+        Self::styled(content, self.style)
     }
 
     /// Returns an iterator over the graphemes held by this span.
@@ -428,8 +464,9 @@ impl Widget for &Span<'_> {
             return;
         }
         let Rect { mut x, y, .. } = area;
+        let width_policy = buf.width_policy();
         for (i, grapheme) in self.styled_graphemes(Style::default()).enumerate() {
-            let symbol_width = grapheme.symbol.cell_width();
+            let symbol_width = width_policy.width(grapheme.symbol);
             let next_x = x.saturating_add(symbol_width);
             if next_x > area.right() {
                 break;
@@ -439,7 +476,8 @@ impl Widget for &Span<'_> {
                 // the first grapheme is always set on the cell
                 buf[(x, y)]
                     .set_symbol(grapheme.symbol)
-                    .set_style(grapheme.style);
+                    .set_style(grapheme.style)
+                    .set_width(symbol_width);
             } else if x == area.x {
                 // there is one or more zero-width graphemes in the first cell, so the first cell
                 // must be appended to.
@@ -455,7 +493,8 @@ impl Widget for &Span<'_> {
                 // just a normal grapheme (not first, not zero-width, not overflowing the area)
                 buf[(x, y)]
                     .set_symbol(grapheme.symbol)
-                    .set_style(grapheme.style);
+                    .set_style(grapheme.style)
+                    .set_width(symbol_width);
             }
 
             // multi-width graphemes must clear the cells of characters that are hidden by the
@@ -631,6 +670,28 @@ mod tests {
         assert_eq!(Span::raw("test\ncontent").width(), 12);
     }
 
+    #[test]
+    fn truncated() {
+        let span = Span::raw("test content").green();
+        assert_eq!(span.truncated(12, &Truncation::end()), span);
+        assert_eq!(
+            span.truncated(8, &Truncation::end()),
+            Span::raw("test co…").green()
+        );
+        assert_eq!(
+            span.truncated(8, &Truncation::start()),
+            Span::raw("…content").green()
+        );
+        assert_eq!(
+            span.truncated(8, &Truncation::middle()),
+            Span::raw("test…ent").green()
+        );
+        assert_eq!(
+            span.truncated(8, &Truncation::Clip),
+            Span::raw("test con").green()
+        );
+    }
+
     #[test]
     fn stylize() {
         let span = Span::raw("test content").green();
@@ -10,7 +10,7 @@ use unicode_width::UnicodeWidthStr;
 use crate::buffer::Buffer;
 use crate::layout::{Alignment, Rect};
 use crate::style::{Style, Styled};
-use crate::text::{Line, Span};
+use crate::text::{Line, Span, Truncation};
 use crate::widgets::Widget;
 
 /// A string split over one or more lines.
@@ -46,6 +46,7 @@ use crate::widgets::Widget;
 /// - [`Text::left_aligned`] sets the alignment to [`Alignment::Left`].
 /// - [`Text::centered`] sets the alignment to [`Alignment::Center`].
 /// - [`Text::right_aligned`] sets the alignment to [`Alignment::Right`].
+/// - [`Text::truncation`] sets how lines are shortened when they do not fit.
 ///
 /// # Iteration Methods
 ///
@@ -61,6 +62,7 @@ use crate::widgets::Widget;
 /// - [`Text::reset_style`] resets the style of the `Text`.
 /// - [`Text::push_line`] adds a line to the text.
 /// - [`Text::push_span`] adds a span to the last line of the text.
+/// - [`Text::truncated`] returns a copy of the text with each line shortened to a given width.
 ///
 /// # Examples
 ///
@@ -199,6 +201,9 @@ pub struct Text<'a> {
     pub alignment: Option<Alignment>,
     /// The style of this text.
     pub style: Style,
+    /// How the lines of this text are shortened when they do not fit, set with
+    /// [`Text::truncation`].
+    pub(crate) truncation: Option<Truncation<'a>>,
     /// The lines that make up this piece of text.
     pub lines: Vec<Line<'a>>,
 }
@@ -221,6 +226,9 @@ impl fmt::Debug for Text<'_> {
             Some(Alignment::Right) => f.write_str(".right_aligned()")?,
             _ => (),
         }
+        if let Some(truncation) = &self.truncation {
+            write!(f, ".truncation(Truncation::{truncation:?})")?;
+        }
         Ok(())
     }
 }
@@ -287,7 +295,7 @@ impl<'a> Text<'a> {
     /// assert_eq!(15, text.width());
     /// ```
     pub fn width(&self) -> usize {
-        UnicodeWidthStr::width(self)
+        self.lines.iter().map(Line::width).max().unwrap_or_default()
     }
 
     /// Returns the height.
@@ -509,6 +517,74 @@ impl<'a> Text<'a> {
         self.alignment(Alignment::Right)
     }
 
+    /// Sets how the lines of this text are shortened when they are rendered in an area that is too
+    /// narrow.
+    ///
+    /// Truncation can be set individually on each line to override this text's truncation. See
+    /// [`Truncation`] for the available policies.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::buffer::Buffer;
+    /// use ratatui_core::layout::Rect;
+    /// use ratatui_core::text::{Line, Text, Truncation};
+    /// use ratatui_core::widgets::Widget;
+    ///
+    /// let text = Text::from(vec![
+    ///     Line::from("src/widgets/table.rs"),
+    ///     Line::from("src/widgets/list.rs").truncation(Truncation::end()),
+    /// ])
+    /// .truncation(Truncation::start());
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 10, 2));
+    /// text.render(buf.area, &mut buf);
+    /// assert_eq!(buf, Buffer::with_lines(["…/table.rs", "src/widge…"]));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn truncation(mut self, truncation: Truncation<'a>) -> Self {
+        self.truncation = Some(truncation);
+        self
+    }
+
+    /// Returns a copy of this text with each line shortened to fit in `max_width` cells.
+    ///
+    /// Each line is shortened according to its own [`Truncation`], falling back to the truncation
+    /// of the text. If neither is set, the end of the line is cut. Lines that already fit are left
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::{Text, Truncation};
+    ///
+    /// let text = Text::from("first line\nsecond line").truncation(Truncation::end());
+    /// assert_eq!(text.truncated(8).to_string(), "first l…\nsecond …");
+    /// ```
+    #[must_use]
+    pub fn truncated(&self, max_width: usize) -> Self {
+        let lines = self
+            .lines
+            .iter()
+            .map(|line| {
+                let truncation = line.truncation.as_ref().or(self.truncation.as_ref());
+                let truncation = truncation.cloned().unwrap_or_default();
+                Line {
+                    style: line.style,
+                    alignment: line.alignment,
+                    truncation: line.truncation.clone(),
+                    spans: truncation.truncate_spans(&line.spans, max_width),
+                }
+            })
+            .collect();
+        Self {
+            alignment: self.alignment,
+            style: self.style,
+            truncation: self.truncation.clone(),
+            lines,
+        }
+    }
+
     /// Returns an iterator over the lines of the text.
     pub fn iter(&self) -> core::slice::Iter<'_, Line<'a>> {
         self.lines.iter()
@@ -770,7 +846,7 @@ impl Widget for &Text<'_> {
         let area = area.intersection(buf.area);
         buf.set_style(area, self.style);
         for (line, line_area) in self.iter().zip(area.rows()) {
-            line.render_with_alignment(line_area, buf, self.alignment);
+            line.render_with_alignment(line_area, buf, self.alignment, self.truncation.as_ref());
         }
     }
 }
@@ -1015,6 +1091,7 @@ mod tests {
                 lines: vec![Line::raw("Red"), Line::raw("Blue").blue()],
                 style: Style::new().red(),
                 alignment: None,
+                truncation: None,
             }
         );
     }
@@ -1027,6 +1104,7 @@ mod tests {
                 lines: vec![Line::raw("Red"), Line::raw("Blue")],
                 style: Style::new().red(),
                 alignment: None,
+                truncation: None,
             }
         );
     }
@@ -1041,6 +1119,7 @@ mod tests {
                 lines: vec![Line::raw("Red"), Line::raw("Blue")],
                 style: Style::new().red(),
                 alignment: None,
+                truncation: None,
             }
         );
     }
@@ -1055,6 +1134,7 @@ mod tests {
                 lines: vec![Line::raw("Red"), Line::raw("Blue").blue()],
                 style: Style::new().red(),
                 alignment: None,
+                truncation: None,
             }
         );
     }
@@ -1305,6 +1385,37 @@ mod tests {
             assert_eq!(buf, Buffer::with_lines(["234567"]));
         }
 
+        #[test]
+        fn render_with_truncation() {
+            let text = Text::from(vec![
+                Line::from("Hello world"),
+                Line::from("Hello world").truncation(Truncation::start()),
+                Line::from("Hi"),
+            ])
+            .truncation(Truncation::end());
+
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 3));
+            text.render(buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines(["Hello…", "…world", "Hi    "]));
+        }
+
+        #[test]
+        fn truncated() {
+            let text = Text::from(vec![
+                Line::from("Hello world"),
+                Line::from("Hello world").truncation(Truncation::start()),
+            ])
+            .truncation(Truncation::end());
+            assert_eq!(
+                text.truncated(6),
+                Text::from(vec![
+                    Line::from_iter(["Hello", "…"]),
+                    Line::from_iter(["…", "world"]).truncation(Truncation::start()),
+                ])
+                .truncation(Truncation::end())
+            );
+        }
+
         #[test]
         fn render_one_line_right() {
             let text = Text::from(vec![
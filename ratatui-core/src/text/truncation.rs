@@ -0,0 +1,288 @@
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::buffer::WidthPolicy;
+use crate::text::Span;
+
+/// Describes how text is shortened when it is wider than the space available to it.
+///
+/// By default, text that does not fit is clipped at the edge of the area it is rendered in
+/// ([`Truncation::Clip`]). The other variants replace the hidden part of the text with a marker
+/// (usually an ellipsis) so that it is visible that something was left out:
+///
+/// - [`Truncation::End`] keeps the start of the text: `"Hello, wo…"`
+/// - [`Truncation::Start`] keeps the end of the text: `"…o, world!"`
+/// - [`Truncation::Middle`] keeps both ends of the text, which works well for paths:
+///   `"/home/…/main.rs"`
+///
+/// Widths are measured in terminal cells with the [active](WidthPolicy::active) width policy, so
+/// wide graphemes (such as CJK characters and emoji) are never split. This means that truncated
+/// text may be narrower than the available width when a wide grapheme does not fit.
+///
+/// A truncation policy can be set on a [`Line`] with [`Line::truncation`] or on a [`Text`] with
+/// [`Text::truncation`], in which case it is applied whenever the text is rendered, including when
+/// it is rendered by a widget. [`Span::truncated`], [`Line::truncated`] and [`Text::truncated`]
+/// apply a policy directly.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::text::{Line, Truncation};
+///
+/// let line = Line::from("Hello, world!");
+/// let truncated = line.clone().truncation(Truncation::end()).truncated(10);
+/// assert_eq!(truncated.to_string(), "Hello, wo…");
+/// let truncated = line.clone().truncation(Truncation::start()).truncated(10);
+/// assert_eq!(truncated.to_string(), "…o, world!");
+/// let truncated = line.truncation(Truncation::End("...".into())).truncated(10);
+/// assert_eq!(truncated.to_string(), "Hello, ...");
+/// ```
+///
+/// [`Line`]: crate::text::Line
+/// [`Line::truncation`]: crate::text::Line::truncation
+/// [`Line::truncated`]: crate::text::Line::truncated
+/// [`Text`]: crate::text::Text
+/// [`Text::truncation`]: crate::text::Text::truncation
+/// [`Text::truncated`]: crate::text::Text::truncated
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub enum Truncation<'a> {
+    /// Cut the text at the edge of the area without a marker.
+    ///
+    /// When rendering, which side is cut depends on the alignment of the text.
+    #[default]
+    Clip,
+    /// Keep the start of the text and put the marker at the end.
+    End(Cow<'a, str>),
+    /// Keep the end of the text and put the marker at the start.
+    Start(Cow<'a, str>),
+    /// Keep the start and the end of the text and put the marker in the middle.
+    Middle(Cow<'a, str>),
+}
+
+impl<'a> Truncation<'a> {
+    /// The default marker used to show that text was truncated (`…`).
+    pub const ELLIPSIS: &'static str = "…";
+
+    /// Keep the start of the text and put an ellipsis at the end.
+    pub const fn end() -> Self {
+        Self::End(Cow::Borrowed(Self::ELLIPSIS))
+    }
+
+    /// Keep the end of the text and put an ellipsis at the start.
+    pub const fn start() -> Self {
+        Self::Start(Cow::Borrowed(Self::ELLIPSIS))
+    }
+
+    /// Keep the start and the end of the text and put an ellipsis in the middle.
+    pub const fn middle() -> Self {
+        Self::Middle(Cow::Borrowed(Self::ELLIPSIS))
+    }
+
+    /// Returns the marker that replaces the truncated text, or `None` for [`Truncation::Clip`].
+    pub fn marker(&self) -> Option<&str> {
+        match self {
+            Self::Clip => None,
+            Self::End(marker) | Self::Start(marker) | Self::Middle(marker) => Some(marker),
+        }
+    }
+
+    /// Shortens `spans` so that they fit in `max_width` cells.
+    ///
+    /// The spans are returned unchanged if they already fit. If the marker itself does not fit,
+    /// the spans are clipped at the end instead.
+    pub(crate) fn truncate_spans(&self, spans: &[Span<'a>], max_width: usize) -> Vec<Span<'a>> {
+        let graphemes = spans
+            .iter()
+            .enumerate()
+            .flat_map(|(index, span)| {
+                span.content
+                    .grapheme_indices(true)
+                    .map(move |(offset, grapheme)| SpanGrapheme {
+                        span: index,
+                        range: offset..offset + grapheme.len(),
+                        width: grapheme_width(grapheme),
+                    })
+            })
+            .collect::<Vec<_>>();
+        let total_width = graphemes.iter().map(|g| g.width).sum::<usize>();
+        if total_width <= max_width {
+            return spans.to_vec();
+        }
+
+        let marker = match self {
+            Self::Clip => None,
+            Self::End(marker) | Self::Start(marker) | Self::Middle(marker) => Some(marker),
+        };
+        let Some(marker) = marker.filter(|marker| grapheme_width(marker) <= max_width) else {
+            let head = take_width(graphemes.iter(), max_width);
+            return slice_spans(spans, &graphemes[..head]);
+        };
+
+        let available_width = max_width - grapheme_width(marker);
+        let (head, tail) = match self {
+            Self::Clip | Self::End(_) => (take_width(graphemes.iter(), available_width), 0),
+            Self::Start(_) => (0, take_width(graphemes.iter().rev(), available_width)),
+            Self::Middle(_) => {
+                let head = take_width(graphemes.iter(), available_width.div_ceil(2));
+                let head_width = graphemes[..head].iter().map(|g| g.width).sum::<usize>();
+                let tail = take_width(graphemes.iter().rev(), available_width - head_width);
+                (head, tail)
+            }
+        };
+
+        // the marker takes the style of the span where the text was cut
+        let cut = match self {
+            Self::Start(_) => graphemes.len() - tail - 1,
+            _ => head,
+        };
+        let marker_style = spans[graphemes[cut].span].style;
+
+        let mut truncated = slice_spans(spans, &graphemes[..head]);
+        truncated.push(Span::styled(marker.clone(), marker_style));
+        truncated.extend(slice_spans(spans, &graphemes[graphemes.len() - tail..]));
+        truncated
+    }
+}
+
+/// A grapheme of a span, identified by the index of the span and the byte range in its content.
+struct SpanGrapheme {
+    span: usize,
+    range: Range<usize>,
+    width: usize,
+}
+
+/// Returns the width of a grapheme with the active width policy, ignoring control characters
+/// which are not rendered.
+fn grapheme_width(grapheme: &str) -> usize {
+    if grapheme.contains(char::is_control) {
+        0
+    } else {
+        usize::from(WidthPolicy::active().width(grapheme))
+    }
+}
+
+/// Returns how many graphemes from the iterator fit in `max_width`.
+fn take_width<'g>(graphemes: impl Iterator<Item = &'g SpanGrapheme>, max_width: usize) -> usize {
+    let mut width = 0;
+    graphemes
+        .take_while(|grapheme| {
+            width += grapheme.width;
+            width <= max_width
+        })
+        .count()
+}
+
+/// Builds spans from a contiguous run of graphemes, keeping the style of the original spans.
+fn slice_spans<'a>(spans: &[Span<'a>], graphemes: &[SpanGrapheme]) -> Vec<Span<'a>> {
+    graphemes
+        .chunk_by(|a, b| a.span == b.span)
+        .map(|run| {
+            let span = &spans[run[0].span];
+            let range = run[0].range.start..run[run.len() - 1].range.end;
+            Span::styled(slice_cow(&span.content, range), span.style)
+        })
+        .collect()
+}
+
+/// Slices a `Cow<str>`, borrowing from the original when possible.
+#[expect(clippy::string_slice)] // Is safe as the range comes from UnicodeSegmentation
+fn slice_cow<'a>(content: &Cow<'a, str>, range: Range<usize>) -> Cow<'a, str> {
+    match content {
+        Cow::Borrowed(content) => Cow::Borrowed(&content[range]),
+        Cow::Owned(content) => Cow::Owned(content[range].to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::Stylize;
+
+    #[rstest]
+    #[case::fits(Truncation::end(), 13, vec!["Hello, world!"])]
+    #[case::end(Truncation::end(), 10, vec!["Hello, wo", "…"])]
+    #[case::start(Truncation::start(), 10, vec!["…", "o, world!"])]
+    #[case::middle(Truncation::middle(), 10, vec!["Hello", "…", "rld!"])]
+    #[case::custom_marker(Truncation::End("...".into()), 10, vec!["Hello, ", "..."])]
+    #[case::only_marker(Truncation::end(), 1, vec!["…"])]
+    #[case::marker_too_wide(Truncation::End("...".into()), 2, vec!["He"])]
+    #[case::clip(Truncation::Clip, 5, vec!["Hello"])]
+    #[case::zero_width(Truncation::end(), 0, vec![])]
+    fn truncate_single_span(
+        #[case] truncation: Truncation,
+        #[case] max_width: usize,
+        #[case] expected: Vec<&str>,
+    ) {
+        let spans = [Span::raw("Hello, world!")];
+        let truncated = truncation.truncate_spans(&spans, max_width);
+        let expected = expected.into_iter().map(Span::raw).collect::<Vec<_>>();
+        assert_eq!(truncated, expected);
+    }
+
+    #[test]
+    fn truncate_keeps_span_styles() {
+        let spans = [
+            Span::raw("/home/user/").blue(),
+            Span::raw("src/main.rs").red(),
+        ];
+        let truncated = Truncation::middle().truncate_spans(&spans, 13);
+        assert_eq!(
+            truncated,
+            [
+                Span::raw("/home/").blue(),
+                Span::raw("…").blue(),
+                Span::raw("ain.rs").red(),
+            ]
+        );
+    }
+
+    #[test]
+    fn truncate_start_marker_style() {
+        let spans = [Span::raw("abc").blue(), Span::raw("def").red()];
+        let truncated = Truncation::start().truncate_spans(&spans, 3);
+        assert_eq!(truncated, [Span::raw("…").red(), Span::raw("ef").red()]);
+    }
+
+    #[test]
+    fn truncate_wide_graphemes() {
+        let spans = [Span::raw("コンピュータ")];
+        let truncated = Truncation::end().truncate_spans(&spans, 6);
+        assert_eq!(truncated, [Span::raw("コン"), Span::raw("…")]);
+        let truncated = Truncation::start().truncate_spans(&spans, 6);
+        assert_eq!(truncated, [Span::raw("…"), Span::raw("ータ")]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn truncate_with_active_width_policy() {
+        let spans = [Span::raw("±±±±")];
+        let truncated = Truncation::end().truncate_spans(&spans, 5);
+        assert_eq!(truncated, [Span::raw("±±±±")]);
+        let truncated =
+            WidthPolicy::CJK.with_active(|| Truncation::end().truncate_spans(&spans, 5));
+        assert_eq!(truncated, [Span::raw("±"), Span::raw("…")]);
+    }
+
+    #[test]
+    fn truncate_owned_content() {
+        let spans = [Span::raw(String::from("Hello, world!"))];
+        let truncated = Truncation::end().truncate_spans(&spans, 6);
+        assert_eq!(truncated, [Span::raw("Hello"), Span::raw("…")]);
+    }
+
+    #[test]
+    fn marker() {
+        assert_eq!(Truncation::Clip.marker(), None);
+        assert_eq!(Truncation::end().marker(), Some("…"));
+        assert_eq!(Truncation::Middle(">>".into()).marker(), Some(">>"));
+    }
+}
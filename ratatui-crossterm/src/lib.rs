@@ -74,6 +74,10 @@
 use std::io::{self, Write};
 
 use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{
+    KeyCode as CrosstermKeyCode, KeyEvent as CrosstermKeyEvent,
+    KeyModifiers as CrosstermKeyModifiers,
+};
 #[cfg(feature = "underline-color")]
 use crossterm::style::SetUnderlineColor;
 use crossterm::style::{
@@ -98,6 +102,7 @@ cfg_if::cfg_if! {
 }
 use ratatui_core::backend::{Backend, ClearType, WindowSize};
 use ratatui_core::buffer::Cell;
+use ratatui_core::input::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui_core::layout::{Position, Size};
 use ratatui_core::style::{Color, Modifier, Style};
 
@@ -695,6 +700,64 @@ impl FromCrossterm<ContentStyle> for Style {
     }
 }
 
+impl FromCrossterm<CrosstermKeyCode> for KeyCode {
+    fn from_crossterm(value: CrosstermKeyCode) -> Self {
+        match value {
+            CrosstermKeyCode::Char(c) => Self::Char(c),
+            CrosstermKeyCode::Backspace => Self::Backspace,
+            CrosstermKeyCode::Delete => Self::Delete,
+            CrosstermKeyCode::Insert => Self::Insert,
+            CrosstermKeyCode::Enter => Self::Enter,
+            CrosstermKeyCode::Esc => Self::Esc,
+            CrosstermKeyCode::Tab => Self::Tab,
+            CrosstermKeyCode::BackTab => Self::BackTab,
+            CrosstermKeyCode::Left => Self::Left,
+            CrosstermKeyCode::Right => Self::Right,
+            CrosstermKeyCode::Up => Self::Up,
+            CrosstermKeyCode::Down => Self::Down,
+            CrosstermKeyCode::Home => Self::Home,
+            CrosstermKeyCode::End => Self::End,
+            CrosstermKeyCode::PageUp => Self::PageUp,
+            CrosstermKeyCode::PageDown => Self::PageDown,
+            CrosstermKeyCode::F(n) => Self::F(n),
+            _ => Self::Unidentified,
+        }
+    }
+}
+
+impl FromCrossterm<CrosstermKeyModifiers> for KeyModifiers {
+    fn from_crossterm(value: CrosstermKeyModifiers) -> Self {
+        let mut res = Self::empty();
+        if value.contains(CrosstermKeyModifiers::SHIFT) {
+            res |= Self::SHIFT;
+        }
+        if value.contains(CrosstermKeyModifiers::CONTROL) {
+            res |= Self::CONTROL;
+        }
+        if value.contains(CrosstermKeyModifiers::ALT) {
+            res |= Self::ALT;
+        }
+        if value.contains(CrosstermKeyModifiers::SUPER) {
+            res |= Self::SUPER;
+        }
+        res
+    }
+}
+
+/// Converts a crossterm key event to a Ratatui key event.
+///
+/// The kind of the event is not converted, so applications that enable the keyboard enhancement
+/// flags should only convert [`KeyEventKind::Press`](crossterm::event::KeyEventKind::Press)
+/// events.
+impl FromCrossterm<CrosstermKeyEvent> for KeyEvent {
+    fn from_crossterm(value: CrosstermKeyEvent) -> Self {
+        Self::new(
+            KeyCode::from_crossterm(value.code),
+            KeyModifiers::from_crossterm(value.modifiers),
+        )
+    }
+}
+
 /// A command that scrolls the terminal screen a given number of rows up in a specific scrolling
 /// region.
 ///
@@ -797,6 +860,31 @@ mod tests {
 
     use super::*;
 
+    #[rstest]
+    #[case(CrosstermKeyCode::Char('a'), KeyCode::Char('a'))]
+    #[case(CrosstermKeyCode::Backspace, KeyCode::Backspace)]
+    #[case(CrosstermKeyCode::Left, KeyCode::Left)]
+    #[case(CrosstermKeyCode::F(5), KeyCode::F(5))]
+    #[case(CrosstermKeyCode::CapsLock, KeyCode::Unidentified)]
+    fn from_crossterm_key_code(#[case] from: CrosstermKeyCode, #[case] to: KeyCode) {
+        assert_eq!(KeyCode::from_crossterm(from), to);
+    }
+
+    #[test]
+    fn from_crossterm_key_event() {
+        let event = CrosstermKeyEvent::new(
+            CrosstermKeyCode::Char('w'),
+            CrosstermKeyModifiers::CONTROL | CrosstermKeyModifiers::SHIFT,
+        );
+        assert_eq!(
+            KeyEvent::from_crossterm(event),
+            KeyEvent::new(
+                KeyCode::Char('w'),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT
+            )
+        );
+    }
+
     #[rstest]
     #[case(CrosstermColor::Reset, Color::Reset)]
     #[case(CrosstermColor::Black, Color::Black)]
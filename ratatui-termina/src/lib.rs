@@ -28,6 +28,7 @@ use std::io::{self, Write};
 
 use ratatui_core::backend::{Backend, ClearType, WindowSize};
 use ratatui_core::buffer::Cell;
+use ratatui_core::input::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui_core::layout::{Position, Size};
 use ratatui_core::style::{Color, Modifier, Style};
 pub use termina;
@@ -35,6 +36,9 @@ use termina::escape::csi::{
     Csi, Cursor, DecPrivateMode, DecPrivateModeCode, Edit, EraseInDisplay, EraseInLine, Mode, Sgr,
     SgrAttributes, SgrModifiers,
 };
+use termina::event::{
+    KeyCode as TerminaKeyCode, KeyEvent as TerminaKeyEvent, Modifiers as TerminaModifiers,
+};
 use termina::style::{Blink, ColorSpec, Intensity, RgbColor, Underline};
 use termina::{Event, OneBased, Terminal};
 
@@ -496,6 +500,63 @@ impl FromTermina<Blink> for Modifier {
     }
 }
 
+impl FromTermina<TerminaKeyCode> for KeyCode {
+    fn from_termina(value: TerminaKeyCode) -> Self {
+        match value {
+            TerminaKeyCode::Char(c) => Self::Char(c),
+            TerminaKeyCode::Backspace => Self::Backspace,
+            TerminaKeyCode::Delete => Self::Delete,
+            TerminaKeyCode::Insert => Self::Insert,
+            TerminaKeyCode::Enter => Self::Enter,
+            TerminaKeyCode::Escape => Self::Esc,
+            TerminaKeyCode::Tab => Self::Tab,
+            TerminaKeyCode::BackTab => Self::BackTab,
+            TerminaKeyCode::Left => Self::Left,
+            TerminaKeyCode::Right => Self::Right,
+            TerminaKeyCode::Up => Self::Up,
+            TerminaKeyCode::Down => Self::Down,
+            TerminaKeyCode::Home => Self::Home,
+            TerminaKeyCode::End => Self::End,
+            TerminaKeyCode::PageUp => Self::PageUp,
+            TerminaKeyCode::PageDown => Self::PageDown,
+            TerminaKeyCode::Function(n) => Self::F(n),
+            _ => Self::Unidentified,
+        }
+    }
+}
+
+impl FromTermina<TerminaModifiers> for KeyModifiers {
+    fn from_termina(value: TerminaModifiers) -> Self {
+        let mut res = Self::empty();
+        if value.contains(TerminaModifiers::SHIFT) {
+            res |= Self::SHIFT;
+        }
+        if value.contains(TerminaModifiers::CONTROL) {
+            res |= Self::CONTROL;
+        }
+        if value.contains(TerminaModifiers::ALT) {
+            res |= Self::ALT;
+        }
+        if value.contains(TerminaModifiers::SUPER) {
+            res |= Self::SUPER;
+        }
+        res
+    }
+}
+
+/// Converts a termina key event to a Ratatui key event.
+///
+/// The kind and state of the event are not converted, so applications should only convert
+/// [`KeyEventKind::Press`](termina::event::KeyEventKind::Press) events.
+impl FromTermina<TerminaKeyEvent> for KeyEvent {
+    fn from_termina(value: TerminaKeyEvent) -> Self {
+        Self::new(
+            KeyCode::from_termina(value.code),
+            KeyModifiers::from_termina(value.modifiers),
+        )
+    }
+}
+
 impl<T> fmt::Debug for TerminaBackend<T>
 where
     T: Terminal + fmt::Debug,
@@ -517,6 +578,20 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn from_termina_key_event() {
+        let event = TerminaKeyEvent::new(TerminaKeyCode::Char('w'), TerminaModifiers::CONTROL);
+        assert_eq!(
+            KeyEvent::from_termina(event),
+            KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(KeyCode::from_termina(TerminaKeyCode::Escape), KeyCode::Esc);
+        assert_eq!(
+            KeyCode::from_termina(TerminaKeyCode::CapsLock),
+            KeyCode::Unidentified
+        );
+    }
+
     #[derive(Debug)]
     struct MockTerminal {
         output: Vec<u8>,
@@ -42,10 +42,12 @@ use std::io::{self, Write};
 
 use ratatui_core::backend::{Backend, ClearType, WindowSize};
 use ratatui_core::buffer::Cell;
+use ratatui_core::input::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui_core::layout::{Position, Size};
 use ratatui_core::style::{Color, Modifier, Style};
 pub use termion;
 use termion::color::Color as _;
+use termion::event::Key;
 use termion::{color as tcolor, style as tstyle};
 
 /// A [`Backend`] implementation that uses [Termion] to render to the terminal.
@@ -542,6 +544,48 @@ impl FromTermion<termion::style::Reset> for Modifier {
     }
 }
 
+impl FromTermion<Key> for KeyEvent {
+    fn from_termion(value: Key) -> Self {
+        let (code, modifiers) = match value {
+            Key::Char('\n') => (KeyCode::Enter, KeyModifiers::NONE),
+            Key::Char('\t') => (KeyCode::Tab, KeyModifiers::NONE),
+            Key::Char(c) => (KeyCode::Char(c), KeyModifiers::NONE),
+            Key::Alt(c) => (KeyCode::Char(c), KeyModifiers::ALT),
+            Key::Ctrl(c) => (KeyCode::Char(c), KeyModifiers::CONTROL),
+            Key::Backspace => (KeyCode::Backspace, KeyModifiers::NONE),
+            Key::Delete => (KeyCode::Delete, KeyModifiers::NONE),
+            Key::Insert => (KeyCode::Insert, KeyModifiers::NONE),
+            Key::Esc => (KeyCode::Esc, KeyModifiers::NONE),
+            Key::BackTab => (KeyCode::BackTab, KeyModifiers::NONE),
+            Key::Left => (KeyCode::Left, KeyModifiers::NONE),
+            Key::ShiftLeft => (KeyCode::Left, KeyModifiers::SHIFT),
+            Key::AltLeft => (KeyCode::Left, KeyModifiers::ALT),
+            Key::CtrlLeft => (KeyCode::Left, KeyModifiers::CONTROL),
+            Key::Right => (KeyCode::Right, KeyModifiers::NONE),
+            Key::ShiftRight => (KeyCode::Right, KeyModifiers::SHIFT),
+            Key::AltRight => (KeyCode::Right, KeyModifiers::ALT),
+            Key::CtrlRight => (KeyCode::Right, KeyModifiers::CONTROL),
+            Key::Up => (KeyCode::Up, KeyModifiers::NONE),
+            Key::ShiftUp => (KeyCode::Up, KeyModifiers::SHIFT),
+            Key::AltUp => (KeyCode::Up, KeyModifiers::ALT),
+            Key::CtrlUp => (KeyCode::Up, KeyModifiers::CONTROL),
+            Key::Down => (KeyCode::Down, KeyModifiers::NONE),
+            Key::ShiftDown => (KeyCode::Down, KeyModifiers::SHIFT),
+            Key::AltDown => (KeyCode::Down, KeyModifiers::ALT),
+            Key::CtrlDown => (KeyCode::Down, KeyModifiers::CONTROL),
+            Key::Home => (KeyCode::Home, KeyModifiers::NONE),
+            Key::CtrlHome => (KeyCode::Home, KeyModifiers::CONTROL),
+            Key::End => (KeyCode::End, KeyModifiers::NONE),
+            Key::CtrlEnd => (KeyCode::End, KeyModifiers::CONTROL),
+            Key::PageUp => (KeyCode::PageUp, KeyModifiers::NONE),
+            Key::PageDown => (KeyCode::PageDown, KeyModifiers::NONE),
+            Key::F(n) => (KeyCode::F(n), KeyModifiers::NONE),
+            _ => (KeyCode::Unidentified, KeyModifiers::NONE),
+        };
+        Self::new(code, modifiers)
+    }
+}
+
 /// Set scrolling region.
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub struct SetRegion(pub u16, pub u16);
@@ -566,6 +610,27 @@ impl fmt::Display for ResetRegion {
 mod tests {
     use super::*;
 
+    #[test]
+    fn from_termion_key() {
+        assert_eq!(KeyEvent::from_termion(Key::Char('a')), KeyEvent::from('a'));
+        assert_eq!(
+            KeyEvent::from_termion(Key::Char('\n')),
+            KeyEvent::from(KeyCode::Enter)
+        );
+        assert_eq!(
+            KeyEvent::from_termion(Key::Ctrl('w')),
+            KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            KeyEvent::from_termion(Key::AltLeft),
+            KeyEvent::new(KeyCode::Left, KeyModifiers::ALT)
+        );
+        assert_eq!(
+            KeyEvent::from_termion(Key::Null),
+            KeyEvent::from(KeyCode::Unidentified)
+        );
+    }
+
     #[test]
     fn from_termion_color() {
         assert_eq!(Color::from_termion(tcolor::Reset), Color::Reset);
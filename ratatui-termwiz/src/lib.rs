@@ -43,12 +43,16 @@ use std::io;
 
 use ratatui_core::backend::{Backend, ClearType, WindowSize};
 use ratatui_core::buffer::Cell;
+use ratatui_core::input::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui_core::layout::{Position, Size};
 use ratatui_core::style::{Color, Modifier, Style};
 pub use termwiz;
 use termwiz::caps::Capabilities;
 use termwiz::cell::{AttributeChange, Blink, CellAttributes, Intensity, Underline};
 use termwiz::color::{AnsiColor, ColorAttribute, ColorSpec, LinearRgba, RgbColor, SrgbaTuple};
+use termwiz::input::{
+    KeyCode as TermwizKeyCode, KeyEvent as TermwizKeyEvent, Modifiers as TermwizModifiers,
+};
 use termwiz::surface::{Change, CursorVisibility, Position as TermwizPosition};
 use termwiz::terminal::buffered::BufferedTerminal;
 use termwiz::terminal::{ScreenSize, SystemTerminal, Terminal};
@@ -529,6 +533,64 @@ impl FromTermwiz<LinearRgba> for Color {
     }
 }
 
+impl FromTermwiz<TermwizKeyCode> for KeyCode {
+    fn from_termwiz(value: TermwizKeyCode) -> Self {
+        match value {
+            TermwizKeyCode::Char(c) => Self::Char(c),
+            TermwizKeyCode::Backspace => Self::Backspace,
+            TermwizKeyCode::Delete => Self::Delete,
+            TermwizKeyCode::Insert => Self::Insert,
+            TermwizKeyCode::Enter => Self::Enter,
+            TermwizKeyCode::Escape => Self::Esc,
+            TermwizKeyCode::Tab => Self::Tab,
+            TermwizKeyCode::LeftArrow | TermwizKeyCode::ApplicationLeftArrow => Self::Left,
+            TermwizKeyCode::RightArrow | TermwizKeyCode::ApplicationRightArrow => Self::Right,
+            TermwizKeyCode::UpArrow | TermwizKeyCode::ApplicationUpArrow => Self::Up,
+            TermwizKeyCode::DownArrow | TermwizKeyCode::ApplicationDownArrow => Self::Down,
+            TermwizKeyCode::Home | TermwizKeyCode::KeyPadHome => Self::Home,
+            TermwizKeyCode::End | TermwizKeyCode::KeyPadEnd => Self::End,
+            TermwizKeyCode::PageUp | TermwizKeyCode::KeyPadPageUp => Self::PageUp,
+            TermwizKeyCode::PageDown | TermwizKeyCode::KeyPadPageDown => Self::PageDown,
+            TermwizKeyCode::Function(n) => Self::F(n),
+            _ => Self::Unidentified,
+        }
+    }
+}
+
+impl FromTermwiz<TermwizModifiers> for KeyModifiers {
+    fn from_termwiz(value: TermwizModifiers) -> Self {
+        let mut res = Self::empty();
+        if value.intersects(TermwizModifiers::SHIFT) {
+            res |= Self::SHIFT;
+        }
+        if value.intersects(TermwizModifiers::CTRL) {
+            res |= Self::CONTROL;
+        }
+        if value.intersects(TermwizModifiers::ALT) {
+            res |= Self::ALT;
+        }
+        if value.intersects(TermwizModifiers::SUPER) {
+            res |= Self::SUPER;
+        }
+        res
+    }
+}
+
+/// Converts a termwiz key event to a Ratatui key event.
+///
+/// Termwiz reports shift + tab as tab with the shift modifier, which is converted to
+/// [`KeyCode::BackTab`] to match the other backends.
+impl FromTermwiz<TermwizKeyEvent> for KeyEvent {
+    fn from_termwiz(value: TermwizKeyEvent) -> Self {
+        let modifiers: KeyModifiers = value.modifiers.into_ratatui();
+        let code = match value.key {
+            TermwizKeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            key => key.into_ratatui(),
+        };
+        Self::new(code, modifiers)
+    }
+}
+
 #[inline]
 fn u16_max(i: usize) -> u16 {
     u16::try_from(i).unwrap_or(u16::MAX)
@@ -538,6 +600,27 @@ fn u16_max(i: usize) -> u16 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn from_termwiz_key_event() {
+        let key = |key, modifiers| KeyEvent::from_termwiz(TermwizKeyEvent { key, modifiers });
+        assert_eq!(
+            key(TermwizKeyCode::Char('a'), TermwizModifiers::NONE),
+            KeyEvent::from('a')
+        );
+        assert_eq!(
+            key(TermwizKeyCode::LeftArrow, TermwizModifiers::CTRL),
+            KeyEvent::new(KeyCode::Left, KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            key(TermwizKeyCode::Tab, TermwizModifiers::SHIFT),
+            KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT)
+        );
+        assert_eq!(
+            key(TermwizKeyCode::VolumeUp, TermwizModifiers::NONE),
+            KeyEvent::from(KeyCode::Unidentified)
+        );
+    }
+
     mod into_color {
         use Color as C;
 
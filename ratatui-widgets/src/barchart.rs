@@ -5,17 +5,21 @@ use alloc::vec::Vec;
 
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::{Direction, Rect};
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::style::{Modifier, Style, Styled, Theme};
 use ratatui_core::symbols;
 use ratatui_core::text::Line;
 use ratatui_core::widgets::Widget;
+use unicode_width::UnicodeWidthStr;
 
 pub use self::bar::Bar;
+use self::bar::StackSegment;
 pub use self::bar_group::BarGroup;
+pub use self::bar_segment::BarSegment;
 use crate::block::{Block, BlockExt};
 
 mod bar;
 mod bar_group;
+mod bar_segment;
 
 /// A chart showing values as [bars](Bar).
 ///
@@ -41,6 +45,11 @@ mod bar_group;
 /// A [`BarGroup`] is a set of [`Bar`], multiple can be added to a `BarChart` using
 /// [`BarChart::data`] multiple time as demonstrated in the example below.
 ///
+/// A [`Bar`] can also be made of several [`BarSegment`]s stacked along the bar direction (see
+/// [`Bar::segments`]), or have a negative value (see [`Bar::signed_value`]). When a bar has
+/// segments or a negative value, the chart draws all its bars from a zero baseline, with positive
+/// values on one side and negative values on the other side.
+///
 /// The chart can have a [`Direction`] (by default the bars are [`Vertical`](Direction::Vertical)).
 /// This is set using [`BarChart::direction`].
 ///
@@ -395,6 +404,19 @@ impl<'a> BarChart<'a> {
         self
     }
 
+    /// Sets the styles of the chart from a [`Theme`].
+    ///
+    /// The style, bar style, value style and label style are replaced by the `barchart`,
+    /// `barchart.bar`, `barchart.value` and `barchart.label` styles of the theme.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style("barchart");
+        self.bar_style = theme.style("barchart.bar");
+        self.value_style = theme.style("barchart.value");
+        self.label_style = theme.style("barchart.label");
+        self
+    }
+
     /// Set the direction of the bars.
     ///
     /// [`Vertical`](ratatui_core::layout::Direction::Vertical) bars are the default.
@@ -428,6 +450,48 @@ struct LabelInfo {
     height: u16,
 }
 
+/// The extents of the bars of a chart drawn from a zero baseline
+#[derive(Clone, Copy)]
+struct Stacking {
+    /// the largest sum of the positive values of a bar
+    positive: u128,
+    /// the largest sum of the negative values of a bar
+    negative: u128,
+}
+
+impl Stacking {
+    /// Returns the number of cells above (or right of) the baseline, out of `length` cells.
+    fn positive_cells(self, length: u16) -> u16 {
+        let total = self.positive + self.negative;
+        ((2 * u128::from(length) * self.positive + total) / (2 * total)) as u16
+    }
+
+    /// Returns the start and end of the segments on one side of the baseline, in `units` for the
+    /// whole length of the chart, and limited to the `capacity` of that side.
+    fn spans(
+        self,
+        stack: &[StackSegment],
+        negative: bool,
+        units: u128,
+        capacity: u128,
+    ) -> Vec<(u128, u128, &StackSegment)> {
+        let total = self.positive + self.negative;
+        let mut sum = 0;
+        let mut start = 0;
+        stack
+            .iter()
+            .filter(|segment| segment.value != 0 && (segment.value < 0) == negative)
+            .map(|segment| {
+                sum += segment.value.unsigned_abs();
+                let end = (sum * units / total).min(capacity);
+                let span = (start, end, segment);
+                start = end;
+                span
+            })
+            .collect()
+    }
+}
+
 impl BarChart<'_> {
     /// Returns the visible bars length in ticks. A cell contains 8 ticks.
     /// `available_space` used to calculate how many bars can fit in the space
@@ -474,6 +538,41 @@ impl BarChart<'_> {
         ticks.min(max_ticks) as u64
     }
 
+    /// Returns the extents of the bars when they are drawn from a zero baseline, which is the
+    /// case when a bar has segments or a negative value.
+    fn stacking(&self) -> Option<Stacking> {
+        let bars = || self.data.iter().flat_map(|group| &group.bars);
+        if !bars().any(Bar::is_stacked) {
+            return None;
+        }
+        let (positive, negative) = bars().map(Bar::extents).fold(
+            (0, 0),
+            |(positive, negative), (bar_positive, bar_negative)| {
+                (positive.max(bar_positive), negative.max(bar_negative))
+            },
+        );
+        let positive = self.max.map_or(positive, u128::from);
+        Some(Stacking {
+            positive: positive.max(u128::from(negative == 0)),
+            negative,
+        })
+    }
+
+    /// Returns the symbol of a cell filled with the given number of ticks.
+    const fn tick_symbol(&self, ticks: u64) -> &str {
+        match ticks {
+            0 => self.bar_set.empty,
+            1 => self.bar_set.one_eighth,
+            2 => self.bar_set.one_quarter,
+            3 => self.bar_set.three_eighths,
+            4 => self.bar_set.half,
+            5 => self.bar_set.five_eighths,
+            6 => self.bar_set.three_quarters,
+            7 => self.bar_set.seven_eighths,
+            _ => self.bar_set.full,
+        }
+    }
+
     /// Get label information.
     ///
     /// height is the number of lines, which depends on whether we need to print the bar
@@ -535,11 +634,20 @@ impl BarChart<'_> {
         };
 
         let group_ticks = self.group_ticks(bars_area.height, bars_area.width);
+        let stacking = self.stacking();
 
         // print all visible bars, label and values
         let mut bar_y = bars_area.top();
         for (ticks_vec, group) in group_ticks.into_iter().zip(self.data.iter()) {
             for (ticks, bar) in ticks_vec.into_iter().zip(group.bars.iter()) {
+                if let Some(stacking) = stacking {
+                    self.render_horizontal_stack(buf, bars_area, bar_y, bar, stacking);
+                    if let Some(label) = &bar.label {
+                        buf.set_line(label_x, bar_y + (self.bar_width >> 1), label, label_size);
+                    }
+                    bar_y += self.bar_gap + self.bar_width;
+                    continue;
+                }
                 let bar_length = (ticks / 8) as u16;
                 let bar_style = self.bar_style.patch(bar.style);
 
@@ -607,22 +715,18 @@ impl BarChart<'_> {
 
     fn render_vertical_bars(&self, area: Rect, buf: &mut Buffer, group_ticks: &[Vec<u64>]) {
         // print all visible bars (without labels and values)
+        let stacking = self.stacking();
         let mut bar_x = area.left();
         for (ticks_vec, group) in group_ticks.iter().zip(&self.data) {
             for (ticks, bar) in ticks_vec.iter().zip(&group.bars) {
+                if let Some(stacking) = stacking {
+                    self.render_vertical_stack(buf, area, bar_x, bar, stacking);
+                    bar_x += self.bar_gap + self.bar_width;
+                    continue;
+                }
                 let mut ticks = *ticks;
                 for j in (0..area.height).rev() {
-                    let symbol = match ticks {
-                        0 => self.bar_set.empty,
-                        1 => self.bar_set.one_eighth,
-                        2 => self.bar_set.one_quarter,
-                        3 => self.bar_set.three_eighths,
-                        4 => self.bar_set.half,
-                        5 => self.bar_set.five_eighths,
-                        6 => self.bar_set.three_quarters,
-                        7 => self.bar_set.seven_eighths,
-                        _ => self.bar_set.full,
-                    };
+                    let symbol = self.tick_symbol(ticks);
 
                     let bar_style = self.bar_style.patch(bar.style);
 
@@ -640,6 +744,165 @@ impl BarChart<'_> {
         }
     }
 
+    /// Render a bar from the zero baseline, with the value of each segment printed inside it.
+    fn render_vertical_stack(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        bar_x: u16,
+        bar: &Bar,
+        stacking: Stacking,
+    ) {
+        let positive_cells = stacking.positive_cells(area.height);
+        let baseline = area.top() + positive_cells;
+        let bar_style = self.bar_style.patch(bar.style);
+        let stack = bar.stack();
+        for negative in [false, true] {
+            let cells = if negative {
+                area.height - positive_cells
+            } else {
+                positive_cells
+            };
+            let spans = stacking.spans(
+                &stack,
+                negative,
+                u128::from(area.height) * 8,
+                u128::from(cells) * 8,
+            );
+            let cell_y = |cell: u16| {
+                if negative {
+                    baseline + cell
+                } else {
+                    baseline - 1 - cell
+                }
+            };
+
+            for cell in 0..cells {
+                let (symbol, style) = self.stack_cell(&spans, cell, negative, bar_style);
+                for x in 0..self.bar_width {
+                    buf[(bar_x + x, cell_y(cell))]
+                        .set_symbol(symbol)
+                        .set_style(style);
+                }
+            }
+
+            // print each value in the first cell fully covered by its segment
+            for &(start, end, segment) in &spans {
+                let cell = start.div_ceil(8);
+                let width = segment.text.width() as u16;
+                if segment.text.is_empty() || (cell + 1) * 8 > end || width > self.bar_width {
+                    continue;
+                }
+                buf.set_string(
+                    bar_x + (self.bar_width - width) / 2,
+                    cell_y(cell as u16),
+                    &segment.text,
+                    self.value_style.patch(segment.value_style),
+                );
+            }
+        }
+    }
+
+    /// Returns the symbol and style of a cell of a stacked bar, counted from the baseline.
+    ///
+    /// When a segment ends in the middle of the cell, the next segment fills the rest of the cell
+    /// with its foreground color as the background color.
+    fn stack_cell(
+        &self,
+        spans: &[(u128, u128, &StackSegment)],
+        cell: u16,
+        negative: bool,
+        bar_style: Style,
+    ) -> (&str, Style) {
+        let low = u128::from(cell) * 8;
+        let Some(index) = spans.iter().position(|&(_, end, _)| end > low) else {
+            return (self.bar_set.empty, bar_style);
+        };
+        let (_, end, segment) = spans[index];
+        let style = self.bar_style.patch(segment.style);
+        let fill = (end - low).min(8) as u64;
+        if fill == 8 {
+            return (self.bar_set.full, style);
+        }
+        let style = match spans
+            .get(index + 1)
+            .and_then(|(_, _, next)| self.bar_style.patch(next.style).fg)
+        {
+            Some(color) => style.bg(color),
+            None => style,
+        };
+        if negative {
+            // the bar symbols fill cells from the bottom, so cells of bars going down are drawn
+            // with the complementary symbol and reversed colors
+            (
+                self.tick_symbol(8 - fill),
+                style.add_modifier(Modifier::REVERSED),
+            )
+        } else {
+            (self.tick_symbol(fill), style)
+        }
+    }
+
+    /// Render a horizontal bar from the zero baseline, with the value of each segment printed
+    /// at its start.
+    fn render_horizontal_stack(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        bar_y: u16,
+        bar: &Bar,
+        stacking: Stacking,
+    ) {
+        let negative_cells = area.width - stacking.positive_cells(area.width);
+        let baseline = area.left() + negative_cells;
+        let value_y = bar_y + (self.bar_width >> 1);
+        let bar_style = self.bar_style.patch(bar.style);
+        let stack = bar.stack();
+        for negative in [false, true] {
+            let cells = if negative {
+                negative_cells
+            } else {
+                area.width - negative_cells
+            };
+            let spans = stacking.spans(&stack, negative, u128::from(area.width), u128::from(cells));
+
+            for cell in 0..cells {
+                let x = if negative {
+                    baseline - 1 - cell
+                } else {
+                    baseline + cell
+                };
+                let (symbol, style) = spans
+                    .iter()
+                    .find(|&&(_, end, _)| end > u128::from(cell))
+                    .map_or((self.bar_set.empty, bar_style), |(_, _, segment)| {
+                        (self.bar_set.full, self.bar_style.patch(segment.style))
+                    });
+                for y in 0..self.bar_width {
+                    buf[(x, bar_y + y)].set_symbol(symbol).set_style(style);
+                }
+            }
+
+            for &(start, end, segment) in &spans {
+                let width = segment.text.width() as u128;
+                if segment.text.is_empty() || width > end - start {
+                    continue;
+                }
+                let x = if negative {
+                    baseline - end as u16
+                } else {
+                    baseline + start as u16
+                };
+                buf.set_string(
+                    x,
+                    value_y,
+                    &segment.text,
+                    self.value_style.patch(segment.value_style),
+                );
+            }
+        }
+    }
+
     /// get the maximum data value. the returned value is always greater equal 1
     fn maximum_data_value(&self) -> u64 {
         self.max
@@ -661,6 +924,7 @@ impl BarChart<'_> {
         group_ticks: &[Vec<u64>],
     ) {
         // print labels and values in one go
+        let stacked = self.stacking().is_some();
         let mut bar_x = area.left();
         let bar_y = area.bottom() - label_info.height - 1;
         for (group, ticks_vec) in self.data.iter().zip(group_ticks) {
@@ -686,7 +950,10 @@ impl BarChart<'_> {
                     bar.render_label(buf, self.bar_width, bar_x, bar_y + 1, self.label_style);
                 }
 
-                bar.render_value(buf, self.bar_width, bar_x, bar_y, self.value_style, *ticks);
+                // the values of stacked bars are printed with their segments
+                if !stacked {
+                    bar.render_value(buf, self.bar_width, bar_x, bar_y, self.value_style, *ticks);
+                }
 
                 bar_x += self.bar_gap + self.bar_width;
             }
@@ -1573,4 +1840,81 @@ mod tests {
         widget.render(buffer.area, &mut buffer);
         assert_eq!(buffer, Buffer::with_lines(["████████", "██中文██"]));
     }
+
+    #[test]
+    fn render_stacked_vertical_bars() {
+        let chart = BarChart::new([
+            Bar::with_label("a", 0).segments([BarSegment::new(2).red(), BarSegment::new(2).blue()]),
+            Bar::with_label("b", 0).segments([1, 1]),
+        ]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 5));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["█  ", "2  ", "█ 1", "2 1", "a b"]);
+        expected.set_style(Rect::new(0, 0, 1, 2), Color::Blue);
+        expected.set_style(Rect::new(0, 2, 1, 2), Color::Red);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_stacked_segment_boundary_in_cell() {
+        let chart = BarChart::new([
+            Bar::new(0).segments([BarSegment::new(3).red(), BarSegment::new(1).blue()])
+        ]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 2));
+        chart.render(buffer.area, &mut buffer);
+        // the blue segment fills the top of the cell shared with the red segment
+        let mut expected = Buffer::with_lines(["▄", "3"]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red().on_blue());
+        expected.set_style(Rect::new(0, 1, 1, 1), Color::Red);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_signed_vertical_bars() {
+        let chart = BarChart::new([
+            Bar::with_label("a", 4),
+            Bar::with_label("b", 0).signed_value(-2),
+            Bar::with_label("c", 0).signed_value(-1),
+        ]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 7));
+        chart.render(buffer.area, &mut buffer);
+        // negative values go down from the baseline under the fourth row
+        let expected = Buffer::with_lines([
+            "█    ",
+            "█    ",
+            "█    ",
+            "4    ",
+            "  █ █",
+            "  █  ",
+            "a b c",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_signed_horizontal_stacked_bars() {
+        let chart = BarChart::horizontal([
+            Bar::with_label("a", 0)
+                .segments([BarSegment::new(20).red(), BarSegment::new(-10).blue()]),
+            Bar::with_label("b", 0).signed_value(-20),
+        ]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 3));
+        chart.render(buffer.area, &mut buffer);
+        // the baseline is in the middle of the bars area, after the labels
+        let mut expected = Buffer::with_lines(["a   █20█", "        ", "b -20   "]);
+        expected.set_style(Rect::new(4, 0, 1, 1), Color::Blue);
+        expected.set_style(Rect::new(5, 0, 3, 1), Color::Red);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_partial_negative_bar_reversed() {
+        let chart = BarChart::new([Bar::new(8), Bar::new(0).signed_value(-4).text_value("")]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        chart.render(buffer.area, &mut buffer);
+        // the top of the cell is filled by drawing the complementary symbol with reversed colors
+        let mut expected = Buffer::with_lines(["8  ", "  ▃"]);
+        expected.set_style(Rect::new(2, 1, 1, 1), Modifier::REVERSED);
+        assert_eq!(buffer, expected);
+    }
 }
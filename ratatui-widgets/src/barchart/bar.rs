@@ -1,4 +1,5 @@
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
@@ -7,6 +8,8 @@ use ratatui_core::text::Line;
 use ratatui_core::widgets::Widget;
 use unicode_width::UnicodeWidthStr;
 
+use crate::barchart::BarSegment;
+
 /// A bar to be shown by the [`BarChart`](super::BarChart) widget.
 ///
 /// Here is an explanation of a `Bar`'s components.
@@ -42,6 +45,18 @@ pub struct Bar<'a> {
     pub(super) value_style: Style,
     /// optional `text_value` to be shown on the bar instead of the actual value
     pub(super) text_value: Option<String>,
+    /// whether the value is below zero (see [`Bar::signed_value`])
+    pub(super) negative: bool,
+    /// optional segments stacked in the bar instead of the value
+    pub(super) segments: Vec<BarSegment>,
+}
+
+/// A segment of a [`Bar`] as it is stacked by the chart
+pub(super) struct StackSegment {
+    pub(super) value: i128,
+    pub(super) style: Style,
+    pub(super) value_style: Style,
+    pub(super) text: String,
 }
 
 impl<'a> Bar<'a> {
@@ -61,6 +76,8 @@ impl<'a> Bar<'a> {
             style: Style::new(),
             value_style: Style::new(),
             text_value: None,
+            negative: false,
+            segments: Vec::new(),
         }
     }
 
@@ -82,6 +99,8 @@ impl<'a> Bar<'a> {
             style: Style::new(),
             value_style: Style::new(),
             text_value: None,
+            negative: false,
+            segments: Vec::new(),
         }
     }
 
@@ -96,6 +115,58 @@ impl<'a> Bar<'a> {
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn value(mut self, value: u64) -> Self {
         self.value = value;
+        self.negative = false;
+        self
+    }
+
+    /// Set a signed value for this bar.
+    ///
+    /// When a bar of the [`BarChart`](super::BarChart) has a negative value, the chart draws its
+    /// bars from a zero baseline: positive values go up (or right for horizontal bars) and
+    /// negative values go down (or left).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::Bar;
+    ///
+    /// let bar = Bar::with_label("Loss", 0).signed_value(-12);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn signed_value(mut self, value: i64) -> Self {
+        self.value = value.unsigned_abs();
+        self.negative = value < 0;
+        self
+    }
+
+    /// Stack segments in the bar.
+    ///
+    /// `segments` accepts anything that yields [`BarSegment`]s or signed values. The segments
+    /// replace the value of the bar: positive segments are stacked from the zero baseline of the
+    /// chart in the given order, and negative segments are stacked below it. The value of each
+    /// segment is printed inside the segment when it fits.
+    ///
+    /// The style of the bar is used as the default style of its segments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Stylize;
+    /// use ratatui::widgets::{Bar, BarSegment};
+    ///
+    /// Bar::with_label("Q1", 0).segments([
+    ///     BarSegment::new(12).green(),
+    ///     BarSegment::new(7).yellow(),
+    ///     BarSegment::new(-4).red(),
+    /// ]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn segments<I>(mut self, segments: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<BarSegment>,
+    {
+        self.segments = segments.into_iter().map(Into::into).collect();
         self
     }
 
@@ -192,6 +263,57 @@ impl<'a> Bar<'a> {
         self
     }
 
+    /// Returns whether the bar needs to be drawn from a zero baseline, because it has segments or
+    /// a negative value
+    pub(super) const fn is_stacked(&self) -> bool {
+        self.negative || !self.segments.is_empty()
+    }
+
+    /// Returns the segments to stack in the bar. A bar without segments is a single segment.
+    pub(super) fn stack(&self) -> Vec<StackSegment> {
+        if self.segments.is_empty() {
+            let value = i128::from(self.value);
+            let value = if self.negative { -value } else { value };
+            let text = match &self.text_value {
+                Some(text) => text.clone(),
+                None if value == 0 => String::new(),
+                None => value.to_string(),
+            };
+            return Vec::from([StackSegment {
+                value,
+                style: self.style,
+                value_style: self.value_style,
+                text,
+            }]);
+        }
+        self.segments
+            .iter()
+            .map(|segment| StackSegment {
+                value: i128::from(segment.value),
+                style: self.style.patch(segment.style),
+                value_style: self.value_style.patch(segment.value_style),
+                text: match &segment.text_value {
+                    Some(text) => text.clone(),
+                    None if segment.value == 0 => String::new(),
+                    None => segment.value.to_string(),
+                },
+            })
+            .collect()
+    }
+
+    /// Returns the sums of the positive and the negative values of the stack
+    pub(super) fn extents(&self) -> (u128, u128) {
+        self.stack()
+            .iter()
+            .fold((0, 0), |(positive, negative), segment| {
+                if segment.value < 0 {
+                    (positive, negative + segment.value.unsigned_abs())
+                } else {
+                    (positive + segment.value.unsigned_abs(), negative)
+                }
+            })
+    }
+
     /// Render the value of the bar.
     ///
     /// [`text_value`](Bar::text_value) is used if set, otherwise the value is converted to string.
@@ -0,0 +1,132 @@
+use alloc::string::String;
+
+use ratatui_core::style::{Style, Styled};
+
+/// A segment of a stacked [`Bar`](super::Bar).
+///
+/// The segments of a bar are stacked along the bar direction, starting from the zero baseline of
+/// the [`BarChart`](super::BarChart). Positive segments are stacked upwards (or to the right for
+/// horizontal bars) and negative segments are stacked downwards (or to the left).
+///
+/// The value of each segment is printed inside the segment when there is enough room for it.
+///
+/// # Example
+///
+/// The following example creates a bar with a red segment of 10 and a blue segment of 5 stacked
+/// on top of it.
+///
+/// ```
+/// use ratatui::style::Stylize;
+/// use ratatui::widgets::{Bar, BarSegment};
+///
+/// Bar::default().segments([BarSegment::new(10).red(), BarSegment::new(5).blue()]);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct BarSegment {
+    /// Signed value of the segment
+    pub(super) value: i64,
+    /// style of the segment
+    pub(super) style: Style,
+    /// style of the value printed in the segment
+    pub(super) value_style: Style,
+    /// optional `text_value` to be shown in the segment instead of the actual value
+    pub(super) text_value: Option<String>,
+}
+
+impl BarSegment {
+    /// Creates a new `BarSegment` with the given signed value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::BarSegment;
+    ///
+    /// let segment = BarSegment::new(-42);
+    /// ```
+    pub const fn new(value: i64) -> Self {
+        Self {
+            value,
+            style: Style::new(),
+            value_style: Style::new(),
+            text_value: None,
+        }
+    }
+
+    /// Set the value of this segment.
+    ///
+    /// Negative values are stacked below the zero baseline of the chart.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn value(mut self, value: i64) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Set the style of the segment.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The style is patched over the style of the bar. The foreground color of the segment is
+    /// also used to fill the cell it shares with the next segment of the stack.
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the style of the value printed in the segment.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn value_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.value_style = style.into();
+        self
+    }
+
+    /// Set the text value printed in the segment.
+    ///
+    /// If `text_value` is not set, then the [`ToString`](alloc::string::ToString) representation
+    /// of `value` is printed. An empty text hides the value of the segment.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn text_value<T: Into<String>>(mut self, text_value: T) -> Self {
+        self.text_value = Some(text_value.into());
+        self
+    }
+}
+
+impl From<i64> for BarSegment {
+    fn from(value: i64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Styled for BarSegment {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Stylize};
+
+    use super::*;
+
+    #[test]
+    fn segment_can_be_stylized() {
+        let segment = BarSegment::new(-3).red().on_white();
+        assert_eq!(segment.value, -3);
+        assert_eq!(segment.style, Style::new().fg(Color::Red).bg(Color::White));
+    }
+}
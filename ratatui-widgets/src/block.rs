@@ -10,7 +10,7 @@ use alloc::vec::Vec;
 use itertools::Itertools;
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::{Alignment, Rect};
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::style::{Style, Styled, Theme};
 use ratatui_core::symbols::border;
 use ratatui_core::symbols::merge::MergeStrategy;
 use ratatui_core::text::Line;
@@ -564,6 +564,29 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Sets the styles of the block from a [`Theme`].
+    ///
+    /// The block, border and title styles are replaced by the `block`, `block.border` and
+    /// `block.title` styles of the theme, so styles set before this call are overwritten.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::{Style, Stylize, Theme};
+    /// use ratatui::widgets::Block;
+    ///
+    /// let theme = Theme::new().with_style("block.border", Style::new().blue());
+    /// let block = Block::bordered().theme(&theme);
+    /// assert_eq!(block, Block::bordered().border_style(Style::new().blue()));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style("block");
+        self.border_style = theme.style("block.border");
+        self.titles_style = theme.style("block.title");
+        self
+    }
+
     /// Defines which borders to display.
     ///
     /// [`Borders`] can also be styled with [`Block::border_style`] and [`Block::border_type`].
@@ -1139,6 +1162,25 @@ impl Block<'_> {
         let bottom = self.padding.bottom.saturating_add(u16::from(has_bottom));
         (top, bottom)
     }
+
+    /// Returns the borders that are directly next to the inner area, without padding between them.
+    ///
+    /// Widgets use this to join their inner lines with the borders of the [`Block`].
+    pub(crate) fn adjacent_borders(&self) -> Borders {
+        let mut borders = self.borders;
+        let padding = [
+            (Borders::LEFT, self.padding.left),
+            (Borders::TOP, self.padding.top),
+            (Borders::RIGHT, self.padding.right),
+            (Borders::BOTTOM, self.padding.bottom),
+        ];
+        for (border, padding) in padding {
+            if padding > 0 {
+                borders.remove(border);
+            }
+        }
+        borders
+    }
 }
 
 /// An extension trait for [`Block`] that provides some convenience methods.
@@ -1177,6 +1219,7 @@ mod tests {
     use itertools::iproduct;
     use ratatui_core::layout::Offset;
     use ratatui_core::style::{Color, Modifier, Stylize};
+    use ratatui_core::text::Truncation;
     use rstest::rstest;
     use strum::ParseError;
 
@@ -1488,6 +1531,21 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn title_truncation() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        Block::bordered()
+            .title(Line::from("A long title").truncation(Truncation::end()))
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌A long …┐",
+            "│        │",
+            "└────────┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn title_alignment() {
         let tests = vec![
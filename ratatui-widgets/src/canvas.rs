@@ -5,13 +5,27 @@
 //!
 //! The available shapes are:
 //!
+//! - [`Arc`]: An arc of a circle
 //! - [`Circle`]: A basic circle
+//! - [`CubicBezier`]: A cubic Bézier curve
+//! - [`Ellipse`]: An ellipse
+//! - [`FilledCircle`]: A filled circle
+//! - [`FilledLine`]: A line filling the space under or over it
+//! - [`FilledPolygon`]: A filled polygon
+//! - [`FilledRectangle`]: A filled rectangle
 //! - [`Line`]: A line between two points
 //! - [`Map`]: A world map
 //! - [`Points`]: A scatter of points
+//! - [`Polygon`]: The outline of a polygon
+//! - [`Polyline`]: A path joining a list of points
+//! - [`QuadraticBezier`]: A quadratic Bézier curve
 //! - [`Rectangle`]: A basic rectangle
 //!
 //! You can also implement your own custom [`Shape`]s.
+//!
+//! The shapes can be moved, scaled and rotated with the [`Transform`] of the [`Context`], and a
+//! [`CanvasState`] zooms and pans the view of a canvas rendered as a
+//! [`StatefulWidget`](ratatui_core::widgets::StatefulWidget).
 
 use alloc::boxed::Box;
 use alloc::vec;
@@ -27,22 +41,34 @@ use ratatui_core::symbols::braille::BRAILLE;
 use ratatui_core::symbols::pixel::{OCTANTS, QUADRANTS, SEXTANTS};
 use ratatui_core::symbols::{self, Marker};
 use ratatui_core::text::Line as TextLine;
-use ratatui_core::widgets::Widget;
+use ratatui_core::widgets::{StatefulWidget, Widget};
 
-pub use self::circle::Circle;
+pub use self::arc::Arc;
+pub use self::bezier::{CubicBezier, QuadraticBezier};
+pub use self::circle::{Circle, FilledCircle};
+pub use self::ellipse::Ellipse;
 pub use self::line::{FilledLine, Line};
 pub use self::map::{Map, MapResolution};
 pub use self::points::Points;
-pub use self::rectangle::Rectangle;
+pub use self::polygon::{FilledPolygon, Polygon, Polyline};
+pub use self::rectangle::{FilledRectangle, Rectangle};
+pub use self::state::CanvasState;
+pub use self::transform::Transform;
 use crate::block::{Block, BlockExt};
 #[cfg(not(feature = "std"))]
 use crate::polyfills::F64Polyfills;
 
+mod arc;
+mod bezier;
 mod circle;
+mod ellipse;
 mod line;
 mod map;
 mod points;
+mod polygon;
 mod rectangle;
+mod state;
+mod transform;
 mod world;
 
 /// Something that can be drawn on a [`Canvas`].
@@ -422,6 +448,9 @@ impl Painter<'_, '_> {
     /// Points are rounded to the nearest grid cell (with points exactly in the center of a cell
     /// rounding up).
     ///
+    /// The coordinates are first mapped with the [`Transform`] of the [`Context`], so that shapes
+    /// follow the translations, scalings and rotations applied while painting.
+    ///
     /// # Examples
     ///
     /// ```
@@ -447,6 +476,13 @@ impl Painter<'_, '_> {
     /// assert_eq!(point, Some((0, 0)));
     /// ```
     pub fn get_point(&self, x: f64, y: f64) -> Option<(usize, usize)> {
+        let (x, y) = self.context.transform.apply(x, y);
+        self.grid_point(x, y)
+    }
+
+    /// Convert the `(x, y)` coordinates of the canvas, after the transform of the [`Context`], to
+    /// the location of a point on the grid
+    fn grid_point(&self, x: f64, y: f64) -> Option<(usize, usize)> {
         let [left, right] = self.context.x_bounds;
         let [bottom, top] = self.context.y_bounds;
         if x < left || x > right || y < bottom || y > top {
@@ -495,6 +531,26 @@ impl Painter<'_, '_> {
     pub const fn bounds(&self) -> (&[f64; 2], &[f64; 2]) {
         (&self.context.x_bounds, &self.context.y_bounds)
     }
+
+    /// The [`Transform`] applied to the coordinates of the shapes
+    ///
+    /// [`Painter::get_point`] already applies it; shapes that work in the coordinate system of the
+    /// canvas, e.g. to clip against its [bounds](Painter::bounds), apply it themselves.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::symbols;
+    /// use ratatui::widgets::canvas::{Context, Painter, Transform};
+    ///
+    /// let mut ctx = Context::new(1, 1, [0.0, 2.0], [0.0, 2.0], symbols::Marker::Braille);
+    /// ctx.translate(1.0, 0.0);
+    /// let painter = Painter::from(&mut ctx);
+    /// assert_eq!(painter.transform(), Transform::translation(1.0, 0.0));
+    /// ```
+    pub const fn transform(&self) -> Transform {
+        self.context.transform
+    }
 }
 
 impl<'a, 'b> From<&'a mut Context<'b>> for Painter<'a, 'b> {
@@ -529,6 +585,10 @@ pub struct Context<'a> {
     dirty: bool,
     layers: Vec<Layer>,
     labels: Vec<Label<'a>>,
+    // Transform applied to the coordinates of the shapes and labels
+    transform: Transform,
+    // Transforms saved by `push_transform`
+    transforms: Vec<Transform>,
 }
 
 impl<'a> Context<'a> {
@@ -572,6 +632,8 @@ impl<'a> Context<'a> {
             dirty: false,
             layers: Vec::new(),
             labels: Vec::new(),
+            transform: Transform::IDENTITY,
+            transforms: Vec::new(),
         }
     }
 
@@ -626,13 +688,15 @@ impl<'a> Context<'a> {
     /// Print a [`Text`] on the [`Canvas`] at the given position.
     ///
     /// Note that the text is always printed on top of the canvas and is **not** affected by the
-    /// layers.
+    /// layers. The position follows the current [`Transform`], but the text itself is neither
+    /// scaled nor rotated.
     ///
     /// [`Text`]: ratatui_core::text::Text
     pub fn print<T>(&mut self, x: f64, y: f64, line: T)
     where
         T: Into<TextLine<'a>>,
     {
+        let (x, y) = self.transform.apply(x, y);
         self.labels.push(Label {
             x,
             y,
@@ -640,6 +704,89 @@ impl<'a> Context<'a> {
         });
     }
 
+    /// Returns the [`Transform`] applied to the coordinates of the shapes and labels
+    pub const fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    /// Replace the [`Transform`] applied to the coordinates of the shapes and labels
+    pub const fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    /// Move the origin of the shapes drawn next by `dx` and `dy`
+    ///
+    /// Like the other transforms, the translation applies to the coordinates of the shapes before
+    /// the transforms already in place, e.g. a translation after a rotation moves the shapes along
+    /// the rotated axes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use ratatui::widgets::canvas::{Canvas, Rectangle};
+    ///
+    /// Canvas::default()
+    ///     .x_bounds([0.0, 10.0])
+    ///     .y_bounds([0.0, 10.0])
+    ///     .paint(|ctx| {
+    ///         for x in [1.0, 4.0, 7.0] {
+    ///             ctx.push_transform();
+    ///             ctx.translate(x, 1.0);
+    ///             ctx.draw(&Rectangle::new(0.0, 0.0, 2.0, 2.0, Color::Red));
+    ///             ctx.pop_transform();
+    ///         }
+    ///     });
+    /// ```
+    pub fn translate(&mut self, dx: f64, dy: f64) {
+        self.apply_transform(Transform::translation(dx, dy));
+    }
+
+    /// Scale the shapes drawn next by `sx` and `sy` around the origin
+    pub fn scale(&mut self, sx: f64, sy: f64) {
+        self.apply_transform(Transform::scaling(sx, sy));
+    }
+
+    /// Rotate the shapes drawn next around the origin by the given angle, in degrees
+    ///
+    /// Positive angles rotate counter-clockwise. Rotating around another point is done by
+    /// translating to it first:
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use ratatui::widgets::canvas::{Canvas, Rectangle};
+    ///
+    /// Canvas::default()
+    ///     .x_bounds([-10.0, 10.0])
+    ///     .y_bounds([-10.0, 10.0])
+    ///     .paint(|ctx| {
+    ///         // a square rotated around its center at (2.0, 2.0)
+    ///         ctx.translate(2.0, 2.0);
+    ///         ctx.rotate(45.0);
+    ///         ctx.draw(&Rectangle::new(-1.0, -1.0, 2.0, 2.0, Color::Red));
+    ///     });
+    /// ```
+    pub fn rotate(&mut self, degrees: f64) {
+        self.apply_transform(Transform::rotation(degrees));
+    }
+
+    /// Save the current [`Transform`], to restore it with [`Context::pop_transform`]
+    pub fn push_transform(&mut self) {
+        self.transforms.push(self.transform);
+    }
+
+    /// Restore the [`Transform`] saved by the last call to [`Context::push_transform`]
+    ///
+    /// When no transform is saved, the transform is reset to [`Transform::IDENTITY`].
+    pub fn pop_transform(&mut self) {
+        self.transform = self.transforms.pop().unwrap_or(Transform::IDENTITY);
+    }
+
+    /// Apply `transform` to the coordinates of the shapes before the current transform
+    fn apply_transform(&mut self, transform: Transform) {
+        self.transform = transform.then(self.transform);
+    }
+
     /// Save the last layer if necessary
     fn finish(&mut self) {
         if self.dirty {
@@ -681,7 +828,13 @@ impl<'a> Context<'a> {
 /// and start a new layer. This is useful if you want to draw multiple shapes on the canvas in
 /// specific order. The [`Context`] object also provides a [`Context::print`] method that can be
 /// used to print text on the canvas. Note that the text is always printed on top of the canvas and
-/// is not affected by the layers.
+/// is not affected by the layers. The [`Context::translate`], [`Context::scale`] and
+/// [`Context::rotate`] methods transform the shapes drawn next, and [`Context::push_transform`]
+/// and [`Context::pop_transform`] save and restore the current [`Transform`].
+///
+/// The canvas can also be rendered as a [`StatefulWidget`] with a [`CanvasState`], which zooms
+/// and pans the view of the `x` and `y` bounds and maps the positions of the terminal back to the
+/// coordinates of the canvas, e.g. to hit test the mouse.
 ///
 /// # Examples
 ///
@@ -862,10 +1015,52 @@ where
     F: Fn(&mut Context),
 {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_view(area, buf, self.x_bounds, self.y_bounds);
+    }
+}
+
+impl<F> StatefulWidget for Canvas<'_, F>
+where
+    F: Fn(&mut Context),
+{
+    type State = CanvasState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl<F> StatefulWidget for &Canvas<'_, F>
+where
+    F: Fn(&mut Context),
+{
+    type State = CanvasState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let x_bounds = state.view(0, self.x_bounds);
+        let y_bounds = state.view(1, self.y_bounds);
+        let canvas_area = self.render_view(area, buf, x_bounds, y_bounds);
+        state.update(canvas_area, [x_bounds, y_bounds]);
+    }
+}
+
+impl<F> Canvas<'_, F>
+where
+    F: Fn(&mut Context),
+{
+    /// Renders the canvas with the given bounds and returns the area of the canvas inside its
+    /// block
+    fn render_view(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        x_bounds: [f64; 2],
+        y_bounds: [f64; 2],
+    ) -> Rect {
         self.block.as_ref().render(area, buf);
         let canvas_area = self.block.inner_if_some(area);
         if canvas_area.is_empty() {
-            return;
+            return canvas_area;
         }
 
         buf.set_style(canvas_area, Style::default().bg(self.background_color));
@@ -873,15 +1068,15 @@ where
         let width = canvas_area.width as usize;
 
         let Some(ref painter) = self.paint_func else {
-            return;
+            return canvas_area;
         };
 
         // Create a blank context that match the size of the canvas
         let mut ctx = Context::new(
             canvas_area.width,
             canvas_area.height,
-            self.x_bounds,
-            self.y_bounds,
+            x_bounds,
+            y_bounds,
             self.marker,
         );
         // Paint to this context
@@ -910,12 +1105,10 @@ where
         }
 
         // Finally draw the labels
-        let left = self.x_bounds[0];
-        let right = self.x_bounds[1];
-        let top = self.y_bounds[1];
-        let bottom = self.y_bounds[0];
-        let width = (self.x_bounds[1] - self.x_bounds[0]).abs();
-        let height = (self.y_bounds[1] - self.y_bounds[0]).abs();
+        let [left, right] = x_bounds;
+        let [bottom, top] = y_bounds;
+        let width = (right - left).abs();
+        let height = (top - bottom).abs();
         let resolution = {
             let width = f64::from(canvas_area.width - 1);
             let height = f64::from(canvas_area.height - 1);
@@ -930,6 +1123,7 @@ where
             let y = ((top - label.y) * resolution.1 / height) as u16 + canvas_area.top();
             buf.set_line(x, y, &label.line, canvas_area.right() - x);
         }
+        canvas_area
     }
 }
 
@@ -937,6 +1131,7 @@ where
 mod tests {
     use indoc::indoc;
     use ratatui_core::buffer::Cell;
+    use ratatui_core::layout::Position;
     use rstest::rstest;
 
     use super::*;
@@ -1039,15 +1234,15 @@ mod tests {
             y2: 10.0,
             color: Color::Reset,
         };
-        Canvas::default()
+        let canvas = Canvas::default()
             .marker(marker)
             .paint(|ctx| {
                 ctx.draw(&vertical_line);
                 ctx.draw(&horizontal_line);
             })
             .x_bounds([0.0, 10.0])
-            .y_bounds([0.0, 10.0])
-            .render(area, &mut buf);
+            .y_bounds([0.0, 10.0]);
+        Widget::render(canvas, area, &mut buf);
         assert_eq!(buf, Buffer::with_lines(expected.lines()));
     }
 
@@ -1148,15 +1343,15 @@ mod tests {
             y2: 0.0,
             color: Color::Reset,
         };
-        Canvas::default()
+        let canvas = Canvas::default()
             .marker(marker)
             .paint(|ctx| {
                 ctx.draw(&diagonal_down);
                 ctx.draw(&diagonal_up);
             })
             .x_bounds([0.0, 10.0])
-            .y_bounds([0.0, 10.0])
-            .render(area, &mut buf);
+            .y_bounds([0.0, 10.0]);
+        Widget::render(canvas, area, &mut buf);
         assert_eq!(buf, Buffer::with_lines(expected.lines()));
     }
 
@@ -1204,7 +1399,7 @@ mod tests {
             .y_bounds([0.0, 10.0])
             .paint(|_ctx| {});
         // This should not panic, even if the buffer is too small to render the canvas.
-        canvas.render(buffer.area, &mut buffer);
+        Widget::render(canvas, buffer.area, &mut buffer);
         assert_eq!(buffer, Buffer::with_lines([" "]));
     }
 
@@ -1216,6 +1411,63 @@ mod tests {
             .y_bounds([0.0, 10.0])
             .paint(|_ctx| {});
         // This should not panic, even if the buffer has zero size.
-        canvas.render(buffer.area, &mut buffer);
+        Widget::render(canvas, buffer.area, &mut buffer);
+    }
+
+    #[test]
+    fn draws_shapes_with_transforms() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 5));
+        let canvas = Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 8.0])
+            .y_bounds([0.0, 4.0])
+            .paint(|ctx| {
+                ctx.push_transform();
+                ctx.translate(4.0, 2.0);
+                ctx.rotate(90.0);
+                ctx.draw(&Line::new(0.0, 0.0, 2.0, 0.0, Color::Reset));
+                ctx.scale(1.0, 2.0);
+                ctx.draw(&Points::new(&[(-1.0, -2.0)], Color::Reset));
+                ctx.pop_transform();
+                ctx.draw(&Points::new(&[(0.0, 0.0)], Color::Reset));
+                ctx.translate(1.0, 1.0);
+                ctx.print(0.0, 0.0, "x");
+            });
+        Widget::render(canvas, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "    •    ",
+            "    •    ",
+            "    •    ",
+            " x      •",
+            "•        ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn stateful_render_uses_the_viewport() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 5));
+        let mut state = CanvasState::default();
+        state.zoom(2.0, 2.0);
+        state.pan(0.5, 0.0);
+        let canvas = Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 8.0])
+            .y_bounds([0.0, 4.0])
+            .paint(|ctx| {
+                ctx.draw(&Points::new(&[(4.0, 2.0), (8.0, 3.0)], Color::Reset));
+            });
+        StatefulWidget::render(canvas, buffer.area, &mut buffer, &mut state);
+        let expected = Buffer::with_lines([
+            "        •",
+            "         ",
+            "•        ",
+            "         ",
+            "         ",
+        ]);
+        assert_eq!(buffer, expected);
+        assert_eq!(state.area(), buffer.area);
+        assert_eq!(state.visible_bounds(), [[4.0, 8.0], [1.0, 3.0]]);
+        assert_eq!(state.canvas_point(Position::new(8, 0)), Some((8.0, 3.0)));
     }
 }
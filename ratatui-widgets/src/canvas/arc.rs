@@ -0,0 +1,104 @@
+use ratatui_core::style::Color;
+
+use crate::canvas::ellipse::ellipse_points;
+use crate::canvas::polygon::draw_path;
+use crate::canvas::{Painter, Shape};
+
+/// An arc of a circle with a given center and radius and with a given color
+///
+/// The arc goes from the `start_angle` to the `end_angle`, in degrees. An angle of `0.0` points
+/// along the `x` axis and the angles increase counter-clockwise, so an arc whose end angle is
+/// smaller than its start angle is drawn clockwise.
+///
+/// # Examples
+///
+/// A gauge drawn as the upper half of a circle:
+///
+/// ```rust
+/// # use ratatui_core::style::Color;
+/// # use ratatui_widgets::canvas::{Arc, Canvas};
+/// Canvas::default().paint(|ctx| {
+///     ctx.draw(&Arc::new(0.0, 0.0, 1.0, 0.0, 180.0, Color::Red));
+/// });
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Arc {
+    /// `x` coordinate of the arc's center
+    pub x: f64,
+    /// `y` coordinate of the arc's center
+    pub y: f64,
+    /// Radius of the arc
+    pub radius: f64,
+    /// Angle of the start of the arc, in degrees
+    pub start_angle: f64,
+    /// Angle of the end of the arc, in degrees
+    pub end_angle: f64,
+    /// Color of the arc
+    pub color: Color,
+}
+
+impl Arc {
+    /// Create a new arc with the given center, radius, angles in degrees, and color
+    pub const fn new(
+        x: f64,
+        y: f64,
+        radius: f64,
+        start_angle: f64,
+        end_angle: f64,
+        color: Color,
+    ) -> Self {
+        Self {
+            x,
+            y,
+            radius,
+            start_angle,
+            end_angle,
+            color,
+        }
+    }
+}
+
+impl Shape for Arc {
+    fn draw(&self, painter: &mut Painter) {
+        let points = ellipse_points(
+            painter,
+            self.x,
+            self.y,
+            self.radius,
+            self.radius,
+            self.start_angle.to_radians(),
+            self.end_angle.to_radians(),
+        );
+        draw_path(painter, points, self.color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::buffer::Buffer;
+    use ratatui_core::layout::Rect;
+    use ratatui_core::symbols::Marker;
+    use ratatui_core::widgets::Widget;
+
+    use super::*;
+    use crate::canvas::Canvas;
+
+    #[test]
+    fn draws_an_arc() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 5));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([-4.0, 4.0])
+            .y_bounds([0.0, 4.0])
+            .paint(|ctx| ctx.draw(&Arc::new(0.0, 0.0, 4.0, 0.0, 180.0, Color::Reset)))
+            .render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "  •••••  ",
+            " ••   •• ",
+            "••     ••",
+            "•       •",
+            "•       •",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+}
@@ -0,0 +1,202 @@
+use ratatui_core::style::Color;
+
+use crate::canvas::polygon::{curve_steps, draw_path};
+use crate::canvas::{Painter, Shape};
+#[cfg(not(feature = "std"))]
+use crate::polyfills::F64Polyfills;
+
+/// A quadratic Bézier curve from `start` to `end`, bent towards a `control` point
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Color;
+/// # use ratatui_widgets::canvas::{Canvas, QuadraticBezier};
+/// Canvas::default().paint(|ctx| {
+///     ctx.draw(&QuadraticBezier::new(
+///         (0.0, 0.0),
+///         (1.0, 2.0),
+///         (2.0, 0.0),
+///         Color::Red,
+///     ));
+/// });
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct QuadraticBezier {
+    /// The starting point
+    pub start: (f64, f64),
+    /// The control point
+    pub control: (f64, f64),
+    /// The ending point
+    pub end: (f64, f64),
+    /// Color of the curve
+    pub color: Color,
+}
+
+impl QuadraticBezier {
+    /// Create a new quadratic Bézier curve with the given points and color
+    pub const fn new(
+        start: (f64, f64),
+        control: (f64, f64),
+        end: (f64, f64),
+        color: Color,
+    ) -> Self {
+        Self {
+            start,
+            control,
+            end,
+            color,
+        }
+    }
+}
+
+impl Shape for QuadraticBezier {
+    fn draw(&self, painter: &mut Painter) {
+        let points = [self.start, self.control, self.end];
+        draw_curve(painter, &points, self.color);
+    }
+}
+
+/// A cubic Bézier curve from `start` to `end`, bent towards two control points
+///
+/// The curve leaves `start` towards `control1` and reaches `end` coming from `control2`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Color;
+/// # use ratatui_widgets::canvas::{Canvas, CubicBezier};
+/// Canvas::default().paint(|ctx| {
+///     ctx.draw(&CubicBezier::new(
+///         (0.0, 0.0),
+///         (0.0, 2.0),
+///         (2.0, -2.0),
+///         (2.0, 0.0),
+///         Color::Red,
+///     ));
+/// });
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CubicBezier {
+    /// The starting point
+    pub start: (f64, f64),
+    /// The control point of the start of the curve
+    pub control1: (f64, f64),
+    /// The control point of the end of the curve
+    pub control2: (f64, f64),
+    /// The ending point
+    pub end: (f64, f64),
+    /// Color of the curve
+    pub color: Color,
+}
+
+impl CubicBezier {
+    /// Create a new cubic Bézier curve with the given points and color
+    pub const fn new(
+        start: (f64, f64),
+        control1: (f64, f64),
+        control2: (f64, f64),
+        end: (f64, f64),
+        color: Color,
+    ) -> Self {
+        Self {
+            start,
+            control1,
+            control2,
+            end,
+            color,
+        }
+    }
+}
+
+impl Shape for CubicBezier {
+    fn draw(&self, painter: &mut Painter) {
+        let points = [self.start, self.control1, self.control2, self.end];
+        draw_curve(painter, &points, self.color);
+    }
+}
+
+/// Draws a Bézier curve with segments of about one point of the grid.
+///
+/// The length of the control polygon bounds the length of the curve, so it gives the number of
+/// segments needed.
+fn draw_curve<const N: usize>(painter: &mut Painter, points: &[(f64, f64); N], color: Color) {
+    let length = points.windows(2).fold((0.0, 0.0), |(x, y), pair| {
+        (
+            x + (pair[1].0 - pair[0].0).abs(),
+            y + (pair[1].1 - pair[0].1).abs(),
+        )
+    });
+    let steps = curve_steps(painter, length);
+    let curve = (0..=steps).map(|step| de_casteljau(*points, step as f64 / steps as f64));
+    draw_path(painter, curve, color);
+}
+
+/// Returns the point of the Bézier curve at `t`, between `0.0` and `1.0`
+fn de_casteljau<const N: usize>(mut points: [(f64, f64); N], t: f64) -> (f64, f64) {
+    for n in (1..N).rev() {
+        for i in 0..n {
+            let ((x1, y1), (x2, y2)) = (points[i], points[i + 1]);
+            points[i] = ((x2 - x1).mul_add(t, x1), (y2 - y1).mul_add(t, y1));
+        }
+    }
+    points[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::buffer::Buffer;
+    use ratatui_core::layout::Rect;
+    use ratatui_core::symbols::Marker;
+    use ratatui_core::widgets::Widget;
+
+    use super::*;
+    use crate::canvas::Canvas;
+
+    fn render(shape: &impl Shape) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 5));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 8.0])
+            .y_bounds([0.0, 4.0])
+            .paint(|ctx| ctx.draw(shape))
+            .render(buffer.area, &mut buffer);
+        buffer
+    }
+
+    #[test]
+    fn draws_a_quadratic_bezier() {
+        let curve = QuadraticBezier::new((0.0, 0.0), (4.0, 8.0), (8.0, 0.0), Color::Reset);
+        let buffer = render(&curve);
+        let expected = Buffer::with_lines([
+            "   •••   ",
+            "  •   •  ",
+            " •     • ",
+            "••     ••",
+            "•       •",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn draws_a_cubic_bezier() {
+        let curve = CubicBezier::new((0.0, 0.0), (0.0, 4.0), (8.0, 0.0), (8.0, 4.0), Color::Reset);
+        let buffer = render(&curve);
+        let expected = Buffer::with_lines([
+            "        •",
+            "        •",
+            " ••••••• ",
+            "•        ",
+            "•        ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn de_casteljau_interpolates_the_points() {
+        let points = [(0.0, 0.0), (2.0, 4.0), (4.0, 0.0)];
+        assert_eq!(de_casteljau(points, 0.0), (0.0, 0.0));
+        assert_eq!(de_casteljau(points, 0.5), (2.0, 2.0));
+        assert_eq!(de_casteljau(points, 1.0), (4.0, 0.0));
+    }
+}
@@ -1,5 +1,10 @@
+use alloc::vec::Vec;
+use core::f64::consts::TAU;
+
 use ratatui_core::style::Color;
 
+use crate::canvas::ellipse::ellipse_points;
+use crate::canvas::polygon::fill_polygon;
 use crate::canvas::{Painter, Shape};
 #[cfg(not(feature = "std"))]
 use crate::polyfills::F64Polyfills;
@@ -42,6 +47,40 @@ impl Shape for Circle {
     }
 }
 
+/// A disc with a given center and radius, filled with a given color
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FilledCircle {
+    /// `x` coordinate of the circle's center
+    pub x: f64,
+    /// `y` coordinate of the circle's center
+    pub y: f64,
+    /// Radius of the circle
+    pub radius: f64,
+    /// Color of the circle
+    pub color: Color,
+}
+
+impl FilledCircle {
+    /// Create a new filled circle with the given center, radius, and color
+    pub const fn new(x: f64, y: f64, radius: f64, color: Color) -> Self {
+        Self {
+            x,
+            y,
+            radius,
+            color,
+        }
+    }
+}
+
+impl Shape for FilledCircle {
+    fn draw(&self, painter: &mut Painter<'_, '_>) {
+        let outline = ellipse_points(painter, self.x, self.y, self.radius, self.radius, 0.0, TAU)
+            .collect::<Vec<_>>();
+        fill_polygon(painter, &outline, self.color);
+        Circle::new(self.x, self.y, self.radius, self.color).draw(painter);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use ratatui_core::buffer::Buffer;
@@ -50,7 +89,7 @@ mod tests {
     use ratatui_core::symbols::Marker;
     use ratatui_core::widgets::Widget;
 
-    use crate::canvas::{Canvas, Circle};
+    use crate::canvas::{Canvas, Circle, FilledCircle};
 
     #[test]
     fn test_it_draws_a_circle() {
@@ -77,4 +116,25 @@ mod tests {
         ]);
         assert_eq!(buffer, expected);
     }
+
+    #[test]
+    fn test_it_draws_a_filled_circle() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 5));
+        let canvas = Canvas::default()
+            .paint(|ctx| {
+                ctx.draw(&FilledCircle::new(0.0, 0.0, 2.0, Color::Reset));
+            })
+            .marker(Marker::Dot)
+            .x_bounds([-4.0, 4.0])
+            .y_bounds([-2.0, 2.0]);
+        canvas.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "   •••   ",
+            "  •••••  ",
+            "  •••••  ",
+            "  •••••  ",
+            "   •••   ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
 }
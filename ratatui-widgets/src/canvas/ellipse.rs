@@ -0,0 +1,116 @@
+use core::f64::consts::TAU;
+
+use ratatui_core::style::Color;
+
+use crate::canvas::polygon::{curve_steps, draw_path};
+use crate::canvas::{Painter, Shape};
+#[cfg(not(feature = "std"))]
+use crate::polyfills::F64Polyfills;
+
+/// An ellipse with a given center and radii along the `x` and `y` axes and with a given color
+///
+/// As the cells of a terminal are taller than they are wide, an ellipse is useful to draw a round
+/// shape on a canvas whose bounds do not match the aspect ratio of its area.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Color;
+/// # use ratatui_widgets::canvas::{Canvas, Ellipse};
+/// Canvas::default().paint(|ctx| {
+///     ctx.draw(&Ellipse::new(0.0, 0.0, 2.0, 1.0, Color::Red));
+/// });
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Ellipse {
+    /// `x` coordinate of the ellipse's center
+    pub x: f64,
+    /// `y` coordinate of the ellipse's center
+    pub y: f64,
+    /// Radius of the ellipse along the `x` axis
+    pub x_radius: f64,
+    /// Radius of the ellipse along the `y` axis
+    pub y_radius: f64,
+    /// Color of the ellipse
+    pub color: Color,
+}
+
+impl Ellipse {
+    /// Create a new ellipse with the given center, radii, and color
+    pub const fn new(x: f64, y: f64, x_radius: f64, y_radius: f64, color: Color) -> Self {
+        Self {
+            x,
+            y,
+            x_radius,
+            y_radius,
+            color,
+        }
+    }
+}
+
+impl Shape for Ellipse {
+    fn draw(&self, painter: &mut Painter) {
+        let points = ellipse_points(
+            painter,
+            self.x,
+            self.y,
+            self.x_radius,
+            self.y_radius,
+            0.0,
+            TAU,
+        );
+        draw_path(painter, points, self.color);
+    }
+}
+
+/// Returns the points of an elliptic arc from the `start` angle to the `end` angle, in radians,
+/// with a point for about each point of the grid.
+pub(super) fn ellipse_points(
+    painter: &Painter,
+    x: f64,
+    y: f64,
+    x_radius: f64,
+    y_radius: f64,
+    start: f64,
+    end: f64,
+) -> impl Iterator<Item = (f64, f64)> + use<> {
+    let sweep = end - start;
+    let steps = curve_steps(painter, (x_radius * sweep, y_radius * sweep));
+    (0..=steps).map(move |step| {
+        let angle = (step as f64 / steps as f64).mul_add(sweep, start);
+        (
+            x_radius.mul_add(angle.cos(), x),
+            y_radius.mul_add(angle.sin(), y),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::buffer::Buffer;
+    use ratatui_core::layout::Rect;
+    use ratatui_core::symbols::Marker;
+    use ratatui_core::widgets::Widget;
+
+    use super::*;
+    use crate::canvas::Canvas;
+
+    #[test]
+    fn draws_an_ellipse() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 5));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([-4.0, 4.0])
+            .y_bounds([-2.0, 2.0])
+            .paint(|ctx| ctx.draw(&Ellipse::new(0.0, 0.0, 4.0, 2.0, Color::Reset)))
+            .render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "  •••••  ",
+            "••     ••",
+            "•       •",
+            "••     ••",
+            "  •••••  ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+}
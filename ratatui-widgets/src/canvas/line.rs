@@ -46,16 +46,20 @@ impl Line {
 impl Shape for Line {
     #[expect(clippy::similar_names)]
     fn draw(&self, painter: &mut Painter) {
+        // the line is clipped in the coordinate system of the canvas, after the transform
+        let transform = painter.transform();
+        let (x1, y1) = transform.apply(self.x1, self.y1);
+        let (x2, y2) = transform.apply(self.x2, self.y2);
         let (x_bounds, y_bounds) = painter.bounds();
         let Some((world_x1, world_y1, world_x2, world_y2)) =
-            clip_line(x_bounds, y_bounds, self.x1, self.y1, self.x2, self.y2)
+            clip_line(x_bounds, y_bounds, x1, y1, x2, y2)
         else {
             return;
         };
-        let Some((x1, y1)) = painter.get_point(world_x1, world_y1) else {
+        let Some((x1, y1)) = painter.grid_point(world_x1, world_y1) else {
             return;
         };
-        let Some((x2, y2)) = painter.get_point(world_x2, world_y2) else {
+        let Some((x2, y2)) = painter.grid_point(world_x2, world_y2) else {
             return;
         };
 
@@ -205,21 +209,26 @@ impl FilledLine {
 impl Shape for FilledLine {
     #[expect(clippy::similar_names)]
     fn draw(&self, painter: &mut Painter) {
+        // the line is clipped in the coordinate system of the canvas, after the transform
+        let transform = painter.transform();
+        let (x1, y1) = transform.apply(self.x1, self.y1);
+        let (x2, y2) = transform.apply(self.x2, self.y2);
         let (x_bounds, y_bounds) = painter.bounds();
         let Some((world_x1, world_y1, world_x2, world_y2)) =
-            clip_line(x_bounds, y_bounds, self.x1, self.y1, self.x2, self.y2)
+            clip_line(x_bounds, y_bounds, x1, y1, x2, y2)
         else {
             return;
         };
-        let Some((x1, y1)) = painter.get_point(world_x1, world_y1) else {
+        let Some((x1, y1)) = painter.grid_point(world_x1, world_y1) else {
             return;
         };
-        let Some((x2, y2)) = painter.get_point(world_x2, world_y2) else {
+        let Some((x2, y2)) = painter.grid_point(world_x2, world_y2) else {
             return;
         };
 
-        let y_fill = self.fill_to_y.clamp(y_bounds[0], y_bounds[1]);
-        let Some((_, y_fill)) = painter.get_point(world_x1, y_fill) else {
+        let (_, y_fill) = transform.apply(self.x1, self.fill_to_y);
+        let y_fill = y_fill.clamp(y_bounds[0], y_bounds[1]);
+        let Some((_, y_fill)) = painter.grid_point(world_x1, y_fill) else {
             return;
         };
 
@@ -0,0 +1,286 @@
+use alloc::vec::Vec;
+
+use itertools::Itertools;
+use ratatui_core::style::Color;
+
+use crate::canvas::{Line, Painter, Shape};
+#[cfg(not(feature = "std"))]
+use crate::polyfills::F64Polyfills;
+
+/// The maximum number of segments used to draw a curve
+const MAX_STEPS: f64 = 4096.0;
+
+/// An open path joining a list of points with straight lines
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Color;
+/// # use ratatui_widgets::canvas::{Canvas, Polyline};
+/// Canvas::default().paint(|ctx| {
+///     ctx.draw(&Polyline::new(
+///         &[(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)],
+///         Color::Red,
+///     ));
+/// });
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Polyline<'a> {
+    /// List of the points of the path
+    pub coords: &'a [(f64, f64)],
+    /// Color of the path
+    pub color: Color,
+}
+
+impl<'a> Polyline<'a> {
+    /// Create a new polyline joining the given points with the given color
+    pub const fn new(coords: &'a [(f64, f64)], color: Color) -> Self {
+        Self { coords, color }
+    }
+}
+
+impl Shape for Polyline<'_> {
+    fn draw(&self, painter: &mut Painter) {
+        draw_path(painter, self.coords.iter().copied(), self.color);
+    }
+}
+
+/// The outline of a polygon, which joins the last point back to the first one
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Color;
+/// # use ratatui_widgets::canvas::{Canvas, Polygon};
+/// Canvas::default().paint(|ctx| {
+///     ctx.draw(&Polygon::new(
+///         &[(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)],
+///         Color::Red,
+///     ));
+/// });
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Polygon<'a> {
+    /// List of the vertices of the polygon
+    pub coords: &'a [(f64, f64)],
+    /// Color of the outline
+    pub color: Color,
+}
+
+impl<'a> Polygon<'a> {
+    /// Create a new polygon with the given vertices and color
+    pub const fn new(coords: &'a [(f64, f64)], color: Color) -> Self {
+        Self { coords, color }
+    }
+}
+
+impl Shape for Polygon<'_> {
+    fn draw(&self, painter: &mut Painter) {
+        let closing = self.coords.first().copied();
+        draw_path(
+            painter,
+            self.coords.iter().copied().chain(closing),
+            self.color,
+        );
+    }
+}
+
+/// A polygon filled with the given color
+///
+/// The polygon is filled one row of the grid at a time using the even-odd rule, so
+/// self-intersecting polygons have holes where they overlap. The fill uses the resolution of the
+/// [`Marker`](ratatui_core::symbols::Marker) of the canvas.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Color;
+/// # use ratatui_widgets::canvas::{Canvas, FilledPolygon};
+/// Canvas::default().paint(|ctx| {
+///     ctx.draw(&FilledPolygon::new(
+///         &[(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)],
+///         Color::Red,
+///     ));
+/// });
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FilledPolygon<'a> {
+    /// List of the vertices of the polygon
+    pub coords: &'a [(f64, f64)],
+    /// Color of the polygon
+    pub color: Color,
+}
+
+impl<'a> FilledPolygon<'a> {
+    /// Create a new filled polygon with the given vertices and color
+    pub const fn new(coords: &'a [(f64, f64)], color: Color) -> Self {
+        Self { coords, color }
+    }
+}
+
+impl Shape for FilledPolygon<'_> {
+    fn draw(&self, painter: &mut Painter) {
+        fill_polygon(painter, self.coords, self.color);
+        // the outline covers the edges that fall between the rows of the grid
+        Polygon::new(self.coords, self.color).draw(painter);
+    }
+}
+
+/// Draws straight lines between consecutive points
+pub(super) fn draw_path<I>(painter: &mut Painter, points: I, color: Color)
+where
+    I: IntoIterator<Item = (f64, f64)>,
+{
+    for ((x1, y1), (x2, y2)) in points.into_iter().tuple_windows() {
+        Line::new(x1, y1, x2, y2, color).draw(painter);
+    }
+}
+
+/// Fills a polygon with a scanline on each row of the grid.
+///
+/// A point of the grid is filled when it is between an odd number of edges on its left.
+pub(super) fn fill_polygon(painter: &mut Painter, coords: &[(f64, f64)], color: Color) {
+    let (&[left, right], &[bottom, top]) = painter.bounds();
+    let (columns, rows) = painter.resolution;
+    if coords.len() < 3 || right <= left || top <= bottom {
+        return;
+    }
+    let transform = painter.transform();
+    let coords = coords
+        .iter()
+        .map(|&(x, y)| transform.apply(x, y))
+        .collect::<Vec<_>>();
+    let x_scale = (columns - 1.0) / (right - left);
+    let y_step = (top - bottom) / (rows - 1.0).max(1.0);
+
+    let edges = coords.iter().copied().circular_tuple_windows::<(_, _)>();
+    let mut crossings = Vec::new();
+    for row in 0..rows as usize {
+        // the inverse of `Painter::get_point` for the row
+        let y = (row as f64).mul_add(-y_step, top);
+        crossings.clear();
+        // the edges are half-open, so that a vertex on the row is crossed once
+        crossings.extend(
+            edges
+                .clone()
+                .filter(|&((_, y1), (_, y2))| (y1 <= y) != (y2 <= y))
+                .map(|((x1, y1), (x2, y2))| (y - y1).mul_add((x2 - x1) / (y2 - y1), x1)),
+        );
+        crossings.sort_by(f64::total_cmp);
+        for pair in crossings.chunks_exact(2) {
+            let start = ((pair[0] - left) * x_scale).ceil().max(0.0);
+            let end = ((pair[1] - left) * x_scale).floor().min(columns - 1.0);
+            if start > end {
+                continue;
+            }
+            for x in start as usize..=end as usize {
+                painter.paint(x, row, color);
+            }
+        }
+    }
+}
+
+/// Returns the number of segments needed to draw a curve whose points are at most `length`
+/// apart along the x and y axes, so that each segment spans about one point of the grid.
+///
+/// The length is measured after the transform of the painter.
+pub(super) fn curve_steps(painter: &Painter, length: (f64, f64)) -> usize {
+    let (x_length, y_length) = painter.transform().stretch(length);
+    let (&[left, right], &[bottom, top]) = painter.bounds();
+    let (columns, rows) = painter.resolution;
+    let x_pixels = x_length.abs() * (columns - 1.0) / (right - left);
+    let y_pixels = y_length.abs() * (rows - 1.0) / (top - bottom);
+    let steps = (x_pixels + y_pixels).ceil();
+    if steps.is_finite() {
+        steps.clamp(1.0, MAX_STEPS) as usize
+    } else {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::buffer::Buffer;
+    use ratatui_core::layout::Rect;
+    use ratatui_core::symbols::Marker;
+    use ratatui_core::widgets::Widget;
+
+    use super::*;
+    use crate::canvas::Canvas;
+
+    fn render(shape: &impl Shape) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 5));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 8.0])
+            .y_bounds([0.0, 4.0])
+            .paint(|ctx| ctx.draw(shape))
+            .render(buffer.area, &mut buffer);
+        buffer
+    }
+
+    const TRIANGLE: [(f64, f64); 3] = [(0.0, 0.0), (4.0, 4.0), (8.0, 0.0)];
+
+    #[test]
+    fn draws_a_polyline() {
+        let buffer = render(&Polyline::new(&TRIANGLE, Color::Reset));
+        let expected = Buffer::with_lines([
+            "    •    ",
+            "   • •   ",
+            "  •   •  ",
+            " •     • ",
+            "•       •",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn draws_a_polygon() {
+        let buffer = render(&Polygon::new(&TRIANGLE, Color::Reset));
+        let expected = Buffer::with_lines([
+            "    •    ",
+            "   • •   ",
+            "  •   •  ",
+            " •     • ",
+            "•••••••••",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn draws_a_filled_polygon() {
+        let buffer = render(&FilledPolygon::new(&TRIANGLE, Color::Reset));
+        let expected = Buffer::with_lines([
+            "    •    ",
+            "   •••   ",
+            "  •••••  ",
+            " ••••••• ",
+            "•••••••••",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn fills_self_intersecting_polygons_with_holes() {
+        // two overlapping rectangles drawn as a single polygon
+        let coords = [
+            (0.0, 0.0),
+            (6.0, 0.0),
+            (6.0, 4.0),
+            (2.0, 4.0),
+            (2.0, 1.0),
+            (8.0, 1.0),
+            (8.0, 3.0),
+            (0.0, 3.0),
+        ];
+        let buffer = render(&FilledPolygon::new(&coords, Color::Reset));
+        let expected = Buffer::with_lines([
+            "  •••••  ",
+            "•••••••••",
+            "•••   •••",
+            "•••••••••",
+            "•••••••  ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+}
@@ -1,5 +1,6 @@
 use ratatui_core::style::Color;
 
+use crate::canvas::polygon::fill_polygon;
 use crate::canvas::{Line, Painter, Shape};
 
 /// A rectangle to draw on a [`Canvas`](crate::canvas::Canvas)
@@ -75,6 +76,54 @@ impl Shape for Rectangle {
     }
 }
 
+/// A rectangle filled with the given color
+///
+/// The rectangle is positioned from its bottom left corner, like a [`Rectangle`].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FilledRectangle {
+    /// The `x` position of the rectangle.
+    ///
+    /// The rectangle is positioned from its bottom left corner.
+    pub x: f64,
+    /// The `y` position of the rectangle.
+    ///
+    /// The rectangle is positioned from its bottom left corner.
+    pub y: f64,
+    /// The width of the rectangle.
+    pub width: f64,
+    /// The height of the rectangle.
+    pub height: f64,
+    /// The color of the rectangle.
+    pub color: Color,
+}
+
+impl FilledRectangle {
+    /// Create a new filled rectangle with the given position, size, and color
+    pub const fn new(x: f64, y: f64, width: f64, height: f64, color: Color) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            color,
+        }
+    }
+}
+
+impl Shape for FilledRectangle {
+    fn draw(&self, painter: &mut Painter) {
+        let (right, top) = (self.x + self.width, self.y + self.height);
+        let corners = [
+            (self.x, self.y),
+            (self.x, top),
+            (right, top),
+            (right, self.y),
+        ];
+        fill_polygon(painter, &corners, self.color);
+        Rectangle::new(self.x, self.y, self.width, self.height, self.color).draw(painter);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use ratatui_core::buffer::Buffer;
@@ -236,4 +285,19 @@ mod tests {
         canvas.render(buffer.area, &mut buffer);
         buffer
     }
+
+    #[test]
+    fn draw_filled_rectangle() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 4));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 5.0])
+            .y_bounds([0.0, 3.0])
+            .paint(|context| {
+                context.draw(&FilledRectangle::new(1.0, 1.0, 3.0, 1.0, Color::Reset));
+            })
+            .render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["      ", " •••• ", " •••• ", "      "]);
+        assert_eq!(buffer, expected);
+    }
 }
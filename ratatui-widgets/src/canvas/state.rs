@@ -0,0 +1,250 @@
+use ratatui_core::layout::{Position, Rect};
+
+#[cfg(not(feature = "std"))]
+use crate::polyfills::F64Polyfills;
+
+/// The factor by which [`CanvasState::zoom_in`] and [`CanvasState::zoom_out`] zoom the canvas
+const ZOOM_STEP: f64 = 1.5;
+
+/// State of a [`Canvas`] widget
+///
+/// This state holds the viewport of a canvas rendered as a [`StatefulWidget`]. The
+/// [zoom](CanvasState::zoom) and the [pan](CanvasState::pan) are relative to the `x` and `y`
+/// bounds of the canvas, so the shapes do not need to change to explore them.
+///
+/// The state also records the area and the bounds of the last render, which maps the positions of
+/// the terminal back to the coordinates of the canvas with [`CanvasState::canvas_point`], e.g. to
+/// find the shape under the mouse.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::layout::Position;
+/// use ratatui::style::Color;
+/// use ratatui::widgets::canvas::{Canvas, CanvasState, Circle};
+///
+/// # fn ui(frame: &mut Frame) {
+/// let canvas = Canvas::default()
+///     .x_bounds([-10.0, 10.0])
+///     .y_bounds([-10.0, 10.0])
+///     .paint(|ctx| ctx.draw(&Circle::new(0.0, 0.0, 5.0, Color::Red)));
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = CanvasState::default();
+/// state.zoom_in();
+/// state.pan(0.25, 0.0);
+///
+/// frame.render_stateful_widget(canvas, frame.area(), &mut state);
+/// if let Some((x, y)) = state.canvas_point(Position::new(10, 5)) {
+///     // (x, y) are the coordinates of the canvas under the position
+/// }
+/// # }
+/// ```
+///
+/// [`Canvas`]: super::Canvas
+/// [`StatefulWidget`]: ratatui_core::widgets::StatefulWidget
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CanvasState {
+    /// The zoom factors along the `x` and `y` axes
+    zoom: [f64; 2],
+    /// The center of the view, relative to the center of the bounds of the canvas, in fractions
+    /// of their range
+    center: [f64; 2],
+    /// The area of the canvas at the last render, inside its block
+    area: Rect,
+    /// The `x` and `y` bounds of the canvas at the last render
+    visible_bounds: [[f64; 2]; 2],
+}
+
+impl Default for CanvasState {
+    fn default() -> Self {
+        Self {
+            zoom: [1.0, 1.0],
+            center: [0.0, 0.0],
+            area: Rect::ZERO,
+            visible_bounds: [[0.0, 0.0]; 2],
+        }
+    }
+}
+
+impl CanvasState {
+    /// Returns the zoom factors along the `x` and `y` axes
+    ///
+    /// A factor of `1.0` displays the bounds of the canvas, and a factor of `2.0` displays half of
+    /// them.
+    pub const fn zoom_factors(&self) -> [f64; 2] {
+        self.zoom
+    }
+
+    /// Zooms along the `x` and `y` axes around the center of the view by the given factors
+    ///
+    /// A factor greater than `1.0` zooms in, and a factor less than `1.0` zooms out. Factors that
+    /// are not strictly positive are ignored.
+    pub fn zoom(&mut self, x_factor: f64, y_factor: f64) {
+        for (zoom, factor) in self.zoom.iter_mut().zip([x_factor, y_factor]) {
+            if factor > 0.0 && factor.is_finite() {
+                *zoom *= factor;
+            }
+        }
+    }
+
+    /// Zooms both axes by the given factor, keeping the point under `position` at the same place
+    ///
+    /// This is useful to zoom with the mouse wheel. The position is relative to the area of the
+    /// last render; when it is outside of it, the canvas is zoomed around the center of the view.
+    pub fn zoom_at(&mut self, position: Position, factor: f64) {
+        if !(factor > 0.0 && factor.is_finite()) {
+            return;
+        }
+        let anchor = self.fractions(position);
+        for axis in 0..2 {
+            let offset = anchor.map_or(0.0, |anchor| anchor[axis] - 0.5);
+            let value = self.center[axis] + offset / self.zoom[axis];
+            self.zoom[axis] *= factor;
+            self.center[axis] = value - offset / self.zoom[axis];
+        }
+    }
+
+    /// Zooms in both axes around the center of the view
+    pub fn zoom_in(&mut self) {
+        self.zoom(ZOOM_STEP, ZOOM_STEP);
+    }
+
+    /// Zooms out both axes around the center of the view
+    pub fn zoom_out(&mut self) {
+        self.zoom(1.0 / ZOOM_STEP, 1.0 / ZOOM_STEP);
+    }
+
+    /// Returns the offsets of the center of the view from the center of the bounds of the canvas,
+    /// in fractions of their range
+    pub const fn pan_offsets(&self) -> [f64; 2] {
+        self.center
+    }
+
+    /// Moves the view by the given fractions of the visible range
+    ///
+    /// Positive values move the view to the right and to the top, e.g. `pan(0.5, 0.0)` moves
+    /// the view by half of its width to the right.
+    pub fn pan(&mut self, dx: f64, dy: f64) {
+        for ((center, zoom), delta) in self.center.iter_mut().zip(self.zoom).zip([dx, dy]) {
+            if delta.is_finite() {
+                *center += delta / zoom;
+            }
+        }
+    }
+
+    /// Resets the zoom and the pan, to display the bounds of the canvas
+    pub const fn reset_view(&mut self) {
+        self.zoom = [1.0, 1.0];
+        self.center = [0.0, 0.0];
+    }
+
+    /// Returns the area of the canvas at the last render, inside its block
+    pub const fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Returns the `x` and `y` bounds displayed at the last render
+    pub const fn visible_bounds(&self) -> [[f64; 2]; 2] {
+        self.visible_bounds
+    }
+
+    /// Returns the coordinates of the canvas displayed at the given position of the terminal at
+    /// the last render, or `None` if the position is outside of the canvas
+    ///
+    /// The coordinates are those of the canvas itself: a shape drawn with a transform of the
+    /// [`Context`](super::Context) can map them back to its own coordinates with the
+    /// [inverse](super::Transform::inverse) of the transform.
+    pub fn canvas_point(&self, position: Position) -> Option<(f64, f64)> {
+        let [x, y] = self.fractions(position)?;
+        let [[left, right], [bottom, top]] = self.visible_bounds;
+        Some((
+            x.mul_add(right - left, left),
+            y.mul_add(top - bottom, bottom),
+        ))
+    }
+
+    /// Returns the bounds displayed on an axis, given the bounds of the canvas on that axis
+    pub(crate) fn view(&self, axis: usize, [min, max]: [f64; 2]) -> [f64; 2] {
+        let range = max - min;
+        let center = f64::midpoint(min, max) + self.center[axis] * range;
+        let half = range / 2.0 / self.zoom[axis];
+        [center - half, center + half]
+    }
+
+    /// Records the layout of the last render
+    pub(crate) const fn update(&mut self, area: Rect, visible_bounds: [[f64; 2]; 2]) {
+        self.area = area;
+        self.visible_bounds = visible_bounds;
+    }
+
+    /// Returns the position in the area of the last render, from `0.0` to `1.0` from left to right
+    /// and from bottom to top
+    fn fractions(&self, position: Position) -> Option<[f64; 2]> {
+        let area = self.area;
+        if !area.contains(position) {
+            return None;
+        }
+        let fraction = |offset: u16, length: u16| {
+            if length > 1 {
+                f64::from(offset) / f64::from(length - 1)
+            } else {
+                0.5
+            }
+        };
+        Some([
+            fraction(position.x - area.x, area.width),
+            1.0 - fraction(position.y - area.y, area.height),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[expect(clippy::float_cmp)]
+    fn zoom_and_pan() {
+        let mut state = CanvasState::default();
+        state.zoom(2.0, 1.0);
+        assert_eq!(state.view(0, [0.0, 100.0]), [25.0, 75.0]);
+        assert_eq!(state.view(1, [0.0, 100.0]), [0.0, 100.0]);
+        state.pan(0.5, -0.5);
+        assert_eq!(state.view(0, [0.0, 100.0]), [50.0, 100.0]);
+        assert_eq!(state.view(1, [0.0, 100.0]), [-50.0, 50.0]);
+        state.reset_view();
+        assert_eq!(state, CanvasState::default());
+    }
+
+    #[test]
+    fn zoom_ignores_invalid_factors() {
+        let mut state = CanvasState::default();
+        state.zoom(0.0, f64::NAN);
+        state.zoom_at(Position::ORIGIN, -1.0);
+        assert_eq!(state, CanvasState::default());
+    }
+
+    #[test]
+    #[expect(clippy::float_cmp)]
+    fn zoom_at_keeps_the_point_under_the_position() {
+        let mut state = CanvasState::default();
+        state.update(Rect::new(0, 0, 11, 11), [[0.0, 10.0]; 2]);
+        state.zoom_at(Position::new(10, 10), 2.0);
+        // the bottom right corner stays at the bottom right
+        assert_eq!(state.view(0, [0.0, 10.0]), [5.0, 10.0]);
+        assert_eq!(state.view(1, [0.0, 10.0]), [0.0, 5.0]);
+    }
+
+    #[test]
+    fn canvas_point_maps_positions_to_coordinates() {
+        let mut state = CanvasState::default();
+        state.update(Rect::new(2, 1, 11, 6), [[-10.0, 10.0], [0.0, 50.0]]);
+        assert_eq!(state.canvas_point(Position::new(2, 1)), Some((-10.0, 50.0)));
+        assert_eq!(state.canvas_point(Position::new(7, 3)), Some((0.0, 30.0)));
+        assert_eq!(state.canvas_point(Position::new(12, 6)), Some((10.0, 0.0)));
+        assert_eq!(state.canvas_point(Position::new(13, 6)), None);
+    }
+}
@@ -0,0 +1,174 @@
+#[cfg(not(feature = "std"))]
+use crate::polyfills::F64Polyfills;
+
+/// An affine transform of the coordinates of a [`Canvas`](super::Canvas)
+///
+/// A transform maps the coordinates given to the shapes to the coordinate system of the canvas,
+/// which the `x` and `y` bounds of the canvas then map to the grid. Transforms are usually built
+/// with the [`Context::translate`], [`Context::scale`] and [`Context::rotate`] methods while
+/// painting, but they can also be combined by hand with [`Transform::then`].
+///
+/// [`Context::translate`]: super::Context::translate
+/// [`Context::scale`]: super::Context::scale
+/// [`Context::rotate`]: super::Context::rotate
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::widgets::canvas::Transform;
+///
+/// let transform = Transform::rotation(90.0).then(Transform::translation(1.0, 0.0));
+/// let (x, y) = transform.apply(1.0, 0.0);
+/// assert!((x - 1.0).abs() < 1e-9 && (y - 1.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    /// The linear part of the transform, row by row
+    matrix: [[f64; 2]; 2],
+    /// The translation applied after the linear part
+    offset: [f64; 2],
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Transform {
+    /// The transform that leaves the coordinates unchanged
+    pub const IDENTITY: Self = Self {
+        matrix: [[1.0, 0.0], [0.0, 1.0]],
+        offset: [0.0, 0.0],
+    };
+
+    /// Creates a transform that moves the coordinates by `dx` and `dy`
+    pub const fn translation(dx: f64, dy: f64) -> Self {
+        Self {
+            matrix: Self::IDENTITY.matrix,
+            offset: [dx, dy],
+        }
+    }
+
+    /// Creates a transform that scales the coordinates by `sx` and `sy` around the origin
+    pub const fn scaling(sx: f64, sy: f64) -> Self {
+        Self {
+            matrix: [[sx, 0.0], [0.0, sy]],
+            offset: [0.0, 0.0],
+        }
+    }
+
+    /// Creates a transform that rotates the coordinates around the origin by the given angle, in
+    /// degrees
+    ///
+    /// As the `y` axis of the canvas points up, positive angles rotate counter-clockwise.
+    pub fn rotation(degrees: f64) -> Self {
+        let (sin, cos) = (degrees.to_radians().sin(), degrees.to_radians().cos());
+        Self {
+            matrix: [[cos, -sin], [sin, cos]],
+            offset: [0.0, 0.0],
+        }
+    }
+
+    /// Returns the transform that applies this transform and then the `next` one
+    #[must_use = "method returns the combined transform and does not modify self"]
+    pub fn then(self, next: Self) -> Self {
+        let column = |index: usize| next.linear(self.matrix[0][index], self.matrix[1][index]);
+        let (first, second) = (column(0), column(1));
+        let (x, y) = next.apply(self.offset[0], self.offset[1]);
+        Self {
+            matrix: [[first.0, second.0], [first.1, second.1]],
+            offset: [x, y],
+        }
+    }
+
+    /// Maps the point `(x, y)` with this transform
+    pub fn apply(&self, x: f64, y: f64) -> (f64, f64) {
+        let (x, y) = self.linear(x, y);
+        (x + self.offset[0], y + self.offset[1])
+    }
+
+    /// Returns the transform that undoes this one, or `None` if this transform collapses the
+    /// plane (e.g. a scaling by `0.0`)
+    ///
+    /// This is useful to map a position on the canvas, e.g. from
+    /// [`CanvasState::canvas_point`](super::CanvasState::canvas_point), back to the coordinates
+    /// of a transformed shape.
+    pub fn inverse(&self) -> Option<Self> {
+        let [[xx, xy], [yx, yy]] = self.matrix;
+        let determinant = xx.mul_add(yy, -(xy * yx));
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+        let linear = Self {
+            matrix: [
+                [yy / determinant, -xy / determinant],
+                [-yx / determinant, xx / determinant],
+            ],
+            offset: [0.0, 0.0],
+        };
+        let (x, y) = linear.apply(-self.offset[0], -self.offset[1]);
+        Some(Self {
+            offset: [x, y],
+            ..linear
+        })
+    }
+
+    /// Returns the extent along the `x` and `y` axes of a vector whose extents are `x` and `y`
+    /// before the transform
+    pub(super) fn stretch(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        let [[xx, xy], [yx, yy]] = self.matrix;
+        (
+            xx.abs().mul_add(x.abs(), xy.abs() * y.abs()),
+            yx.abs().mul_add(x.abs(), yy.abs() * y.abs()),
+        )
+    }
+
+    /// Maps the vector `(x, y)` with the linear part of this transform
+    fn linear(&self, x: f64, y: f64) -> (f64, f64) {
+        let [[xx, xy], [yx, yy]] = self.matrix;
+        (xx.mul_add(x, xy * y), yx.mul_add(x, yy * y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near((x, y): (f64, f64), (expected_x, expected_y): (f64, f64)) {
+        assert!(
+            (x - expected_x).abs() < 1e-9 && (y - expected_y).abs() < 1e-9,
+            "({x}, {y}) != ({expected_x}, {expected_y})"
+        );
+    }
+
+    #[test]
+    fn applies_transforms() {
+        assert_near(Transform::IDENTITY.apply(1.0, 2.0), (1.0, 2.0));
+        assert_near(
+            Transform::translation(1.0, -1.0).apply(1.0, 2.0),
+            (2.0, 1.0),
+        );
+        assert_near(Transform::scaling(2.0, 3.0).apply(1.0, 2.0), (2.0, 6.0));
+        assert_near(Transform::rotation(90.0).apply(1.0, 2.0), (-2.0, 1.0));
+    }
+
+    #[test]
+    fn combines_transforms_in_order() {
+        let scale_then_move = Transform::scaling(2.0, 2.0).then(Transform::translation(1.0, 0.0));
+        assert_near(scale_then_move.apply(1.0, 1.0), (3.0, 2.0));
+        let move_then_scale = Transform::translation(1.0, 0.0).then(Transform::scaling(2.0, 2.0));
+        assert_near(move_then_scale.apply(1.0, 1.0), (4.0, 2.0));
+    }
+
+    #[test]
+    fn inverse_undoes_the_transform() {
+        let transform = Transform::scaling(2.0, 0.5)
+            .then(Transform::rotation(30.0))
+            .then(Transform::translation(3.0, -4.0));
+        let inverse = transform.inverse().unwrap();
+        let (x, y) = transform.apply(1.5, -2.5);
+        assert_near(inverse.apply(x, y), (1.5, -2.5));
+        assert_eq!(Transform::scaling(0.0, 1.0).inverse(), None);
+    }
+}
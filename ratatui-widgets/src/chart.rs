@@ -1,18 +1,43 @@
 //! The [`Chart`] widget is used to plot one or more [`Dataset`] in a cartesian coordinate system.
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec;
 use alloc::vec::Vec;
 use core::cmp::max;
+use core::fmt;
 use core::ops::Not;
+use core::panic::{RefUnwindSafe, UnwindSafe};
 
+use itertools::Itertools;
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::{Alignment, Constraint, Flex, Layout, Position, Rect};
-use ratatui_core::style::{Color, Style, Styled};
+use ratatui_core::style::{Color, Style, Styled, Theme};
 use ratatui_core::symbols;
-use ratatui_core::text::Line;
-use ratatui_core::widgets::Widget;
+use ratatui_core::text::{Line, Span};
+use ratatui_core::widgets::{StatefulWidget, Widget};
 use strum::{Display, EnumString};
+use unicode_width::UnicodeWidthStr;
 
+pub use self::scale::AxisScale;
+pub use self::series::{BoxPlot, Candle, ErrorBar};
+use self::series::{SeriesItem, draw_series};
+pub use self::state::ChartState;
 use crate::block::{Block, BlockExt};
 use crate::canvas::{Canvas, FilledLine, Line as CanvasLine, Points};
+use crate::clear::Clear;
+#[cfg(not(feature = "std"))]
+use crate::polyfills::F64Polyfills;
+
+mod scale;
+mod series;
+mod state;
+
+/// The symbol displayed in the legend before the name of the datasets of the primary Y axis
+const PRIMARY_AXIS_SYMBOL: &str = "◂ ";
+
+/// The symbol displayed in the legend before the name of the datasets of the secondary Y axis
+const SECONDARY_AXIS_SYMBOL: &str = "▸ ";
 
 /// An X or Y axis for the [`Chart`] widget
 ///
@@ -48,6 +73,39 @@ pub struct Axis<'a> {
     style: Style,
     /// The alignment of the labels of the Axis
     labels_alignment: Alignment,
+    /// The scale used to place the values on the axis
+    scale: AxisScale,
+    /// Whether the bounds are computed from the data of the datasets
+    auto_bounds: bool,
+    /// Whether the labels are generated at ticks chosen from the bounds
+    auto_ticks: bool,
+    /// The function used to format the automatic ticks
+    label_formatter: LabelFormatter,
+    /// The values at which the labels are displayed, or empty if the labels are evenly spaced
+    ticks: Vec<f64>,
+}
+
+/// The function used to format the labels of the automatic ticks of an [`Axis`], if any
+///
+/// A function can't be compared, so the formatter is left out of the comparison of the axes.
+#[derive(Default, Clone)]
+struct LabelFormatter(
+    Option<Arc<dyn Fn(f64) -> String + Send + Sync + UnwindSafe + RefUnwindSafe>>,
+);
+
+impl fmt::Debug for LabelFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(_) => f.write_str("Some(..)"),
+            None => f.write_str("None"),
+        }
+    }
+}
+
+impl PartialEq for LabelFormatter {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
 }
 
 impl<'a> Axis<'a> {
@@ -139,6 +197,15 @@ impl<'a> Axis<'a> {
         self
     }
 
+    /// Sets the style of the axis from the `chart.axis` style of a [`Theme`]
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style("chart.axis");
+        self
+    }
+
     /// Sets the labels alignment of the axis
     ///
     /// The alignment behaves differently based on the axis:
@@ -151,6 +218,224 @@ impl<'a> Axis<'a> {
         self.labels_alignment = alignment;
         self
     }
+
+    /// Sets the scale of the axis
+    ///
+    /// The default is [`AxisScale::Linear`]. With a [log](AxisScale::Log) or
+    /// [symlog](AxisScale::Symlog) scale, the data of the datasets and the bounds of the axis are
+    /// transformed before being displayed, and the data points that can't be displayed (e.g.
+    /// negative values on a log scale) are skipped. A [time](AxisScale::Time) axis displays
+    /// timestamps in seconds since the Unix epoch, and its automatic ticks are labeled with dates
+    /// and times.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Axis, AxisScale};
+    ///
+    /// let axis = Axis::default()
+    ///     .scale(AxisScale::Log)
+    ///     .bounds([1.0, 1000.0])
+    ///     .auto_ticks(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn scale(mut self, scale: AxisScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Computes the bounds of the axis from the data of the datasets
+    ///
+    /// When enabled, the [bounds](Axis::bounds) are replaced, each time the chart is rendered, by
+    /// the smallest and largest values of the data on this axis that the [scale](Axis::scale) can
+    /// display. With [automatic ticks](Axis::auto_ticks), the bounds are then extended to the
+    /// ticks around them.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Axis, Chart, Dataset};
+    ///
+    /// let data = [(0.0, 3.0), (1.0, 17.0)];
+    /// let chart = Chart::new(vec![Dataset::default().data(&data)])
+    ///     .x_axis(Axis::default().auto_bounds(true))
+    ///     // displays the labels 0, 5, 10, 15 and 20
+    ///     .y_axis(Axis::default().auto_bounds(true).auto_ticks(true));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn auto_bounds(mut self, auto_bounds: bool) -> Self {
+        self.auto_bounds = auto_bounds;
+        self
+    }
+
+    /// Generates the labels of the axis at "nice" values between its bounds
+    ///
+    /// When enabled, the [labels](Axis::labels) are replaced, each time the chart is rendered, by
+    /// labels displayed at the values of ticks chosen for the [scale](Axis::scale) and the size
+    /// of the chart:
+    ///
+    /// - on a linear axis, the ticks are multiples of 1, 2 or 5 times a power of ten
+    /// - on a log or symlog axis, the ticks are powers of ten
+    /// - on a time axis, the ticks are round seconds, minutes, hours or days
+    ///
+    /// Unlike the labels given to [`Axis::labels`], which are evenly spaced, each label is
+    /// displayed at the position of its value, and the labels that would overlap are skipped.
+    /// The labels are formatted for the scale, or with the [`Axis::label_formatter`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Axis;
+    ///
+    /// let axis = Axis::default().bounds([0.0, 1.0]).auto_ticks(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn auto_ticks(mut self, auto_ticks: bool) -> Self {
+        self.auto_ticks = auto_ticks;
+        self
+    }
+
+    /// Sets the function used to format the labels of the [automatic ticks](Axis::auto_ticks)
+    ///
+    /// By default, the labels are formatted with the number of decimals needed to tell the ticks
+    /// apart, in scientific notation for the large and small powers of ten, and as dates or times
+    /// on a time axis.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Axis;
+    ///
+    /// let unit = "ms";
+    /// let axis = Axis::default()
+    ///     .bounds([0.0, 1000.0])
+    ///     .auto_ticks(true)
+    ///     .label_formatter(move |value| format!("{value} {unit}"));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label_formatter<F>(mut self, formatter: F) -> Self
+    where
+        F: Fn(f64) -> String + Send + Sync + UnwindSafe + RefUnwindSafe + 'static,
+    {
+        self.label_formatter = LabelFormatter(Some(Arc::new(formatter)));
+        self
+    }
+
+    /// Returns `true` if the bounds or the labels are computed when the chart is rendered
+    const fn is_automatic(&self) -> bool {
+        self.auto_bounds || self.auto_ticks
+    }
+
+    /// Returns a copy of the axis with its automatic bounds and ticks computed from the `values`
+    /// of the data on this axis, aiming for `count` intervals between the ticks
+    fn resolve(&self, values: impl Iterator<Item = f64>, count: usize) -> Self {
+        let mut axis = self.clone();
+        if self.auto_bounds {
+            let (min, max) = values
+                .filter(|&value| self.scale.transform(value).is_some())
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                    (min.min(value), max.max(value))
+                });
+            if min <= max {
+                let bounds = if min < max {
+                    [min, max]
+                } else if self.scale == AxisScale::Log {
+                    [min / 10.0, max * 10.0]
+                } else {
+                    [min - 1.0, max + 1.0]
+                };
+                axis.bounds = if self.auto_ticks {
+                    self.scale.nice_bounds(bounds, count)
+                } else {
+                    bounds
+                };
+            }
+        }
+        if self.auto_ticks {
+            let (ticks, step) = self.scale.ticks(axis.bounds, count);
+            axis.labels = ticks
+                .iter()
+                .map(|&tick| {
+                    let label = self
+                        .label_formatter
+                        .0
+                        .as_ref()
+                        .map_or_else(|| self.scale.format(tick, step), |format| format(tick));
+                    Line::from(label)
+                })
+                .collect();
+            axis.ticks = ticks;
+        }
+        axis
+    }
+
+    /// Returns the bounds in the linear space of the canvas
+    fn canvas_bounds(&self) -> [f64; 2] {
+        if self.scale == AxisScale::Linear {
+            self.bounds
+        } else {
+            self.scale.transform_bounds(self.bounds)
+        }
+    }
+
+    /// Returns the coordinate of `value` on a canvas whose bounds correspond to the bounds of the
+    /// axis, or `None` if the value can't be displayed
+    fn project(&self, value: f64, [min, max]: [f64; 2]) -> Option<f64> {
+        let value = self.scale.transform(value)?;
+        let [axis_min, axis_max] = self.canvas_bounds();
+        if axis_max > axis_min {
+            Some(min + (value - axis_min) / (axis_max - axis_min) * (max - min))
+        } else {
+            Some(min)
+        }
+    }
+
+    /// Returns a copy of the axis displaying the given bounds of the linear space of the canvas
+    ///
+    /// The labels of the axis are replaced by automatic ticks, as they don't match the new bounds.
+    fn zoomed(&self, [min, max]: [f64; 2], count: usize) -> Self {
+        let axis = Self {
+            bounds: [self.scale.inverse(min), self.scale.inverse(max)],
+            auto_bounds: false,
+            auto_ticks: self.auto_ticks || !self.labels.is_empty(),
+            ..self.clone()
+        };
+        axis.resolve(core::iter::empty(), count)
+    }
+
+    /// Formats a value with the precision of one cell of the axis, when it is `length` cells long
+    fn format_value(&self, value: f64, length: u16) -> String {
+        if let Some(format) = &self.label_formatter.0 {
+            return format(value);
+        }
+        let [min, max] = self.canvas_bounds();
+        let cell = (max - min) / f64::from(length.max(1));
+        let precision = self.scale.transform(value).map_or(cell, |position| {
+            (self.scale.inverse(position + cell) - value).abs()
+        });
+        self.scale.format_value(value, precision)
+    }
+
+    /// Returns the position of `value` between the bounds, from `0.0` to `1.0`, or `None` if the
+    /// value is outside of the bounds or can't be displayed
+    fn fraction(&self, value: f64) -> Option<f64> {
+        let [min, max] = self.canvas_bounds();
+        let value = self.scale.transform(value)?;
+        let fraction = if max > min {
+            (value - min) / (max - min)
+        } else {
+            0.0
+        };
+        (0.0..=1.0).contains(&fraction).then_some(fraction)
+    }
 }
 
 /// Used to determine which style of graphing to use
@@ -173,6 +458,31 @@ pub enum GraphType {
     /// between each following point, but also fills the area between the line and the y-coordinate
     /// specified by [`Dataset::fill_to_y`].
     Area,
+
+    /// Draw the [candles](Dataset::candles) of the dataset, with a wick from the low to the high
+    /// value and a body from the open to the close value.
+    Candlestick,
+
+    /// Draw the [error bars](Dataset::error_bars) of the dataset, a point with a vertical line
+    /// from the minimum to the maximum value.
+    ErrorBar,
+
+    /// Draw the [box plots](Dataset::box_plots) of the dataset, a box from the first to the third
+    /// quartile with whiskers to the minimum and maximum values.
+    BoxPlot,
+}
+
+/// The Y axis of a [`Chart`] that a [`Dataset`] is plotted against
+///
+/// See [`Dataset::y_axis`] and [`Chart::secondary_y_axis`]
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum YAxis {
+    /// The Y axis on the left of the chart. This is the default.
+    #[default]
+    Primary,
+
+    /// The Y axis on the right of the chart, set with [`Chart::secondary_y_axis`]
+    Secondary,
 }
 
 /// Allow users to specify the position of a legend in a [`Chart`]
@@ -200,7 +510,7 @@ pub enum LegendPosition {
 }
 
 impl LegendPosition {
-    fn layout(
+    pub(crate) fn layout(
         self,
         area: Rect,
         legend_width: u16,
@@ -335,6 +645,16 @@ pub struct Dataset<'a> {
     style: Style,
     /// The y-coordinate to fill area to when using [`GraphType::Area`]
     fill_to_y: f64,
+    /// The Y axis the data is plotted against
+    y_axis: YAxis,
+    /// The candles drawn with [`GraphType::Candlestick`]
+    candles: &'a [Candle],
+    /// The error bars drawn with [`GraphType::ErrorBar`]
+    error_bars: &'a [ErrorBar],
+    /// The box plots drawn with [`GraphType::BoxPlot`]
+    box_plots: &'a [BoxPlot],
+    /// The width of the candles, the error bars and the box plots
+    bar_width: Option<f64>,
 }
 
 impl<'a> Dataset<'a> {
@@ -373,6 +693,107 @@ impl<'a> Dataset<'a> {
         self
     }
 
+    /// Sets the candles of this dataset and draws them as a [`GraphType::Candlestick`] chart
+    ///
+    /// Each [`Candle`] has a wick from its low to its high value and a body from its open to its
+    /// close value. The body is hollow when the value went up during the period, and filled when
+    /// it went down. The candles are drawn with the [marker](Dataset::marker) of the dataset, so a
+    /// marker with a higher resolution, like [`Marker::Braille`](symbols::Marker::Braille), draws
+    /// thinner candles.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::symbols::Marker;
+    /// use ratatui::widgets::{Candle, Dataset};
+    ///
+    /// let candles = [
+    ///     Candle::new(1.0, 10.0, 14.0, 9.0, 13.0),
+    ///     Candle::new(2.0, 13.0, 13.5, 8.0, 9.0),
+    /// ];
+    /// let dataset = Dataset::default()
+    ///     .name("ACME")
+    ///     .marker(Marker::HalfBlock)
+    ///     .candles(&candles);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn candles(mut self, candles: &'a [Candle]) -> Self {
+        self.candles = candles;
+        self.graph_type = GraphType::Candlestick;
+        self
+    }
+
+    /// Sets the error bars of this dataset and draws them as a [`GraphType::ErrorBar`] chart
+    ///
+    /// Each [`ErrorBar`] is drawn as a point at its value, and a vertical line with caps at its
+    /// minimum and maximum values.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn error_bars(mut self, error_bars: &'a [ErrorBar]) -> Self {
+        self.error_bars = error_bars;
+        self.graph_type = GraphType::ErrorBar;
+        self
+    }
+
+    /// Sets the box plots of this dataset and draws them as a [`GraphType::BoxPlot`] chart
+    ///
+    /// Each [`BoxPlot`] is drawn as a box from its first to its third quartile, split by a line
+    /// at its median, with whiskers to its minimum and maximum values.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn box_plots(mut self, box_plots: &'a [BoxPlot]) -> Self {
+        self.box_plots = box_plots;
+        self.graph_type = GraphType::BoxPlot;
+        self
+    }
+
+    /// Sets the width of the candles, the error bars and the box plots, in units of the X axis
+    ///
+    /// By default, they are 60% as wide as the smallest distance between two of them, so that
+    /// there is a gap between them. When the X axis has a non-linear [scale](Axis::scale), the
+    /// width is in units of the scaled axis, e.g. in powers of ten for [`AxisScale::Log`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bar_width(mut self, width: f64) -> Self {
+        self.bar_width = Some(width);
+        self
+    }
+
+    /// Returns the points that the cursor of a [`ChartState`] snaps to: the data points, followed
+    /// by the value of each candle, error bar and box plot
+    fn anchors(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        fn values<T: SeriesItem>(items: &[T]) -> impl Iterator<Item = (f64, f64)> + '_ {
+            items.iter().map(|item| (item.x(), item.value()))
+        }
+        self.data
+            .iter()
+            .copied()
+            .chain(values(self.candles))
+            .chain(values(self.error_bars))
+            .chain(values(self.box_plots))
+    }
+
+    /// Returns the points that the bounds of the chart must contain to display the dataset
+    fn extents(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        fn ranges<T: SeriesItem>(items: &[T]) -> impl Iterator<Item = (f64, f64)> + '_ {
+            items.iter().flat_map(|item| {
+                let [min, max] = item.y_range();
+                [(item.x(), min), (item.x(), max)]
+            })
+        }
+        self.data
+            .iter()
+            .copied()
+            .chain(ranges(self.candles))
+            .chain(ranges(self.error_bars))
+            .chain(ranges(self.box_plots))
+    }
+
     /// Sets the kind of character to use to display this dataset
     ///
     /// You can use dots (`•`), blocks (`█`), bars (`▄`), braille (`⠓`, `⣇`, `⣿`), half-blocks
@@ -441,6 +862,33 @@ impl<'a> Dataset<'a> {
         self.fill_to_y = fill_to_y;
         self
     }
+
+    /// Sets the Y axis the dataset is plotted against
+    ///
+    /// The default is [`YAxis::Primary`], the axis on the left of the chart. A dataset bound to
+    /// [`YAxis::Secondary`] is scaled to the bounds of the [`Chart::secondary_y_axis`], which
+    /// allows plotting values with different units on the same chart. When the chart has no
+    /// secondary axis, all the datasets are plotted against the primary one.
+    ///
+    /// When the chart has a secondary axis, the legend shows the axis of each dataset with an
+    /// arrow pointing to it.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Dataset, YAxis};
+    ///
+    /// let dataset = Dataset::default()
+    ///     .name("p99 latency")
+    ///     .y_axis(YAxis::Secondary);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn y_axis(mut self, axis: YAxis) -> Self {
+        self.y_axis = axis;
+        self
+    }
 }
 
 /// A container that holds all the infos about where to display each elements of the chart (axis,
@@ -458,6 +906,12 @@ struct ChartLayout {
     axis_x: Option<u16>,
     /// X coordinate of the vertical axis
     axis_y: Option<u16>,
+    /// Location of the title of the secondary y axis
+    title_y2: Option<Position>,
+    /// X coordinate of the labels of the secondary y axis
+    label_y2: Option<u16>,
+    /// X coordinate of the secondary vertical axis
+    axis_y2: Option<u16>,
     /// Area of the legend
     legend_area: Option<Rect>,
     /// Area of the graph
@@ -534,6 +988,8 @@ pub struct Chart<'a> {
     x_axis: Axis<'a>,
     /// The vertical axis
     y_axis: Axis<'a>,
+    /// The vertical axis on the right of the chart
+    secondary_y_axis: Option<Axis<'a>>,
     /// A reference to the datasets
     datasets: Vec<Dataset<'a>>,
     /// The widget base style
@@ -543,6 +999,10 @@ pub struct Chart<'a> {
     /// The position determine where the length is shown or hide regardless of
     /// `hidden_legend_constraints`
     legend_position: Option<LegendPosition>,
+    /// The style of the crosshair drawn at the cursor of the [`ChartState`]
+    crosshair_style: Style,
+    /// The style of the tooltip drawn at the cursor of the [`ChartState`]
+    tooltip_style: Style,
 }
 
 impl<'a> Chart<'a> {
@@ -578,10 +1038,13 @@ impl<'a> Chart<'a> {
             block: None,
             x_axis: Axis::default(),
             y_axis: Axis::default(),
+            secondary_y_axis: None,
             style: Style::default(),
             datasets,
             hidden_legend_constraints: (Constraint::Ratio(1, 4), Constraint::Ratio(1, 4)),
             legend_position: Some(LegendPosition::default()),
+            crosshair_style: Style::default(),
+            tooltip_style: Style::default(),
         }
     }
 
@@ -656,6 +1119,46 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Sets the secondary Y [`Axis`], displayed on the right of the chart
+    ///
+    /// The secondary axis has its own bounds, labels, scale and style. The datasets bound to it
+    /// with [`Dataset::y_axis`] are scaled to its bounds, while the other datasets are scaled to
+    /// the bounds of the [primary axis](Chart::y_axis). Its labels are displayed on the right of
+    /// the axis, and its title in the top right corner of the chart.
+    ///
+    /// The default is no secondary axis.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Axis, Chart, Dataset, YAxis};
+    ///
+    /// let requests = [(0.0, 120.0), (1.0, 180.0), (2.0, 150.0)];
+    /// let latency = [(0.0, 0.2), (1.0, 0.9), (2.0, 0.4)];
+    /// let chart = Chart::new(vec![
+    ///     Dataset::default().name("requests/s").data(&requests),
+    ///     Dataset::default()
+    ///         .name("p99 latency")
+    ///         .data(&latency)
+    ///         .y_axis(YAxis::Secondary),
+    /// ])
+    /// .x_axis(Axis::default().bounds([0.0, 2.0]))
+    /// .y_axis(Axis::default().bounds([0.0, 200.0]).labels(["0", "200"]))
+    /// .secondary_y_axis(
+    ///     Axis::default()
+    ///         .title("s")
+    ///         .bounds([0.0, 1.0])
+    ///         .labels(["0", "1"]),
+    /// );
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn secondary_y_axis(mut self, axis: Axis<'a>) -> Self {
+        self.secondary_y_axis = Some(axis);
+        self
+    }
+
     /// Sets the constraints used to determine whether the legend should be shown or not.
     ///
     /// The tuple's first constraint is used for the width and the second for the height. If the
@@ -747,6 +1250,56 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Sets the style of the crosshair drawn on the data point nearest to the cursor
+    ///
+    /// The crosshair is only drawn when the chart is rendered with a [`ChartState`] that has a
+    /// [cursor](ChartState::set_cursor). It is drawn on the empty cells of the graph, so it
+    /// doesn't hide the data.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn crosshair_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.crosshair_style = style.into();
+        self
+    }
+
+    /// Sets the style of the tooltip showing the values of the datasets at the cursor
+    ///
+    /// The tooltip is only drawn when the chart is rendered with a [`ChartState`] that has a
+    /// [cursor](ChartState::set_cursor). It shows the X value of the data point nearest to the
+    /// cursor, and the Y value of each named dataset at that X value.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn tooltip_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.tooltip_style = style.into();
+        self
+    }
+
+    /// Sets the styles of the chart and its axes from a [`Theme`]
+    ///
+    /// The style, crosshair style and tooltip style are replaced by the `chart`,
+    /// `chart.crosshair` and `chart.tooltip` styles of the theme, and the axes are themed with
+    /// [`Axis::theme`], so set the axes before calling this method.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style("chart");
+        self.crosshair_style = theme.style("chart.crosshair");
+        self.tooltip_style = theme.style("chart.tooltip");
+        self.x_axis = self.x_axis.theme(theme);
+        self.y_axis = self.y_axis.theme(theme);
+        self.secondary_y_axis = self.secondary_y_axis.map(|axis| axis.theme(theme));
+        self
+    }
+
     /// Compute the internal layout of the chart given the area. If the area is too small some
     /// elements may be automatically hidden
     fn layout(&self, area: Rect) -> Option<ChartLayout> {
@@ -777,7 +1330,28 @@ impl<'a> Chart<'a> {
             x += 1;
         }
 
-        let graph_width = area.right().saturating_sub(x);
+        let mut right = area.right();
+        let mut label_y2 = None;
+        let mut axis_y2 = None;
+        if let Some(axis) = &self.secondary_y_axis
+            && !axis.labels.is_empty()
+        {
+            let labels_width = axis
+                .labels
+                .iter()
+                .map(Line::width)
+                .max()
+                .unwrap_or_default() as u16;
+            let labels_width = labels_width.min(area.width / 3);
+            if x + labels_width + 2 < right {
+                right -= labels_width;
+                label_y2 = Some(right);
+                right -= 1;
+                axis_y2 = Some(right);
+            }
+        }
+
+        let graph_width = right.saturating_sub(x);
         let graph_height = y.saturating_sub(area.top()).saturating_add(1);
         debug_assert_ne!(
             graph_width, 0,
@@ -805,45 +1379,22 @@ impl<'a> Chart<'a> {
             }
         }
 
-        let mut legend_area = None;
-        if let Some(legend_position) = self.legend_position {
-            let legends = self
-                .datasets
-                .iter()
-                .filter_map(|d| Some(d.name.as_ref()?.width() as u16));
-
-            if let Some(inner_width) = legends.clone().max() {
-                let legend_width = inner_width + 2;
-                let legend_height = legends.count() as u16 + 2;
-
-                let [max_legend_width] = Layout::horizontal([self.hidden_legend_constraints.0])
-                    .flex(Flex::Start)
-                    .areas(graph_area);
-
-                let [max_legend_height] = Layout::vertical([self.hidden_legend_constraints.1])
-                    .flex(Flex::Start)
-                    .areas(graph_area);
-
-                if inner_width > 0
-                    && legend_width <= max_legend_width.width
-                    && legend_height <= max_legend_height.height
-                {
-                    legend_area = legend_position.layout(
-                        graph_area,
-                        legend_width,
-                        legend_height,
-                        title_x
-                            .and(self.x_axis.title.as_ref())
-                            .map(|t| t.width() as u16)
-                            .unwrap_or_default(),
-                        title_y
-                            .and(self.y_axis.title.as_ref())
-                            .map(|t| t.width() as u16)
-                            .unwrap_or_default(),
-                    );
-                }
+        let mut title_y2 = None;
+        if let Some(title) = self
+            .secondary_y_axis
+            .as_ref()
+            .and_then(|axis| axis.title.as_ref())
+        {
+            let w = title.width() as u16;
+            let left = title_y
+                .and(self.y_axis.title.as_ref())
+                .map_or(x, |title| x + title.width() as u16 + 1);
+            if left + w < graph_area.right() && graph_area.height > 2 {
+                title_y2 = Some(Position::new(graph_area.right() - w, area.top()));
             }
         }
+
+        let legend_area = self.legend_area(graph_area, title_x, title_y, title_y2);
         Some(ChartLayout {
             title_x,
             title_y,
@@ -851,11 +1402,72 @@ impl<'a> Chart<'a> {
             label_y,
             axis_x,
             axis_y,
+            title_y2,
+            label_y2,
+            axis_y2,
             legend_area,
             graph_area,
         })
     }
 
+    /// Returns the area of the legend, or `None` if it is hidden or doesn't fit in the graph
+    fn legend_area(
+        &self,
+        graph_area: Rect,
+        title_x: Option<Position>,
+        title_y: Option<Position>,
+        title_y2: Option<Position>,
+    ) -> Option<Rect> {
+        let legend_position = self.legend_position?;
+        let symbol_width = self.secondary_y_axis.as_ref().map_or(0, |_| {
+            PRIMARY_AXIS_SYMBOL
+                .width()
+                .max(SECONDARY_AXIS_SYMBOL.width()) as u16
+        });
+        let legends = self
+            .datasets
+            .iter()
+            .filter_map(|d| Some(d.name.as_ref()?.width() as u16 + symbol_width));
+
+        let inner_width = legends.clone().max()?;
+        let legend_width = inner_width + 2;
+        let legend_height = legends.count() as u16 + 2;
+
+        let [max_legend_width] = Layout::horizontal([self.hidden_legend_constraints.0])
+            .flex(Flex::Start)
+            .areas(graph_area);
+
+        let [max_legend_height] = Layout::vertical([self.hidden_legend_constraints.1])
+            .flex(Flex::Start)
+            .areas(graph_area);
+
+        if inner_width > 0
+            && legend_width <= max_legend_width.width
+            && legend_height <= max_legend_height.height
+        {
+            return legend_position.layout(
+                graph_area,
+                legend_width,
+                legend_height,
+                title_x
+                    .and(self.x_axis.title.as_ref())
+                    .map(|t| t.width() as u16)
+                    .unwrap_or_default(),
+                // the title of the secondary axis is in the top right corner, so the legends
+                // at the top are moved below the titles
+                if title_y2.is_some() {
+                    graph_area.width
+                } else {
+                    title_y
+                        .and(self.y_axis.title.as_ref())
+                        .map(|t| t.width() as u16)
+                        .unwrap_or_default()
+                },
+            );
+        }
+        None
+    }
+
     fn max_width_of_labels_left_of_y_axis(&self, area: Rect, has_y_axis: bool) -> u16 {
         let mut max_width = self
             .y_axis
@@ -891,6 +1503,10 @@ impl<'a> Chart<'a> {
         graph_area: Rect,
     ) {
         let Some(y) = layout.label_x else { return };
+        if !self.x_axis.ticks.is_empty() {
+            self.render_x_tick_labels(buf, y, chart_area, graph_area);
+            return;
+        }
         let labels = &self.x_axis.labels;
         let labels_len = labels.len() as u16;
         if labels_len < 2 {
@@ -969,27 +1585,103 @@ impl<'a> Chart<'a> {
         chart_area: Rect,
         graph_area: Rect,
     ) {
-        let Some(x) = layout.label_y else { return };
-        let labels = &self.y_axis.labels;
+        if let Some(x) = layout.label_y {
+            let width = (graph_area.left() - chart_area.left()).saturating_sub(1);
+            let column = Rect::new(x, graph_area.top(), width, graph_area.height);
+            Self::render_y_axis_labels(buf, &self.y_axis, column);
+        }
+        if let Some(axis) = &self.secondary_y_axis
+            && let Some(x) = layout.label_y2
+        {
+            let width = chart_area.right().saturating_sub(x);
+            let column = Rect::new(x, graph_area.top(), width, graph_area.height);
+            Self::render_y_axis_labels(buf, axis, column);
+        }
+    }
+
+    /// Renders the labels of the X axis centered below the position of their ticks, skipping the
+    /// labels that would overlap the previous one
+    fn render_x_tick_labels(&self, buf: &mut Buffer, y: u16, chart_area: Rect, graph_area: Rect) {
+        let mut next_free_x = chart_area.left();
+        for (&tick, label) in self.x_axis.ticks.iter().zip(&self.x_axis.labels) {
+            let Some(fraction) = self.x_axis.fraction(tick) else {
+                continue;
+            };
+            let offset = (fraction * f64::from(graph_area.width.saturating_sub(1))).round() as u16;
+            let label_width = (label.width() as u16).min(chart_area.width);
+            let x = (graph_area.left() + offset)
+                .saturating_sub(label_width / 2)
+                .clamp(chart_area.left(), chart_area.right() - label_width);
+            if x < next_free_x {
+                continue;
+            }
+            Self::render_label(buf, label, Rect::new(x, y, label_width, 1), Alignment::Left);
+            next_free_x = x + label_width + 1;
+        }
+    }
+
+    /// Renders the labels of a Y axis in `column`, whose rows are the ones of
+    /// the graph
+    fn render_y_axis_labels(buf: &mut Buffer, axis: &Axis, column: Rect) {
+        if !axis.ticks.is_empty() {
+            Self::render_y_tick_labels(buf, axis, column);
+            return;
+        }
+        let labels = &axis.labels;
         let labels_len = labels.len() as u16;
         if labels_len < 2 {
             return;
         }
 
         for (i, label) in labels.iter().enumerate() {
-            let dy = i as u16 * (graph_area.height - 1) / (labels_len - 1);
-            if dy < graph_area.bottom() {
+            let dy = i as u16 * (column.height - 1) / (labels_len - 1);
+            if dy < column.bottom() {
                 let label_area = Rect::new(
-                    x,
-                    graph_area.bottom().saturating_sub(1) - dy,
-                    (graph_area.left() - chart_area.left()).saturating_sub(1),
+                    column.x,
+                    column.bottom().saturating_sub(1) - dy,
+                    column.width,
                     1,
                 );
-                Self::render_label(buf, label, label_area, self.y_axis.labels_alignment);
+                Self::render_label(buf, label, label_area, axis.labels_alignment);
             }
         }
     }
-}
+
+    /// Renders the labels of a Y axis on the row of their ticks, skipping the labels that would
+    /// be on the same row as the previous one
+    fn render_y_tick_labels(buf: &mut Buffer, axis: &Axis, column: Rect) {
+        let mut previous_y = None;
+        for (&tick, label) in axis.ticks.iter().zip(&axis.labels) {
+            let Some(fraction) = axis.fraction(tick) else {
+                continue;
+            };
+            let offset = (fraction * f64::from(column.height.saturating_sub(1))).round() as u16;
+            let y = column.bottom().saturating_sub(1) - offset;
+            if previous_y == Some(y) {
+                continue;
+            }
+            let label_area = Rect::new(column.x, y, column.width, 1);
+            Self::render_label(buf, label, label_area, axis.labels_alignment);
+            previous_y = Some(y);
+        }
+    }
+
+    /// Returns the secondary axis if the dataset is plotted against it
+    fn secondary_axis_of(&self, dataset: &Dataset) -> Option<&Axis<'a>> {
+        self.secondary_y_axis
+            .as_ref()
+            .filter(|_| dataset.y_axis == YAxis::Secondary)
+    }
+
+    /// Returns the symbol displayed in the legend before the name of the dataset
+    fn legend_symbol(&self, dataset: &Dataset) -> Option<&'static str> {
+        self.secondary_y_axis.as_ref()?;
+        match self.secondary_axis_of(dataset) {
+            Some(_) => Some(SECONDARY_AXIS_SYMBOL),
+            None => Some(PRIMARY_AXIS_SYMBOL),
+        }
+    }
+}
 
 impl Widget for Chart<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
@@ -998,65 +1690,142 @@ impl Widget for Chart<'_> {
 }
 
 impl Widget for &Chart<'_> {
-    #[expect(clippy::too_many_lines)]
     fn render(self, area: Rect, buf: &mut Buffer) {
         buf.set_style(area, self.style);
 
         self.block.as_ref().render(area, buf);
         let chart_area = self.block.inner_if_some(area);
-        let Some(layout) = self.layout(chart_area) else {
-            return;
-        };
-        let graph_area = layout.graph_area;
+        if self.x_axis.is_automatic()
+            || self.y_axis.is_automatic()
+            || self
+                .secondary_y_axis
+                .as_ref()
+                .is_some_and(Axis::is_automatic)
+        {
+            self.resolved(chart_area, None)
+                .render_chart(area, chart_area, buf);
+        } else {
+            self.render_chart(area, chart_area, buf);
+        }
+    }
+}
 
-        // Sample the style of the entire widget. This sample will be used to reset the style of
-        // the cells that are part of the components put on top of the grah area (i.e legend and
-        // axis names).
-        let original_style = buf[(area.left(), area.top())].style();
+impl StatefulWidget for Chart<'_> {
+    type State = ChartState;
 
-        self.render_x_labels(buf, &layout, chart_area, graph_area);
-        self.render_y_labels(buf, &layout, chart_area, graph_area);
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
 
-        if let Some(y) = layout.axis_x {
-            for x in graph_area.left()..graph_area.right() {
-                buf[(x, y)]
-                    .set_symbol(symbols::line::HORIZONTAL)
-                    .set_style(self.x_axis.style);
-            }
-        }
+impl StatefulWidget for &Chart<'_> {
+    type State = ChartState;
 
-        if let Some(x) = layout.axis_y {
-            for y in graph_area.top()..graph_area.bottom() {
-                buf[(x, y)]
-                    .set_symbol(symbols::line::VERTICAL)
-                    .set_style(self.y_axis.style);
-            }
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+
+        self.block.as_ref().render(area, buf);
+        let chart_area = self.block.inner_if_some(area);
+        let chart = self.resolved(chart_area, Some(state));
+        let Some(graph_area) = chart.render_chart(area, chart_area, buf) else {
+            state.update(Rect::ZERO, [[0.0; 2]; 2], None);
+            return;
+        };
+        let hovered = state
+            .cursor()
+            .and_then(|cursor| chart.hovered_point(graph_area, cursor));
+        state.update(
+            graph_area,
+            [chart.x_axis.bounds, chart.y_axis.bounds],
+            hovered.map(|(indices, _)| indices),
+        );
+        if let Some((indices, position)) = hovered {
+            chart.render_crosshair(graph_area, position, buf);
+            chart.render_tooltip(graph_area, indices, position, buf);
         }
+    }
+}
 
-        if let Some(y) = layout.axis_x
-            && let Some(x) = layout.axis_y
-        {
-            buf[(x, y)]
-                .set_symbol(symbols::line::BOTTOM_LEFT)
-                .set_style(self.x_axis.style);
+impl Chart<'_> {
+    /// Returns a copy of the chart with the automatic bounds and ticks of its axes computed for
+    /// the given area, and the viewport of the state applied to them
+    fn resolved(&self, area: Rect, state: Option<&ChartState>) -> Self {
+        let points = || self.datasets.iter().flat_map(Dataset::extents);
+        let y_points = |secondary: bool| {
+            self.datasets
+                .iter()
+                .filter(move |dataset| self.secondary_axis_of(dataset).is_some() == secondary)
+                .flat_map(|dataset| dataset.extents().map(|(_, y)| y))
+        };
+        let y_count = usize::from(area.height / 3).max(2);
+        let x_count = usize::from(area.width / 12).max(2);
+        let mut x_axis = self.x_axis.resolve(points().map(|(x, _)| x), x_count);
+        let mut y_axis = self.y_axis.resolve(y_points(false), y_count);
+        let mut secondary_y_axis = self
+            .secondary_y_axis
+            .as_ref()
+            .map(|axis| axis.resolve(y_points(true), y_count));
+        if let Some(state) = state.filter(|state| state.is_transformed()) {
+            x_axis = x_axis.zoomed(state.view(0, x_axis.canvas_bounds()), x_count);
+            y_axis = y_axis.zoomed(state.view(1, y_axis.canvas_bounds()), y_count);
+            secondary_y_axis = secondary_y_axis
+                .map(|axis| axis.zoomed(state.view(1, axis.canvas_bounds()), y_count));
         }
+        Self {
+            x_axis,
+            y_axis,
+            secondary_y_axis,
+            ..self.clone()
+        }
+    }
 
-        Canvas::default()
+    /// Plots the datasets in the graph area
+    #[expect(clippy::too_many_lines)]
+    fn render_datasets(&self, graph_area: Rect, buf: &mut Buffer) {
+        let x_bounds = self.x_axis.canvas_bounds();
+        let y_bounds = self.y_axis.canvas_bounds();
+        let is_linear =
+            self.x_axis.scale == AxisScale::Linear && self.y_axis.scale == AxisScale::Linear;
+        let canvas = Canvas::default()
             .background_color(self.style.bg.unwrap_or(Color::Reset))
-            .x_bounds(self.x_axis.bounds)
-            .y_bounds(self.y_axis.bounds)
+            .x_bounds(x_bounds)
+            .y_bounds(y_bounds)
             .paint(|ctx| {
                 for dataset in &self.datasets {
                     ctx.marker(dataset.marker);
 
+                    // the values of the secondary axis are scaled to the bounds of the canvas
+                    let secondary_axis = self.secondary_axis_of(dataset);
+                    let to_canvas_y = |y: f64| match secondary_axis {
+                        Some(axis) => axis.project(y, y_bounds),
+                        None => self.y_axis.scale.transform(y),
+                    };
+                    // the base of the bars and areas is the bottom of the chart when it can't be
+                    // displayed
+                    let to_canvas_base = |y: f64| to_canvas_y(y).unwrap_or(y_bounds[0]);
+                    let to_canvas_x = |x: f64| self.x_axis.scale.transform(x);
+                    let data = if is_linear && secondary_axis.is_none() {
+                        Cow::Borrowed(dataset.data)
+                    } else {
+                        Cow::Owned(
+                            dataset
+                                .data
+                                .iter()
+                                .filter_map(|&(x, y)| {
+                                    let x = to_canvas_x(x)?;
+                                    Some((x, to_canvas_y(y)?))
+                                })
+                                .collect_vec(),
+                        )
+                    };
                     let color = dataset.style.fg.unwrap_or(Color::Reset);
                     ctx.draw(&Points {
-                        coords: dataset.data,
+                        coords: &data,
                         color,
                     });
                     match dataset.graph_type {
                         GraphType::Line => {
-                            for data in dataset.data.windows(2) {
+                            for data in data.windows(2) {
                                 ctx.draw(&CanvasLine {
                                     x1: data[0].0,
                                     y1: data[0].1,
@@ -1067,34 +1836,126 @@ impl Widget for &Chart<'_> {
                             }
                         }
                         GraphType::Bar => {
-                            for (x, y) in dataset.data {
+                            let base = to_canvas_base(0.0);
+                            for &(x, y) in data.iter() {
                                 ctx.draw(&CanvasLine {
-                                    x1: *x,
-                                    y1: 0.0,
-                                    x2: *x,
-                                    y2: *y,
+                                    x1: x,
+                                    y1: base,
+                                    x2: x,
+                                    y2: y,
                                     color,
                                 });
                             }
                         }
                         GraphType::Area => {
-                            for data in dataset.data.windows(2) {
+                            let fill_to_y = to_canvas_base(dataset.fill_to_y);
+                            for data in data.windows(2) {
                                 ctx.draw(&FilledLine {
                                     x1: data[0].0,
                                     y1: data[0].1,
                                     x2: data[1].0,
                                     y2: data[1].1,
-                                    fill_to_y: dataset.fill_to_y,
+                                    fill_to_y,
                                     color,
                                 });
                             }
                         }
 
+                        GraphType::Candlestick => draw_series(
+                            ctx,
+                            dataset.candles,
+                            to_canvas_x,
+                            to_canvas_y,
+                            dataset.bar_width,
+                            x_bounds,
+                            color,
+                        ),
+                        GraphType::ErrorBar => draw_series(
+                            ctx,
+                            dataset.error_bars,
+                            to_canvas_x,
+                            to_canvas_y,
+                            dataset.bar_width,
+                            x_bounds,
+                            color,
+                        ),
+                        GraphType::BoxPlot => draw_series(
+                            ctx,
+                            dataset.box_plots,
+                            to_canvas_x,
+                            to_canvas_y,
+                            dataset.bar_width,
+                            x_bounds,
+                            color,
+                        ),
                         GraphType::Scatter => {}
                     }
                 }
-            })
-            .render(graph_area, buf);
+            });
+        Widget::render(canvas, graph_area, buf);
+    }
+
+    /// Render the axes, the datasets, the titles and the legend of the chart in `chart_area`
+    ///
+    /// `area` is the area of the whole widget, including its block.
+    #[expect(clippy::too_many_lines)]
+    ///
+    /// Returns the area of the graph, or `None` if the chart area is empty.
+    fn render_chart(&self, area: Rect, chart_area: Rect, buf: &mut Buffer) -> Option<Rect> {
+        let layout = self.layout(chart_area)?;
+        let graph_area = layout.graph_area;
+
+        // Sample the style of the entire widget. This sample will be used to reset the style of
+        // the cells that are part of the components put on top of the grah area (i.e legend and
+        // axis names).
+        let original_style = buf[(area.left(), area.top())].style();
+
+        self.render_x_labels(buf, &layout, chart_area, graph_area);
+        self.render_y_labels(buf, &layout, chart_area, graph_area);
+
+        if let Some(y) = layout.axis_x {
+            for x in graph_area.left()..graph_area.right() {
+                buf[(x, y)]
+                    .set_symbol(symbols::line::HORIZONTAL)
+                    .set_style(self.x_axis.style);
+            }
+        }
+
+        if let Some(x) = layout.axis_y {
+            for y in graph_area.top()..graph_area.bottom() {
+                buf[(x, y)]
+                    .set_symbol(symbols::line::VERTICAL)
+                    .set_style(self.y_axis.style);
+            }
+        }
+
+        if let Some(x) = layout.axis_y2
+            && let Some(axis) = &self.secondary_y_axis
+        {
+            for y in graph_area.top()..graph_area.bottom() {
+                buf[(x, y)]
+                    .set_symbol(symbols::line::VERTICAL)
+                    .set_style(axis.style);
+            }
+        }
+
+        if let Some(y) = layout.axis_x
+            && let Some(x) = layout.axis_y
+        {
+            buf[(x, y)]
+                .set_symbol(symbols::line::BOTTOM_LEFT)
+                .set_style(self.x_axis.style);
+        }
+
+        if let Some(y) = layout.axis_x
+            && let Some(x) = layout.axis_y2
+        {
+            buf[(x, y)]
+                .set_symbol(symbols::line::BOTTOM_RIGHT)
+                .set_style(self.x_axis.style);
+        }
+
+        self.render_datasets(graph_area, buf);
 
         if let Some(Position { x, y }) = layout.title_x {
             let title = self.x_axis.title.as_ref().unwrap();
@@ -1132,17 +1993,35 @@ impl Widget for &Chart<'_> {
             buf.set_line(x, y, title, width);
         }
 
+        if let Some(Position { x, y }) = layout.title_y2
+            && let Some(title) = self
+                .secondary_y_axis
+                .as_ref()
+                .and_then(|axis| axis.title.as_ref())
+        {
+            let width = graph_area
+                .right()
+                .saturating_sub(x)
+                .min(title.width() as u16);
+            buf.set_style(Rect::new(x, y, width, 1), original_style);
+            buf.set_line(x, y, title, width);
+        }
+
         if let Some(legend_area) = layout.legend_area {
             buf.set_style(legend_area, original_style);
             Block::bordered().render(legend_area, buf);
 
-            for (i, (dataset_name, dataset_style)) in self
+            for (i, (dataset, dataset_name)) in self
                 .datasets
                 .iter()
-                .filter_map(|ds| Some((ds.name.as_ref()?, ds.style())))
+                .filter_map(|ds| Some((ds, ds.name.as_ref()?)))
                 .enumerate()
             {
-                let name = dataset_name.clone().patch_style(dataset_style);
+                let mut name = dataset_name.clone();
+                if let Some(symbol) = self.legend_symbol(dataset) {
+                    name.spans.insert(0, Span::raw(symbol));
+                }
+                let name = name.patch_style(dataset.style());
                 name.render(
                     Rect {
                         x: legend_area.x + 1,
@@ -1154,6 +2033,142 @@ impl Widget for &Chart<'_> {
                 );
             }
         }
+        Some(graph_area)
+    }
+
+    /// Returns the dataset and point indices of the data point nearest to the cursor, and the
+    /// position of its cell
+    fn hovered_point(
+        &self,
+        graph_area: Rect,
+        cursor: Position,
+    ) -> Option<((usize, usize), Position)> {
+        if !graph_area.contains(cursor) {
+            return None;
+        }
+        self.datasets
+            .iter()
+            .enumerate()
+            .flat_map(|(i, dataset)| {
+                dataset.anchors().enumerate().filter_map(move |(j, point)| {
+                    Some(((i, j), self.cell_of(dataset, point, graph_area)?))
+                })
+            })
+            // the cells are about twice as high as wide
+            .min_by_key(|(_, cell)| {
+                let dx = u32::from(cell.x.abs_diff(cursor.x));
+                let dy = u32::from(cell.y.abs_diff(cursor.y));
+                dx * dx + 4 * dy * dy
+            })
+    }
+
+    /// Returns the position of the cell of a data point of the dataset, or `None` if the point is
+    /// not displayed
+    fn cell_of(&self, dataset: &Dataset, (x, y): (f64, f64), graph_area: Rect) -> Option<Position> {
+        let y_axis = self.secondary_axis_of(dataset).unwrap_or(&self.y_axis);
+        let x = self.x_axis.fraction(x)? * f64::from(graph_area.width.saturating_sub(1));
+        let y = y_axis.fraction(y)? * f64::from(graph_area.height.saturating_sub(1));
+        Some(Position::new(
+            graph_area.left() + x.round() as u16,
+            graph_area.bottom().saturating_sub(1) - y.round() as u16,
+        ))
+    }
+
+    /// Draws a crosshair through the hovered data point on the empty cells of the graph
+    fn render_crosshair(&self, graph_area: Rect, point: Position, buf: &mut Buffer) {
+        let mut draw = |x: u16, y: u16, symbol: &str| {
+            let cell = &mut buf[(x, y)];
+            if cell.symbol() == " " {
+                cell.set_symbol(symbol).set_style(self.crosshair_style);
+            }
+        };
+        for x in (graph_area.left()..graph_area.right()).filter(|&x| x != point.x) {
+            draw(x, point.y, symbols::line::HORIZONTAL);
+        }
+        for y in (graph_area.top()..graph_area.bottom()).filter(|&y| y != point.y) {
+            draw(point.x, y, symbols::line::VERTICAL);
+        }
+    }
+
+    /// Draws a tooltip next to the hovered data point with its X value and the Y values of the
+    /// named datasets at that X value
+    fn render_tooltip(
+        &self,
+        graph_area: Rect,
+        (dataset_index, point_index): (usize, usize),
+        point: Position,
+        buf: &mut Buffer,
+    ) {
+        let Some(dataset) = self.datasets.get(dataset_index) else {
+            return;
+        };
+        let Some((x, y)) = dataset.anchors().nth(point_index) else {
+            return;
+        };
+        let format_y = |dataset: &Dataset, y: f64| {
+            self.secondary_axis_of(dataset)
+                .unwrap_or(&self.y_axis)
+                .format_value(y, graph_area.height)
+        };
+        let mut lines = vec![Line::from(self.x_axis.format_value(x, graph_area.width))];
+        let position = |x: f64| self.x_axis.scale.transform(x);
+        let hovered_x = position(x).unwrap_or(x);
+        for dataset in &self.datasets {
+            let Some(name) = &dataset.name else {
+                continue;
+            };
+            // the value of the dataset at the X value nearest to the hovered one
+            let nearest = dataset
+                .anchors()
+                .filter_map(|(x, y)| Some(((position(x)? - hovered_x).abs(), y)))
+                .min_by(|(a, _), (b, _)| a.total_cmp(b));
+            if let Some((_, y)) = nearest {
+                let mut line = name.clone();
+                line.spans.push(Span::raw(": "));
+                line.spans.push(Span::raw(format_y(dataset, y)));
+                lines.push(line.patch_style(dataset.style()));
+            }
+        }
+        if lines.len() == 1 {
+            lines.push(Line::from(format_y(dataset, y)).patch_style(dataset.style()));
+        }
+
+        let width = lines.iter().map(Line::width).max().unwrap_or_default() as u16 + 2;
+        let height = lines.len() as u16 + 2;
+        if width > graph_area.width || height > graph_area.height {
+            return;
+        }
+        // next to the point when there is room on its right or its left, above or below it
+        // otherwise, so that the tooltip never hides the point
+        let centered = |position: u16, length: u16, start: u16, end: u16| {
+            position
+                .saturating_sub(length / 2)
+                .clamp(start, end - length)
+        };
+        let area = if point.x + 2 + width <= graph_area.right() {
+            let y = centered(point.y, height, graph_area.top(), graph_area.bottom());
+            Rect::new(point.x + 2, y, width, height)
+        } else if point.x > graph_area.left() + width {
+            let y = centered(point.y, height, graph_area.top(), graph_area.bottom());
+            Rect::new(point.x - width - 1, y, width, height)
+        } else {
+            let x = centered(point.x, width, graph_area.left(), graph_area.right());
+            let y = if point.y >= graph_area.top() + height {
+                point.y - height
+            } else if point.y + 1 + height <= graph_area.bottom() {
+                point.y + 1
+            } else {
+                return;
+            };
+            Rect::new(x, y, width, height)
+        };
+        Clear.render(area, buf);
+        let block = Block::bordered().style(self.tooltip_style);
+        let inner = block.inner(area);
+        block.render(area, buf);
+        for (line, row) in lines.iter().zip(inner.rows()) {
+            line.render(row, buf);
+        }
     }
 }
 
@@ -1298,7 +2313,7 @@ mod tests {
             .y_axis(Axis::default().title("xxxxxxxxxxxxxxxx"))
             .x_axis(Axis::default().title("xxxxxxxxxxxxxxxx"));
         let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 4));
-        widget.render(buffer.area, &mut buffer);
+        Widget::render(widget, buffer.area, &mut buffer);
         assert_eq!(buffer, Buffer::with_lines(vec![" ".repeat(8); 4]));
     }
 
@@ -1307,7 +2322,7 @@ mod tests {
         let widget = Chart::new(vec![]).y_axis(Axis::default().bounds([0.0, 1.0]).labels(["only"]));
         let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 5));
 
-        widget.render(buffer.area, &mut buffer);
+        Widget::render(widget, buffer.area, &mut buffer);
     }
 
     #[test]
@@ -1341,7 +2356,7 @@ mod tests {
         let widget = Chart::new(vec![long_dataset_name, short_dataset])
             .hidden_legend_constraints((100.into(), 100.into()));
         let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 5));
-        widget.render(buffer.area, &mut buffer);
+        Widget::render(widget, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "    ┌──────────────┐",
             "    │Very long name│",
@@ -1358,7 +2373,7 @@ mod tests {
             .legend_position(Some(LegendPosition::TopLeft));
         let area = Rect::new(0, 0, 30, 20);
         let mut buffer = Buffer::empty(area);
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "┌───┐                         ",
             "│Ds1│                         ",
@@ -1390,7 +2405,7 @@ mod tests {
             .y_axis(Axis::default().title("The title overlap a legend."));
         let area = Rect::new(0, 0, 30, 20);
         let mut buffer = Buffer::empty(area);
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "The title overlap a legend.   ",
             "                         ┌───┐",
@@ -1422,7 +2437,7 @@ mod tests {
             .y_axis(Axis::default().title("The title overlap a legend."));
         let area = Rect::new(0, 0, 10, 10);
         let mut buffer = Buffer::empty(area);
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "          ",
             "          ",
@@ -1457,7 +2472,7 @@ mod tests {
         ] {
             let chart = chart.clone().legend_position(Some(position));
             buffer.reset();
-            chart.render(buffer.area, &mut buffer);
+            Widget::render(chart, buffer.area, &mut buffer);
             #[rustfmt::skip]
             let expected = Buffer::with_lines([
                 "┌────┐",
@@ -1554,7 +2569,7 @@ mod tests {
         let chart = Chart::new(vec![Dataset::default().name(name)])
             .legend_position(legend_position)
             .hidden_legend_constraints((Constraint::Percentage(100), Constraint::Percentage(100)));
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         assert_eq!(buffer, Buffer::with_lines(expected));
     }
 
@@ -1578,7 +2593,7 @@ mod tests {
         .y_axis(Axis::default().bounds([0.0, 10.0]));
         let area = Rect::new(0, 0, 11, 11);
         let mut buffer = Buffer::empty(area);
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "          •",
             "        • •",
@@ -1618,7 +2633,7 @@ mod tests {
             .y_axis(Axis::default().bounds([0.0, 5.0]));
         let area = Rect::new(0, 0, 5, 5);
         let mut buffer = Buffer::empty(area);
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         #[rustfmt::skip]
         let mut expected = Buffer::with_lines([
             format!("{symbol}   █"),
@@ -1665,7 +2680,7 @@ mod tests {
         .y_axis(Axis::default().bounds([0.0, 10.0]));
         let area = Rect::new(0, 0, 11, 11);
         let mut buffer = Buffer::empty(area);
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "           ",
             "           ",
@@ -1689,7 +2704,7 @@ mod tests {
             .x_axis(Axis::default().bounds([0.0, 1.0]))
             .y_axis(Axis::default().bounds([0.0, 1.0]));
         // This should not panic, even if the buffer is too small to render the chart.
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         assert_eq!(buffer, Buffer::with_lines(["•"]));
     }
 
@@ -1700,6 +2715,308 @@ mod tests {
             .x_axis(Axis::default().bounds([0.0, 1.0]))
             .y_axis(Axis::default().bounds([0.0, 1.0]));
         // This should not panic, even if the buffer has zero size.
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
+    }
+
+    #[test]
+    fn render_with_auto_ticks() {
+        let data = [(0.0, 3.0), (7.0, 12.0), (22.0, 8.0)];
+        let chart = Chart::new(vec![
+            Dataset::default().data(&data).marker(symbols::Marker::Dot),
+        ])
+        .x_axis(Axis::default().auto_bounds(true).auto_ticks(true))
+        .y_axis(Axis::default().auto_bounds(true).auto_ticks(true));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 8));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "  │                           ",
+            "  │      •                    ",
+            "10│                   •       ",
+            "  │                           ",
+            "  │•                          ",
+            "0 │                           ",
+            "  └───────────────────────────",
+            "   0               20         ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_with_log_scale() {
+        let data = [
+            (1.0, 1.0),
+            (2.0, 10.0),
+            (3.0, 100.0),
+            (4.0, 1000.0),
+            (5.0, 0.0),
+        ];
+        let chart = Chart::new(vec![
+            Dataset::default().data(&data).marker(symbols::Marker::Dot),
+        ])
+        .x_axis(Axis::default().bounds([1.0, 5.0]))
+        .y_axis(
+            Axis::default()
+                .bounds([1.0, 1000.0])
+                .scale(AxisScale::Log)
+                .auto_ticks(true),
+        );
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 10));
+        Widget::render(chart, buffer.area, &mut buffer);
+        // the point at 0 can't be displayed on a log scale and is skipped
+        let expected = Buffer::with_lines([
+            "1000│      •  ",
+            "    │         ",
+            "    │         ",
+            "100 │    •    ",
+            "    │         ",
+            "    │         ",
+            "10  │  •      ",
+            "    │         ",
+            "    │         ",
+            "1   │•        ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn axis_eq_ignores_label_formatter() {
+        let axis = Axis::default().bounds([0.0, 1.0]);
+        assert_eq!(
+            axis.clone().label_formatter(|value| format!("{value}%")),
+            axis.label_formatter(|value| format!("{value} ms"))
+        );
+    }
+
+    #[test]
+    fn render_with_label_formatter() {
+        let chart = Chart::new(vec![])
+            .x_axis(
+                Axis::default()
+                    .bounds([0.0, 100.0])
+                    .auto_ticks(true)
+                    .label_formatter(|value| format!("{value}%")),
+            )
+            .y_axis(Axis::default().bounds([0.0, 1.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 3));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "                              ",
+            "  ────────────────────────────",
+            " 0%            50%        100%",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_with_secondary_y_axis() {
+        let requests = [(1.0, 100.0), (3.0, 60.0)];
+        let latency = [(3.0, 1.0)];
+        let chart = Chart::new(vec![
+            Dataset::default()
+                .name("req")
+                .data(&requests)
+                .marker(symbols::Marker::Dot),
+            Dataset::default()
+                .name("lat")
+                .data(&latency)
+                .marker(symbols::Marker::Dot)
+                .y_axis(YAxis::Secondary),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 4.0]).labels(["0", "4"]))
+        .y_axis(Axis::default().bounds([0.0, 100.0]).labels(["0", "100"]))
+        .secondary_y_axis(
+            Axis::default()
+                .title("s")
+                .bounds([0.0, 1.0])
+                .labels(["0", "1"]),
+        )
+        .legend_position(Some(LegendPosition::BottomLeft))
+        .hidden_legend_constraints((Constraint::Min(0), Constraint::Min(0)));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 8));
+        Widget::render(chart, buffer.area, &mut buffer);
+        // the latency is scaled to the secondary axis, so its highest point is at the top
+        let expected = Buffer::with_lines([
+            "100│   •      •  s│1",
+            "   │              │ ",
+            "   │┌─────┐   •   │ ",
+            "   ││◂ req│       │ ",
+            "   ││▸ lat│       │ ",
+            "0  │└─────┘       │0",
+            "   └──────────────┘ ",
+            "   0             4  ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn secondary_dataset_without_secondary_axis_uses_primary_axis() {
+        let data = [(0.0, 0.0), (1.0, 1.0)];
+        let chart = Chart::new(vec![
+            Dataset::default()
+                .data(&data)
+                .marker(symbols::Marker::Dot)
+                .y_axis(YAxis::Secondary),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 1.0]))
+        .y_axis(Axis::default().bounds([0.0, 1.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 2));
+        Widget::render(chart, buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([" •", "• "]));
+    }
+
+    #[rstest]
+    #[case::half_block(symbols::Marker::HalfBlock, [
+        "      ▄  ",
+        "  █   █  ",
+        " █▀█ ███ ",
+        " █ █ ███ ",
+        " █ █ ███ ",
+        " █▄█ ███ ",
+        "  █   █  ",
+        "      █  ",
+    ])]
+    #[case::braille(symbols::Marker::Braille, [
+        "      ⢠  ",
+        "  ⡇   ⢸  ",
+        " ⡏⠉⢹ ⣿⣿⣿ ",
+        " ⡇ ⢸ ⣿⣿⣿ ",
+        " ⡇ ⢸ ⣿⣿⣿ ",
+        " ⣇⣀⣸ ⣿⣿⣿ ",
+        "  ⡇   ⢸  ",
+        "      ⢸  ",
+    ])]
+    fn render_candlestick(#[case] marker: symbols::Marker, #[case] expected: [&str; 8]) {
+        let candles = [
+            Candle::new(1.0, 2.0, 7.0, 1.0, 6.0),
+            Candle::new(3.0, 6.0, 7.5, 0.0, 2.0),
+        ];
+        let chart = Chart::new(vec![Dataset::default().candles(&candles).marker(marker)])
+            .x_axis(Axis::default().bounds([0.0, 4.0]))
+            .y_axis(Axis::default().bounds([0.0, 8.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 8));
+        Widget::render(chart, buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn render_error_bars() {
+        let error_bars = [
+            ErrorBar::new(1.0, 4.0, 2.0, 6.0),
+            ErrorBar::new(3.0, 5.0, 4.0, 7.0),
+        ];
+        let chart = Chart::new(vec![
+            Dataset::default()
+                .error_bars(&error_bars)
+                .marker(symbols::Marker::Dot),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 8.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 9));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "         ",
+            "     ••• ",
+            " •••  •  ",
+            "  •   •  ",
+            "  •  ••• ",
+            "  •      ",
+            " •••     ",
+            "         ",
+            "         ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_box_plots() {
+        let box_plots = [BoxPlot::new(2.0, 0.0, 2.0, 4.0, 6.0, 8.0)];
+        let chart = Chart::new(vec![
+            Dataset::default()
+                .box_plots(&box_plots)
+                .marker(symbols::Marker::Dot)
+                .bar_width(2.0),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 8.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 9));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "   •••   ",
+            "    •    ",
+            "  •••••  ",
+            "  •   •  ",
+            "  •••••  ",
+            "  •   •  ",
+            "  •••••  ",
+            "    •    ",
+            "   •••   ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_crosshair_and_tooltip() {
+        let data = [(0.0, 0.0), (5.0, 5.0), (10.0, 10.0)];
+        let chart = Chart::new(vec![
+            Dataset::default()
+                .name("a")
+                .data(&data)
+                .marker(symbols::Marker::Dot),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 10.0]))
+        .y_axis(Axis::default().bounds([0.0, 10.0]))
+        .crosshair_style(Style::new())
+        .tooltip_style(Style::new())
+        .hidden_legend_constraints((Constraint::Length(0), Constraint::Length(0)));
+        let mut state = ChartState::default();
+        state.set_cursor(Some(Position::new(4, 4)));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 11));
+        StatefulWidget::render(chart, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.hovered(), Some((0, 1)));
+        let expected = Buffer::with_lines([
+            "     │    •",
+            " ┌──────┐  ",
+            " │5.0   │  ",
+            " │a: 5.0│  ",
+            " └──────┘  ",
+            "─────•─────",
+            "     │     ",
+            "     │     ",
+            "     │     ",
+            "     │     ",
+            "•    │     ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_zoomed_replaces_labels() {
+        let data = [(0.0, 0.0), (10.0, 10.0)];
+        let chart = Chart::new(vec![Dataset::default().data(&data)])
+            .x_axis(Axis::default().bounds([0.0, 10.0]).labels(["start", "end"]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]));
+        let mut state = ChartState::default();
+        state.zoom(2.0, 1.0);
+        state.pan(0.5, 0.0);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 5));
+        StatefulWidget::render(chart, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.visible_bounds(), [[5.0, 10.0], [0.0, 10.0]]);
+        let expected = Buffer::with_lines([
+            "                             •",
+            "                              ",
+            "                              ",
+            " ─────────────────────────────",
+            "       6          8         10",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn auto_bounds_include_candles() {
+        let candles = [Candle::new(1.0, 2.0, 9.0, -3.0, 6.0)];
+        let chart = Chart::new(vec![Dataset::default().candles(&candles)])
+            .y_axis(Axis::default().auto_bounds(true));
+        let chart = chart.resolved(Rect::new(0, 0, 10, 10), None);
+        assert_eq!(chart.y_axis.bounds.to_vec(), [-3.0, 9.0]);
     }
 }
@@ -0,0 +1,437 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use crate::polyfills::F64Polyfills;
+
+/// The scale used to place the values of an [`Axis`] on a [`Chart`]
+///
+/// The scale transforms the data of the [`Dataset`]s, the [bounds](Axis::bounds) of the axis and
+/// the values of its [automatic ticks](Axis::auto_ticks), which are chosen and formatted for the
+/// scale.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::{Axis, AxisScale};
+///
+/// let latency = Axis::default()
+///     .scale(AxisScale::Log)
+///     .auto_bounds(true)
+///     .auto_ticks(true);
+/// let time = Axis::default()
+///     .scale(AxisScale::Time)
+///     .auto_bounds(true)
+///     .auto_ticks(true);
+/// ```
+///
+/// [`Axis`]: crate::chart::Axis
+/// [`Axis::bounds`]: crate::chart::Axis::bounds
+/// [`Axis::auto_ticks`]: crate::chart::Axis::auto_ticks
+/// [`Chart`]: crate::chart::Chart
+/// [`Dataset`]: crate::chart::Dataset
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AxisScale {
+    /// Equal differences of values are displayed at equal distances (default)
+    #[default]
+    Linear,
+    /// Equal ratios of values are displayed at equal distances, with ticks at the powers of ten
+    ///
+    /// Values less than or equal to zero are not displayed.
+    Log,
+    /// Linear close to zero and logarithmic beyond the given threshold
+    ///
+    /// This displays values spanning several orders of magnitude with both signs, e.g. a balance
+    /// or a delta. The ticks are at zero and at the powers of ten above the threshold.
+    Symlog(f64),
+    /// Linear scale of timestamps, in seconds since the Unix epoch
+    ///
+    /// The ticks are at round seconds, minutes, hours or days, and are labeled with the time or
+    /// the date in UTC depending on the visible range.
+    Time,
+}
+
+/// The durations between the ticks of a time axis, in seconds
+const TIME_STEPS: [f64; 19] = [
+    1.0, 2.0, 5.0, 10.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 900.0, 1800.0, 3600.0, 7200.0,
+    10800.0, 21600.0, 43200.0, 86400.0, 172_800.0,
+];
+
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+/// Tolerance used to keep the ticks that are at the bounds despite rounding errors
+const EPSILON: f64 = 1e-9;
+
+impl AxisScale {
+    /// Maps a value to the linear space in which it is displayed, or returns `None` when the value
+    /// can't be displayed with this scale
+    pub(crate) fn transform(self, value: f64) -> Option<f64> {
+        if !value.is_finite() {
+            return None;
+        }
+        match self {
+            Self::Linear | Self::Time => Some(value),
+            Self::Log => (value > 0.0).then(|| value.log10()),
+            Self::Symlog(threshold) => {
+                let threshold = threshold.abs().max(f64::MIN_POSITIVE);
+                Some((1.0 + value.abs() / threshold).log10().copysign(value))
+            }
+        }
+    }
+
+    /// Maps bounds to the linear space in which they are displayed
+    ///
+    /// Bounds that can't be displayed, e.g. `0` on a log scale, are replaced by the smallest
+    /// displayable value so that the chart still shows something.
+    pub(crate) fn transform_bounds(self, [min, max]: [f64; 2]) -> [f64; 2] {
+        let min = self
+            .transform(min)
+            .or_else(|| self.transform(max.min(1.0) / 1e3))
+            .unwrap_or_default();
+        let max = self.transform(max).unwrap_or(min + 1.0);
+        [min, max]
+    }
+
+    /// Returns the values of the ticks between the bounds, aiming for `count` intervals, and the
+    /// step used to format them
+    pub(crate) fn ticks(self, [min, max]: [f64; 2], count: usize) -> (Vec<f64>, f64) {
+        if !(min.is_finite() && max.is_finite()) || min > max {
+            return (Vec::new(), 0.0);
+        }
+        match self {
+            Self::Linear => {
+                let step = nice_step(max - min, count);
+                (multiples(min, max, step), step)
+            }
+            Self::Time => {
+                let step = time_step(max - min, count);
+                (multiples(min, max, step), step)
+            }
+            Self::Log => {
+                let first = magnitude(min.max(f64::MIN_POSITIVE));
+                let last = magnitude(max.max(f64::MIN_POSITIVE));
+                let powers = (first..=last)
+                    .map(|exponent| 10f64.powi(exponent))
+                    .filter(|&value| {
+                        value >= min * (1.0 - EPSILON) && value <= max * (1.0 + EPSILON)
+                    });
+                (thin(powers, count), 0.0)
+            }
+            Self::Symlog(threshold) => {
+                let threshold = threshold.abs().max(f64::MIN_POSITIVE);
+                let largest = min.abs().max(max.abs()).max(threshold);
+                let powers = (magnitude(threshold)..=magnitude(largest))
+                    .map(|exponent| 10f64.powi(exponent))
+                    .collect::<Vec<_>>();
+                let negative = powers.iter().rev().map(|value| -value);
+                let values = negative
+                    .chain([0.0])
+                    .chain(powers.iter().copied())
+                    .filter(|&value| value >= min - EPSILON && value <= max + EPSILON);
+                (thin(values, count), 0.0)
+            }
+        }
+    }
+
+    /// Extends the bounds to the ticks around them, so that the first and last ticks are at the
+    /// ends of the axis
+    pub(crate) fn nice_bounds(self, [min, max]: [f64; 2], count: usize) -> [f64; 2] {
+        match self {
+            Self::Linear | Self::Time => {
+                let step = if self == Self::Time {
+                    time_step(max - min, count)
+                } else {
+                    nice_step(max - min, count)
+                };
+                if step > 0.0 {
+                    let min = (min / step + EPSILON).floor() * step;
+                    let max = (max / step - EPSILON).ceil() * step;
+                    [min, max]
+                } else {
+                    [min, max]
+                }
+            }
+            Self::Log if min > 0.0 => [10f64.powi(magnitude(min)), 10f64.powi(magnitude_ceil(max))],
+            Self::Log | Self::Symlog(_) => [min, max],
+        }
+    }
+
+    /// Maps a coordinate of the linear space in which the values are displayed back to a value
+    ///
+    /// This is the inverse of [`AxisScale::transform`].
+    pub(crate) fn inverse(self, value: f64) -> f64 {
+        match self {
+            Self::Linear | Self::Time => value,
+            Self::Log => 10f64.powf(value),
+            Self::Symlog(threshold) => {
+                let threshold = threshold.abs().max(f64::MIN_POSITIVE);
+                (10f64.powf(value.abs()) - 1.0).copysign(value) * threshold
+            }
+        }
+    }
+
+    /// Formats a value that is displayed with the given precision, e.g. the value of a data point
+    /// rather than a tick
+    pub(crate) fn format_value(self, value: f64, precision: f64) -> String {
+        // avoid formatting -0
+        let value = value + 0.0;
+        match self {
+            Self::Time => format_time(value, precision),
+            Self::Linear | Self::Log | Self::Symlog(_) => {
+                format!("{value:.*}", decimals(precision))
+            }
+        }
+    }
+
+    /// Formats the value of a tick, given the step between the ticks
+    pub(crate) fn format(self, value: f64, step: f64) -> String {
+        // avoid formatting -0
+        let value = value + 0.0;
+        match self {
+            Self::Linear => format!("{value:.*}", decimals(step)),
+            Self::Log | Self::Symlog(_) => format_power(value),
+            Self::Time => format_time(value, step),
+        }
+    }
+}
+
+/// Returns the exponent of the power of ten less than or equal to the value
+fn magnitude(value: f64) -> i32 {
+    (value.log10() + EPSILON).floor() as i32
+}
+
+/// Returns the exponent of the power of ten greater than or equal to the value
+fn magnitude_ceil(value: f64) -> i32 {
+    (value.log10() - EPSILON).ceil() as i32
+}
+
+/// Returns the step of 1, 2 or 5 times a power of ten that splits the range in the number of
+/// intervals closest to `count`
+fn nice_step(range: f64, count: usize) -> f64 {
+    if range <= 0.0 || !range.is_finite() {
+        return 0.0;
+    }
+    let raw = range / count.max(1) as f64;
+    let power = 10f64.powi(magnitude(raw));
+    let fraction = raw / power;
+    let nice = if fraction < 1.5 {
+        1.0
+    } else if fraction < 3.0 {
+        2.0
+    } else if fraction < 7.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * power
+}
+
+/// Returns a step of round seconds, minutes, hours or days that splits the range in about
+/// `count` intervals
+fn time_step(range: f64, count: usize) -> f64 {
+    let raw = range / count.max(1) as f64;
+    if raw < 1.0 {
+        return nice_step(range, count);
+    }
+    TIME_STEPS
+        .into_iter()
+        .find(|&step| step >= raw)
+        .unwrap_or_else(|| nice_step(raw / SECONDS_PER_DAY, 1) * SECONDS_PER_DAY)
+}
+
+/// Returns the multiples of `step` between the bounds
+fn multiples(min: f64, max: f64, step: f64) -> Vec<f64> {
+    if step <= 0.0 {
+        return Vec::new();
+    }
+    let first = (min / step - EPSILON).ceil() as i64;
+    let last = (max / step + EPSILON).floor() as i64;
+    (first..=last).map(|i| i as f64 * step).collect()
+}
+
+/// Returns the number of decimals needed to show the difference between two ticks
+fn decimals(step: f64) -> usize {
+    if step <= 0.0 || step >= 1.0 {
+        0
+    } else {
+        (-magnitude(step)).clamp(0, 15) as usize
+    }
+}
+
+/// Formats a power of ten, or a value of a symlog axis, in plain or scientific notation
+fn format_power(value: f64) -> String {
+    if value == 0.0 {
+        return String::from("0");
+    }
+    let exponent = magnitude(value.abs());
+    if (-3..=4).contains(&exponent) {
+        format!("{value:.*}", (-exponent).max(0) as usize)
+    } else {
+        let sign = if value < 0.0 { "-" } else { "" };
+        format!("{sign}1e{exponent}")
+    }
+}
+
+/// Formats a timestamp as a date, a time or seconds depending on the step between the ticks
+fn format_time(value: f64, step: f64) -> String {
+    let days = (value / SECONDS_PER_DAY).floor();
+    let seconds = value - days * SECONDS_PER_DAY;
+    let (hours, minutes) = ((seconds / 3600.0) as u32, (seconds % 3600.0 / 60.0) as u32);
+    if step >= SECONDS_PER_DAY {
+        let (year, month, day) = civil_from_days(days as i64);
+        format!("{year:04}-{month:02}-{day:02}")
+    } else if step >= 60.0 {
+        format!("{hours:02}:{minutes:02}")
+    } else if step >= 1.0 {
+        let seconds = (seconds % 60.0) as u32;
+        format!("{hours:02}:{minutes:02}:{seconds:02}")
+    } else {
+        let decimals = decimals(step);
+        let seconds = seconds % 60.0;
+        format!(
+            "{minutes:02}:{seconds:0width$.decimals$}",
+            width = decimals + 3
+        )
+    }
+}
+
+/// Converts a number of days since the Unix epoch to a year, month and day of the proleptic
+/// Gregorian calendar
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month as u32, day as u32)
+}
+
+/// Keeps every n-th value so that there are at most `count + 1` values
+fn thin(values: impl Iterator<Item = f64>, count: usize) -> Vec<f64> {
+    let values = values.collect::<Vec<_>>();
+    let stride = values.len().saturating_sub(1).div_ceil(count.max(1)).max(1);
+    values.into_iter().step_by(stride).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::units([0.0, 10.0], 5, vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0])]
+    #[case::offset([3.0, 17.0], 3, vec![5.0, 10.0, 15.0])]
+    #[case::decimals([0.0, 1.0], 4, vec![0.0, 0.2, 0.4, 0.6, 0.8, 1.0])]
+    #[case::negative([-100.0, 100.0], 2, vec![-100.0, 0.0, 100.0])]
+    fn linear_ticks(#[case] bounds: [f64; 2], #[case] count: usize, #[case] expected: Vec<f64>) {
+        let (ticks, _) = AxisScale::Linear.ticks(bounds, count);
+        assert_eq!(ticks.len(), expected.len());
+        for (tick, expected) in ticks.iter().zip(expected) {
+            assert!((tick - expected).abs() < 1e-9, "{ticks:?}");
+        }
+    }
+
+    #[test]
+    fn linear_format() {
+        let (ticks, step) = AxisScale::Linear.ticks([0.0, 1.0], 4);
+        let labels = ticks
+            .iter()
+            .map(|&tick| AxisScale::Linear.format(tick, step))
+            .collect::<Vec<_>>();
+        assert_eq!(labels, ["0.0", "0.2", "0.4", "0.6", "0.8", "1.0"]);
+        assert_eq!(AxisScale::Linear.format(-0.0, 1.0), "0");
+    }
+
+    #[test]
+    fn log_ticks() {
+        let (ticks, _) = AxisScale::Log.ticks([1.0, 10_000.0], 10);
+        assert_eq!(ticks, [1.0, 10.0, 100.0, 1000.0, 10_000.0]);
+        let (ticks, _) = AxisScale::Log.ticks([1.0, 10_000.0], 2);
+        assert_eq!(ticks, [1.0, 100.0, 10_000.0]);
+        let labels = [0.001, 1.0, 1e4, 1e6].map(|value| AxisScale::Log.format(value, 0.0));
+        assert_eq!(labels, ["0.001", "1", "10000", "1e6"]);
+    }
+
+    #[test]
+    #[expect(clippy::float_cmp)]
+    fn log_transform() {
+        assert_eq!(AxisScale::Log.transform(0.0), None);
+        assert_eq!(AxisScale::Log.transform(-1.0), None);
+        assert!((AxisScale::Log.transform(1000.0).unwrap() - 3.0).abs() < 1e-12);
+        assert_eq!(AxisScale::Log.nice_bounds([3.0, 420.0], 5), [1.0, 1000.0]);
+    }
+
+    #[rstest]
+    #[case::linear(AxisScale::Linear, -2.5)]
+    #[case::log(AxisScale::Log, 420.0)]
+    #[case::symlog(AxisScale::Symlog(2.0), -420.0)]
+    fn inverse(#[case] scale: AxisScale, #[case] value: f64) {
+        let inverse = scale.inverse(scale.transform(value).unwrap());
+        assert!((inverse - value).abs() < 1e-9, "{inverse}");
+    }
+
+    #[test]
+    fn format_value() {
+        assert_eq!(AxisScale::Log.format_value(1234.5678, 0.01), "1234.57");
+        assert_eq!(AxisScale::Linear.format_value(-0.0, 1.0), "0");
+        assert_eq!(AxisScale::Time.format_value(5400.0, 60.0), "01:30");
+    }
+
+    #[test]
+    fn symlog() {
+        let scale = AxisScale::Symlog(1.0);
+        assert_eq!(scale.transform(0.0), Some(0.0));
+        assert_eq!(scale.transform(-9.0), Some(-1.0));
+        assert_eq!(scale.transform(9.0), Some(1.0));
+        let (ticks, _) = scale.ticks([-100.0, 100.0], 10);
+        assert_eq!(ticks, [-100.0, -10.0, -1.0, 0.0, 1.0, 10.0, 100.0]);
+        assert_eq!(scale.format(-100.0, 0.0), "-100");
+    }
+
+    #[rstest]
+    #[case::seconds([0.0, 10.0], vec!["00:00:00", "00:00:05", "00:00:10"])]
+    #[case::minutes([3600.0, 5400.0], vec!["01:00", "01:15", "01:30"])]
+    #[case::days([1_700_000_000.0, 1_700_300_000.0], vec!["2023-11-15", "2023-11-17"])]
+    #[case::milliseconds([0.0, 0.5], vec!["00:00.0", "00:00.2", "00:00.4"])]
+    fn time_ticks(#[case] bounds: [f64; 2], #[case] expected: Vec<&str>) {
+        let (ticks, step) = AxisScale::Time.ticks(bounds, 2);
+        let labels = ticks
+            .iter()
+            .map(|&tick| AxisScale::Time.format(tick, step))
+            .collect::<Vec<_>>();
+        assert_eq!(labels, expected);
+    }
+
+    #[test]
+    #[expect(clippy::float_cmp)]
+    fn nice_bounds() {
+        assert_eq!(AxisScale::Linear.nice_bounds([3.0, 17.0], 3), [0.0, 20.0]);
+        assert_eq!(
+            AxisScale::Time.nice_bounds([3700.0, 5300.0], 2),
+            [3600.0, 5400.0]
+        );
+    }
+
+    #[test]
+    fn civil_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+    }
+}
@@ -0,0 +1,342 @@
+use alloc::vec::Vec;
+
+use ratatui_core::style::Color;
+
+use crate::canvas::{Context, FilledLine, Line, Points, Rectangle};
+
+/// The open, high, low and close values of a period, displayed by a [`Dataset`] of
+/// [candles](crate::chart::Dataset::candles)
+///
+/// The wick of the candle goes from the low to the high value, and its body from the open to the
+/// close value. The body is hollow when the close value is greater than or equal to the open value
+/// and filled otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::{Candle, Dataset};
+///
+/// let candles = [
+///     Candle::new(1.0, 10.0, 14.0, 9.0, 13.0),
+///     Candle::new(2.0, 13.0, 13.5, 8.0, 9.0),
+/// ];
+/// let dataset = Dataset::default().name("ACME").candles(&candles);
+/// ```
+///
+/// [`Dataset`]: crate::chart::Dataset
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Candle {
+    /// The position of the candle on the X axis
+    pub x: f64,
+    /// The value at the start of the period
+    pub open: f64,
+    /// The highest value of the period
+    pub high: f64,
+    /// The lowest value of the period
+    pub low: f64,
+    /// The value at the end of the period
+    pub close: f64,
+}
+
+impl Candle {
+    /// Creates a candle at `x` with the given open, high, low and close values
+    pub const fn new(x: f64, open: f64, high: f64, low: f64, close: f64) -> Self {
+        Self {
+            x,
+            open,
+            high,
+            low,
+            close,
+        }
+    }
+}
+
+/// A value with the range of its uncertainty, displayed by a [`Dataset`] of
+/// [error bars](crate::chart::Dataset::error_bars)
+///
+/// The value is displayed as a point, and the range as a vertical line with a cap at both ends.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::{Dataset, ErrorBar};
+///
+/// let timings = [
+///     ErrorBar::new(1.0, 12.0, 10.5, 14.0),
+///     ErrorBar::new(2.0, 8.0, 7.0, 9.5),
+/// ];
+/// let dataset = Dataset::default().name("run time").error_bars(&timings);
+/// ```
+///
+/// [`Dataset`]: crate::chart::Dataset
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ErrorBar {
+    /// The position of the value on the X axis
+    pub x: f64,
+    /// The value
+    pub y: f64,
+    /// The lower end of the range
+    pub min: f64,
+    /// The upper end of the range
+    pub max: f64,
+}
+
+impl ErrorBar {
+    /// Creates an error bar at `x` for the value `y` within `min` and `max`
+    pub const fn new(x: f64, y: f64, min: f64, max: f64) -> Self {
+        Self { x, y, min, max }
+    }
+}
+
+/// The summary of a distribution, displayed by a [`Dataset`] of
+/// [box plots](crate::chart::Dataset::box_plots)
+///
+/// The box goes from the first to the third quartile and is split by a line at the median. The
+/// whiskers go from the box to the minimum and maximum values.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::{BoxPlot, Dataset};
+///
+/// let latencies = [BoxPlot::new(1.0, 2.0, 4.0, 5.0, 7.0, 12.0)];
+/// let dataset = Dataset::default().name("latency").box_plots(&latencies);
+/// ```
+///
+/// [`Dataset`]: crate::chart::Dataset
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct BoxPlot {
+    /// The position of the box on the X axis
+    pub x: f64,
+    /// The lowest value, at the end of the lower whisker
+    pub min: f64,
+    /// The first quartile, at the bottom of the box
+    pub q1: f64,
+    /// The median, displayed as a line across the box
+    pub median: f64,
+    /// The third quartile, at the top of the box
+    pub q3: f64,
+    /// The highest value, at the end of the upper whisker
+    pub max: f64,
+}
+
+impl BoxPlot {
+    /// Creates a box plot at `x` from the minimum, the quartiles and the maximum of a distribution
+    pub const fn new(x: f64, min: f64, q1: f64, median: f64, q3: f64, max: f64) -> Self {
+        Self {
+            x,
+            min,
+            q1,
+            median,
+            q3,
+            max,
+        }
+    }
+}
+
+/// An item of a series that is drawn around a position on the X axis
+pub(crate) trait SeriesItem: Copy {
+    /// Returns the position of the item on the X axis
+    fn x(&self) -> f64;
+
+    /// Returns the main value of the item, which the cursor of a chart snaps to
+    fn value(&self) -> f64;
+
+    /// Returns the lowest and the highest values of the item
+    fn y_range(&self) -> [f64; 2];
+
+    /// Returns the item with its position and values mapped to the coordinates of the canvas, or
+    /// `None` if one of them can't be displayed
+    fn map(&self, x: impl Fn(f64) -> Option<f64>, y: impl Fn(f64) -> Option<f64>) -> Option<Self>;
+
+    /// Draws the item, whose coordinates are the ones of the canvas, with the given width
+    fn draw(&self, ctx: &mut Context, width: f64, color: Color);
+}
+
+impl SeriesItem for Candle {
+    fn x(&self) -> f64 {
+        self.x
+    }
+
+    fn value(&self) -> f64 {
+        self.close
+    }
+
+    fn y_range(&self) -> [f64; 2] {
+        [self.low, self.high]
+    }
+
+    fn map(&self, x: impl Fn(f64) -> Option<f64>, y: impl Fn(f64) -> Option<f64>) -> Option<Self> {
+        Some(Self::new(
+            x(self.x)?,
+            y(self.open)?,
+            y(self.high)?,
+            y(self.low)?,
+            y(self.close)?,
+        ))
+    }
+
+    fn draw(&self, ctx: &mut Context, width: f64, color: Color) {
+        let Self {
+            x,
+            open,
+            high,
+            low,
+            close,
+        } = *self;
+        let (bottom, top) = (open.min(close), open.max(close));
+        let left = x - width / 2.0;
+        ctx.draw(&Line::new(x, low, x, bottom, color));
+        ctx.draw(&Line::new(x, top, x, high, color));
+        if close < open {
+            ctx.draw(&FilledLine::new(
+                left,
+                top,
+                left + width,
+                top,
+                bottom,
+                color,
+            ));
+        } else {
+            ctx.draw(&Rectangle::new(left, bottom, width, top - bottom, color));
+        }
+    }
+}
+
+impl SeriesItem for ErrorBar {
+    fn x(&self) -> f64 {
+        self.x
+    }
+
+    fn value(&self) -> f64 {
+        self.y
+    }
+
+    fn y_range(&self) -> [f64; 2] {
+        [self.min.min(self.y), self.max.max(self.y)]
+    }
+
+    fn map(&self, x: impl Fn(f64) -> Option<f64>, y: impl Fn(f64) -> Option<f64>) -> Option<Self> {
+        Some(Self::new(
+            x(self.x)?,
+            y(self.y)?,
+            y(self.min)?,
+            y(self.max)?,
+        ))
+    }
+
+    fn draw(&self, ctx: &mut Context, width: f64, color: Color) {
+        let Self { x, y, min, max } = *self;
+        let (left, right) = (x - width / 2.0, x + width / 2.0);
+        ctx.draw(&Line::new(x, min, x, max, color));
+        ctx.draw(&Line::new(left, min, right, min, color));
+        ctx.draw(&Line::new(left, max, right, max, color));
+        ctx.draw(&Points::new(&[(x, y)], color));
+    }
+}
+
+impl SeriesItem for BoxPlot {
+    fn x(&self) -> f64 {
+        self.x
+    }
+
+    fn value(&self) -> f64 {
+        self.median
+    }
+
+    fn y_range(&self) -> [f64; 2] {
+        [self.min, self.max]
+    }
+
+    fn map(&self, x: impl Fn(f64) -> Option<f64>, y: impl Fn(f64) -> Option<f64>) -> Option<Self> {
+        Some(Self::new(
+            x(self.x)?,
+            y(self.min)?,
+            y(self.q1)?,
+            y(self.median)?,
+            y(self.q3)?,
+            y(self.max)?,
+        ))
+    }
+
+    fn draw(&self, ctx: &mut Context, width: f64, color: Color) {
+        let Self {
+            x,
+            min,
+            q1,
+            median,
+            q3,
+            max,
+        } = *self;
+        let (left, right) = (x - width / 2.0, x + width / 2.0);
+        // the caps of the whiskers are half as wide as the box
+        let (cap_left, cap_right) = (x - width / 4.0, x + width / 4.0);
+        ctx.draw(&Line::new(x, min, x, q1, color));
+        ctx.draw(&Line::new(x, q3, x, max, color));
+        ctx.draw(&Line::new(cap_left, min, cap_right, min, color));
+        ctx.draw(&Line::new(cap_left, max, cap_right, max, color));
+        ctx.draw(&Rectangle::new(left, q1, width, q3 - q1, color));
+        ctx.draw(&Line::new(left, median, right, median, color));
+    }
+}
+
+/// Draws the items of a series on the canvas
+///
+/// The position and the values of the items are mapped to the coordinates of the canvas with `x`
+/// and `y`. When `width` is `None`, the items are 60% as wide as the smallest distance between
+/// two of them, or a twentieth of the `x_bounds` of the canvas when there is only one item.
+pub(crate) fn draw_series<T: SeriesItem>(
+    ctx: &mut Context,
+    items: &[T],
+    x: impl Fn(f64) -> Option<f64>,
+    y: impl Fn(f64) -> Option<f64>,
+    width: Option<f64>,
+    x_bounds: [f64; 2],
+    color: Color,
+) {
+    let items = items
+        .iter()
+        .filter_map(|item| item.map(&x, &y))
+        .collect::<Vec<_>>();
+    let width = width.unwrap_or_else(|| default_width(&items, x_bounds));
+    for item in &items {
+        item.draw(ctx, width, color);
+    }
+}
+
+/// Returns the width of the items that keeps a gap between them
+fn default_width<T: SeriesItem>(items: &[T], [min, max]: [f64; 2]) -> f64 {
+    let mut positions = items.iter().map(SeriesItem::x).collect::<Vec<_>>();
+    positions.sort_by(f64::total_cmp);
+    positions
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .filter(|&distance| distance > 0.0)
+        .min_by(f64::total_cmp)
+        .map_or((max - min) / 20.0, |distance| distance * 0.6)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_width_uses_smallest_distance() {
+        let items = [
+            ErrorBar::new(0.0, 0.0, 0.0, 0.0),
+            ErrorBar::new(4.0, 0.0, 0.0, 0.0),
+            ErrorBar::new(2.0, 0.0, 0.0, 0.0),
+            ErrorBar::new(2.0, 0.0, 0.0, 0.0),
+        ];
+        assert!((default_width(&items, [0.0, 10.0]) - 1.2).abs() < 1e-9);
+        assert!((default_width(&items[..1], [0.0, 10.0]) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn map_skips_values_that_cant_be_displayed() {
+        let candle = Candle::new(1.0, 2.0, 3.0, 0.0, 2.5);
+        let positive = |value: f64| (value > 0.0).then_some(value);
+        assert_eq!(candle.map(Some, positive), None);
+        assert_eq!(candle.map(Some, Some), Some(candle));
+    }
+}
@@ -0,0 +1,278 @@
+use ratatui_core::layout::{Position, Rect};
+
+/// The factor by which [`ChartState::zoom_in`] and [`ChartState::zoom_out`] zoom the chart
+const ZOOM_STEP: f64 = 1.5;
+
+/// State of a [`Chart`] widget
+///
+/// This state can be used to explore a chart rendered as a [`StatefulWidget`]. It holds:
+///
+/// - the viewport of the chart: the [zoom](ChartState::zoom) and the [pan](ChartState::pan) are
+///   relative to the bounds of the [`Axis`] of the chart, so they also apply to the bounds that are
+///   [computed from the data](crate::chart::Axis::auto_bounds) and keep the same view while the
+///   data changes.
+/// - the position of the [cursor](ChartState::set_cursor), e.g. the position of the mouse. When the
+///   cursor is in the graph, the chart draws a crosshair on the nearest data point and a tooltip
+///   with the values of the datasets at that point.
+///
+/// When the chart is zoomed or panned, the labels of its axes are replaced by
+/// [automatic ticks](crate::chart::Axis::auto_ticks), as the given labels no longer match the
+/// displayed bounds.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::layout::{Position, Rect};
+/// use ratatui::widgets::{Axis, Chart, ChartState, Dataset};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let data = [(0.0, 1.0), (1.0, 3.0), (2.0, 2.0)];
+/// let chart = Chart::new(vec![Dataset::default().name("load").data(&data)])
+///     .x_axis(Axis::default().bounds([0.0, 2.0]))
+///     .y_axis(Axis::default().bounds([0.0, 4.0]));
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = ChartState::default();
+/// state.zoom_in();
+/// state.pan(0.25, 0.0);
+/// state.set_cursor(Some(Position::new(10, 5)));
+///
+/// frame.render_stateful_widget(chart, area, &mut state);
+/// if let Some((dataset, point)) = state.hovered() {
+///     // the cursor is near the point `data[point]` of the dataset at index `dataset`
+/// }
+/// # }
+/// ```
+///
+/// [`Axis`]: crate::chart::Axis
+/// [`Chart`]: crate::chart::Chart
+/// [`StatefulWidget`]: ratatui_core::widgets::StatefulWidget
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChartState {
+    /// The zoom factors of the X and Y axes
+    zoom: [f64; 2],
+    /// The center of the view, relative to the center of the bounds of the axes, in fractions of
+    /// their range
+    center: [f64; 2],
+    /// The position of the cursor in the terminal
+    cursor: Option<Position>,
+    /// The dataset and the index of the point nearest to the cursor at the last render
+    hovered: Option<(usize, usize)>,
+    /// The area of the graph at the last render
+    graph_area: Rect,
+    /// The bounds of the X and Y axes at the last render
+    visible_bounds: [[f64; 2]; 2],
+}
+
+impl Default for ChartState {
+    fn default() -> Self {
+        Self {
+            zoom: [1.0, 1.0],
+            center: [0.0, 0.0],
+            cursor: None,
+            hovered: None,
+            graph_area: Rect::ZERO,
+            visible_bounds: [[0.0, 0.0]; 2],
+        }
+    }
+}
+
+impl ChartState {
+    /// Returns the zoom factors of the X and Y axes
+    ///
+    /// A factor of `1.0` displays the bounds of the axis, and a factor of `2.0` displays half of
+    /// them.
+    pub const fn zoom_factors(&self) -> [f64; 2] {
+        self.zoom
+    }
+
+    /// Zooms the X and Y axes around the center of the view by the given factors
+    ///
+    /// A factor greater than `1.0` zooms in, and a factor less than `1.0` zooms out. Factors that
+    /// are not strictly positive are ignored.
+    pub fn zoom(&mut self, x_factor: f64, y_factor: f64) {
+        for (zoom, factor) in self.zoom.iter_mut().zip([x_factor, y_factor]) {
+            if factor > 0.0 && factor.is_finite() {
+                *zoom *= factor;
+            }
+        }
+    }
+
+    /// Zooms both axes by the given factor, keeping the value under `position` at the same place
+    ///
+    /// This is useful to zoom with the mouse wheel. The position is relative to the graph area of
+    /// the last render; when it is outside of it, the chart is zoomed around the center of the
+    /// view.
+    pub fn zoom_at(&mut self, position: Position, factor: f64) {
+        if !(factor > 0.0 && factor.is_finite()) {
+            return;
+        }
+        let anchor = self.fractions(position);
+        for axis in 0..2 {
+            let offset = anchor.map_or(0.0, |anchor| anchor[axis] - 0.5);
+            let value = self.center[axis] + offset / self.zoom[axis];
+            self.zoom[axis] *= factor;
+            self.center[axis] = value - offset / self.zoom[axis];
+        }
+    }
+
+    /// Zooms in both axes around the center of the view
+    pub fn zoom_in(&mut self) {
+        self.zoom(ZOOM_STEP, ZOOM_STEP);
+    }
+
+    /// Zooms out both axes around the center of the view
+    pub fn zoom_out(&mut self) {
+        self.zoom(1.0 / ZOOM_STEP, 1.0 / ZOOM_STEP);
+    }
+
+    /// Returns the offsets of the center of the view from the center of the bounds of the axes,
+    /// in fractions of their range
+    pub const fn pan_offsets(&self) -> [f64; 2] {
+        self.center
+    }
+
+    /// Moves the view by the given fractions of the visible range
+    ///
+    /// Positive values move the view to the right and to the top, e.g. `pan(0.5, 0.0)` moves
+    /// the view by half of its width to the right.
+    pub fn pan(&mut self, dx: f64, dy: f64) {
+        for ((center, zoom), delta) in self.center.iter_mut().zip(self.zoom).zip([dx, dy]) {
+            if delta.is_finite() {
+                *center += delta / zoom;
+            }
+        }
+    }
+
+    /// Resets the zoom and the pan, to display the bounds of the axes
+    pub const fn reset_view(&mut self) {
+        self.zoom = [1.0, 1.0];
+        self.center = [0.0, 0.0];
+    }
+
+    /// Returns the position of the cursor
+    pub const fn cursor(&self) -> Option<Position> {
+        self.cursor
+    }
+
+    /// Sets the position of the cursor in the terminal, e.g. the position of the mouse
+    ///
+    /// When the cursor is in the graph area, the chart snaps it to the nearest data point, draws a
+    /// crosshair on it and a tooltip with the values of the datasets. Set it to `None` to hide
+    /// them.
+    pub const fn set_cursor(&mut self, cursor: Option<Position>) {
+        self.cursor = cursor;
+    }
+
+    /// Returns the dataset index and the point index of the data point nearest to the cursor at
+    /// the last render
+    ///
+    /// The point index is an index in the [data](crate::chart::Dataset::data) of the dataset,
+    /// followed by its candles, error bars and box plots.
+    pub const fn hovered(&self) -> Option<(usize, usize)> {
+        self.hovered
+    }
+
+    /// Returns the bounds of the X and Y axes displayed at the last render
+    pub const fn visible_bounds(&self) -> [[f64; 2]; 2] {
+        self.visible_bounds
+    }
+
+    /// Returns `true` if the view is zoomed or panned
+    #[expect(clippy::float_cmp)] // the default view is exactly represented
+    pub(crate) fn is_transformed(&self) -> bool {
+        self.zoom != [1.0, 1.0] || self.center != [0.0, 0.0]
+    }
+
+    /// Returns the bounds displayed on an axis, given its bounds in the linear space of the canvas
+    pub(crate) fn view(&self, axis: usize, [min, max]: [f64; 2]) -> [f64; 2] {
+        let range = max - min;
+        let center = f64::midpoint(min, max) + self.center[axis] * range;
+        let half = range / 2.0 / self.zoom[axis];
+        [center - half, center + half]
+    }
+
+    /// Records the layout of the last render
+    pub(crate) const fn update(
+        &mut self,
+        graph_area: Rect,
+        visible_bounds: [[f64; 2]; 2],
+        hovered: Option<(usize, usize)>,
+    ) {
+        self.graph_area = graph_area;
+        self.visible_bounds = visible_bounds;
+        self.hovered = hovered;
+    }
+
+    /// Returns the position in the graph area of the last render, from `0.0` to `1.0` from left
+    /// to right and from bottom to top
+    fn fractions(&self, position: Position) -> Option<[f64; 2]> {
+        let area = self.graph_area;
+        if !area.contains(position) {
+            return None;
+        }
+        let fraction = |offset: u16, length: u16| {
+            if length > 1 {
+                f64::from(offset) / f64::from(length - 1)
+            } else {
+                0.5
+            }
+        };
+        Some([
+            fraction(position.x - area.x, area.width),
+            1.0 - fraction(position.y - area.y, area.height),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[expect(clippy::float_cmp)]
+    fn zoom_and_pan() {
+        let mut state = ChartState::default();
+        assert!(!state.is_transformed());
+        state.zoom(2.0, 1.0);
+        assert_eq!(state.view(0, [0.0, 100.0]), [25.0, 75.0]);
+        assert_eq!(state.view(1, [0.0, 100.0]), [0.0, 100.0]);
+        state.pan(0.5, -0.5);
+        assert_eq!(state.view(0, [0.0, 100.0]), [50.0, 100.0]);
+        assert_eq!(state.view(1, [0.0, 100.0]), [-50.0, 50.0]);
+        state.reset_view();
+        assert!(!state.is_transformed());
+    }
+
+    #[test]
+    fn zoom_ignores_invalid_factors() {
+        let mut state = ChartState::default();
+        state.zoom(0.0, f64::NAN);
+        state.zoom_at(Position::ORIGIN, -1.0);
+        assert_eq!(state, ChartState::default());
+    }
+
+    #[test]
+    #[expect(clippy::float_cmp)]
+    fn zoom_at_keeps_the_value_under_the_position() {
+        let mut state = ChartState::default();
+        state.update(Rect::new(0, 0, 11, 11), [[0.0, 10.0]; 2], None);
+        state.zoom_at(Position::new(10, 10), 2.0);
+        // the bottom right corner stays at the bottom right
+        assert_eq!(state.view(0, [0.0, 10.0]), [5.0, 10.0]);
+        assert_eq!(state.view(1, [0.0, 10.0]), [0.0, 5.0]);
+    }
+
+    #[test]
+    #[expect(clippy::float_cmp)]
+    fn zoom_in_and_out() {
+        let mut state = ChartState::default();
+        state.zoom_in();
+        assert_eq!(state.zoom_factors(), [1.5, 1.5]);
+        state.zoom_out();
+        assert_eq!(state.zoom_factors(), [1.0, 1.0]);
+    }
+}
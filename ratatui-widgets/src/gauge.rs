@@ -3,7 +3,7 @@ use alloc::format;
 
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
-use ratatui_core::style::{Color, Style, Styled};
+use ratatui_core::style::{Color, Style, Styled, Theme};
 use ratatui_core::symbols;
 use ratatui_core::text::{Line, Span};
 use ratatui_core::widgets::Widget;
@@ -129,6 +129,16 @@ impl<'a> Gauge<'a> {
         self
     }
 
+    /// Sets the styles of the gauge from the `gauge` and `gauge.bar` styles of a [`Theme`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style("gauge");
+        self.gauge_style = theme.style("gauge.bar");
+        self
+    }
+
     /// Sets the style of the bar.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -413,6 +423,18 @@ impl<'a> LineGauge<'a> {
         self.unfilled_style = style.into();
         self
     }
+
+    /// Sets the styles of the line gauge from a [`Theme`].
+    ///
+    /// The style, filled style and unfilled style are replaced by the `line_gauge`,
+    /// `line_gauge.filled` and `line_gauge.unfilled` styles of the theme.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style("line_gauge");
+        self.filled_style = theme.style("line_gauge.filled");
+        self.unfilled_style = theme.style("line_gauge.unfilled");
+        self
+    }
 }
 
 impl Widget for LineGauge<'_> {
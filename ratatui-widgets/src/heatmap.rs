@@ -0,0 +1,705 @@
+//! The [`Heatmap`] widget displays a grid of values as colors.
+use alloc::format;
+use alloc::vec::Vec;
+
+use ratatui_core::buffer::{Buffer, Cell};
+use ratatui_core::layout::Rect;
+use ratatui_core::style::palette::tailwind;
+use ratatui_core::style::{Color, Style, Styled, Theme};
+use ratatui_core::symbols::Marker;
+use ratatui_core::symbols::half_block::{FULL, LOWER, UPPER};
+use ratatui_core::symbols::pixel::QUADRANTS;
+use ratatui_core::text::Line;
+use ratatui_core::widgets::Widget;
+
+use crate::block::{Block, BlockExt};
+#[cfg(not(feature = "std"))]
+use crate::polyfills::F64Polyfills;
+
+/// The symbols of a cell that displays one value above another, indexed by the bit pattern of the
+/// values that are displayed in the foreground color
+const HALF_BLOCKS: [char; 4] = [' ', UPPER, LOWER, FULL];
+
+/// The symbols of a cell that displays one value, indexed like [`HALF_BLOCKS`]
+const BLOCKS: [char; 2] = [' ', FULL];
+
+/// A widget to display a grid of values as colors
+///
+/// Each value of the [data](Heatmap::new) is displayed with the color of the [`ColorScale`] at its
+/// position between the [bounds](Heatmap::bounds) of the heatmap. The first row of the data is
+/// displayed at the top, and the first value of each row on the left. Values that are not finite
+/// (e.g. `f64::NAN`) are considered missing and are not displayed. Rows and columns that don't fit
+/// in the area are not displayed.
+///
+/// By default, each cell of the terminal displays two values, one above the other, using half
+/// block symbols. As terminal cells are about twice as high as wide, the values are displayed as
+/// squares. The [marker](Heatmap::marker) can be changed to display one value per cell, or four
+/// values per cell using quadrant symbols.
+///
+/// The heatmap can also display labels for its [rows](Heatmap::row_labels) and
+/// [columns](Heatmap::column_labels), and a [legend](Heatmap::legend) with the colors of the
+/// scale between the bounds.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::style::palette::tailwind;
+/// use ratatui::widgets::{Block, ColorScale, Heatmap};
+///
+/// let activity = [
+///     [0.0, 1.0, 4.0, 2.0, 0.0, 8.0, 3.0],
+///     [2.0, 0.0, 5.0, 6.0, 1.0, 0.0, 2.0],
+/// ];
+/// let heatmap = Heatmap::new(activity)
+///     .block(Block::bordered().title("Contributions"))
+///     .scale(ColorScale::from_palette(&tailwind::GREEN))
+///     .column_labels(["Mon", "Wed", "Fri"])
+///     .legend(true);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heatmap<'a> {
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Widget style
+    style: Style,
+    /// The rows of values to display
+    data: Vec<Vec<f64>>,
+    /// The values displayed with the first and the last color of the scale, computed from the
+    /// data when `None`
+    bounds: Option<[f64; 2]>,
+    /// The colors of the values
+    scale: ColorScale,
+    /// The symbols used to display the values
+    marker: Marker,
+    /// The labels of the rows, displayed on the left
+    row_labels: Vec<Line<'a>>,
+    /// The labels of the columns, displayed below the grid
+    column_labels: Vec<Line<'a>>,
+    /// Style of the labels and of the legend
+    label_style: Style,
+    /// Whether to display the legend below the grid
+    legend: bool,
+}
+
+/// The colors used to display the values of a [`Heatmap`]
+///
+/// A scale is made of a list of colors, the stops, that are evenly spread from the lowest value
+/// to the highest one. The colors between two [RGB](Color::Rgb) stops are interpolated; between
+/// other colors, the nearest stop is used.
+///
+/// The stops can be taken from a [tailwind palette](ColorScale::from_palette), or be built from
+/// any color, e.g. with `Color::from_hsl` when the `palette` feature of `ratatui` is enabled.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::style::Color;
+/// use ratatui::widgets::ColorScale;
+///
+/// let scale = ColorScale::new([Color::Rgb(0, 0, 255), Color::Rgb(255, 0, 0)]);
+/// assert_eq!(scale.color(0.5), Color::Rgb(128, 0, 128));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColorScale {
+    stops: Vec<Color>,
+}
+
+impl ColorScale {
+    /// Creates a scale that goes through the given colors, from the lowest value to the highest
+    pub fn new<I>(stops: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Color>,
+    {
+        Self {
+            stops: stops.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Creates a scale from the lightest to the darkest shade of a tailwind palette
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::palette::tailwind;
+    /// use ratatui::widgets::ColorScale;
+    ///
+    /// let scale = ColorScale::from_palette(&tailwind::GREEN);
+    /// assert_eq!(scale.color(0.0), tailwind::GREEN.c100);
+    /// assert_eq!(scale.color(1.0), tailwind::GREEN.c900);
+    /// ```
+    pub fn from_palette(palette: &tailwind::Palette) -> Self {
+        Self::new([
+            palette.c100,
+            palette.c300,
+            palette.c500,
+            palette.c700,
+            palette.c900,
+        ])
+    }
+
+    /// Returns the color at the given fraction of the scale, from `0.0` to `1.0`
+    ///
+    /// Fractions outside of this range are clamped to it. An empty scale returns
+    /// [`Color::Reset`].
+    pub fn color(&self, fraction: f64) -> Color {
+        let Some(last) = self.stops.len().checked_sub(1) else {
+            return Color::Reset;
+        };
+        let fraction = if fraction.is_nan() {
+            0.0
+        } else {
+            fraction.clamp(0.0, 1.0)
+        };
+        let position = fraction * last as f64;
+        let index = (position.floor() as usize).min(last);
+        let Some(&next) = self.stops.get(index + 1) else {
+            return self.stops[last];
+        };
+        let t = position - index as f64;
+        match (self.stops[index], next) {
+            (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => {
+                let mix = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round();
+                Color::Rgb(mix(r1, r2) as u8, mix(g1, g2) as u8, mix(b1, b2) as u8)
+            }
+            (color, _) if t < 0.5 => color,
+            (_, color) => color,
+        }
+    }
+}
+
+impl Default for ColorScale {
+    /// Returns the scale of the [blue](tailwind::BLUE) tailwind palette
+    fn default() -> Self {
+        Self::from_palette(&tailwind::BLUE)
+    }
+}
+
+impl<'a> Heatmap<'a> {
+    /// Creates a heatmap from the rows of values to display
+    ///
+    /// Each row can be any type that can be viewed as a slice of `f64`, e.g. an array or a `Vec`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::Heatmap;
+    ///
+    /// let latencies = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]];
+    /// let heatmap = Heatmap::new(&latencies);
+    /// ```
+    pub fn new<R, V>(data: R) -> Self
+    where
+        R: IntoIterator<Item = V>,
+        V: AsRef<[f64]>,
+    {
+        Self {
+            block: None,
+            style: Style::new(),
+            data: data.into_iter().map(|row| row.as_ref().to_vec()).collect(),
+            bounds: None,
+            scale: ColorScale::default(),
+            marker: Marker::HalfBlock,
+            row_labels: Vec::new(),
+            column_labels: Vec::new(),
+            label_style: Style::new(),
+            legend: false,
+        }
+    }
+
+    /// Wraps the heatmap with the given `block`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the entire widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The background is displayed behind the missing values.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the values displayed with the first and the last color of the scale
+    ///
+    /// Values outside of the bounds are displayed with the color of the nearest bound. By default,
+    /// the bounds are the lowest and the highest values of the data.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bounds(mut self, bounds: [f64; 2]) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Sets the colors used to display the values
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn scale(mut self, scale: ColorScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the symbols used to display the values
+    ///
+    /// - [`Marker::Block`] displays one value per cell.
+    /// - [`Marker::Quadrant`] displays two rows of two values per cell. As a cell has only two
+    ///   colors, the four values are split in a group of high values and a group of low values, and
+    ///   each group is displayed with the color of its average value.
+    /// - The other markers, and [`Marker::HalfBlock`] by default, display one value above another
+    ///   in each cell.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn marker(mut self, marker: Marker) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Sets the labels of the rows, displayed on the left of the grid
+    ///
+    /// The label of a row is displayed on the line of its first value. When several rows are
+    /// displayed on the same line, only the label of the first one is displayed.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn row_labels<I>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Line<'a>>,
+    {
+        self.row_labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the labels of the columns, displayed below the grid
+    ///
+    /// The label of a column starts at its first value. Labels that would overlap the previous
+    /// one are not displayed, so an empty label can be used to skip a column.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_labels<I>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Line<'a>>,
+    {
+        self.column_labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the style of the labels and of the legend
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Sets the styles of the heatmap from the `heatmap` and `heatmap.label` styles of a
+    /// [`Theme`]
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style("heatmap");
+        self.label_style = theme.style("heatmap.label");
+        self
+    }
+
+    /// Sets whether to display a legend below the grid
+    ///
+    /// The legend displays the colors of the scale from the lower bound to the upper bound, with
+    /// the values of the bounds at both ends.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+}
+
+impl Default for Heatmap<'_> {
+    fn default() -> Self {
+        Self::new(Vec::<Vec<f64>>::new())
+    }
+}
+
+impl Styled for Heatmap<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Widget for Heatmap<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Heatmap<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        self.render_heatmap(inner, buf);
+    }
+}
+
+impl Heatmap<'_> {
+    fn render_heatmap(&self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        let label_width = self
+            .row_labels
+            .iter()
+            .map(Line::width)
+            .max()
+            .map_or(0, |width| width as u16 + 1);
+        let footer_height = u16::from(!self.column_labels.is_empty()) + u16::from(self.legend);
+        let grid = Rect {
+            x: area.x.saturating_add(label_width).min(area.right()),
+            width: area.width.saturating_sub(label_width),
+            height: area.height.saturating_sub(footer_height),
+            ..area
+        };
+        let bounds = self.resolved_bounds();
+        let (columns, rows, symbols) = self.pattern();
+
+        for (line, row) in grid.rows().zip(0..) {
+            for (cell, column) in line.columns().zip(0..) {
+                let values = (0..rows)
+                    .flat_map(|dy| (0..columns).map(move |dx| (dy, dx)))
+                    .map(|(dy, dx)| self.value(row * rows + dy, column * columns + dx))
+                    .collect::<Vec<_>>();
+                self.render_cell(&values, symbols, bounds, &mut buf[(cell.x, cell.y)]);
+            }
+        }
+
+        self.render_row_labels(area, grid, rows, buf);
+        let mut footer = Rect::new(grid.x, grid.bottom(), grid.width, 1).intersection(area);
+        if !self.column_labels.is_empty() {
+            self.render_column_labels(footer, columns, buf);
+            footer.y = footer.y.saturating_add(1);
+        }
+        if self.legend {
+            self.render_legend(footer.intersection(area), bounds, buf);
+        }
+    }
+
+    /// Returns the number of values displayed per cell horizontally and vertically, and the
+    /// symbols of the cells
+    const fn pattern(&self) -> (usize, usize, &'static [char]) {
+        match self.marker {
+            Marker::Block => (1, 1, &BLOCKS),
+            Marker::Quadrant => (2, 2, &QUADRANTS),
+            _ => (1, 2, &HALF_BLOCKS),
+        }
+    }
+
+    /// Returns the value at the given row and column, or `None` if it is missing
+    fn value(&self, row: usize, column: usize) -> Option<f64> {
+        let value = *self.data.get(row)?.get(column)?;
+        value.is_finite().then_some(value)
+    }
+
+    /// Returns the bounds of the heatmap, computed from the data when they are not set
+    fn resolved_bounds(&self) -> [f64; 2] {
+        self.bounds.unwrap_or_else(|| {
+            self.data
+                .iter()
+                .flatten()
+                .filter(|value| value.is_finite())
+                .fold(None, |bounds: Option<[f64; 2]>, &value| {
+                    Some(bounds.map_or([value, value], |[min, max]| {
+                        [min.min(value), max.max(value)]
+                    }))
+                })
+                .unwrap_or_default()
+        })
+    }
+
+    /// Returns the color of a value between the bounds
+    fn color(&self, value: f64, [min, max]: [f64; 2]) -> Color {
+        let range = max - min;
+        let fraction = if range > 0.0 {
+            (value - min) / range
+        } else {
+            0.0
+        };
+        self.scale.color(fraction)
+    }
+
+    /// Draws the values of a cell, in row-major order, with the symbols indexed by the bit
+    /// pattern of the values displayed in the foreground color
+    fn render_cell(
+        &self,
+        values: &[Option<f64>],
+        symbols: &[char],
+        bounds: [f64; 2],
+        cell: &mut Cell,
+    ) {
+        let present = values.iter().flatten().copied().collect::<Vec<_>>();
+        if present.is_empty() {
+            return;
+        }
+        let mean = |values: &[f64]| values.iter().sum::<f64>() / values.len() as f64;
+        let mask = |high: &dyn Fn(f64) -> bool| {
+            values
+                .iter()
+                .enumerate()
+                .filter(|(_, value)| value.is_some_and(high))
+                .fold(0, |mask, (i, _)| mask | 1 << i)
+        };
+        if present.len() < values.len() {
+            // the missing values are displayed with the background of the cell
+            cell.set_char(symbols[mask(&|_| true)])
+                .set_fg(self.color(mean(&present), bounds));
+            return;
+        }
+        let low = present.iter().copied().fold(f64::INFINITY, f64::min);
+        let high = present.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let middle = f64::midpoint(low, high);
+        let (highs, lows): (Vec<f64>, Vec<f64>) = present.iter().partition(|&&v| v > middle);
+        if highs.is_empty() {
+            cell.set_char(symbols[symbols.len() - 1])
+                .set_fg(self.color(mean(&lows), bounds));
+        } else {
+            cell.set_char(symbols[mask(&|value| value > middle)])
+                .set_fg(self.color(mean(&highs), bounds))
+                .set_bg(self.color(mean(&lows), bounds));
+        }
+    }
+
+    /// Draws the labels of the rows on the left of the grid
+    fn render_row_labels(&self, area: Rect, grid: Rect, rows: usize, buf: &mut Buffer) {
+        let width = grid.x - area.x;
+        if width == 0 {
+            return;
+        }
+        for (y, label) in grid.rows().zip(self.row_labels.iter().step_by(rows)) {
+            let label_area = Rect::new(area.x, y.y, width - 1, 1);
+            buf.set_style(label_area, self.label_style);
+            label.render(label_area, buf);
+        }
+    }
+
+    /// Draws the labels of the columns, skipping the ones that overlap the previous label
+    fn render_column_labels(&self, area: Rect, columns: usize, buf: &mut Buffer) {
+        let mut next_x = area.left();
+        for (i, label) in self.column_labels.iter().enumerate() {
+            let Ok(offset) = u16::try_from(i / columns) else {
+                break;
+            };
+            let x = area.x.saturating_add(offset);
+            if x >= area.right() {
+                break;
+            }
+            if x < next_x || label.width() == 0 {
+                continue;
+            }
+            let width = (label.width() as u16).min(area.right() - x);
+            let label_area = Rect::new(x, area.y, width, 1);
+            buf.set_style(label_area, self.label_style);
+            label.render(label_area, buf);
+            next_x = x + width + 1;
+        }
+    }
+
+    /// Draws the colors of the scale between the values of the bounds
+    fn render_legend(&self, area: Rect, bounds: [f64; 2], buf: &mut Buffer) {
+        let [min, max] = bounds.map(|value| format!("{value}"));
+        let labels_width = (min.len() + max.len() + 2) as u16;
+        if area.is_empty() || area.width <= labels_width {
+            return;
+        }
+        buf.set_style(area, self.label_style);
+        buf.set_string(area.x, area.y, &min, self.label_style);
+        buf.set_string(
+            area.right() - max.len() as u16,
+            area.y,
+            &max,
+            self.label_style,
+        );
+        let gradient = Rect {
+            x: area.x + min.len() as u16 + 1,
+            width: area.width - labels_width,
+            ..area
+        };
+        let last = f64::from(gradient.width.saturating_sub(1).max(1));
+        for (position, i) in gradient.columns().zip(0..) {
+            buf[(position.x, position.y)]
+                .set_char(FULL)
+                .set_fg(self.scale.color(f64::from(i) / last));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use ratatui_core::style::Stylize;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::start(0.0, Color::Rgb(0, 0, 0))]
+    #[case::middle(0.25, Color::Rgb(50, 0, 0))]
+    #[case::stop(0.5, Color::Rgb(100, 0, 0))]
+    #[case::end(1.0, Color::Rgb(100, 200, 0))]
+    #[case::clamped_low(-1.0, Color::Rgb(0, 0, 0))]
+    #[case::clamped_high(2.0, Color::Rgb(100, 200, 0))]
+    #[case::nan(f64::NAN, Color::Rgb(0, 0, 0))]
+    fn color_scale_interpolates_rgb(#[case] fraction: f64, #[case] expected: Color) {
+        let scale = ColorScale::new([
+            Color::Rgb(0, 0, 0),
+            Color::Rgb(100, 0, 0),
+            Color::Rgb(100, 200, 0),
+        ]);
+        assert_eq!(scale.color(fraction), expected);
+    }
+
+    #[test]
+    fn color_scale_uses_nearest_named_color() {
+        let scale = ColorScale::new([Color::Red, Color::Rgb(0, 0, 255)]);
+        assert_eq!(scale.color(0.4), Color::Red);
+        assert_eq!(scale.color(0.6), Color::Rgb(0, 0, 255));
+        assert_eq!(ColorScale::new::<[Color; 0]>([]).color(0.5), Color::Reset);
+    }
+
+    fn scale() -> ColorScale {
+        ColorScale::new([Color::Black, Color::Red, Color::Yellow, Color::White])
+    }
+
+    #[test]
+    fn render_blocks() {
+        let data = [vec![0.0], vec![1.0, 2.0, 3.0]];
+        let heatmap = Heatmap::new(data).scale(scale()).marker(Marker::Block);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        heatmap.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["█  ", "███"]);
+        expected[(0, 0)].set_fg(Color::Black);
+        expected[(0, 1)].set_fg(Color::Red);
+        expected[(1, 1)].set_fg(Color::Yellow);
+        expected[(2, 1)].set_fg(Color::White);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_half_blocks() {
+        let data = [vec![0.0, f64::NAN, 3.0], vec![1.0, 2.0]];
+        let heatmap = Heatmap::new(data).scale(scale());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        heatmap.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["▄▄▀"]);
+        expected[(0, 0)].set_fg(Color::Red).set_bg(Color::Black);
+        expected[(1, 0)].set_fg(Color::Yellow);
+        expected[(2, 0)].set_fg(Color::White);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_quadrants() {
+        let data = [[0.0, 3.0, 1.0], [3.0, 3.0, f64::NAN]];
+        let heatmap = Heatmap::new(data).scale(scale()).marker(Marker::Quadrant);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        heatmap.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["▟▘"]);
+        expected[(0, 0)].set_fg(Color::White).set_bg(Color::Black);
+        expected[(1, 0)].set_fg(Color::Red);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_labels_and_legend() {
+        let data = [[0.0, 1.0, 2.0, 3.0], [3.0, 2.0, 1.0, 0.0]];
+        let heatmap = Heatmap::new(data)
+            .scale(scale())
+            .marker(Marker::Block)
+            .row_labels(["a", "bb"])
+            .column_labels(["1", "", "3"])
+            .legend(true)
+            .label_style(Style::new().italic());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 4));
+        heatmap.render(buffer.area, &mut buffer);
+        let mut expected =
+            Buffer::with_lines(["a  ████    ", "bb ████    ", "   1 3     ", "   0 ████ 3"]);
+        expected.set_style(Rect::new(0, 0, 2, 2), Style::new().italic());
+        expected.set_style(Rect::new(3, 2, 1, 1), Style::new().italic());
+        expected.set_style(Rect::new(5, 2, 1, 1), Style::new().italic());
+        expected.set_style(Rect::new(3, 3, 8, 1), Style::new().italic());
+        for (x, color) in [Color::Black, Color::Red, Color::Yellow, Color::White]
+            .into_iter()
+            .enumerate()
+        {
+            let x = x as u16;
+            expected[(3 + x, 0)].set_fg(color);
+            expected[(6 - x, 1)].set_fg(color);
+            expected[(5 + x, 3)].set_fg(color);
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn bounds_clamp_values() {
+        let heatmap = Heatmap::new([[-5.0, 3.0, 50.0]])
+            .scale(scale())
+            .bounds([0.0, 10.0])
+            .marker(Marker::Block);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        heatmap.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["███"]);
+        expected[(0, 0)].set_fg(Color::Black);
+        expected[(1, 0)].set_fg(Color::Red);
+        expected[(2, 0)].set_fg(Color::White);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_in_small_area() {
+        let heatmap = Heatmap::new([[1.0, 2.0]])
+            .row_labels(["long label"])
+            .column_labels(["a"])
+            .legend(true);
+        for width in 0..4 {
+            for height in 0..4 {
+                let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+                heatmap.clone().render(buffer.area, &mut buffer);
+            }
+        }
+    }
+
+    #[test]
+    fn can_be_stylized() {
+        assert_eq!(
+            Heatmap::default().black().on_white().bold().style,
+            Style::default().black().on_white().bold()
+        );
+    }
+}
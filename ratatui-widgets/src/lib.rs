@@ -41,15 +41,22 @@
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
 //! - [`Fill`]: paints every cell in its area with a single repeated symbol and style.
 //! - [`Gauge`]: displays progress percentage using block characters.
+//! - [`Heatmap`]: displays a grid of values as colors.
 //! - [`LineGauge`]: displays progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
+//! - [`Menu`]: displays a menu bar or a context menu with nested submenus.
 //! - [`RatatuiLogo`]: displays the Ratatui logo.
 //! - [`RatatuiMascot`]: displays the Ratatui mascot.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
+//! - [`PieChart`]: displays the proportions of values as the slices of a pie or a donut.
+//! - [`Popup`]: displays a dialog with a title, a body and buttons over other widgets.
 //! - [`Scrollbar`]: displays a scrollbar.
-//! - [`Sparkline`]: displays a single dataset as a sparkline.
+//! - [`Sparkline`]: displays one or more datasets as a sparkline.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`TextArea`]: edits multiple lines of text.
+//! - [`TextInput`]: edits a single line of text.
+//! - [`Tree`]: displays hierarchical items and allows opening, closing and selecting them.
 //!
 //! [`BarChart`]: crate::barchart::BarChart
 //! [`Block`]: crate::block::Block
@@ -59,15 +66,22 @@
 //! [`Clear`]: crate::clear::Clear
 //! [`Fill`]: crate::fill::Fill
 //! [`Gauge`]: crate::gauge::Gauge
+//! [`Heatmap`]: crate::heatmap::Heatmap
 //! [`LineGauge`]: crate::gauge::LineGauge
 //! [`List`]: crate::list::List
+//! [`Menu`]: crate::menu::Menu
 //! [`RatatuiLogo`]: crate::logo::RatatuiLogo
 //! [`RatatuiMascot`]: crate::mascot::RatatuiMascot
 //! [`Paragraph`]: crate::paragraph::Paragraph
+//! [`PieChart`]: crate::piechart::PieChart
+//! [`Popup`]: crate::popup::Popup
 //! [`Scrollbar`]: crate::scrollbar::Scrollbar
 //! [`Sparkline`]: crate::sparkline::Sparkline
 //! [`Table`]: crate::table::Table
 //! [`Tabs`]: crate::tabs::Tabs
+//! [`TextArea`]: crate::text_area::TextArea
+//! [`TextInput`]: crate::text_input::TextInput
+//! [`Tree`]: crate::tree::Tree
 //!
 //! All these widgets are re-exported directly under `ratatui::widgets` in the `ratatui` crate.
 //!
@@ -120,18 +134,26 @@ pub mod chart;
 pub mod clear;
 pub mod fill;
 pub mod gauge;
+pub mod heatmap;
 pub mod list;
 pub mod logo;
 pub mod mascot;
+pub mod menu;
 pub mod paragraph;
+pub mod piechart;
+pub mod popup;
 pub mod scrollbar;
 pub mod sparkline;
 pub mod table;
 pub mod tabs;
+pub mod text_area;
+pub mod text_input;
+pub mod tree;
 
 #[cfg(not(feature = "std"))]
 mod polyfills;
 mod reflow;
+mod render_cache;
 
 mod as_ref;
 #[cfg(feature = "calendar")]
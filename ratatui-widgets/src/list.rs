@@ -3,22 +3,28 @@
 
 use alloc::vec::Vec;
 
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::style::{Modifier, Style, Styled, Theme};
 use ratatui_core::text::Line;
 use strum::{Display, EnumString};
 
 pub use self::item::ListItem;
+pub use self::source::ListSource;
+pub(crate) use self::source::SourceRef;
 pub use self::state::ListState;
 use crate::block::Block;
 use crate::table::HighlightSpacing;
 
 mod item;
 mod rendering;
+mod source;
 mod state;
 
+const DEFAULT_SELECTION_STYLE: Style = Style::new().add_modifier(Modifier::BOLD);
+
 /// A widget to display several items among which one can be selected (optional)
 ///
-/// A list is a collection of [`ListItem`]s.
+/// A list is a collection of [`ListItem`]s. For very large datasets, [`List::from_source`] creates
+/// a list whose items are created on demand by a [`ListSource`] when they are visible.
 ///
 /// This is different from a [`Table`] because it does not handle columns, headers or footers and
 /// the item's height is automatically determined. A `List` can also be put in reverse order (i.e.
@@ -40,6 +46,7 @@ mod state;
 ///
 /// - [`List::highlight_style`] sets the style of the selected item.
 /// - [`List::highlight_symbol`] sets the symbol to be displayed in front of the selected item.
+/// - [`List::selection_style`] sets the style of the items of the multi-selection.
 /// - [`List::repeat_highlight_symbol`] sets whether to repeat the symbol and style over selected
 ///   multi-line items
 /// - [`List::direction`] sets the list direction
@@ -105,18 +112,22 @@ mod state;
 /// [`Text::alignment`]: ratatui_core::text::Text::alignment
 /// [`StatefulWidget`]: ratatui_core::widgets::StatefulWidget
 /// [`Widget`]: ratatui_core::widgets::Widget
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct List<'a> {
     /// An optional block to wrap the widget in
     pub(crate) block: Option<Block<'a>>,
     /// The items in the list
     pub(crate) items: Vec<ListItem<'a>>,
+    /// The source that creates the items on demand, used instead of `items` when set
+    pub(crate) source: Option<SourceRef<'a, dyn ListSource + 'a>>,
     /// Style used as a base style for the widget
     pub(crate) style: Style,
     /// List display direction
     pub(crate) direction: ListDirection,
     /// Style used to render selected item
     pub(crate) highlight_style: Style,
+    /// Style used to render the items of the multi-selection
+    pub(crate) selection_style: Style,
     /// Symbol in front of the selected item (Shift all items to the right)
     pub(crate) highlight_symbol: Option<Line<'a>>,
     /// Whether to repeat the highlight symbol for each line of the selected item
@@ -142,6 +153,24 @@ pub enum ListDirection {
     BottomToTop,
 }
 
+impl Default for List<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            items: Vec::new(),
+            source: None,
+            style: Style::new(),
+            direction: ListDirection::default(),
+            highlight_style: Style::new(),
+            selection_style: DEFAULT_SELECTION_STYLE,
+            highlight_symbol: None,
+            repeat_highlight_symbol: false,
+            highlight_spacing: HighlightSpacing::default(),
+            scroll_padding: 0,
+        }
+    }
+}
+
 impl<'a> List<'a> {
     /// Creates a new list from [`ListItem`]s
     ///
@@ -188,14 +217,47 @@ impl<'a> List<'a> {
         T::Item: Into<ListItem<'a>>,
     {
         Self {
-            block: None,
-            style: Style::default(),
             items: items.into_iter().map(Into::into).collect(),
-            direction: ListDirection::default(),
             ..Self::default()
         }
     }
 
+    /// Creates a new list whose items are created on demand by a [`ListSource`]
+    ///
+    /// Only the items that are visible are created when the list is rendered, and only the
+    /// heights of the items around the offset and the selection are queried, so the list renders
+    /// in constant time whatever the number of items.
+    ///
+    /// Setting the [items](List::items) afterwards replaces the source.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{List, ListItem, ListSource};
+    ///
+    /// #[derive(Debug)]
+    /// struct Squares;
+    ///
+    /// impl ListSource for Squares {
+    ///     fn len(&self) -> usize {
+    ///         1_000_000
+    ///     }
+    ///
+    ///     fn item(&self, index: usize) -> ListItem<'_> {
+    ///         ListItem::new(format!("{}", index * index))
+    ///     }
+    /// }
+    ///
+    /// let list = List::from_source(&Squares);
+    /// assert_eq!(list.len(), 1_000_000);
+    /// ```
+    pub fn from_source(source: &'a dyn ListSource) -> Self {
+        Self {
+            source: Some(SourceRef(source)),
+            ..Self::new(Vec::<ListItem>::new())
+        }
+    }
+
     /// Set the items
     ///
     /// The `items` parameter accepts any value that can be converted into an iterator of
@@ -219,6 +281,7 @@ impl<'a> List<'a> {
         T::Item: Into<ListItem<'a>>,
     {
         self.items = items.into_iter().map(Into::into).collect();
+        self.source = None;
         self
     }
 
@@ -283,6 +346,29 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Sets the styles of the list from a [`Theme`].
+    ///
+    /// The style, highlight style and selection style are replaced by the `list`,
+    /// `list.highlight` and `list.selection` styles of the theme. The selection style is patched
+    /// on top of the default bold style.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self.
+    ///
+    /// ```rust
+    /// use ratatui::style::Theme;
+    /// use ratatui::widgets::List;
+    ///
+    /// let theme = Theme::tailwind_dark();
+    /// let list = List::new(["Item 1", "Item 2"]).theme(&theme);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style("list");
+        self.highlight_style = theme.style("list.highlight");
+        self.selection_style = DEFAULT_SELECTION_STYLE.patch(theme.style("list.selection"));
+        self
+    }
+
     /// Set the symbol to be displayed in front of the selected item
     ///
     /// By default there are no highlight symbol.
@@ -331,6 +417,34 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Set the style of the items of the multi-selection
+    ///
+    /// The items added to the multi-selection of the [`ListState`] are rendered with this style,
+    /// under the [highlight style](List::highlight_style) of the selected item. By default, they
+    /// are bold.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::List;
+    ///
+    /// let items = ["Item 1", "Item 2"];
+    /// let list = List::new(items).selection_style(Style::new().green());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selection_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selection_style = style.into();
+        self
+    }
+
     /// Set whether to repeat the highlight symbol and style over selected multi-line items
     ///
     /// This is `false` by default.
@@ -417,14 +531,18 @@ impl<'a> List<'a> {
         self
     }
 
-    /// Returns the number of [`ListItem`]s in the list
-    pub const fn len(&self) -> usize {
-        self.items.len()
+    /// Returns the number of items in the list
+    ///
+    /// This is the length of the [`ListSource`] of the list if it has one, and the number of
+    /// [`ListItem`]s otherwise.
+    pub fn len(&self) -> usize {
+        self.source
+            .map_or(self.items.len(), |source| source.0.len())
     }
 
     /// Returns true if the list contains no elements.
-    pub const fn is_empty(&self) -> bool {
-        self.items.is_empty()
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 }
 
@@ -481,6 +599,19 @@ mod tests {
         assert_eq!(collected, expected);
     }
 
+    #[test]
+    fn theme() {
+        let theme = Theme::new()
+            .with_style("list", Style::new().white())
+            .with_style("list.highlight", Style::new().bold())
+            .with_style("list.selection", Style::new().italic());
+        let list = List::new(["Item 0"]).theme(&theme);
+        assert_eq!(list.style, Style::new().white());
+        assert_eq!(list.highlight_style, Style::new().white().bold());
+        assert_eq!(list.selection_style, Style::new().white().bold().italic());
+        assert_eq!(List::new(["Item 0"]).style, Style::new());
+    }
+
     #[test]
     fn can_be_stylized() {
         assert_eq!(
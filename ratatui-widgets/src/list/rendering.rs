@@ -1,10 +1,12 @@
+use alloc::borrow::Cow;
+
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
 use ratatui_core::text::{Line, ToLine};
 use ratatui_core::widgets::{StatefulWidget, Widget};
 
 use crate::block::BlockExt;
-use crate::list::{List, ListDirection, ListState};
+use crate::list::{List, ListDirection, ListItem, ListState};
 
 impl Widget for List<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
@@ -39,14 +41,17 @@ impl StatefulWidget for &List<'_> {
             return;
         }
 
-        if self.items.is_empty() {
+        let item_count = self.len();
+        if item_count == 0 {
             state.select(None);
+            state.selection.clear();
             return;
         }
+        state.selection.truncate(item_count);
 
         // If the selected index is out of bounds, set it to the last item
-        if state.selected.is_some_and(|s| s >= self.items.len()) {
-            state.select(Some(self.items.len().saturating_sub(1)));
+        if state.selected.is_some_and(|s| s >= item_count) {
+            state.select(Some(item_count.saturating_sub(1)));
         }
 
         let list_height = list_area.height as usize;
@@ -69,23 +74,19 @@ impl StatefulWidget for &List<'_> {
 
         let mut current_height = 0;
         let selection_spacing = self.highlight_spacing.should_add(state.selected.is_some());
-        for (i, item) in self
-            .items
-            .iter()
-            .enumerate()
-            .skip(state.offset)
-            .take(last_visible_index - first_visible_index)
-        {
+        for i in first_visible_index..last_visible_index {
+            let item = self.item(i);
+            let item_height = self.item_height(i) as u16;
             let (x, y) = if self.direction == ListDirection::BottomToTop {
-                current_height += item.height() as u16;
+                current_height += item_height;
                 (list_area.left(), list_area.bottom() - current_height)
             } else {
                 let pos = (list_area.left(), list_area.top() + current_height);
-                current_height += item.height() as u16;
+                current_height += item_height;
                 pos
             };
 
-            let row_area = Rect::new(x, y, list_area.width, item.height() as u16);
+            let row_area = Rect::new(x, y, list_area.width, item_height);
 
             let item_style = self.style.patch(item.style);
             buf.set_style(row_area, item_style);
@@ -103,11 +104,14 @@ impl StatefulWidget for &List<'_> {
             };
             Widget::render(&item.content, item_area, buf);
 
+            if state.selection.contains(i) {
+                buf.set_style(row_area, self.selection_style);
+            }
             if is_selected {
                 buf.set_style(row_area, self.highlight_style);
             }
             if selection_spacing {
-                for j in 0..item.content.height() {
+                for j in 0..item.content.height().min(item_height as usize) {
                     // if the item is selected, we need to display the highlight symbol:
                     // - either for the first line of the item only,
                     // - or for each line of the item if the appropriate option is set
@@ -125,6 +129,22 @@ impl StatefulWidget for &List<'_> {
 }
 
 impl List<'_> {
+    /// Returns the item at `index`, borrowed from the list or created by its source
+    fn item(&self, index: usize) -> Cow<'_, ListItem<'_>> {
+        match self.source {
+            Some(source) => Cow::Owned(source.0.item(index)),
+            None => Cow::Borrowed(&self.items[index]),
+        }
+    }
+
+    /// Returns the height of the item at `index`, as given by the source of the list if any
+    fn item_height(&self, index: usize) -> usize {
+        match self.source {
+            Some(source) => usize::from(source.0.height(index)),
+            None => self.items[index].height(),
+        }
+    }
+
     /// Given an offset, calculate which items can fit in a given area
     fn get_items_bounds(
         &self,
@@ -132,7 +152,8 @@ impl List<'_> {
         offset: usize,
         max_height: usize,
     ) -> (usize, usize) {
-        let offset = offset.min(self.items.len().saturating_sub(1));
+        let item_count = self.len();
+        let offset = offset.min(item_count.saturating_sub(1));
 
         // Note: visible here implies visible in the given area
         let mut first_visible_index = offset;
@@ -143,12 +164,13 @@ impl List<'_> {
 
         // Calculate the last visible index and total height of the items
         // that will fit in the available space
-        for item in self.items.iter().skip(offset) {
-            if height_from_offset + item.height() > max_height {
+        for index in offset..item_count {
+            let item_height = self.item_height(index);
+            if height_from_offset + item_height > max_height {
                 break;
             }
 
-            height_from_offset += item.height();
+            height_from_offset += item_height;
 
             last_visible_index += 1;
         }
@@ -171,7 +193,7 @@ impl List<'_> {
         // the offset is still set), we still need to show this item
         while index_to_display >= last_visible_index {
             height_from_offset =
-                height_from_offset.saturating_add(self.items[last_visible_index].height());
+                height_from_offset.saturating_add(self.item_height(last_visible_index));
 
             last_visible_index += 1;
 
@@ -179,7 +201,7 @@ impl List<'_> {
             // for the selected/offset item
             while height_from_offset > max_height {
                 height_from_offset =
-                    height_from_offset.saturating_sub(self.items[first_visible_index].height());
+                    height_from_offset.saturating_sub(self.item_height(first_visible_index));
 
                 // Remove this item to view by starting at the next item index
                 first_visible_index += 1;
@@ -192,14 +214,14 @@ impl List<'_> {
             first_visible_index -= 1;
 
             height_from_offset =
-                height_from_offset.saturating_add(self.items[first_visible_index].height());
+                height_from_offset.saturating_add(self.item_height(first_visible_index));
 
             // Don't show an item if it is beyond our viewable height
             while height_from_offset > max_height {
                 last_visible_index -= 1;
 
                 height_from_offset =
-                    height_from_offset.saturating_sub(self.items[last_visible_index].height());
+                    height_from_offset.saturating_sub(self.item_height(last_visible_index));
             }
         }
 
@@ -217,7 +239,7 @@ impl List<'_> {
         first_visible_index: usize,
         last_visible_index: usize,
     ) -> Option<usize> {
-        let last_valid_index = self.items.len().saturating_sub(1);
+        let last_valid_index = self.len().saturating_sub(1);
         let selected = selected?.min(last_valid_index);
 
         // The below loop handles situations where the list item sizes may not be consistent,
@@ -232,7 +254,7 @@ impl List<'_> {
                     .saturating_add(scroll_padding)
                     .min(last_valid_index)
             {
-                height_around_selected += self.items[index].height();
+                height_around_selected += self.item_height(index);
             }
             if height_around_selected <= max_height {
                 break;
@@ -262,7 +284,7 @@ mod tests {
     use pretty_assertions::assert_eq;
     use ratatui_core::layout::{Alignment, Rect};
     use ratatui_core::style::{Color, Modifier, Style, Stylize};
-    use ratatui_core::text::Line;
+    use ratatui_core::text::{Line, Text, Truncation};
     use ratatui_core::widgets::{StatefulWidget, Widget};
     use rstest::{fixture, rstest};
 
@@ -669,6 +691,28 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn selection_style() {
+        let list = List::new(["Item 0", "Item 1", "Item 2", "Item 3"])
+            .highlight_style(Style::default().fg(Color::Yellow))
+            .selection_style(Style::default().fg(Color::Green));
+        let mut state = ListState::default()
+            .with_selected(Some(1))
+            .with_selection([1, 2, 7].into_iter().collect());
+        let buffer = stateful_widget(list, &mut state, 10, 4);
+        let expected = Buffer::with_lines([
+            "Item 0    ".into(),
+            "Item 1    ".yellow(),
+            "Item 2    ".green(),
+            "Item 3    ".into(),
+        ]);
+        assert_eq!(buffer, expected);
+        assert_eq!(
+            state.selection().iter().collect::<Vec<_>>(),
+            (1..3).collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn highlight_symbol_style_and_style() {
         let list = List::new(["Item 0", "Item 1", "Item 2"])
@@ -854,6 +898,18 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn truncate_long_items() {
+        let list = List::new([
+            ListItem::new(Line::from("A very long item").truncation(Truncation::end())),
+            ListItem::new(Text::from("src/widgets/list.rs").truncation(Truncation::middle())),
+            ListItem::new("Not truncated"),
+        ]);
+        let buffer = widget(list, 10, 3);
+        let expected = Buffer::with_lines(["A very lo…", "src/w…t.rs", "Not trunca"]);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn offset_renders_shifted() {
         let list = List::new([
@@ -1269,4 +1325,55 @@ mod tests {
         StatefulWidget::render(list, single_line_buf.area, &mut single_line_buf, &mut state);
         assert_eq!(single_line_buf, Buffer::with_lines([expected]));
     }
+
+    /// A source of a million items that counts the items it creates
+    #[derive(Debug, Default)]
+    struct Counted {
+        created: core::sync::atomic::AtomicUsize,
+    }
+
+    impl crate::list::ListSource for Counted {
+        fn len(&self) -> usize {
+            1_000_000
+        }
+
+        fn item(&self, index: usize) -> ListItem<'_> {
+            self.created
+                .fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+            ListItem::new(alloc::format!("Item {index}"))
+        }
+
+        fn height(&self, _index: usize) -> u16 {
+            1
+        }
+    }
+
+    #[test]
+    fn source_only_creates_visible_items() {
+        let source = Counted::default();
+        let list = List::from_source(&source).highlight_symbol(">>");
+        let mut state = ListState::default().with_selected(Some(500_000));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 3));
+        StatefulWidget::render(&list, buf.area, &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["  Item 499998 ", "  Item 499999 ", ">>Item 500000 ",])
+        );
+        assert_eq!(state.offset, 499_998);
+        assert_eq!(
+            source.created.load(core::sync::atomic::Ordering::Relaxed),
+            3
+        );
+    }
+
+    #[test]
+    fn source_clamps_selection() {
+        let source = Counted::default();
+        let list = List::from_source(&source);
+        let mut state = ListState::default().with_selected(Some(usize::MAX));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+        StatefulWidget::render(&list, buf.area, &mut buf, &mut state);
+        assert_eq!(state.selected, Some(999_999));
+        assert_eq!(buf, Buffer::with_lines(["Item 999999 "]));
+    }
 }
@@ -0,0 +1,158 @@
+use core::hash::{Hash, Hasher};
+use core::panic::RefUnwindSafe;
+use core::{fmt, ptr};
+
+use crate::list::ListItem;
+
+/// A provider of [`ListItem`]s that are created on demand
+///
+/// A [`List`] created with [`List::from_source`] does not store its items. When it is rendered,
+/// it asks the source for the number of items and for the height of the items around the
+/// [offset](crate::list::ListState::offset) and the selection, and only creates the items that
+/// are visible. This keeps the cost of a frame independent of the size of the dataset, so a list
+/// can display millions of rows read from a database, a file or a computed sequence.
+///
+/// The height of an item is computed from the item itself by default. When creating an item is
+/// expensive, override [`ListSource::height`] to return a known or an estimated height: the item
+/// is then rendered in an area of that height, and clipped if it is taller.
+///
+/// A source is borrowed by the list, so it must be [`Sync`] and [`RefUnwindSafe`] to keep the
+/// auto traits of [`List`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::widgets::{List, ListItem, ListSource, ListState};
+///
+/// #[derive(Debug)]
+/// struct Numbers(usize);
+///
+/// impl ListSource for Numbers {
+///     fn len(&self) -> usize {
+///         self.0
+///     }
+///
+///     fn item(&self, index: usize) -> ListItem<'_> {
+///         ListItem::new(format!("Item {index}"))
+///     }
+///
+///     fn height(&self, _index: usize) -> u16 {
+///         1
+///     }
+/// }
+///
+/// # fn ui(frame: &mut Frame) {
+/// let numbers = Numbers(10_000_000);
+/// let list = List::from_source(&numbers).highlight_symbol(">>");
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = ListState::default().with_selected(Some(5_000_000));
+///
+/// frame.render_stateful_widget(list, frame.area(), &mut state);
+/// # }
+/// ```
+///
+/// [`List`]: crate::list::List
+/// [`List::from_source`]: crate::list::List::from_source
+pub trait ListSource: fmt::Debug + Sync + RefUnwindSafe {
+    /// Returns the number of items
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no items
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Creates the item at `index`
+    ///
+    /// `index` is always less than [`ListSource::len`].
+    fn item(&self, index: usize) -> ListItem<'_>;
+
+    /// Returns the height of the item at `index`, in lines
+    ///
+    /// The default implementation creates the item and returns its height.
+    fn height(&self, index: usize) -> u16 {
+        u16::try_from(self.item(index).height()).unwrap_or(u16::MAX)
+    }
+}
+
+/// A borrowed data source of a widget, compared and hashed by address
+pub(crate) struct SourceRef<'a, T: ?Sized>(pub(crate) &'a T);
+
+impl<T: ?Sized> Clone for SourceRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for SourceRef<'_, T> {}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for SourceRef<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: ?Sized> PartialEq for SourceRef<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::addr_eq(self.0, other.0)
+    }
+}
+
+impl<T: ?Sized> Eq for SourceRef<'_, T> {}
+
+impl<T: ?Sized> Hash for SourceRef<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        ptr::hash(ptr::from_ref(self.0).cast::<()>(), state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+    use crate::list::List;
+
+    #[derive(Debug)]
+    struct Numbers(usize);
+
+    impl ListSource for Numbers {
+        fn len(&self) -> usize {
+            self.0
+        }
+
+        fn item(&self, index: usize) -> ListItem<'_> {
+            ListItem::new(format!("Item {index}\nLine 2"))
+        }
+    }
+
+    #[test]
+    fn default_height_uses_item() {
+        assert_eq!(Numbers(3).height(1), 2);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(Numbers(0).is_empty());
+        assert!(!Numbers(1).is_empty());
+    }
+
+    #[test]
+    fn list_len() {
+        let list = List::from_source(&Numbers(3));
+        assert_eq!(list.len(), 3);
+        assert!(!list.is_empty());
+        assert!(List::from_source(&Numbers(0)).is_empty());
+        assert_eq!(List::new(["a", "b"]).len(), 2);
+    }
+
+    #[test]
+    fn source_ref_compares_addresses() {
+        let a = Numbers(1);
+        let b = Numbers(1);
+        assert_eq!(SourceRef(&a), SourceRef(&a));
+        assert_ne!(SourceRef(&a), SourceRef(&b));
+    }
+}
@@ -1,3 +1,5 @@
+use crate::table::{Selection, SelectionEdit};
+
 /// State of the [`List`] widget
 ///
 /// This state can be used to scroll through items and select one. When the list is rendered as a
@@ -5,12 +7,21 @@
 /// that the selected item is visible. This will modify the [`ListState`] object passed to the
 /// `Frame::render_stateful_widget` method.
 ///
-/// The state consists of two fields:
+/// The state consists of three fields:
 /// - [`offset`]: the index of the first item to be displayed
 /// - [`selected`]: the index of the selected item, which can be `None` if no item is selected
+/// - [`selection`]: the indices of the items of the multi-selection
+///
+/// The selected item is the cursor of the list, highlighted with [`List::highlight_style`]. The
+/// multi-selection is independent of it: items are added to it with
+/// [`ListState::toggle_selection`], [`ListState::extend_selection_to`] and
+/// [`ListState::select_all`], and are highlighted with [`List::selection_style`].
 ///
 /// [`offset`]: ListState::offset()
 /// [`selected`]: ListState::selected()
+/// [`selection`]: ListState::selection()
+/// [`List::highlight_style`]: super::List::highlight_style
+/// [`List::selection_style`]: super::List::selection_style
 ///
 /// See the list in the [Examples] directory for a more in depth example of the various
 /// configuration options and for how to handle state.
@@ -40,11 +51,19 @@
 /// ```
 ///
 /// [`List`]: super::List
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ListState {
     pub(crate) offset: usize,
     pub(crate) selected: Option<usize>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Selection::is_empty")
+    )]
+    pub(crate) selection: Selection,
+    /// The anchors of the multi-selection while it is being edited
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) edit: SelectionEdit,
 }
 
 impl ListState {
@@ -268,10 +287,163 @@ impl ListState {
         let selected = self.selected.unwrap_or_default();
         self.select(Some(selected.saturating_sub(amount as usize)));
     }
+
+    /// Sets the multi-selection
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let state = ListState::default().with_selection([1, 3].into_iter().collect());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_selection(mut self, selection: Selection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Returns the indices of the items of the multi-selection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default().with_selected(Some(2));
+    /// state.toggle_selection();
+    /// assert!(state.selection().contains(2));
+    /// ```
+    pub const fn selection(&self) -> &Selection {
+        &self.selection
+    }
+
+    /// Returns a mutable reference to the multi-selection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.selection_mut().insert_range(2..4);
+    /// ```
+    pub const fn selection_mut(&mut self) -> &mut Selection {
+        &mut self.selection
+    }
+
+    /// Adds the selected item to the multi-selection, or removes it if it is already there
+    ///
+    /// The item becomes the anchor from which the selection is
+    /// [extended](ListState::extend_selection_to). Nothing happens if no item is selected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default().with_selected(Some(1));
+    /// state.toggle_selection();
+    /// ```
+    pub fn toggle_selection(&mut self) {
+        if let Some(selected) = self.selected {
+            self.selection.toggle(selected);
+            self.edit.anchor = Some(selected);
+            self.edit.extension = None;
+        }
+    }
+
+    /// Selects the item at `index` and extends the multi-selection to it from the anchor
+    ///
+    /// This is the behavior of `Shift` with the arrow keys or a click: the items between the
+    /// anchor and `index` are added to the multi-selection, replacing the range added by the
+    /// previous extension, so the selection shrinks when the cursor moves back towards the
+    /// anchor. The anchor is the last item [toggled](ListState::toggle_selection), or the selected
+    /// item when the extension starts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default().with_selected(Some(2));
+    /// state.extend_selection_to(5);
+    /// state.extend_selection_to(4);
+    /// assert_eq!(state.selection().iter().collect::<Vec<_>>(), [2, 3, 4]);
+    /// ```
+    pub fn extend_selection_to(&mut self, index: usize) {
+        let anchor = *self
+            .edit
+            .anchor
+            .get_or_insert_with(|| self.selected.unwrap_or(index));
+        if let Some(extension) = self.edit.extension.take() {
+            self.selection.remove_range(extension);
+        }
+        let extension = anchor.min(index)..anchor.max(index).saturating_add(1);
+        self.selection.insert_range(extension.clone());
+        self.edit.extension = Some(extension);
+        self.selected = Some(index);
+    }
+
+    /// Adds all the items to the multi-selection
+    ///
+    /// Note: until the list is rendered, the number of items is not known, so all the indices are
+    /// selected and the selection will be corrected when the list is rendered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.select_all();
+    /// ```
+    pub fn select_all(&mut self) {
+        self.selection.insert_range(0..usize::MAX);
+        self.edit.extension = None;
+    }
+
+    /// Inverts the multi-selection
+    ///
+    /// Note: until the list is rendered, the number of items is not known, so the selection will
+    /// be corrected when the list is rendered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.invert_selection();
+    /// ```
+    pub fn invert_selection(&mut self) {
+        self.selection.invert(usize::MAX);
+        self.edit.extension = None;
+    }
+
+    /// Empties the multi-selection and resets its anchor
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.clear_selection();
+    /// ```
+    pub fn clear_selection(&mut self) {
+        self.selection.clear();
+        self.edit.anchor = None;
+        self.edit.extension = None;
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+
     use pretty_assertions::assert_eq;
 
     use crate::list::ListState;
@@ -354,4 +526,51 @@ mod tests {
         state.scroll_up_by(4);
         assert_eq!(state.selected, Some(0));
     }
+
+    #[test]
+    fn extend_selection() {
+        let mut state = ListState::default().with_selected(Some(3));
+        state.extend_selection_to(6);
+        assert_eq!(state.selected, Some(6));
+        assert_eq!(
+            state.selection.iter().collect::<Vec<_>>(),
+            (3..7).collect::<Vec<_>>()
+        );
+
+        // extending again replaces the previous extension, around the same anchor
+        state.extend_selection_to(1);
+        assert_eq!(
+            state.selection.iter().collect::<Vec<_>>(),
+            (1..4).collect::<Vec<_>>()
+        );
+
+        // toggling moves the anchor
+        state.select(Some(8));
+        state.toggle_selection();
+        state.extend_selection_to(9);
+        assert_eq!(state.selection.ranges(), [1..4, 8..10]);
+    }
+
+    #[test]
+    fn select_all_and_invert() {
+        let mut state = ListState::default();
+        state.select_all();
+        state.selection.truncate(4);
+        assert_eq!(
+            state.selection.iter().collect::<Vec<_>>(),
+            (0..4).collect::<Vec<_>>()
+        );
+
+        state.selection.remove(1);
+        state.invert_selection();
+        state.selection.truncate(4);
+        assert_eq!(
+            state.selection.iter().collect::<Vec<_>>(),
+            (1..2).collect::<Vec<_>>()
+        );
+
+        state.clear_selection();
+        assert!(state.selection.is_empty());
+        assert_eq!(state.edit.anchor, None);
+    }
 }
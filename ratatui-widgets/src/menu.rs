@@ -0,0 +1,313 @@
+//! The [`Menu`] widget displays a menu bar with dropdown menus, or a context menu, with nested
+//! submenus.
+
+use alloc::vec::Vec;
+
+use ratatui_core::style::{Modifier, Style, Styled, Theme};
+
+pub use self::item::MenuItem;
+pub use self::state::MenuState;
+use crate::block::{Block, Shadow};
+
+mod item;
+mod rendering;
+mod state;
+
+const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_DISABLED_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+
+/// A widget to display a menu bar or a context menu, with nested submenus
+///
+/// A menu is a list of root [`MenuItem`]s, each of which can have children that are displayed in
+/// a submenu. There are two kinds of menus:
+///
+/// - A [menu bar](Menu::bar) displays the root items on the first row of its area. The children of
+///   the open bar item are displayed in a dropdown below it.
+/// - A [context menu](Menu::context) displays the root items in a popup at the position given to
+///   [`MenuState::open_at`], usually where the mouse was clicked, and nothing while it is closed.
+///
+/// Dropdowns and submenus are popups: they are drawn over the content under them, which is
+/// cleared with [`Clear`], and surrounded by a [`Block`], bordered by default, with an optional
+/// [`Shadow`]. Submenus open to the right of their parent item, or to the left when there is not
+/// enough room. Popups are moved with [`Rect::clamp`] to stay inside the area of the frame for a
+/// menu bar, and inside the area of the widget for a context menu, so the area of a context menu
+/// is usually the whole frame.
+///
+/// A menu is a [`StatefulWidget`] that works with a [`MenuState`], which tracks the open path of
+/// the menu and handles the keyboard navigation and accelerators. The menu should be rendered
+/// after the rest of the interface so that its popups are drawn on top.
+///
+/// [`Clear`]: crate::clear::Clear
+/// [`Rect::clamp`]: ratatui_core::layout::Rect::clamp
+/// [`StatefulWidget`]: ratatui_core::widgets::StatefulWidget
+///
+/// # Fluent setters
+///
+/// - [`Menu::highlight_style`] sets the style of the highlighted items.
+/// - [`Menu::disabled_style`] sets the style of the disabled items.
+/// - [`Menu::accelerator_style`] sets the style of the accelerators.
+/// - [`Menu::block`] and [`Menu::shadow`] set the block and the shadow of the popups.
+/// - [`Menu::check_symbol`] and [`Menu::submenu_symbol`] set the symbols of checked items and of
+///   items with a submenu.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::input::{KeyCode, KeyEvent, KeyModifiers};
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Style, Stylize};
+/// use ratatui::widgets::{Menu, MenuItem, MenuState};
+///
+/// # fn ui(frame: &mut Frame) {
+/// let menu = Menu::bar([
+///     MenuItem::new("File").children([
+///         MenuItem::new("Open")
+///             .accelerator(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::CONTROL)),
+///         MenuItem::new("Recent").children(["notes.md", "todo.md"]),
+///         MenuItem::separator(),
+///         MenuItem::new("Quit"),
+///     ]),
+///     MenuItem::new("View").children([MenuItem::new("Wrap").checked(true)]),
+/// ])
+/// .style(Style::new().on_blue())
+/// .highlight_style(Style::new().reversed());
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = MenuState::default();
+/// state.select(vec![0, 1, 0]);
+///
+/// let area = Rect::new(0, 0, frame.area().width, 1);
+/// frame.render_stateful_widget(menu, area, &mut state);
+/// //  File  View
+/// // ┌──────────────────┐
+/// // │ Open    Ctrl+O   │┌──────────┐
+/// // │ Recent         ▶ ││ notes.md │
+/// // │──────────────────││ todo.md  │
+/// // │ Quit             │└──────────┘
+/// // └──────────────────┘
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Menu<'a> {
+    pub(crate) kind: MenuKind,
+    /// The root items of the menu
+    pub(crate) items: Vec<MenuItem<'a>>,
+    /// Style used as a base style for the bar and the popups
+    pub(crate) style: Style,
+    /// Style used to render the highlighted items
+    pub(crate) highlight_style: Style,
+    /// Style used to render the disabled items
+    pub(crate) disabled_style: Style,
+    /// Style used to render the accelerators
+    pub(crate) accelerator_style: Style,
+    /// Block surrounding the popups
+    pub(crate) block: Block<'a>,
+    /// Shadow rendered behind the popups
+    pub(crate) shadow: Option<Shadow>,
+    /// Symbol in front of checked items
+    pub(crate) check_symbol: &'a str,
+    /// Symbol after items with a submenu
+    pub(crate) submenu_symbol: &'a str,
+}
+
+/// The kind of a [`Menu`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub(crate) enum MenuKind {
+    Bar,
+    Context,
+}
+
+impl Default for Menu<'_> {
+    fn default() -> Self {
+        Self::bar(Vec::<MenuItem>::new())
+    }
+}
+
+impl<'a> Menu<'a> {
+    /// Creates a menu bar from its root items
+    ///
+    /// `items` accepts any iterator of items convertible to [`MenuItem`], such as `&str` or
+    /// [`Line`](ratatui_core::text::Line).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Menu, MenuItem};
+    ///
+    /// let menu = Menu::bar([MenuItem::new("File").children(["Open", "Quit"])]);
+    /// ```
+    pub fn bar<T>(items: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<MenuItem<'a>>,
+    {
+        Self::new(MenuKind::Bar, items)
+    }
+
+    /// Creates a context menu from its root items
+    ///
+    /// The menu is displayed when it is opened with [`MenuState::open_at`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Menu, MenuItem};
+    ///
+    /// let menu = Menu::context(["Cut", "Copy", "Paste"]);
+    /// ```
+    pub fn context<T>(items: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<MenuItem<'a>>,
+    {
+        Self::new(MenuKind::Context, items)
+    }
+
+    fn new<T>(kind: MenuKind, items: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<MenuItem<'a>>,
+    {
+        Self {
+            kind,
+            items: items.into_iter().map(Into::into).collect(),
+            style: Style::new(),
+            highlight_style: DEFAULT_HIGHLIGHT_STYLE,
+            disabled_style: DEFAULT_DISABLED_STYLE,
+            accelerator_style: Style::new(),
+            block: Block::bordered(),
+            shadow: None,
+            check_symbol: "✓ ",
+            submenu_symbol: "▶",
+        }
+    }
+
+    /// Set the root items
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn items<T>(mut self, items: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<MenuItem<'a>>,
+    {
+        self.items = items.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the base style of the menu bar and of the popups
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the style of the highlighted items
+    ///
+    /// The style is applied to the whole row of the item, on top of the base style and the style
+    /// of the item. By default, highlighted items are reversed.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Set the style of the disabled items and separators
+    ///
+    /// By default, disabled items are dimmed.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.disabled_style = style.into();
+        self
+    }
+
+    /// Set the style of the accelerators displayed on the right of the items
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn accelerator_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.accelerator_style = style.into();
+        self
+    }
+
+    /// Sets the styles of the menu from a [`Theme`]
+    ///
+    /// The styles are replaced by the `menu`, `menu.highlight`, `menu.disabled` and
+    /// `menu.accelerator` styles of the theme. The highlight and disabled styles are patched on top
+    /// of their default reversed and dimmed styles.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style("menu");
+        self.highlight_style = DEFAULT_HIGHLIGHT_STYLE.patch(theme.style("menu.highlight"));
+        self.disabled_style = DEFAULT_DISABLED_STYLE.patch(theme.style("menu.disabled"));
+        self.accelerator_style = theme.style("menu.accelerator");
+        self
+    }
+
+    /// Sets the [`Block`] surrounding the dropdowns, submenus and context menu
+    ///
+    /// The default is [`Block::bordered`]. The titles of the block are displayed on every popup.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Adds a [`Shadow`] behind the dropdowns, submenus and context menu
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Sets the symbol displayed in front of checked items, `"✓ "` by default
+    ///
+    /// Unchecked items are indented by the width of the symbol when the popup contains checkable
+    /// items.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn check_symbol(mut self, symbol: &'a str) -> Self {
+        self.check_symbol = symbol;
+        self
+    }
+
+    /// Sets the symbol displayed after items with a submenu, `"▶"` by default
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn submenu_symbol(mut self, symbol: &'a str) -> Self {
+        self.submenu_symbol = symbol;
+        self
+    }
+}
+
+impl Styled for Menu<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
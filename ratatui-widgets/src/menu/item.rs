@@ -0,0 +1,262 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use ratatui_core::input::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui_core::style::{Style, Styled};
+use ratatui_core::text::Line;
+
+/// An entry of a [`Menu`](super::Menu)
+///
+/// A menu item is a single [`Line`] label with an optional list of children, which are displayed
+/// in a submenu (or in a dropdown for the items of a menu bar). Items can also be
+/// [separators](MenuItem::separator), be [disabled](MenuItem::disabled), show a
+/// [check mark](MenuItem::checked) and have a keyboard [accelerator](MenuItem::accelerator).
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::input::{KeyCode, KeyEvent, KeyModifiers};
+/// use ratatui::widgets::MenuItem;
+///
+/// let file = MenuItem::new("File").children([
+///     MenuItem::new("Save").accelerator(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL)),
+///     MenuItem::new("Revert").disabled(true),
+///     MenuItem::separator(),
+///     MenuItem::new("Autosave").checked(true),
+/// ]);
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct MenuItem<'a> {
+    pub(crate) label: Line<'a>,
+    pub(crate) style: Style,
+    pub(crate) children: Vec<Self>,
+    pub(crate) accelerator: Option<KeyEvent>,
+    pub(crate) disabled: bool,
+    pub(crate) checked: Option<bool>,
+    pub(crate) separator: bool,
+}
+
+impl<'a> MenuItem<'a> {
+    /// Creates a new item without children.
+    ///
+    /// `label` accepts any type that is convertible to [`Line`] (e.g. `&str`, `String`, [`Span`]
+    /// or [`Line`]).
+    ///
+    /// [`Span`]: ratatui_core::text::Span
+    pub fn new<T: Into<Line<'a>>>(label: T) -> Self {
+        Self {
+            label: label.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a separator, a horizontal line between groups of items
+    ///
+    /// Separators cannot be selected.
+    pub fn separator() -> Self {
+        Self {
+            separator: true,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the children of the item, which are displayed in a submenu
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn children<I>(mut self, children: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Self>,
+    {
+        self.children = children.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the key that activates the item, even when the menu is closed
+    ///
+    /// The key is displayed on the right of the label, e.g. `Ctrl+S`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn accelerator(mut self, key: KeyEvent) -> Self {
+        self.accelerator = Some(key);
+        self
+    }
+
+    /// Disables the item
+    ///
+    /// Disabled items are displayed with the disabled style of the menu and cannot be selected or
+    /// activated, and neither can their children.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Makes the item checkable and sets whether it is checked
+    ///
+    /// Checked items are displayed with the check symbol of the menu in front of the label.
+    /// Activating the item does not toggle it: the application updates the items it renders
+    /// according to its own state.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    /// Sets the style of the item
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns `true` if the item can be selected, i.e. it is neither disabled nor a separator.
+    pub const fn is_enabled(&self) -> bool {
+        !self.disabled && !self.separator
+    }
+
+    /// Returns `true` if the item is a separator.
+    pub const fn is_separator(&self) -> bool {
+        self.separator
+    }
+
+    /// Returns the text displayed for the accelerator of the item, e.g. `Ctrl+S`.
+    pub(crate) fn accelerator_label(&self) -> Option<String> {
+        self.accelerator.map(key_label)
+    }
+}
+
+impl<'a, T> From<T> for MenuItem<'a>
+where
+    T: Into<Line<'a>>,
+{
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Styled for MenuItem<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// Formats a key the way it is usually written in menus, e.g. `Ctrl+Shift+S` or `F5`.
+fn key_label(key: KeyEvent) -> String {
+    let mut label = String::new();
+    for (modifier, name) in [
+        (KeyModifiers::CONTROL, "Ctrl+"),
+        (KeyModifiers::ALT, "Alt+"),
+        (KeyModifiers::SUPER, "Super+"),
+        (KeyModifiers::SHIFT, "Shift+"),
+    ] {
+        if key.modifiers.contains(modifier) {
+            label.push_str(name);
+        }
+    }
+    match key.code {
+        KeyCode::Char(' ') => label.push_str("Space"),
+        KeyCode::Char(c) => label.extend(c.to_uppercase()),
+        KeyCode::F(n) => {
+            let _ = write!(label, "F{n}");
+        }
+        code => label.push_str(match code {
+            KeyCode::Backspace => "Backspace",
+            KeyCode::Delete => "Del",
+            KeyCode::Insert => "Ins",
+            KeyCode::Enter => "Enter",
+            KeyCode::Esc => "Esc",
+            KeyCode::Tab | KeyCode::BackTab => "Tab",
+            KeyCode::Left => "Left",
+            KeyCode::Right => "Right",
+            KeyCode::Up => "Up",
+            KeyCode::Down => "Down",
+            KeyCode::Home => "Home",
+            KeyCode::End => "End",
+            KeyCode::PageUp => "PgUp",
+            KeyCode::PageDown => "PgDn",
+            _ => "?",
+        }),
+    }
+    label
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Stylize;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let item = MenuItem::new("Open");
+        assert_eq!(item.label, Line::from("Open"));
+        assert!(item.is_enabled());
+        assert!(!item.is_separator());
+        assert_eq!(item.checked, None);
+    }
+
+    #[test]
+    fn separator() {
+        let item = MenuItem::separator();
+        assert!(item.is_separator());
+        assert!(!item.is_enabled());
+    }
+
+    #[test]
+    fn disabled() {
+        assert!(!MenuItem::new("Open").disabled(true).is_enabled());
+    }
+
+    #[test]
+    fn children() {
+        let item = MenuItem::new("File").children(["Open", "Save"]);
+        assert_eq!(
+            item.children,
+            [MenuItem::new("Open"), MenuItem::new("Save")]
+        );
+    }
+
+    #[rstest]
+    #[case::ctrl(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL), "Ctrl+S")]
+    #[case::ctrl_shift(
+        KeyEvent::new(KeyCode::Char('S'), KeyModifiers::CONTROL | KeyModifiers::SHIFT),
+        "Ctrl+Shift+S"
+    )]
+    #[case::alt_space(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::ALT), "Alt+Space")]
+    #[case::function(KeyEvent::from(KeyCode::F(5)), "F5")]
+    #[case::delete(KeyEvent::new(KeyCode::Delete, KeyModifiers::SHIFT), "Shift+Del")]
+    fn accelerator_label(#[case] key: KeyEvent, #[case] expected: &str) {
+        let item = MenuItem::new("Item").accelerator(key);
+        assert_eq!(item.accelerator_label().as_deref(), Some(expected));
+    }
+
+    #[test]
+    fn can_be_stylized() {
+        assert_eq!(
+            MenuItem::new("Open").red().on_black().style,
+            Style::new().red().on_black()
+        );
+    }
+}
@@ -0,0 +1,494 @@
+use alloc::vec::Vec;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Position, Rect};
+use ratatui_core::symbols::line;
+use ratatui_core::widgets::{StatefulWidget, Widget};
+use unicode_width::UnicodeWidthStr;
+
+use crate::block::Block;
+use crate::clear::Clear;
+use crate::menu::{Menu, MenuItem, MenuKind, MenuState};
+
+impl Widget for Menu<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Menu<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = MenuState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for Menu<'_> {
+    type State = MenuState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &Menu<'_> {
+    type State = MenuState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.update(&self.items, self.kind == MenuKind::Bar);
+        match self.kind {
+            MenuKind::Bar => {
+                let area = area.intersection(buf.area);
+                if area.is_empty() {
+                    return;
+                }
+                let bar_area = Rect { height: 1, ..area };
+                let titles = self.render_bar(bar_area, buf, state.path.first().copied());
+                if let [index, ref path @ ..] = state.path[..]
+                    && !path.is_empty()
+                {
+                    let position = Position::new(titles[index], bar_area.bottom());
+                    let bounds = buf.area;
+                    self.render_popups(&self.items[index].children, path, position, bounds, buf);
+                }
+            }
+            MenuKind::Context => {
+                if let Some(position) = state.position {
+                    self.render_popups(&self.items, &state.path, position, area, buf);
+                }
+            }
+        }
+    }
+}
+
+impl Menu<'_> {
+    /// Draws the root items on a single row and returns the column of each item.
+    fn render_bar(&self, area: Rect, buf: &mut Buffer, highlighted: Option<usize>) -> Vec<u16> {
+        buf.set_style(area, self.style);
+        let mut x = area.x;
+        let mut titles = Vec::with_capacity(self.items.len());
+        for (index, item) in self.items.iter().enumerate() {
+            titles.push(x);
+            let width = u16::try_from(item.label.width())
+                .unwrap_or(u16::MAX)
+                .saturating_add(2);
+            let title_area = Rect::new(x, area.y, width, 1).intersection(area);
+            buf.set_style(
+                title_area,
+                self.item_style(item, highlighted == Some(index)),
+            );
+            let label_x = x.saturating_add(1);
+            buf.set_line(
+                label_x,
+                area.y,
+                &item.label,
+                area.right().saturating_sub(label_x),
+            );
+            x = x.saturating_add(width).min(area.right());
+        }
+        titles
+    }
+
+    /// Draws the popup of `items` at `position` and the open submenus along `path`.
+    ///
+    /// Submenus open to the right of their parent, or to the left when they only fit there, and
+    /// keep opening to the left from then on.
+    fn render_popups(
+        &self,
+        mut items: &[MenuItem],
+        path: &[usize],
+        position: Position,
+        bounds: Rect,
+        buf: &mut Buffer,
+    ) {
+        let block = self.popup_block();
+        let (width, height) = self.popup_size(&block, items);
+        let mut popup = Rect::new(position.x, position.y, width, height).clamp(bounds);
+        let mut leftward = false;
+        for depth in 0..path.len().max(1) {
+            let highlighted = path.get(depth).copied();
+            self.render_popup(&block, items, highlighted, popup, buf);
+
+            let Some(index) = highlighted.filter(|_| depth + 1 < path.len()) else {
+                break;
+            };
+            items = &items[index].children;
+            let (width, height) = self.popup_size(&block, items);
+            let fits_right = popup.right().saturating_add(width) <= bounds.right();
+            let fits_left = popup.x >= bounds.x.saturating_add(width);
+            leftward = fits_left && (leftward || !fits_right);
+            let x = if leftward {
+                popup.x - width
+            } else {
+                popup.right()
+            };
+            // align the first item of the submenu with its parent item
+            let row = block
+                .inner(popup)
+                .y
+                .saturating_add(u16::try_from(index).unwrap_or(u16::MAX));
+            let y = row.saturating_sub(block.inner(Rect::new(0, 0, width, height)).y);
+            popup = Rect::new(x, y, width, height).clamp(bounds);
+        }
+    }
+
+    /// Draws a single popup with its block and items.
+    fn render_popup(
+        &self,
+        block: &Block,
+        items: &[MenuItem],
+        highlighted: Option<usize>,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        Clear.render(area, buf);
+        buf.set_style(area, self.style);
+        block.render(area, buf);
+        let inner = block.inner(area);
+        if inner.is_empty() {
+            return;
+        }
+        let columns = Columns::new(self, items);
+        for (index, item) in items.iter().enumerate().take(usize::from(inner.height)) {
+            let row = Rect::new(inner.x, inner.y + index as u16, inner.width, 1);
+            if item.is_separator() {
+                for x in row.left()..row.right() {
+                    buf[(x, row.y)]
+                        .set_symbol(line::HORIZONTAL)
+                        .set_style(self.disabled_style);
+                }
+                continue;
+            }
+            buf.set_style(row, self.item_style(item, highlighted == Some(index)));
+            let mut x = row.x.saturating_add(1);
+            if columns.check > 0 {
+                if item.checked == Some(true) {
+                    let width = usize::from(row.right().saturating_sub(x));
+                    buf.set_stringn(x, row.y, self.check_symbol, width, item.style);
+                }
+                x = x.saturating_add(columns.check);
+            }
+            buf.set_line(x, row.y, &item.label, row.right().saturating_sub(x));
+
+            let mut right = row.right().saturating_sub(1);
+            if columns.submenu > 0 {
+                right = right.saturating_sub(columns.submenu);
+                if !item.children.is_empty() {
+                    buf.set_string(right, row.y, self.submenu_symbol, item.style);
+                }
+                right = right.saturating_sub(1);
+            }
+            if let Some(accelerator) = item.accelerator_label() {
+                let width = u16::try_from(accelerator.width()).unwrap_or(u16::MAX);
+                let x = right.saturating_sub(width).max(x);
+                buf.set_stringn(
+                    x,
+                    row.y,
+                    accelerator,
+                    usize::from(right.saturating_sub(x)),
+                    self.accelerator_style,
+                );
+            }
+        }
+    }
+
+    /// Returns the size of the popup of `items`, including the block.
+    fn popup_size(&self, block: &Block, items: &[MenuItem]) -> (u16, u16) {
+        let columns = Columns::new(self, items);
+        let accelerator = if columns.accelerator > 0 {
+            columns.accelerator.saturating_add(2)
+        } else {
+            0
+        };
+        let submenu = if columns.submenu > 0 {
+            columns.submenu.saturating_add(1)
+        } else {
+            0
+        };
+        let content_width = [columns.check, columns.label, accelerator, submenu, 1]
+            .into_iter()
+            .fold(1, u16::saturating_add);
+        let max = Rect::new(0, 0, u16::MAX, u16::MAX);
+        let inner = block.inner(max);
+        (
+            content_width.saturating_add(max.width - inner.width),
+            u16::try_from(items.len())
+                .unwrap_or(u16::MAX)
+                .saturating_add(max.height - inner.height),
+        )
+    }
+
+    fn item_style(&self, item: &MenuItem, highlighted: bool) -> ratatui_core::style::Style {
+        let mut style = self.style.patch(item.style);
+        if !item.is_enabled() {
+            style = style.patch(self.disabled_style);
+        }
+        if highlighted {
+            style = style.patch(self.highlight_style);
+        }
+        style
+    }
+
+    fn popup_block(&self) -> Block<'_> {
+        match &self.shadow {
+            Some(shadow) => self.block.clone().shadow(shadow.clone()),
+            None => self.block.clone(),
+        }
+    }
+}
+
+/// The widths of the columns of a popup, which are 0 when no item uses them.
+struct Columns {
+    check: u16,
+    label: u16,
+    accelerator: u16,
+    submenu: u16,
+}
+
+impl Columns {
+    fn new(menu: &Menu, items: &[MenuItem]) -> Self {
+        let max = |width: fn(&MenuItem) -> usize| {
+            u16::try_from(items.iter().map(width).max().unwrap_or_default()).unwrap_or(u16::MAX)
+        };
+        let checkable = items.iter().any(|item| item.checked.is_some());
+        let submenus = items.iter().any(|item| !item.children.is_empty());
+        Self {
+            check: if checkable {
+                u16::try_from(menu.check_symbol.width()).unwrap_or(u16::MAX)
+            } else {
+                0
+            },
+            label: max(|item| item.label.width()),
+            accelerator: max(|item| item.accelerator_label().map_or(0, |label| label.width())),
+            submenu: if submenus {
+                u16::try_from(menu.submenu_symbol.width()).unwrap_or(u16::MAX)
+            } else {
+                0
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use ratatui_core::input::{KeyCode, KeyEvent, KeyModifiers};
+    use ratatui_core::style::{Style, Stylize};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::block::Shadow;
+
+    #[fixture]
+    fn items() -> Vec<MenuItem<'static>> {
+        vec![
+            MenuItem::new("File").children([
+                MenuItem::new("Open")
+                    .accelerator(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::CONTROL)),
+                MenuItem::new("Recent").children(["a", "b"]),
+                MenuItem::separator(),
+                MenuItem::new("Quit"),
+            ]),
+            MenuItem::new("View").children([
+                MenuItem::new("Wrap").checked(true),
+                MenuItem::new("Ruler").checked(false),
+                MenuItem::new("Zoom").disabled(true),
+            ]),
+        ]
+    }
+
+    fn menu(menu: Menu) -> Menu {
+        menu.highlight_style(Style::new())
+            .disabled_style(Style::new())
+    }
+
+    fn render(menu: &Menu, state: &mut MenuState, area: Rect, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        StatefulWidget::render(menu, area, &mut buf, state);
+        buf
+    }
+
+    #[rstest]
+    fn render_bar(items: Vec<MenuItem<'static>>) {
+        let menu = Menu::bar(items).highlight_style(Style::new().reversed());
+        let mut state = MenuState::default();
+        state.select(vec![1]);
+        let buf = render(&menu, &mut state, Rect::new(0, 0, 14, 1), 14, 2);
+        let mut expected = Buffer::with_lines([" File  View   ", "              "]);
+        expected.set_style(Rect::new(6, 0, 6, 1), Style::new().reversed());
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    fn render_dropdown(items: Vec<MenuItem<'static>>) {
+        let menu = menu(Menu::bar(items));
+        let mut state = MenuState::default();
+        state.select(vec![0, 0]);
+        let buf = render(&menu, &mut state, Rect::new(0, 0, 22, 1), 22, 7);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                " File  View           ",
+                "┌──────────────────┐  ",
+                "│ Open    Ctrl+O   │  ",
+                "│ Recent         ▶ │  ",
+                "│──────────────────│  ",
+                "│ Quit             │  ",
+                "└──────────────────┘  ",
+            ])
+        );
+    }
+
+    #[rstest]
+    fn render_checked_items(items: Vec<MenuItem<'static>>) {
+        let menu = menu(Menu::bar(items));
+        let mut state = MenuState::default();
+        state.select(vec![1, 0]);
+        let buf = render(&menu, &mut state, Rect::new(0, 0, 20, 1), 20, 6);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                " File  View         ",
+                "      ┌─────────┐   ",
+                "      │ ✓ Wrap  │   ",
+                "      │   Ruler │   ",
+                "      │   Zoom  │   ",
+                "      └─────────┘   ",
+            ])
+        );
+    }
+
+    #[rstest]
+    fn render_submenu(items: Vec<MenuItem<'static>>) {
+        let menu = menu(Menu::bar(items));
+        let mut state = MenuState::default();
+        state.select(vec![0, 1, 1]);
+        let buf = render(&menu, &mut state, Rect::new(0, 0, 26, 1), 26, 7);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                " File  View               ",
+                "┌──────────────────┐      ",
+                "│ Open    Ctrl+O   │┌───┐ ",
+                "│ Recent         ▶ ││ a │ ",
+                "│──────────────────││ b │ ",
+                "│ Quit             │└───┘ ",
+                "└──────────────────┘      ",
+            ])
+        );
+    }
+
+    #[rstest]
+    fn render_submenu_clamped(items: Vec<MenuItem<'static>>) {
+        let menu = menu(Menu::bar(items));
+        let mut state = MenuState::default();
+        state.select(vec![0, 1, 1]);
+        let buf = render(&menu, &mut state, Rect::new(0, 0, 22, 1), 22, 7);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                " File  View           ",
+                "┌──────────────────┐  ",
+                "│ Open    Ctrl+O ┌───┐",
+                "│ Recent         │ a │",
+                "│────────────────│ b │",
+                "│ Quit           └───┘",
+                "└──────────────────┘  ",
+            ])
+        );
+    }
+
+    #[rstest]
+    fn render_submenus_leftward(items: Vec<MenuItem<'static>>) {
+        let menu = menu(Menu::context(items));
+        let mut state = MenuState::default();
+        state.open_at(Position::new(30, 0));
+        state.select(vec![0, 1, 0]);
+        let buf = render(&menu, &mut state, Rect::new(0, 0, 40, 6), 40, 6);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "          ┌──────────────────┐┌────────┐",
+                "     ┌───┐│ Open    Ctrl+O   ││ File ▶ │",
+                "     │ a ││ Recent         ▶ ││ View ▶ │",
+                "     │ b ││──────────────────│└────────┘",
+                "     └───┘│ Quit             │          ",
+                "          └──────────────────┘          ",
+            ])
+        );
+    }
+
+    #[rstest]
+    fn render_context_clamped(items: Vec<MenuItem<'static>>) {
+        let menu = menu(Menu::context(items)).shadow(Shadow::block());
+        let mut state = MenuState::default();
+        state.open_at(Position::new(8, 3));
+        let buf = render(&menu, &mut state, Rect::new(0, 0, 12, 5), 13, 6);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "             ",
+                "  ┌────────┐ ",
+                "  │ File ▶ │█",
+                "  │ View ▶ │█",
+                "  └────────┘█",
+                "   ██████████",
+            ])
+        );
+    }
+
+    #[rstest]
+    fn render_closed_context(items: Vec<MenuItem<'static>>) {
+        let menu = Menu::context(items);
+        let buf = render(
+            &menu,
+            &mut MenuState::default(),
+            Rect::new(0, 0, 4, 2),
+            4,
+            2,
+        );
+        assert_eq!(buf, Buffer::with_lines(["    ", "    "]));
+    }
+
+    #[rstest]
+    fn render_updates_navigation(items: Vec<MenuItem<'static>>) {
+        let mut state = MenuState::default();
+        state.select(vec![1, 9]);
+        render(&Menu::bar(items), &mut state, Rect::new(0, 0, 20, 1), 20, 6);
+        assert_eq!(state.path(), [1]);
+        state.handle_key(KeyEvent::from(KeyCode::Down));
+        state.handle_key(KeyEvent::from(KeyCode::Down));
+        state.handle_key(KeyEvent::from(KeyCode::Down));
+        assert_eq!(state.path(), [1, 0]);
+    }
+
+    #[test]
+    fn render_empty() {
+        let buf = render(
+            &Menu::default(),
+            &mut MenuState::default(),
+            Rect::ZERO,
+            2,
+            1,
+        );
+        assert_eq!(buf, Buffer::with_lines(["  "]));
+    }
+
+    #[test]
+    fn popup_size_saturates() {
+        let label = "x".repeat(usize::from(u16::MAX - 1));
+        let menu = Menu::context([MenuItem::new(label.as_str())
+            .accelerator(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::CONTROL))]);
+        let (width, height) = menu.popup_size(&menu.block, &menu.items);
+        assert_eq!((width, height), (u16::MAX, 3));
+    }
+
+    #[test]
+    fn can_be_stylized() {
+        assert_eq!(
+            Menu::default().red().on_black().style,
+            Style::new().red().on_black()
+        );
+    }
+}
@@ -0,0 +1,582 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use ratatui_core::input::{KeyCode, KeyEvent};
+use ratatui_core::layout::Position;
+
+use super::MenuItem;
+
+/// State of the [`Menu`] widget
+///
+/// The state tracks the open path of the menu: the index of the highlighted item at each open
+/// level, starting with the root items. For a menu bar, `[1]` highlights the second bar item and
+/// `[1, 0]` opens its dropdown and highlights its first item. For a context menu, `[1]`
+/// highlights the second item of the menu and `[1, 0]` opens its submenu. Every item of the path
+/// but the last one is an open menu.
+///
+/// A context menu is opened at a position with [`MenuState::open_at`], usually the position of a
+/// mouse click. A menu bar is opened by [selecting](MenuState::select) a bar item, or by pressing
+/// `Alt` and the first letter of a bar item.
+///
+/// When an item is activated, with [`MenuState::activate`] or [`MenuState::handle_key`], the menu
+/// is closed and the path of the item is stored until the application takes it with
+/// [`MenuState::take_activated`].
+///
+/// When the menu is rendered, the state remembers the structure of the items so that the
+/// navigation methods and [`MenuState::handle_key`] can skip separators and disabled items and
+/// find accelerators.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::input::KeyEvent;
+/// use ratatui::widgets::{Menu, MenuItem, MenuState};
+///
+/// # fn ui(frame: &mut Frame, key: KeyEvent) {
+/// let menu = Menu::bar([
+///     MenuItem::new("File").children(["Open", "Quit"]),
+///     MenuItem::new("Help").children(["About"]),
+/// ]);
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = MenuState::default();
+///
+/// frame.render_stateful_widget(menu, frame.area(), &mut state);
+///
+/// state.handle_key(key);
+/// if state.take_activated().as_deref() == Some(&[0, 1]) {
+///     // quit
+/// }
+/// # }
+/// ```
+///
+/// [`Menu`]: super::Menu
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MenuState {
+    pub(crate) path: Vec<usize>,
+    pub(crate) position: Option<Position>,
+    pub(crate) activated: Option<Vec<usize>>,
+    /// The structure of the items in the last render
+    pub(crate) nodes: Vec<MenuNode>,
+    /// Whether the last render was a menu bar
+    pub(crate) bar: bool,
+}
+
+/// An item of the last render of a [`Menu`](super::Menu).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct MenuNode {
+    pub enabled: bool,
+    pub accelerator: Option<KeyEvent>,
+    /// The first character of the label, in lowercase
+    pub initial: Option<char>,
+    pub children: Vec<Self>,
+}
+
+impl MenuNode {
+    pub fn from_items(items: &[MenuItem]) -> Vec<Self> {
+        items
+            .iter()
+            .map(|item| Self {
+                enabled: item.is_enabled(),
+                accelerator: item.accelerator,
+                initial: item
+                    .label
+                    .spans
+                    .iter()
+                    .flat_map(|span| span.content.chars())
+                    .next()
+                    .and_then(|c| c.to_lowercase().next()),
+                children: Self::from_items(&item.children),
+            })
+            .collect()
+    }
+}
+
+impl MenuState {
+    /// Returns `true` if the menu bar has the focus or the context menu is open.
+    pub const fn is_open(&self) -> bool {
+        !self.path.is_empty() || self.position.is_some()
+    }
+
+    /// The index of the highlighted item at each open level of the menu
+    pub fn path(&self) -> &[usize] {
+        &self.path
+    }
+
+    /// The position at which the context menu is open, or `None` if it is closed
+    pub const fn position(&self) -> Option<Position> {
+        self.position
+    }
+
+    /// Highlights the item at `path`, opening its ancestors
+    ///
+    /// If the path does not exist when the menu is rendered, it is truncated to its deepest
+    /// existing ancestor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::MenuState;
+    ///
+    /// let mut state = MenuState::default();
+    /// state.select(vec![0, 2]); // open the first dropdown of a menu bar
+    /// assert!(state.is_open());
+    /// ```
+    pub fn select(&mut self, path: Vec<usize>) {
+        self.path = path;
+    }
+
+    /// Opens the context menu at `position`, without highlighted item
+    ///
+    /// The menu is displayed below and to the right of the position when it fits in the area of
+    /// the widget, and is moved inside the area otherwise.
+    pub fn open_at(&mut self, position: Position) {
+        self.position = Some(position);
+        self.path.clear();
+    }
+
+    /// Closes all the menus, and removes the focus from the menu bar.
+    pub fn close(&mut self) {
+        self.position = None;
+        self.path.clear();
+    }
+
+    /// Takes the path of the last activated item, if any
+    pub const fn take_activated(&mut self) -> Option<Vec<usize>> {
+        self.activated.take()
+    }
+
+    /// Highlights the next enabled item of the deepest open menu, wrapping around
+    pub fn select_next(&mut self) {
+        self.step(true, false);
+    }
+
+    /// Highlights the previous enabled item of the deepest open menu, wrapping around
+    pub fn select_previous(&mut self) {
+        self.step(false, false);
+    }
+
+    /// Highlights the first enabled item of the deepest open menu
+    pub fn select_first(&mut self) {
+        self.step(true, true);
+    }
+
+    /// Highlights the last enabled item of the deepest open menu
+    pub fn select_last(&mut self) {
+        self.step(false, true);
+    }
+
+    /// Opens the submenu of the highlighted item and highlights its first enabled item, returning
+    /// `true` if the item has enabled children.
+    pub fn open_submenu(&mut self) -> bool {
+        let Some(node) = self.highlighted().filter(|node| node.enabled) else {
+            return false;
+        };
+        let Some(index) = node.children.iter().position(|child| child.enabled) else {
+            return false;
+        };
+        self.path.push(index);
+        true
+    }
+
+    /// Closes the deepest open menu, returning `true` if a menu was closed.
+    ///
+    /// The root of a context menu and the menu bar are not closed, see [`MenuState::close`].
+    pub fn close_submenu(&mut self) -> bool {
+        if self.path.len() > 1 {
+            self.path.pop();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Activates the highlighted item if it is enabled and has no children, closing the menu.
+    ///
+    /// The path of the item can then be taken with [`MenuState::take_activated`].
+    pub fn activate(&mut self) {
+        if self
+            .highlighted()
+            .is_some_and(|node| node.enabled && node.children.is_empty())
+        {
+            self.activated = Some(self.path.clone());
+            self.close();
+        }
+    }
+
+    /// Handles a key press, returning `true` if the key was handled.
+    ///
+    /// The accelerators of the enabled items are handled whether the menu is open or not. When
+    /// the menu is closed, `Alt` and the first letter of a bar item open its dropdown. The other
+    /// keys are only handled when the menu is open:
+    ///
+    /// | Key | Action |
+    /// |-----|--------|
+    /// | `Up` / `Down` | highlight the [previous](Self::select_previous) / [next](Self::select_next) item, or open the dropdown of the bar item |
+    /// | `Home` / `End` | highlight the [first](Self::select_first) / [last](Self::select_last) item |
+    /// | `Left` | [close the submenu](Self::close_submenu), or open the dropdown of the previous bar item |
+    /// | `Right` | [open the submenu](Self::open_submenu), or open the dropdown of the next bar item |
+    /// | `Enter`, `Space` | open the submenu or [activate](Self::activate) the highlighted item |
+    /// | `Esc` | close the deepest open menu |
+    /// | letters | highlight the next item starting with the letter, and open or activate it |
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if let Some(path) = self.accelerator_path(key) {
+            self.activated = Some(path);
+            self.close();
+            return true;
+        }
+        if !self.is_open() {
+            return self.handle_mnemonic(key);
+        }
+        let on_bar = self.bar && self.path.len() == 1;
+        match key.code {
+            KeyCode::Up | KeyCode::Down if on_bar => {
+                self.open_submenu();
+            }
+            KeyCode::Up => self.select_previous(),
+            KeyCode::Down => self.select_next(),
+            KeyCode::Home => self.select_first(),
+            KeyCode::End => self.select_last(),
+            KeyCode::Left if on_bar => self.select_previous(),
+            KeyCode::Right if on_bar => self.select_next(),
+            KeyCode::Left | KeyCode::Right if self.bar && self.path.len() == 2 => {
+                let forward = key.code == KeyCode::Right;
+                if !forward || !self.open_submenu() {
+                    self.path.truncate(1);
+                    self.step(forward, false);
+                    self.open_submenu();
+                }
+            }
+            KeyCode::Left => {
+                self.close_submenu();
+            }
+            KeyCode::Right => {
+                self.open_submenu();
+            }
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                if !self.open_submenu() {
+                    self.activate();
+                }
+            }
+            KeyCode::Esc if self.bar || self.path.len() > 1 => {
+                self.path.pop();
+            }
+            KeyCode::Esc => self.close(),
+            KeyCode::Char(c) if !key.is_ctrl() && !key.is_alt() => {
+                if !self.select_initial(c) {
+                    return false;
+                }
+                if !self.open_submenu() {
+                    self.activate();
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Opens the dropdown of the bar item starting with the letter of an `Alt` key press.
+    fn handle_mnemonic(&mut self, key: KeyEvent) -> bool {
+        let KeyCode::Char(c) = key.code else {
+            return false;
+        };
+        if !self.bar || !key.is_alt() || !self.select_initial(c) {
+            return false;
+        }
+        self.open_submenu();
+        true
+    }
+
+    /// Returns the items of the deepest open menu.
+    fn level(&self) -> &[MenuNode] {
+        let parent = &self.path[..self.path.len().saturating_sub(1)];
+        let mut nodes = self.nodes.as_slice();
+        for &index in parent {
+            nodes = nodes.get(index).map_or(&[], |node| &node.children);
+        }
+        nodes
+    }
+
+    fn highlighted(&self) -> Option<&MenuNode> {
+        self.level().get(*self.path.last()?)
+    }
+
+    /// Highlights the next or previous enabled item of the deepest open menu, wrapping around, or
+    /// the first or last one when starting from the edge.
+    fn step(&mut self, forward: bool, from_edge: bool) {
+        let level = self.level();
+        let len = level.len();
+        let current = self
+            .path
+            .last()
+            .copied()
+            .filter(|&index| !from_edge && index < len);
+        let next = (1..=len)
+            .map(|step| match (current, forward) {
+                (Some(index), true) => (index + step) % len,
+                (Some(index), false) => (index + len - step) % len,
+                (None, true) => step - 1,
+                (None, false) => len - step,
+            })
+            .find(|&index| level[index].enabled);
+        match (next, self.path.last_mut()) {
+            (Some(next), Some(last)) => *last = next,
+            (Some(next), None) => self.path.push(next),
+            (None, _) => {}
+        }
+    }
+
+    /// Highlights the next enabled item of the deepest open menu whose label starts with `c`,
+    /// returning `true` if there is one.
+    fn select_initial(&mut self, c: char) -> bool {
+        let initial = c.to_lowercase().next();
+        let level = self.level();
+        let len = level.len();
+        let start = self.path.last().map_or(0, |index| index + 1);
+        let Some(index) = (0..len)
+            .map(|step| (start + step) % len)
+            .find(|&index| level[index].enabled && level[index].initial == initial)
+        else {
+            return false;
+        };
+        match self.path.last_mut() {
+            Some(last) => *last = index,
+            None => self.path.push(index),
+        }
+        true
+    }
+
+    /// Returns the path of the enabled item with the accelerator `key`.
+    fn accelerator_path(&self, key: KeyEvent) -> Option<Vec<usize>> {
+        fn find(nodes: &[MenuNode], key: KeyEvent, path: &mut Vec<usize>) -> bool {
+            for (index, node) in nodes.iter().enumerate() {
+                if !node.enabled {
+                    continue;
+                }
+                path.push(index);
+                if node.accelerator == Some(key) || find(&node.children, key, path) {
+                    return true;
+                }
+                path.pop();
+            }
+            false
+        }
+        let mut path = vec![];
+        find(&self.nodes, key, &mut path).then_some(path)
+    }
+
+    /// Remembers the structure of the rendered items and truncates the path to existing items.
+    pub(crate) fn update(&mut self, items: &[MenuItem], bar: bool) {
+        self.nodes = MenuNode::from_items(items);
+        self.bar = bar;
+        let mut nodes = self.nodes.as_slice();
+        let mut depth = 0;
+        while let Some(node) = self.path.get(depth).and_then(|&index| nodes.get(index)) {
+            nodes = &node.children;
+            depth += 1;
+        }
+        self.path.truncate(depth);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::input::KeyModifiers;
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    #[fixture]
+    fn items() -> Vec<MenuItem<'static>> {
+        vec![
+            MenuItem::new("File").children([
+                MenuItem::new("Open").accelerator(ctrl('o')),
+                MenuItem::new("Recent").children(["a.txt", "b.txt"]),
+                MenuItem::separator(),
+                MenuItem::new("Print").disabled(true),
+                MenuItem::new("Quit").accelerator(ctrl('q')),
+            ]),
+            MenuItem::new("Edit")
+                .disabled(true)
+                .children([MenuItem::new("Undo").accelerator(ctrl('z'))]),
+            MenuItem::new("Help").children(["About"]),
+        ]
+    }
+
+    fn bar_state(items: &[MenuItem], path: Vec<usize>) -> MenuState {
+        let mut state = MenuState::default();
+        state.select(path);
+        state.update(items, true);
+        state
+    }
+
+    fn context_state(items: &[MenuItem], path: Vec<usize>) -> MenuState {
+        let mut state = MenuState::default();
+        state.open_at(Position::new(1, 1));
+        state.select(path);
+        state.update(items, false);
+        state
+    }
+
+    #[test]
+    fn default() {
+        let state = MenuState::default();
+        assert!(!state.is_open());
+        assert_eq!(state.path(), []);
+        assert_eq!(state.position(), None);
+    }
+
+    #[test]
+    fn open_at_and_close() {
+        let mut state = MenuState::default();
+        state.select(vec![1]);
+        state.open_at(Position::new(3, 4));
+        assert!(state.is_open());
+        assert_eq!(state.position(), Some(Position::new(3, 4)));
+        assert_eq!(state.path(), []);
+        state.close();
+        assert!(!state.is_open());
+    }
+
+    #[rstest]
+    #[case::skips_separator_and_disabled(vec![0, 1], vec![0, 4])]
+    #[case::wraps(vec![0, 4], vec![0, 0])]
+    #[case::bar_skips_disabled(vec![0], vec![2])]
+    fn select_next(items: Vec<MenuItem>, #[case] path: Vec<usize>, #[case] expected: Vec<usize>) {
+        let mut state = bar_state(&items, path);
+        state.select_next();
+        assert_eq!(state.path(), expected);
+    }
+
+    #[rstest]
+    fn select_previous_wraps(items: Vec<MenuItem>) {
+        let mut state = bar_state(&items, vec![0, 0]);
+        state.select_previous();
+        assert_eq!(state.path(), [0, 4]);
+        state.select_previous();
+        assert_eq!(state.path(), [0, 1]);
+    }
+
+    #[rstest]
+    fn select_first_and_last(items: Vec<MenuItem>) {
+        let mut state = context_state(&items, vec![]);
+        state.select_last();
+        assert_eq!(state.path(), [2]);
+        state.select_first();
+        assert_eq!(state.path(), [0]);
+    }
+
+    #[rstest]
+    fn submenus(items: Vec<MenuItem>) {
+        let mut state = context_state(&items, vec![0, 1]);
+        assert!(state.open_submenu());
+        assert_eq!(state.path(), [0, 1, 0]);
+        assert!(!state.open_submenu());
+        assert!(state.close_submenu());
+        assert!(state.close_submenu());
+        assert!(!state.close_submenu());
+        assert_eq!(state.path(), [0]);
+    }
+
+    #[rstest]
+    fn disabled_submenu_does_not_open(items: Vec<MenuItem>) {
+        let mut state = bar_state(&items, vec![1]);
+        assert!(!state.open_submenu());
+        assert_eq!(state.path(), [1]);
+    }
+
+    #[rstest]
+    fn activate(items: Vec<MenuItem>) {
+        let mut state = bar_state(&items, vec![0, 1]);
+        state.activate();
+        assert_eq!(state.take_activated(), None);
+        state.select(vec![0, 4]);
+        state.activate();
+        assert!(!state.is_open());
+        assert_eq!(state.take_activated(), Some(vec![0, 4]));
+        assert_eq!(state.take_activated(), None);
+    }
+
+    #[rstest]
+    #[case::enabled(ctrl('q'), Some(vec![0, 4]))]
+    #[case::disabled_parent(ctrl('z'), None)]
+    #[case::unknown(ctrl('x'), None)]
+    fn accelerators(
+        items: Vec<MenuItem>,
+        #[case] key: KeyEvent,
+        #[case] expected: Option<Vec<usize>>,
+    ) {
+        let mut state = bar_state(&items, vec![]);
+        assert_eq!(state.handle_key(key), expected.is_some());
+        assert_eq!(state.take_activated(), expected);
+    }
+
+    #[rstest]
+    fn alt_opens_bar_item(items: Vec<MenuItem>) {
+        let mut state = bar_state(&items, vec![]);
+        assert!(!state.handle_key(KeyEvent::from('h')));
+        assert!(state.handle_key(KeyEvent::new(KeyCode::Char('h'), KeyModifiers::ALT)));
+        assert_eq!(state.path(), [2, 0]);
+    }
+
+    #[rstest]
+    #[case::down_opens_dropdown(vec![0], KeyCode::Down, vec![0, 0])]
+    #[case::right_on_bar(vec![0], KeyCode::Right, vec![2])]
+    #[case::right_opens_submenu(vec![0, 1], KeyCode::Right, vec![0, 1, 0])]
+    #[case::right_next_dropdown(vec![0, 0], KeyCode::Right, vec![2, 0])]
+    #[case::left_previous_dropdown(vec![2, 0], KeyCode::Left, vec![0, 0])]
+    #[case::left_closes_submenu(vec![0, 1, 1], KeyCode::Left, vec![0, 1])]
+    #[case::enter_opens_submenu(vec![0, 1], KeyCode::Enter, vec![0, 1, 0])]
+    #[case::esc_closes_dropdown(vec![0, 1], KeyCode::Esc, vec![0])]
+    #[case::esc_leaves_bar(vec![0], KeyCode::Esc, vec![])]
+    #[case::end(vec![0, 0], KeyCode::End, vec![0, 4])]
+    #[case::letter(vec![0, 0], KeyCode::Char('r'), vec![0, 1, 0])]
+    fn handle_key_bar(
+        items: Vec<MenuItem>,
+        #[case] path: Vec<usize>,
+        #[case] code: KeyCode,
+        #[case] expected: Vec<usize>,
+    ) {
+        let mut state = bar_state(&items, path);
+        assert!(state.handle_key(KeyEvent::from(code)));
+        assert_eq!(state.path(), expected);
+    }
+
+    #[rstest]
+    fn handle_key_context(items: Vec<MenuItem>) {
+        let mut state = context_state(&items, vec![]);
+        assert!(state.handle_key(KeyEvent::from(KeyCode::Down)));
+        assert_eq!(state.path(), [0]);
+        assert!(state.handle_key(KeyEvent::from(KeyCode::Left)));
+        assert_eq!(state.path(), [0]);
+        assert!(state.handle_key(KeyEvent::from(KeyCode::Enter)));
+        assert_eq!(state.path(), [0, 0]);
+        assert!(state.handle_key(KeyEvent::from(KeyCode::Esc)));
+        assert_eq!(state.path(), [0]);
+        assert!(state.handle_key(KeyEvent::from(KeyCode::Esc)));
+        assert!(!state.is_open());
+    }
+
+    #[rstest]
+    fn letter_activates_item(items: Vec<MenuItem>) {
+        let mut state = context_state(&items, vec![0, 0]);
+        assert!(!state.handle_key(KeyEvent::from('x')));
+        assert!(state.handle_key(KeyEvent::from('q')));
+        assert_eq!(state.take_activated(), Some(vec![0, 4]));
+        assert!(!state.is_open());
+    }
+
+    #[rstest]
+    fn update_truncates_path(items: Vec<MenuItem>) {
+        let state = bar_state(&items, vec![0, 1, 5, 2]);
+        assert_eq!(state.path(), [0, 1]);
+        assert!(state.bar);
+    }
+}
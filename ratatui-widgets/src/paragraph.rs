@@ -2,12 +2,14 @@
 //! wrapping, alignment, and block styling.
 use ratatui_core::buffer::{Buffer, CellWidth};
 use ratatui_core::layout::{Alignment, Position, Rect};
-use ratatui_core::style::{Style, Styled};
-use ratatui_core::text::{Line, StyledGrapheme, Text};
+use ratatui_core::style::{Style, Styled, Theme};
+use ratatui_core::text::{Line, Span, StyledGrapheme, Text};
 use ratatui_core::widgets::Widget;
 
 use crate::block::{Block, BlockExt};
-use crate::reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine};
+use crate::reflow::{
+    CharWrapper, Continuation, LineComposer, LineTruncator, WordWrapper, WrappedLine,
+};
 
 /// A widget to display some text.
 ///
@@ -24,6 +26,10 @@ use crate::reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine};
 /// The text can be aligned to the left, right, or center. The alignment can be configured with the
 /// [`alignment`] method or with the [`left_aligned`], [`right_aligned`], and [`centered`] methods.
 ///
+/// The strategy used to break wrapped lines can be chosen with the [`wrap_mode`] method, and the
+/// continuation lines of a wrapped line can be indented with [`wrap_indent`] and prefixed with a
+/// marker using [`wrap_marker`].
+///
 /// The text can be scrolled to show a specific part of the text. The scroll offset can be set with
 /// the [`scroll`] method.
 ///
@@ -39,6 +45,9 @@ use crate::reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine};
 ///
 /// [Textwrap crate]: https://crates.io/crates/textwrap
 /// [`wrap`]: Self::wrap
+/// [`wrap_mode`]: Self::wrap_mode
+/// [`wrap_indent`]: Self::wrap_indent
+/// [`wrap_marker`]: Self::wrap_marker
 /// [`alignment`]: Self::alignment
 /// [`left_aligned`]: Self::left_aligned
 /// [`right_aligned`]: Self::right_aligned
@@ -80,6 +89,12 @@ pub struct Paragraph<'a> {
     style: Style,
     /// How to wrap the text
     wrap: Option<Wrap>,
+    /// Where wrapped lines are broken
+    wrap_mode: WrapMode,
+    /// How continuation lines are indented
+    wrap_indent: WrapIndent,
+    /// Marker drawn at the start of continuation lines
+    wrap_marker: Option<Span<'a>>,
     /// The text to display
     text: Text<'a>,
     /// Scroll
@@ -124,6 +139,81 @@ pub struct Wrap {
     pub trim: bool,
 }
 
+/// Describes where wrapped lines are broken.
+///
+/// This only has an effect when wrapping is enabled with [`Paragraph::wrap`].
+///
+/// ## Examples
+///
+/// ```
+/// use ratatui::widgets::{Paragraph, Wrap, WrapMode};
+///
+/// // With a window width of 10 chars:
+/// Paragraph::new("Hello wonderful world")
+///     .wrap(Wrap { trim: true })
+///     .wrap_mode(WrapMode::Word);
+/// // Hello
+/// // wonderful
+/// // world
+///
+/// Paragraph::new("Hello wonderful world")
+///     .wrap(Wrap { trim: true })
+///     .wrap_mode(WrapMode::Character);
+/// // Hello wond
+/// // erful worl
+/// // d
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum WrapMode {
+    /// Break lines on word boundaries, only splitting words that are longer than the line.
+    #[default]
+    Word,
+    /// Break lines at any grapheme boundary, filling each line completely.
+    ///
+    /// This is useful for text that does not separate words with whitespace (such as CJK text) or
+    /// that contains long tokens (such as URLs, hashes, or base64 data).
+    Character,
+}
+
+/// Describes how the continuation lines of a wrapped line are indented.
+///
+/// This only has an effect when wrapping is enabled with [`Paragraph::wrap`]. The indentation is
+/// dropped when it would take up more than half of the available width.
+///
+/// ## Examples
+///
+/// ```
+/// use ratatui::widgets::{Paragraph, Wrap, WrapIndent};
+///
+/// // With a window width of 20 chars:
+/// Paragraph::new("    let value = some_function(argument);")
+///     .wrap(Wrap { trim: false })
+///     .wrap_indent(WrapIndent::Preserve);
+/// //     let value =
+/// //     some_function(ar
+/// //     gument);
+///
+/// Paragraph::new("- First thing goes here and is long")
+///     .wrap(Wrap { trim: true })
+///     .wrap_indent(WrapIndent::Hanging(2));
+/// // - First thing goes
+/// //   here and is long
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum WrapIndent {
+    /// Continuation lines start at the left edge of the text area.
+    #[default]
+    None,
+    /// Continuation lines repeat the leading whitespace of the original line.
+    ///
+    /// This keeps wrapped code and nested lists readable. Note that when [`Wrap::trim`] is set,
+    /// the leading whitespace of the first line is trimmed but still repeated on continuation
+    /// lines.
+    Preserve,
+    /// Continuation lines are indented by the given number of columns.
+    Hanging(u16),
+}
+
 type Horizontal = u16;
 type Vertical = u16;
 
@@ -156,6 +246,9 @@ impl<'a> Paragraph<'a> {
             block: None,
             style: Style::default(),
             wrap: None,
+            wrap_mode: WrapMode::Word,
+            wrap_indent: WrapIndent::None,
+            wrap_marker: None,
             text,
             scroll: Position::ORIGIN,
             alignment,
@@ -201,6 +294,15 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Sets the style of the paragraph from the `paragraph` style of a [`Theme`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style("paragraph");
+        self
+    }
+
     /// Sets the wrapping configuration for the widget.
     ///
     /// See [`Wrap`] for more information on the different options.
@@ -218,6 +320,71 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Sets where wrapped lines are broken.
+    ///
+    /// Defaults to [`WrapMode::Word`]. This has no effect unless wrapping is enabled with
+    /// [`Paragraph::wrap`]. See [`WrapMode`] for more information on the different options.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Paragraph, Wrap, WrapMode};
+    ///
+    /// let paragraph = Paragraph::new("https://ratatui.rs/concepts/layout/")
+    ///     .wrap(Wrap { trim: false })
+    ///     .wrap_mode(WrapMode::Character);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn wrap_mode(mut self, wrap_mode: WrapMode) -> Self {
+        self.wrap_mode = wrap_mode;
+        self
+    }
+
+    /// Sets how the continuation lines of a wrapped line are indented.
+    ///
+    /// Defaults to [`WrapIndent::None`]. This has no effect unless wrapping is enabled with
+    /// [`Paragraph::wrap`]. See [`WrapIndent`] for more information on the different options.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Paragraph, Wrap, WrapIndent};
+    ///
+    /// let paragraph = Paragraph::new("    fn main() { println!(\"Hello, world!\"); }")
+    ///     .wrap(Wrap { trim: false })
+    ///     .wrap_indent(WrapIndent::Preserve);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn wrap_indent(mut self, wrap_indent: WrapIndent) -> Self {
+        self.wrap_indent = wrap_indent;
+        self
+    }
+
+    /// Sets a marker drawn at the start of the continuation lines of a wrapped line.
+    ///
+    /// The marker is drawn after the indentation set with [`Paragraph::wrap_indent`]. It is
+    /// dropped along with the indentation when both would take up more than half of the available
+    /// width. This has no effect unless wrapping is enabled with [`Paragraph::wrap`].
+    ///
+    /// `marker` accepts any type that can be converted into a [`Span`] (e.g. `&str`, `String`, or
+    /// a styled [`Span`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Stylize;
+    /// use ratatui::widgets::{Paragraph, Wrap};
+    ///
+    /// let paragraph = Paragraph::new("A very long log line that needs to be wrapped")
+    ///     .wrap(Wrap { trim: true })
+    ///     .wrap_marker("↪ ".dark_gray());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn wrap_marker<T: Into<Span<'a>>>(mut self, marker: T) -> Self {
+        self.wrap_marker = Some(marker.into());
+        self
+    }
+
     /// Set the scroll offset for the given paragraph
     ///
     /// The scroll offset is a tuple of (y, x) offset. The y offset is the number of lines to
@@ -346,15 +513,19 @@ impl<'a> Paragraph<'a> {
                     .spans
                     .iter()
                     .flat_map(|span| span.styled_graphemes(self.style));
-                let alignment = line.alignment.unwrap_or(self.alignment);
-                (graphemes, alignment)
+                let direction = line.direction();
+                let alignment = direction.mirror(line.alignment.unwrap_or(self.alignment));
+                (graphemes, alignment, direction)
             });
-            let mut line_composer = WordWrapper::new(styled, width, trim);
-            let mut count = 0;
-            while line_composer.next_line().is_some() {
-                count += 1;
+            let continuation = self.continuation(self.style);
+            match self.wrap_mode {
+                WrapMode::Word => {
+                    count_lines(WordWrapper::new(styled, width, trim).continuation(continuation))
+                }
+                WrapMode::Character => {
+                    count_lines(CharWrapper::new(styled, width, trim).continuation(continuation))
+                }
             }
-            count
         } else {
             self.text.height()
         };
@@ -424,19 +595,25 @@ impl Paragraph<'_> {
         buf.set_style(text_area, self.style);
         let styled = self.text.iter().map(|line| {
             let graphemes = line.styled_graphemes(self.text.style);
-            let alignment = line.alignment.unwrap_or(self.alignment);
-            (graphemes, alignment)
+            let direction = line.direction();
+            let alignment = direction.mirror(line.alignment.unwrap_or(self.alignment));
+            (graphemes, alignment, direction)
         });
 
         if let Some(Wrap { trim }) = self.wrap {
-            let mut line_composer = WordWrapper::new(styled, text_area.width, trim);
-            // compute the lines iteratively until we reach the desired scroll offset.
-            for _ in 0..self.scroll.y {
-                if line_composer.next_line().is_none() {
-                    return;
+            let continuation = self.continuation(self.text.style);
+            match self.wrap_mode {
+                WrapMode::Word => {
+                    let line_composer =
+                        WordWrapper::new(styled, text_area.width, trim).continuation(continuation);
+                    self.render_wrapped_lines(line_composer, text_area, buf);
+                }
+                WrapMode::Character => {
+                    let line_composer =
+                        CharWrapper::new(styled, text_area.width, trim).continuation(continuation);
+                    self.render_wrapped_lines(line_composer, text_area, buf);
                 }
             }
-            render_lines(line_composer, text_area, buf);
         } else {
             // avoid unnecessary work by skipping directly to the relevant line before rendering
             let lines = styled.skip(self.scroll.y as usize);
@@ -445,6 +622,42 @@ impl Paragraph<'_> {
             render_lines(line_composer, text_area, buf);
         }
     }
+
+    fn render_wrapped_lines<'a, C: LineComposer<'a>>(
+        &self,
+        mut line_composer: C,
+        text_area: Rect,
+        buf: &mut Buffer,
+    ) {
+        // compute the lines iteratively until we reach the desired scroll offset.
+        for _ in 0..self.scroll.y {
+            if line_composer.next_line().is_none() {
+                return;
+            }
+        }
+        render_lines(line_composer, text_area, buf);
+    }
+
+    /// Returns the indentation and marker prepended to continuation lines when wrapping.
+    fn continuation(&self, base_style: Style) -> Continuation<'_> {
+        let marker = self
+            .wrap_marker
+            .as_ref()
+            .map(|marker| marker.styled_graphemes(base_style).collect())
+            .unwrap_or_default();
+        Continuation {
+            indent: self.wrap_indent,
+            marker,
+        }
+    }
+}
+
+fn count_lines<'a, C: LineComposer<'a>>(mut composer: C) -> usize {
+    let mut count = 0;
+    while composer.next_line().is_some() {
+        count += 1;
+    }
+    count
 }
 
 fn render_lines<'a, C: LineComposer<'a>>(mut composer: C, area: Rect, buf: &mut Buffer) {
@@ -460,7 +673,8 @@ fn render_lines<'a, C: LineComposer<'a>>(mut composer: C, area: Rect, buf: &mut
 
 fn render_line(wrapped: &WrappedLine<'_, '_>, area: Rect, buf: &mut Buffer, y: u16) {
     let mut x = get_line_offset(wrapped.width, area.width, wrapped.alignment);
-    for StyledGrapheme { symbol, style } in wrapped.graphemes {
+    let graphemes = wrapped.direction.reorder(wrapped.graphemes);
+    for StyledGrapheme { symbol, style } in graphemes.iter() {
         let width = symbol.cell_width();
         if width == 0 {
             continue;
@@ -771,6 +985,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_render_paragraph_with_character_wrap() {
+        let text = "Hello wonderful world";
+        let paragraph = Paragraph::new(text)
+            .wrap(Wrap { trim: true })
+            .wrap_mode(WrapMode::Character);
+
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["Hello wond", "erful worl", "d         "]),
+        );
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["Hello wonderf", "ul world     "]),
+        );
+    }
+
+    #[test]
+    fn test_render_paragraph_with_preserved_indent() {
+        let text = "    let value = some_function(argument);";
+        let paragraph = Paragraph::new(text)
+            .wrap(Wrap { trim: false })
+            .wrap_indent(WrapIndent::Preserve);
+
+        test_case(
+            &paragraph,
+            &Buffer::with_lines([
+                "    let value =     ",
+                "    some_function(ar",
+                "    gument);        ",
+            ]),
+        );
+    }
+
+    #[test]
+    fn test_render_paragraph_with_hanging_indent() {
+        let text = "- First thing goes here and is long";
+        let paragraph = Paragraph::new(text)
+            .wrap(Wrap { trim: true })
+            .wrap_indent(WrapIndent::Hanging(2));
+
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["- First thing goes  ", "  here and is long  "]),
+        );
+    }
+
+    #[test]
+    fn test_render_paragraph_with_wrap_marker() {
+        let text = "A very long log line";
+        let paragraph = Paragraph::new(text)
+            .wrap(Wrap { trim: true })
+            .wrap_mode(WrapMode::Character)
+            .wrap_marker("↪ ".blue());
+
+        let mut expected = Buffer::with_lines(["A very long ", "↪ log line  "]);
+        expected.set_style(Rect::new(0, 1, 2, 1), Style::new().blue());
+        test_case(&paragraph, &expected);
+    }
+
+    #[test]
+    fn test_render_paragraph_with_wrap_marker_and_scroll() {
+        let text = "aaaa bbbb cccc dddd";
+        let paragraph = Paragraph::new(text)
+            .wrap(Wrap { trim: true })
+            .wrap_indent(WrapIndent::Hanging(1))
+            .wrap_marker(">")
+            .scroll((1, 0));
+
+        test_case(
+            &paragraph,
+            &Buffer::with_lines([" >bbbb", " >cccc", " >dddd"]),
+        );
+    }
+
+    #[test]
+    fn test_render_paragraph_with_rtl_text() {
+        let paragraph = Paragraph::new("שלום עולם");
+        test_case(&paragraph, &Buffer::with_lines(["   םלוע םולש"]));
+
+        let paragraph = Paragraph::new("שלום עולם").right_aligned();
+        test_case(&paragraph, &Buffer::with_lines(["םלוע םולש   "]));
+
+        let paragraph = Paragraph::new("abc אבג def");
+        test_case(&paragraph, &Buffer::with_lines(["abc גבא def "]));
+    }
+
+    #[test]
+    fn test_render_wrapped_paragraph_with_rtl_text() {
+        let paragraph = Paragraph::new("שלום עולם").wrap(Wrap { trim: true });
+        test_case(&paragraph, &Buffer::with_lines([" םולש", " םלוע"]));
+
+        // the wrapped lines keep the direction of the paragraph they were wrapped from
+        let paragraph = Paragraph::new("גדהו abc אב").wrap(Wrap { trim: true });
+        test_case(&paragraph, &Buffer::with_lines(["  והדג", "בא abc"]));
+
+        let paragraph = Paragraph::new("גדהו abc אב")
+            .wrap(Wrap { trim: true })
+            .wrap_mode(WrapMode::Character);
+        test_case(&paragraph, &Buffer::with_lines(["a והדג", " בא bc"]));
+    }
+
     #[test]
     fn test_render_wrapped_paragraph_with_whitespace_only_line() {
         let text: Text = ["A", "  ", "B", "  a", "C"]
@@ -1131,6 +1447,21 @@ mod tests {
         assert_eq!(paragraph.line_count(6), 200);
     }
 
+    #[test]
+    fn widgets_paragraph_count_rendered_lines_with_wrap_options() {
+        let paragraph = Paragraph::new("Hello wonderful world")
+            .wrap(Wrap { trim: true })
+            .wrap_mode(WrapMode::Character);
+        assert_eq!(paragraph.line_count(10), 3);
+        assert_eq!(paragraph.line_count(21), 1);
+
+        let paragraph = Paragraph::new("aaaa bbbb cccc")
+            .wrap(Wrap { trim: true })
+            .wrap_marker("> ");
+        assert_eq!(paragraph.line_count(6), 3);
+        assert_eq!(paragraph.line_count(9), 2);
+    }
+
     #[test]
     fn widgets_paragraph_rendered_line_count_accounts_block() {
         let block = Block::new();
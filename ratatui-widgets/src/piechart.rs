@@ -0,0 +1,613 @@
+//! The [`PieChart`] widget and its related types (e.g. [`Slice`]).
+use alloc::vec::Vec;
+use core::f64::consts::{FRAC_PI_2, TAU};
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Margin, Rect};
+use ratatui_core::style::{Color, Style, Styled, Theme};
+use ratatui_core::symbols::Marker;
+use ratatui_core::widgets::Widget;
+
+pub use self::slice::Slice;
+use crate::block::{Block, BlockExt};
+use crate::canvas::{Canvas, Painter, Shape};
+use crate::chart::LegendPosition;
+#[cfg(not(feature = "std"))]
+use crate::polyfills::F64Polyfills;
+
+mod slice;
+
+/// The colors of the slices whose style has no foreground color, in order
+const DEFAULT_COLORS: [Color; 6] = [
+    Color::Blue,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Magenta,
+    Color::Cyan,
+];
+
+/// A chart showing the proportions of values as the [slices](Slice) of a disc
+///
+/// The slices are drawn clockwise from the top of the disc, and the angle of each slice is
+/// proportional to its value. The disc is drawn on a [`Canvas`] with the [marker](PieChart::marker)
+/// of the chart, [Braille](Marker::Braille) by default, and is kept round by assuming that the
+/// cells of the terminal are twice as high as wide. A [hole](PieChart::hole) can be left in the
+/// middle of the disc to draw a donut chart.
+///
+/// The labels of the slices are displayed in a legend placed at the
+/// [legend position](PieChart::legend_position), or on the slices when the chart has no legend.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::style::Stylize;
+/// use ratatui::widgets::{Block, LegendPosition, PieChart, Slice};
+///
+/// PieChart::new([
+///     Slice::with_label("Documents", 42.0).blue(),
+///     Slice::with_label("Photos", 28.0).green(),
+///     Slice::with_label("Free", 30.0).dark_gray(),
+/// ])
+/// .block(Block::bordered().title("Disk usage"))
+/// .hole(0.5)
+/// .legend_position(Some(LegendPosition::Right));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PieChart<'a> {
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Widget style
+    style: Style,
+    /// The slices to display
+    slices: Vec<Slice<'a>>,
+    /// The symbols used to draw the slices
+    marker: Marker,
+    /// The radius of the hole in the middle of the chart, as a fraction of its radius
+    hole: f64,
+    /// The position of the legend, or `None` to display the labels on the slices
+    legend_position: Option<LegendPosition>,
+}
+
+impl<'a> PieChart<'a> {
+    /// Creates a chart with the given slices
+    ///
+    /// `slices` can be any iterator of items that can be converted into a [`Slice`], e.g. an array
+    /// or a `Vec` of slices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{PieChart, Slice};
+    ///
+    /// let chart = PieChart::new([
+    ///     Slice::with_label("Rent", 1200.0),
+    ///     Slice::with_label("Food", 400.0),
+    /// ]);
+    /// ```
+    pub fn new<I>(slices: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Slice<'a>>,
+    {
+        Self {
+            block: None,
+            style: Style::new(),
+            slices: slices.into_iter().map(Into::into).collect(),
+            marker: Marker::Braille,
+            hole: 0.0,
+            legend_position: Some(LegendPosition::default()),
+        }
+    }
+
+    /// Wraps the chart with the given `block`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the entire widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The style of the slices is patched on top of it.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the pie chart from the `piechart` style of a [`Theme`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style("piechart");
+        self
+    }
+
+    /// Sets the marker used to draw the slices
+    ///
+    /// [`Marker::Braille`] is the default. [`Marker::Octant`] has the same resolution and draws
+    /// solid slices, but requires a recent font.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn marker(mut self, marker: Marker) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Sets the radius of the hole in the middle of the chart, as a fraction of its radius
+    ///
+    /// The default of `0.0` draws a pie chart, and greater values draw a donut chart. The value is
+    /// clamped between `0.0` and `1.0`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn hole(mut self, hole: f64) -> Self {
+        self.hole = hole;
+        self
+    }
+
+    /// Sets the position of the legend, or `None` to display the labels on the slices
+    ///
+    /// The legend lists the labels of the slices with their style. The disc is drawn next to it,
+    /// in the rest of the area: on its left when the legend is on the right, and so on. The legend
+    /// is hidden when it doesn't fit in the area.
+    ///
+    /// The default is [`LegendPosition::TopRight`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn legend_position(mut self, position: Option<LegendPosition>) -> Self {
+        self.legend_position = position;
+        self
+    }
+}
+
+impl Default for PieChart<'_> {
+    fn default() -> Self {
+        Self::new(Vec::<Slice>::new())
+    }
+}
+
+impl Styled for PieChart<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Widget for PieChart<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &PieChart<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() {
+            return;
+        }
+        let legend_area = self.legend_area(inner);
+        let pie_area = legend_area.map_or(inner, |legend| self.pie_area(inner, legend));
+        self.render_pie(pie_area, buf);
+        if let Some(legend_area) = legend_area {
+            self.render_legend(legend_area, buf);
+        }
+    }
+}
+
+impl PieChart<'_> {
+    /// Returns the color of the slice at the given index
+    fn color(&self, index: usize) -> Color {
+        self.slices[index]
+            .style
+            .fg
+            .unwrap_or(DEFAULT_COLORS[index % DEFAULT_COLORS.len()])
+    }
+
+    /// Returns the area of the legend, or `None` if the chart has no legend or it doesn't fit
+    fn legend_area(&self, area: Rect) -> Option<Rect> {
+        let position = self.legend_position?;
+        let labels = self.slices.iter().filter_map(|slice| slice.label.as_ref());
+        let width = labels.clone().map(|label| label.width() as u16).max()? + 2;
+        let height = labels.count() as u16 + 2;
+        if width > area.width || height > area.height {
+            return None;
+        }
+        position.layout(area, width, height, 0, 0)
+    }
+
+    /// Returns the area of the disc, next to the legend
+    const fn pie_area(&self, area: Rect, legend: Rect) -> Rect {
+        match self.legend_position {
+            Some(LegendPosition::Top) => Rect {
+                y: legend.bottom(),
+                height: area.bottom() - legend.bottom(),
+                ..area
+            },
+            Some(LegendPosition::Bottom) => Rect {
+                height: legend.top() - area.top(),
+                ..area
+            },
+            Some(LegendPosition::TopLeft | LegendPosition::Left | LegendPosition::BottomLeft) => {
+                Rect {
+                    x: legend.right(),
+                    width: area.right() - legend.right(),
+                    ..area
+                }
+            }
+            _ => Rect {
+                width: legend.left() - area.left(),
+                ..area
+            },
+        }
+    }
+
+    /// Draws the slices, and their labels when the chart has no legend
+    fn render_pie(&self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        // a cell is about twice as high as wide, so a unit is the width of a cell on both axes
+        let half_width = f64::from(area.width) / 2.0;
+        let half_height = f64::from(area.height);
+        let radius = half_width.min(half_height);
+        let hole = self.hole.clamp(0.0, 1.0) * radius;
+        let total = self.slices.iter().map(Slice::displayed_value).sum::<f64>();
+        if total <= 0.0 {
+            return;
+        }
+        let mut start = 0.0;
+        let sectors = self
+            .slices
+            .iter()
+            .enumerate()
+            .filter_map(|(i, slice)| {
+                let fraction = slice.displayed_value() / total;
+                start += fraction;
+                (fraction > 0.0).then(|| Sector {
+                    index: i,
+                    start: start - fraction,
+                    end: start,
+                    color: self.color(i),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Canvas::default()
+            .background_color(self.style.bg.unwrap_or(Color::Reset))
+            .marker(self.marker)
+            .x_bounds([-half_width, half_width])
+            .y_bounds([-half_height, half_height])
+            .paint(|ctx| {
+                ctx.draw(&Disc {
+                    radius,
+                    hole,
+                    sectors: &sectors,
+                });
+            })
+            .render(area, buf);
+
+        if self.legend_position.is_some() {
+            return;
+        }
+        // the labels are centered on the middle of the ring of their slice
+        let label_radius = f64::midpoint(radius, hole);
+        for sector in &sectors {
+            let Some(label) = &self.slices[sector.index].label else {
+                continue;
+            };
+            let (x, y) = point(f64::midpoint(sector.start, sector.end), label_radius);
+            let width = label.width() as u16;
+            let column = (x + half_width - f64::from(width) / 2.0).round().max(0.0) as u16;
+            let row = ((half_height - y) / 2.0).floor().max(0.0) as u16;
+            let label_area = Rect::new(
+                area.x + column.min(area.width.saturating_sub(width)),
+                area.y + row.min(area.height - 1),
+                width,
+                1,
+            );
+            label.render(label_area.intersection(area), buf);
+        }
+    }
+
+    /// Draws the labels of the slices in a bordered block
+    fn render_legend(&self, area: Rect, buf: &mut Buffer) {
+        Block::bordered().render(area, buf);
+        let labels =
+            self.slices.iter().enumerate().filter_map(|(i, slice)| {
+                Some((slice.label.as_ref()?, slice.style.fg(self.color(i))))
+            });
+        for ((label, style), row) in labels.zip(area.inner(Margin::new(1, 1)).rows()) {
+            label.clone().patch_style(style).render(row, buf);
+        }
+    }
+}
+
+/// Returns the coordinates of the point at the given fraction of a turn clockwise from the top
+/// of a circle of the given radius centered on the origin
+fn point(fraction: f64, radius: f64) -> (f64, f64) {
+    let angle = FRAC_PI_2 - fraction * TAU;
+    (radius * angle.cos(), radius * angle.sin())
+}
+
+/// Returns a value from `0.0` to `4.0` that increases with the angle of `(x, y)` clockwise from
+/// the top
+///
+/// This is cheaper than computing the angle itself and is enough to compare angles.
+fn pseudo_angle(x: f64, y: f64) -> f64 {
+    if x == 0.0 && y == 0.0 {
+        return 0.0;
+    }
+    // the position on the perimeter of the unit diamond, counterclockwise from the right
+    let diamond = if y >= 0.0 {
+        if x >= 0.0 {
+            y / (x + y)
+        } else {
+            1.0 - x / (y - x)
+        }
+    } else if x < 0.0 {
+        2.0 - y / (-x - y)
+    } else {
+        3.0 + x / (x - y)
+    };
+    let angle = 1.0 - diamond;
+    if angle < 0.0 { angle + 4.0 } else { angle }
+}
+
+/// A slice of the disc, from a fraction of a turn to another
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Sector {
+    /// The index of the slice
+    index: usize,
+    start: f64,
+    end: f64,
+    color: Color,
+}
+
+/// A disc centered on the origin split in sectors, with a hole in the middle
+#[derive(Debug, Clone, PartialEq)]
+struct Disc<'a> {
+    radius: f64,
+    hole: f64,
+    sectors: &'a [Sector],
+}
+
+impl Shape for Disc<'_> {
+    fn draw(&self, painter: &mut Painter) {
+        let (&[left, right], &[bottom, top]) = painter.bounds();
+        let Some((max_x, max_y)) = painter.get_point(right, bottom) else {
+            return;
+        };
+        let ends = self
+            .sectors
+            .iter()
+            .map(|sector| {
+                if sector.end >= 1.0 {
+                    4.0
+                } else {
+                    let (x, y) = point(sector.end, 1.0);
+                    pseudo_angle(x, y)
+                }
+            })
+            .collect::<Vec<_>>();
+        // the bounds are the edges of the area, so the points are sampled at the center of the
+        // pixels rather than on the edges
+        let step_x = (right - left) / (max_x + 1) as f64;
+        let step_y = (top - bottom) / (max_y + 1) as f64;
+        for row in 0..=max_y {
+            let y = (row as f64 + 0.5).mul_add(-step_y, top);
+            for column in 0..=max_x {
+                let x = (column as f64 + 0.5).mul_add(step_x, left);
+                let distance = x * x + y * y;
+                if distance > self.radius * self.radius || distance < self.hole * self.hole {
+                    continue;
+                }
+                let angle = pseudo_angle(x, y);
+                let sector = ends.iter().position(|&end| angle < end);
+                let sector = sector.unwrap_or(self.sectors.len() - 1);
+                if let Some(sector) = self.sectors.get(sector) {
+                    painter.paint(column, row, sector.color);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use ratatui_core::style::Stylize;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn pseudo_angle_increases_clockwise_from_the_top() {
+        let angles = (0..16)
+            .map(|i| {
+                let (x, y) = point(f64::from(i) / 16.0, 2.0);
+                pseudo_angle(x, y)
+            })
+            .collect::<Vec<_>>();
+        assert!(angles[0] < 1e-9);
+        assert!(angles.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(angles[15] < 4.0);
+    }
+
+    #[test]
+    fn render_pie() {
+        let chart =
+            PieChart::new([Slice::new(1.0).red(), Slice::new(3.0).blue()]).marker(Marker::Block);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+        chart.render(buffer.area, &mut buffer);
+        let lines = [
+            "  ██████  ",
+            "██████████",
+            "██████████",
+            "██████████",
+            "  ██████  ",
+        ];
+        for (y, line) in lines.iter().enumerate() {
+            let symbols = (0..10)
+                .map(|x| buffer[(x, y as u16)].symbol())
+                .collect::<String>();
+            assert_eq!(&symbols, line);
+        }
+        // the first quarter is on the top right
+        assert_eq!(buffer[(7, 0)].fg, Color::Red);
+        assert_eq!(buffer[(9, 1)].fg, Color::Red);
+        assert_eq!(buffer[(2, 0)].fg, Color::Blue);
+        assert_eq!(buffer[(7, 3)].fg, Color::Blue);
+    }
+
+    #[test]
+    fn render_donut() {
+        let chart = PieChart::new([Slice::new(1.0), Slice::new(1.0)])
+            .marker(Marker::Braille)
+            .hole(0.5);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([
+            " ⣠⣴⣾⣿⣿⣷⣦⣄ ",
+            "⣰⣿⣿⠛⠉⠉⠛⣿⣿⣆",
+            "⣿⣿⡇    ⢸⣿⣿",
+            "⠹⣿⣿⣤⣀⣀⣤⣿⣿⠏",
+            " ⠙⠻⢿⣿⣿⡿⠟⠋ ",
+        ]);
+        // the slices without a color use the default colors
+        expected.set_style(Rect::new(0, 0, 5, 5), Style::new().fg(Color::Red));
+        expected.set_style(Rect::new(5, 0, 5, 5), Style::new().fg(Color::Blue));
+        for (x, y) in [
+            (3, 2),
+            (4, 2),
+            (5, 2),
+            (6, 2),
+            (0, 0),
+            (9, 0),
+            (0, 4),
+            (9, 4),
+        ] {
+            expected[(x, y)].set_fg(Color::Reset);
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_labels_on_slices() {
+        let chart = PieChart::new([Slice::with_label("a", 1.0), Slice::with_label("bb", 1.0)])
+            .marker(Marker::Dot)
+            .legend_position(None);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 6));
+        chart.render(buffer.area, &mut buffer);
+        let lines = [
+            "   ••••••   ",
+            " •••••••••• ",
+            "••••••••••••",
+            "••bb•••••a••",
+            " •••••••••• ",
+            "   ••••••   ",
+        ];
+        for (y, line) in lines.iter().enumerate() {
+            let symbols = (0..12)
+                .map(|x| buffer[(x, y as u16)].symbol())
+                .collect::<String>();
+            assert_eq!(&symbols, line);
+        }
+    }
+
+    #[rstest]
+    #[case::top_right(LegendPosition::TopRight, [
+        "        ┌──┐",
+        " •••••• │a │",
+        "••••••••│bb│",
+        "••••••••└──┘",
+        " ••••••     ",
+        "            ",
+    ])]
+    #[case::left(LegendPosition::Left, [
+        "            ",
+        "┌──┐ •••••• ",
+        "│a │••••••••",
+        "│bb│••••••••",
+        "└──┘ •••••• ",
+        "            ",
+    ])]
+    #[case::bottom(LegendPosition::Bottom, [
+        "    ••••    ",
+        "    ••••    ",
+        "    ┌──┐    ",
+        "    │a │    ",
+        "    │bb│    ",
+        "    └──┘    ",
+    ])]
+    fn render_legend(#[case] position: LegendPosition, #[case] lines: [&str; 6]) {
+        let chart = PieChart::new([Slice::with_label("a", 1.0), Slice::with_label("bb", 3.0)])
+            .marker(Marker::Dot)
+            .legend_position(Some(position));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 6));
+        chart.render(buffer.area, &mut buffer);
+        for (y, line) in lines.iter().enumerate() {
+            let symbols = (0..12)
+                .map(|x| buffer[(x, y as u16)].symbol())
+                .collect::<String>();
+            assert_eq!(&symbols, line);
+        }
+        let legend = buffer
+            .content()
+            .iter()
+            .filter(|cell| ["a", "b"].contains(&cell.symbol()))
+            .map(|cell| cell.fg)
+            .collect::<Vec<_>>();
+        assert_eq!(legend, [Color::Blue, Color::Red, Color::Red]);
+    }
+
+    #[test]
+    fn render_without_values() {
+        let chart = PieChart::new([Slice::new(0.0), Slice::new(-1.0), Slice::new(f64::NAN)]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::empty(Rect::new(0, 0, 10, 5)));
+    }
+
+    #[test]
+    fn render_in_small_area() {
+        let chart = PieChart::new([Slice::with_label("label", 1.0), Slice::new(2.0)]);
+        for width in 0..4 {
+            for height in 0..4 {
+                let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+                chart
+                    .clone()
+                    .legend_position(None)
+                    .render(buffer.area, &mut buffer);
+                chart.clone().render(buffer.area, &mut buffer);
+            }
+        }
+    }
+
+    #[test]
+    fn can_be_stylized() {
+        assert_eq!(
+            PieChart::default().black().on_white().bold().style,
+            Style::default().black().on_white().bold()
+        );
+    }
+}
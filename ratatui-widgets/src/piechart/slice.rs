@@ -0,0 +1,146 @@
+use ratatui_core::style::{Style, Styled};
+use ratatui_core::text::Line;
+
+/// A slice to be shown by the [`PieChart`](super::PieChart) widget.
+///
+/// The angle of a slice is proportional to its value. Its color is the foreground color of its
+/// style, or one of the default colors of the chart when the style has none.
+///
+/// # Example
+///
+/// The following example creates a red slice with the label "Documents" and a value of 42.
+///
+/// ```
+/// use ratatui::style::Stylize;
+/// use ratatui::widgets::Slice;
+///
+/// Slice::with_label("Documents", 42.0).red();
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Slice<'a> {
+    /// The value of the slice, relative to the sum of the values of all the slices
+    pub(super) value: f64,
+    /// The label displayed in the legend or on the slice
+    pub(super) label: Option<Line<'a>>,
+    /// The style of the slice
+    pub(super) style: Style,
+}
+
+impl<'a> Slice<'a> {
+    /// Creates a new `Slice` with the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::Slice;
+    ///
+    /// let slice = Slice::new(42.0);
+    /// ```
+    pub const fn new(value: f64) -> Self {
+        Self {
+            value,
+            label: None,
+            style: Style::new(),
+        }
+    }
+
+    /// Creates a new `Slice` with the given `label` and value.
+    ///
+    /// a `label` can be a [`&str`], [`String`](alloc::string::String) or anything that can be
+    /// converted into [`Line`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::Slice;
+    ///
+    /// let slice = Slice::with_label("Label", 42.0);
+    /// ```
+    pub fn with_label<T: Into<Line<'a>>>(label: T, value: f64) -> Self {
+        Self {
+            value,
+            label: Some(label.into()),
+            style: Style::new(),
+        }
+    }
+
+    /// Set the value of the slice.
+    ///
+    /// Negative and non-finite values are displayed as empty slices.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn value(mut self, value: f64) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Set the label of the slice.
+    ///
+    /// `label` can be a [`&str`], [`String`](alloc::string::String) or anything that can be
+    /// converted into [`Line`].
+    ///
+    /// The label is displayed in the legend of the chart, or on the slice when the chart has no
+    /// legend. See [`PieChart::legend_position`](super::PieChart::legend_position).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label<T: Into<Line<'a>>>(mut self, label: T) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the style of the slice.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The foreground color is the color of the slice. The style is also applied to the label of
+    /// the slice in the legend.
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the value of the slice that is displayed, which is `0.0` for negative and
+    /// non-finite values
+    pub(super) const fn displayed_value(&self) -> f64 {
+        if self.value.is_finite() {
+            self.value.max(0.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Styled for Slice<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Stylize};
+
+    use super::*;
+
+    #[test]
+    fn slice_can_be_stylized() {
+        let slice = Slice::with_label("a", 1.0).red().on_white();
+        assert_eq!(slice.style, Style::new().fg(Color::Red).bg(Color::White));
+    }
+
+    #[test]
+    fn displayed_value_ignores_invalid_values() {
+        assert!((Slice::new(2.5).displayed_value() - 2.5).abs() < f64::EPSILON);
+        assert!(Slice::new(-1.0).displayed_value().abs() < f64::EPSILON);
+        assert!(Slice::new(f64::NAN).displayed_value().abs() < f64::EPSILON);
+        assert!(Slice::new(f64::INFINITY).displayed_value().abs() < f64::EPSILON);
+    }
+}
@@ -8,7 +8,7 @@
 //!
 //! - <https://github.com/rust-lang/rust/issues/50145>
 //! - <https://github.com/rust-lang/rust/issues/137578>
-use core::f64::consts::{FRAC_1_PI, PI};
+use core::f64::consts::{FRAC_1_PI, LN_2, LOG10_E, PI, SQRT_2};
 
 #[inline]
 fn mul_add(val: f64, a: f64, b: f64) -> f64 {
@@ -29,6 +29,95 @@ fn floor(val: f64) -> f64 {
     res
 }
 
+#[inline]
+fn ceil(val: f64) -> f64 {
+    -floor(-val)
+}
+
+fn powi(val: f64, n: i32) -> f64 {
+    let mut base = val;
+    let mut exponent = n.unsigned_abs();
+    let mut res = 1.0;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            res *= base;
+        }
+        base *= base;
+        exponent >>= 1;
+    }
+    if n < 0 { 1.0 / res } else { res }
+}
+
+fn ln(val: f64) -> f64 {
+    if val.is_nan() || val < 0.0 {
+        return f64::NAN;
+    }
+    if val == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if val.is_infinite() {
+        return val;
+    }
+    // split the value into a mantissa in [1, 2) and an exponent, scaling subnormal values first
+    let (val, offset) = if val < f64::MIN_POSITIVE {
+        (val * powi(2.0, 54), -54)
+    } else {
+        (val, 0)
+    };
+    let bits = val.to_bits();
+    let mut exponent = ((bits >> 52) & 0x7ff) as i32 - 1023 + offset;
+    let mut mantissa = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+    if mantissa > SQRT_2 {
+        mantissa /= 2.0;
+        exponent += 1;
+    }
+    // ln(m) = 2 * atanh((m - 1) / (m + 1)), which converges quickly for m close to 1
+    let z = (mantissa - 1.0) / (mantissa + 1.0);
+    let z2 = z * z;
+    let mut term = z;
+    let mut sum = 0.0;
+    for i in 0..16 {
+        sum += term / f64::from(2 * i + 1);
+        term *= z2;
+    }
+    f64::from(exponent) * LN_2 + 2.0 * sum
+}
+
+#[inline]
+fn log10(val: f64) -> f64 {
+    ln(val) * LOG10_E
+}
+
+fn exp(val: f64) -> f64 {
+    if val.is_nan() {
+        return val;
+    }
+    if val > 709.8 {
+        return f64::INFINITY;
+    }
+    if val < -745.2 {
+        return 0.0;
+    }
+    // split the value into k * ln(2) + r, with |r| <= ln(2) / 2 so that the series converges
+    // quickly
+    let k = round(val / LN_2);
+    let r = val - k * LN_2;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for i in 1..20 {
+        term *= r / f64::from(i);
+        sum += term;
+    }
+    // scale in two steps, as 2^k overflows or underflows for the largest exponents
+    let k = k as i32;
+    sum * powi(2.0, k / 2) * powi(2.0, k - k / 2)
+}
+
+#[inline]
+fn powf(val: f64, n: f64) -> f64 {
+    exp(n * ln(val))
+}
+
 #[inline]
 fn sin(val: f64) -> f64 {
     cos(val - PI / 2.0)
@@ -55,6 +144,17 @@ pub(crate) trait F64Polyfills {
     /// Returns the largest integer less than or equal to `self`.
     fn floor(self) -> f64;
 
+    /// Returns the smallest integer greater than or equal to `self`.
+    fn ceil(self) -> f64;
+
+    /// Raises a number to an integer power.
+    fn powi(self, n: i32) -> f64;
+
+    /// Returns the base 10 logarithm of the number.
+    fn log10(self) -> f64;
+    /// Raises a number to a floating point power.
+    fn powf(self, n: f64) -> f64;
+
     /// Approximates the sine of a number (in radians) with max error of `0.002`.
     fn sin(self) -> f64;
 
@@ -76,6 +176,22 @@ impl F64Polyfills for f64 {
         floor(self)
     }
     #[inline]
+    fn ceil(self) -> f64 {
+        ceil(self)
+    }
+    #[inline]
+    fn powi(self, n: i32) -> f64 {
+        powi(self, n)
+    }
+    #[inline]
+    fn log10(self) -> f64 {
+        log10(self)
+    }
+    #[inline]
+    fn powf(self, n: f64) -> f64 {
+        powf(self, n)
+    }
+    #[inline]
     fn sin(self) -> f64 {
         sin(self)
     }
@@ -100,6 +216,8 @@ mod tests {
 
     const MAX_ERROR: f64 = 0.000_000_000_000_01;
     const TRIG_MAX_ERROR: f64 = 0.002;
+    // the error of the logarithm grows with the exponent
+    const POW_MAX_ERROR: f64 = 0.000_000_000_001;
 
     fn assert_with_error(computed: f64, expected: f64, max_error: f64) {
         let delta = (computed - expected).abs();
@@ -136,6 +254,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn f64_ceil() {
+        for value in TEST_VALUES {
+            let expected = value.ceil();
+            let computed = ceil(value);
+            assert_with_error(computed, expected, MAX_ERROR);
+        }
+    }
+
+    #[test]
+    fn f64_powi() {
+        for (value, n) in TEST_VALUES
+            .into_iter()
+            .zip(-6..)
+            .filter(|(value, _)| *value != 0.0)
+        {
+            let expected = value.powi(n);
+            let computed = powi(value, n);
+            assert_with_error(computed, expected, expected.abs() * MAX_ERROR);
+        }
+    }
+
+    #[test]
+    fn f64_log10() {
+        let values = TEST_VALUES.into_iter().filter(|value| *value > 0.0);
+        for value in values.chain([1e-310, 1e-5, 1e3, 1e300]) {
+            let expected = value.log10();
+            let computed = log10(value);
+            assert_with_error(computed, expected, MAX_ERROR);
+        }
+        assert!(log10(-1.0).is_nan());
+        assert_eq!(log10(0.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn f64_powf() {
+        let values = TEST_VALUES.into_iter().filter(|value| *value > 0.0);
+        for (value, n) in values.zip(
+            [-2.5, -1.0, -0.5, 0.0, 0.5, 1.0, 2.5, 3.0]
+                .into_iter()
+                .cycle(),
+        ) {
+            let expected = value.powf(n);
+            let computed = powf(value, n);
+            assert_with_error(computed, expected, expected.abs() * POW_MAX_ERROR);
+        }
+        for n in [-300.0, -2.5, 0.3, 2.5, 300.0] {
+            let expected = 10f64.powf(n);
+            assert_with_error(powf(10.0, n), expected, expected * POW_MAX_ERROR);
+        }
+    }
+
     #[test]
     fn f64_sin() {
         for value in TEST_VALUES {
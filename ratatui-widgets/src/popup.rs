@@ -0,0 +1,555 @@
+//! The [`Popup`] widget displays a dialog over other widgets, with an optional title, a body and a
+//! row of buttons.
+
+use alloc::vec::Vec;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{HorizontalAlignment, Position, Rect};
+use ratatui_core::style::{Modifier, Style, Styled, Theme};
+use ratatui_core::text::{Line, Text};
+use ratatui_core::widgets::{StatefulWidget, Widget};
+
+pub use self::state::PopupState;
+use crate::block::{Block, Padding, Shadow};
+use crate::clear::Clear;
+
+mod state;
+
+const DEFAULT_SELECTED_BUTTON_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+
+/// A widget to display a popup dialog over other widgets
+///
+/// A popup is a [`Block`], bordered by default, that contains an optional title, a body [`Text`]
+/// and an optional row of buttons. It sizes itself to its content and is positioned relative to
+/// the area it is rendered in, which is usually the whole frame, but can be any other area, such
+/// as the area of a widget that the popup refers to. See [`PopupPosition`] for the available
+/// positions. The popup is moved with [`Rect::clamp`] to stay inside the buffer.
+///
+/// Before being drawn, the area of the popup is cleared with [`Clear`] so that the widgets under
+/// it do not show through. An optional [`Shadow`] can be drawn behind it.
+///
+/// A popup with buttons is a [`StatefulWidget`] that works with a [`PopupState`], which stores the
+/// selected button and handles the keyboard navigation between the buttons. Without buttons, the
+/// popup can also be rendered as a [`Widget`].
+///
+/// # Fluent setters
+///
+/// - [`Popup::title`] sets the title displayed on the top border.
+/// - [`Popup::buttons`] sets the labels of the buttons.
+/// - [`Popup::position`] sets where the popup is displayed.
+/// - [`Popup::width`] and [`Popup::height`] override the size computed from the content.
+/// - [`Popup::block`] and [`Popup::shadow`] set the block and the shadow of the popup.
+/// - [`Popup::button_style`] and [`Popup::selected_button_style`] set the style of the buttons.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::widgets::{Popup, PopupState, Shadow};
+///
+/// # fn ui(frame: &mut Frame) {
+/// let popup = Popup::new("Save changes before closing?")
+///     .title("Quit")
+///     .buttons(["Save", "Discard", "Cancel"])
+///     .shadow(Shadow::dark_shade());
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = PopupState::default();
+///
+/// frame.render_stateful_widget(popup, frame.area(), &mut state);
+/// // ┌Quit───────────────────────────────┐
+/// // │ Save changes before closing?      │▓
+/// // │                                   │▓
+/// // │ [ Save ]  [ Discard ]  [ Cancel ] │▓
+/// // └───────────────────────────────────┘▓
+/// //  ▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Popup<'a> {
+    /// The title displayed on the top border
+    title: Option<Line<'a>>,
+    /// The content of the popup
+    body: Text<'a>,
+    /// The labels of the buttons
+    buttons: Vec<Line<'a>>,
+    /// Block surrounding the popup
+    block: Block<'a>,
+    /// Shadow rendered behind the popup
+    shadow: Option<Shadow>,
+    /// Style used as a base style for the popup
+    style: Style,
+    /// Style of the buttons
+    button_style: Style,
+    /// Style of the selected button
+    selected_button_style: Style,
+    /// Alignment of the row of buttons
+    button_alignment: HorizontalAlignment,
+    /// Where the popup is displayed
+    position: PopupPosition,
+    /// Width of the popup, computed from the content when `None`
+    width: Option<u16>,
+    /// Height of the popup, computed from the content when `None`
+    height: Option<u16>,
+}
+
+/// Where a [`Popup`] is displayed, relative to the area it is rendered in
+///
+/// In every case, the popup is then moved to stay inside the buffer.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PopupPosition {
+    /// Centered in the area
+    #[default]
+    Center,
+    /// Below and to the right of a position, such as the cursor or the position of a mouse click
+    ///
+    /// The position is in the coordinates of the buffer, not relative to the area.
+    At(Position),
+    /// Docked to the top edge of the area, horizontally centered
+    Top,
+    /// Docked to the bottom edge of the area, horizontally centered
+    Bottom,
+    /// Docked to the left edge of the area, vertically centered
+    Left,
+    /// Docked to the right edge of the area, vertically centered
+    Right,
+}
+
+impl Default for Popup<'_> {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl<'a> Popup<'a> {
+    /// Creates a new popup with the given body
+    ///
+    /// `body` accepts any type that is convertible to [`Text`] (e.g. `&str`, `String`, [`Line`]
+    /// or [`Text`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Popup;
+    ///
+    /// let popup = Popup::new("File saved").title("Info");
+    /// ```
+    pub fn new<T: Into<Text<'a>>>(body: T) -> Self {
+        Self {
+            title: None,
+            body: body.into(),
+            buttons: Vec::new(),
+            block: Block::bordered().padding(Padding::horizontal(1)),
+            shadow: None,
+            style: Style::new(),
+            button_style: Style::new(),
+            selected_button_style: DEFAULT_SELECTED_BUTTON_STYLE,
+            button_alignment: HorizontalAlignment::Right,
+            position: PopupPosition::Center,
+            width: None,
+            height: None,
+        }
+    }
+
+    /// Sets the title displayed on the top border of the popup
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn title<T: Into<Line<'a>>>(mut self, title: T) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the content of the popup
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn body<T: Into<Text<'a>>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Sets the labels of the buttons displayed at the bottom of the popup
+    ///
+    /// The buttons are displayed in a single row, each label surrounded by brackets, e.g.
+    /// `[ OK ]`. The selected button is stored in the [`PopupState`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn buttons<I>(mut self, buttons: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Line<'a>>,
+    {
+        self.buttons = buttons.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets where the popup is displayed, [`PopupPosition::Center`] by default
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn position(mut self, position: PopupPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the width of the popup, including its block, instead of computing it from the content
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets the height of the popup, including its block, instead of computing it from the
+    /// content
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn height(mut self, height: u16) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets the [`Block`] surrounding the popup
+    ///
+    /// The default is a bordered block with one column of horizontal padding. The
+    /// [title](Popup::title) of the popup is added to the titles of the block.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Adds a [`Shadow`] behind the popup
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Sets the base style of the popup
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the buttons
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn button_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.button_style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected button, on top of the button style
+    ///
+    /// By default, the selected button is reversed.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selected_button_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selected_button_style = style.into();
+        self
+    }
+
+    /// Sets the styles of the popup from a [`Theme`]
+    ///
+    /// The style, button style and selected button style are replaced by the `popup`,
+    /// `popup.button` and `popup.button.selected` styles of the theme. The selected button style
+    /// is patched on top of the default reversed style.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style("popup");
+        self.button_style = theme.style("popup.button");
+        self.selected_button_style =
+            DEFAULT_SELECTED_BUTTON_STYLE.patch(theme.style("popup.button.selected"));
+        self
+    }
+
+    /// Sets the alignment of the row of buttons, [`HorizontalAlignment::Right`] by default
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn button_alignment(mut self, alignment: HorizontalAlignment) -> Self {
+        self.button_alignment = alignment;
+        self
+    }
+
+    /// Returns the area of the popup when it is rendered in `area` of a buffer whose area is
+    /// `bounds`
+    ///
+    /// This can be used to find out whether a mouse click is inside the popup.
+    pub fn area(&self, area: Rect, bounds: Rect) -> Rect {
+        let block = self.popup_block();
+        let max = Rect::new(0, 0, u16::MAX, u16::MAX);
+        let inner = block.inner(max);
+        let width = self.width.unwrap_or_else(|| {
+            let title_width = self.title.as_ref().map_or(0, Line::width);
+            let content_width = self.body.width().max(self.buttons_width());
+            (content_width as u16)
+                .saturating_add(max.width - inner.width)
+                // leave room for the corners around the title
+                .max((title_width as u16).saturating_add(2))
+        });
+        let height = self.height.unwrap_or_else(|| {
+            let content_height = match (self.body.height(), self.buttons.is_empty()) {
+                (lines, true) => lines,
+                (0, false) => 1,
+                (lines, false) => lines + 2,
+            };
+            (content_height as u16).saturating_add(max.height - inner.height)
+        });
+        let center_x = area.x + area.width.saturating_sub(width) / 2;
+        let center_y = area.y + area.height.saturating_sub(height) / 2;
+        let (x, y) = match self.position {
+            PopupPosition::Center => (center_x, center_y),
+            PopupPosition::At(position) => (position.x, position.y),
+            PopupPosition::Top => (center_x, area.y),
+            PopupPosition::Bottom => (center_x, area.bottom().saturating_sub(height)),
+            PopupPosition::Left => (area.x, center_y),
+            PopupPosition::Right => (area.right().saturating_sub(width), center_y),
+        };
+        Rect::new(x, y, width, height).clamp(bounds)
+    }
+
+    /// Returns the block with the title and the shadow of the popup.
+    fn popup_block(&self) -> Block<'a> {
+        let mut block = self.block.clone();
+        if let Some(title) = &self.title {
+            block = block.title(title.clone());
+        }
+        if let Some(shadow) = &self.shadow {
+            block = block.shadow(shadow.clone());
+        }
+        block
+    }
+
+    /// The width of the row of buttons.
+    fn buttons_width(&self) -> usize {
+        let labels: usize = self.buttons.iter().map(|button| button.width() + 4).sum();
+        labels + 2 * self.buttons.len().saturating_sub(1)
+    }
+
+    fn render_buttons(&self, area: Rect, buf: &mut Buffer, selected: usize) {
+        let width = self.buttons_width() as u16;
+        let mut x = match self.button_alignment {
+            HorizontalAlignment::Left => area.x,
+            HorizontalAlignment::Center => area.x + area.width.saturating_sub(width) / 2,
+            HorizontalAlignment::Right => area.x + area.width.saturating_sub(width),
+        };
+        for (index, button) in self.buttons.iter().enumerate() {
+            let mut style = self.button_style;
+            if index == selected {
+                style = style.patch(self.selected_button_style);
+            }
+            let button_width = button.width() as u16 + 4;
+            let button_area = Rect::new(x, area.y, button_width, 1).intersection(area);
+            buf.set_style(button_area, style);
+            for (symbol, x) in [("[", x), ("]", x.saturating_add(button_width - 1))] {
+                if button_area.contains(Position::new(x, area.y)) {
+                    buf[(x, area.y)].set_symbol(symbol);
+                }
+            }
+            let label_x = x.saturating_add(2);
+            buf.set_line(
+                label_x,
+                area.y,
+                button,
+                button_area.right().saturating_sub(label_x + 2),
+            );
+            x = x.saturating_add(button_width + 2);
+        }
+    }
+}
+
+impl Widget for Popup<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Popup<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = PopupState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for Popup<'_> {
+    type State = PopupState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &Popup<'_> {
+    type State = PopupState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.button_count = self.buttons.len();
+        state.selected = state.selected.min(self.buttons.len().saturating_sub(1));
+        let popup_area = self.area(area, buf.area);
+        state.area = popup_area;
+        if popup_area.is_empty() {
+            return;
+        }
+        Clear.render(popup_area, buf);
+        buf.set_style(popup_area, self.style);
+        let block = self.popup_block();
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+        if self.buttons.is_empty() {
+            (&self.body).render(inner, buf);
+            return;
+        }
+        let body_area = Rect {
+            height: inner.height.saturating_sub(2),
+            ..inner
+        };
+        (&self.body).render(body_area, buf);
+        let buttons_area = Rect {
+            y: inner.bottom().saturating_sub(1),
+            height: inner.height.min(1),
+            ..inner
+        };
+        self.render_buttons(buttons_area, buf, state.selected);
+    }
+}
+
+impl Styled for Popup<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Stylize;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn render(popup: &Popup, state: &mut PopupState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        StatefulWidget::render(popup, buf.area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn sizes_to_content() {
+        let popup = Popup::new("Hello\nworld!").title("Greeting");
+        let buf = render(&popup, &mut PopupState::default(), 14, 6);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "              ",
+                "  ┌Greeting┐  ",
+                "  │ Hello  │  ",
+                "  │ world! │  ",
+                "  └────────┘  ",
+                "              ",
+            ])
+        );
+    }
+
+    #[test]
+    fn buttons() {
+        let popup = Popup::new("Quit?")
+            .buttons(["Yes", "No"])
+            .selected_button_style(Style::new().bold());
+        let mut state = PopupState::default().with_selected(1);
+        let buf = render(&popup, &mut state, 20, 5);
+        let mut expected = Buffer::with_lines([
+            "┌─────────────────┐ ",
+            "│ Quit?           │ ",
+            "│                 │ ",
+            "│ [ Yes ]  [ No ] │ ",
+            "└─────────────────┘ ",
+        ]);
+        expected.set_style(Rect::new(11, 3, 6, 1), Style::new().bold());
+        assert_eq!(buf, expected);
+        assert_eq!(state.area(), Rect::new(0, 0, 19, 5));
+    }
+
+    #[test]
+    fn clears_the_area() {
+        let popup = Popup::new("Hi").block(Block::new());
+        let mut buf = Buffer::with_lines(["xxxx", "xxxx", "xxxx"]);
+        Widget::render(&popup, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["xxxx", "xHix", "xxxx"]));
+    }
+
+    #[rstest]
+    #[case::center(PopupPosition::Center, Rect::new(3, 2, 4, 3))]
+    #[case::at(PopupPosition::At(Position::new(1, 1)), Rect::new(1, 1, 4, 3))]
+    #[case::at_clamped(PopupPosition::At(Position::new(9, 6)), Rect::new(6, 4, 4, 3))]
+    #[case::top(PopupPosition::Top, Rect::new(3, 0, 4, 3))]
+    #[case::bottom(PopupPosition::Bottom, Rect::new(3, 4, 4, 3))]
+    #[case::left(PopupPosition::Left, Rect::new(0, 2, 4, 3))]
+    #[case::right(PopupPosition::Right, Rect::new(6, 2, 4, 3))]
+    fn positions(#[case] position: PopupPosition, #[case] expected: Rect) {
+        let popup = Popup::new("ab").block(Block::bordered()).position(position);
+        let area = Rect::new(0, 0, 10, 7);
+        assert_eq!(popup.area(area, area), expected);
+    }
+
+    #[test]
+    fn anchored_to_area() {
+        let popup = Popup::new("ab").block(Block::bordered());
+        let bounds = Rect::new(0, 0, 20, 10);
+        assert_eq!(
+            popup.area(Rect::new(10, 5, 6, 3), bounds),
+            Rect::new(11, 5, 4, 3)
+        );
+    }
+
+    #[test]
+    fn fixed_size() {
+        let popup = Popup::new("ab").width(8).height(4);
+        let area = Rect::new(0, 0, 10, 6);
+        assert_eq!(popup.area(area, area), Rect::new(1, 1, 8, 4));
+    }
+
+    #[test]
+    fn shadow() {
+        let popup = Popup::new("")
+            .block(Block::bordered())
+            .shadow(Shadow::block());
+        let buf = render(&popup, &mut PopupState::default(), 4, 4);
+        assert_eq!(buf, Buffer::with_lines([" ┌┐ ", " ││█", " └┘█", "  ██"]));
+    }
+
+    #[test]
+    fn can_be_stylized() {
+        assert_eq!(
+            Popup::default().red().on_black().style,
+            Style::new().red().on_black()
+        );
+    }
+}
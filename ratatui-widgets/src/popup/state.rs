@@ -0,0 +1,178 @@
+use ratatui_core::input::{KeyCode, KeyEvent};
+use ratatui_core::layout::Rect;
+
+/// State of the [`Popup`] widget
+///
+/// This state stores the selected button of the popup and the button that was last pressed.
+/// A button is pressed with [`PopupState::press`], or with `Enter` or `Space` in
+/// [`PopupState::handle_key`], and the application takes the index of the pressed button with
+/// [`PopupState::take_pressed`].
+///
+/// When the popup is rendered, the state remembers the number of buttons, so that the selection
+/// can wrap around, and the area of the popup.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::input::{KeyCode, KeyEvent};
+/// use ratatui::widgets::{Popup, PopupState};
+///
+/// # fn ui(frame: &mut Frame) {
+/// let popup = Popup::new("Delete the file?").buttons(["Delete", "Cancel"]);
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = PopupState::default().with_selected(1);
+///
+/// frame.render_stateful_widget(popup, frame.area(), &mut state);
+///
+/// state.handle_key(KeyEvent::from(KeyCode::Enter));
+/// assert_eq!(state.take_pressed(), Some(1));
+/// # }
+/// ```
+///
+/// [`Popup`]: super::Popup
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PopupState {
+    pub(crate) selected: usize,
+    pub(crate) pressed: Option<usize>,
+    /// The number of buttons in the last render
+    pub(crate) button_count: usize,
+    /// The area of the popup in the last render
+    pub(crate) area: Rect,
+}
+
+impl PopupState {
+    /// Sets the index of the selected button
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_selected(mut self, selected: usize) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Index of the selected button
+    pub const fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Selects a button by its index
+    ///
+    /// If the index is out of bounds, the last button is selected when the popup is rendered.
+    pub const fn select(&mut self, index: usize) {
+        self.selected = index;
+    }
+
+    /// Selects the next button, wrapping around to the first one
+    pub const fn select_next(&mut self) {
+        if self.button_count > 0 {
+            self.selected = (self.selected + 1) % self.button_count;
+        }
+    }
+
+    /// Selects the previous button, wrapping around to the last one
+    pub const fn select_previous(&mut self) {
+        if self.button_count > 0 {
+            self.selected = (self.selected + self.button_count - 1) % self.button_count;
+        }
+    }
+
+    /// Presses the selected button
+    ///
+    /// The index of the button can then be taken with [`PopupState::take_pressed`].
+    pub const fn press(&mut self) {
+        if self.selected < self.button_count {
+            self.pressed = Some(self.selected);
+        }
+    }
+
+    /// Takes the index of the last pressed button, if any
+    pub const fn take_pressed(&mut self) -> Option<usize> {
+        self.pressed.take()
+    }
+
+    /// The area of the popup in the last render
+    ///
+    /// This can be used to find out whether a mouse click is inside the popup.
+    pub const fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Handles a key press, returning `true` if the key was handled.
+    ///
+    /// | Key | Action |
+    /// |-----|--------|
+    /// | `Left`, `BackTab` / `Right`, `Tab` | select the [previous](Self::select_previous) / [next](Self::select_next) button |
+    /// | `Home` / `End` | select the first / last button |
+    /// | `Enter`, `Space` | [press](Self::press) the selected button |
+    ///
+    /// Other keys, such as `Esc`, are left for the application to handle.
+    pub const fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if self.button_count == 0 {
+            return false;
+        }
+        match key.code {
+            KeyCode::Left | KeyCode::BackTab => self.select_previous(),
+            KeyCode::Right | KeyCode::Tab => self.select_next(),
+            KeyCode::Home => self.selected = 0,
+            KeyCode::End => self.selected = self.button_count - 1,
+            KeyCode::Enter | KeyCode::Char(' ') => self.press(),
+            _ => return false,
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn state(selected: usize) -> PopupState {
+        PopupState {
+            button_count: 3,
+            ..PopupState::default().with_selected(selected)
+        }
+    }
+
+    #[test]
+    fn select() {
+        let mut state = PopupState::default();
+        state.select(2);
+        assert_eq!(state.selected(), 2);
+    }
+
+    #[rstest]
+    #[case::next(0, KeyCode::Right, 1)]
+    #[case::next_wraps(2, KeyCode::Tab, 0)]
+    #[case::previous(1, KeyCode::Left, 0)]
+    #[case::previous_wraps(0, KeyCode::BackTab, 2)]
+    #[case::home(2, KeyCode::Home, 0)]
+    #[case::end(0, KeyCode::End, 2)]
+    fn handle_key(#[case] selected: usize, #[case] code: KeyCode, #[case] expected: usize) {
+        let mut state = state(selected);
+        assert!(state.handle_key(KeyEvent::from(code)));
+        assert_eq!(state.selected(), expected);
+        assert_eq!(state.take_pressed(), None);
+    }
+
+    #[test]
+    fn press() {
+        let mut state = state(1);
+        assert!(state.handle_key(KeyEvent::from(KeyCode::Enter)));
+        assert_eq!(state.take_pressed(), Some(1));
+        assert_eq!(state.take_pressed(), None);
+        assert!(!state.handle_key(KeyEvent::from(KeyCode::Esc)));
+    }
+
+    #[test]
+    fn without_buttons() {
+        let mut state = PopupState::default();
+        assert!(!state.handle_key(KeyEvent::from(KeyCode::Enter)));
+        state.press();
+        assert_eq!(state.take_pressed(), None);
+    }
+}
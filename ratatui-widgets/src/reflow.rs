@@ -6,9 +6,12 @@ use core::mem;
 
 use ratatui_core::buffer::CellWidth;
 use ratatui_core::layout::Alignment;
-use ratatui_core::text::StyledGrapheme;
+use ratatui_core::style::Style;
+use ratatui_core::text::{StyledGrapheme, TextDirection};
 use unicode_segmentation::UnicodeSegmentation;
 
+use crate::paragraph::WrapIndent;
+
 /// A state machine to pack styled symbols into lines.
 /// Cannot implement it as Iterator since it yields slices of the internal buffer (need streaming
 /// iterators for that).
@@ -24,6 +27,51 @@ pub struct WrappedLine<'lend, 'text> {
     pub width: u16,
     /// Whether the line was aligned left or right
     pub alignment: Alignment,
+    /// The base direction of the line that was wrapped
+    pub direction: TextDirection,
+}
+
+/// The indentation and marker prepended to the continuation lines of a wrapped line.
+#[derive(Debug, Default, Clone)]
+pub struct Continuation<'a> {
+    /// How continuation lines are indented
+    pub indent: WrapIndent,
+    /// Symbols drawn at the start of every continuation line, after the indentation
+    pub marker: Vec<StyledGrapheme<'a>>,
+}
+
+impl<'a> Continuation<'a> {
+    /// Fills `prefix` with the symbols to prepend to continuation lines and returns their width.
+    ///
+    /// `leading_whitespace` is the leading whitespace of the input line, which is repeated when the
+    /// indentation is preserved. The prefix is dropped entirely when it would take up more than
+    /// half of `max_line_width`, so that there is always room left for the wrapped content.
+    fn build_prefix(
+        &self,
+        leading_whitespace: &[StyledGrapheme<'a>],
+        max_line_width: u16,
+        prefix: &mut Vec<StyledGrapheme<'a>>,
+    ) -> u16 {
+        prefix.clear();
+        match self.indent {
+            WrapIndent::None => {}
+            WrapIndent::Preserve => prefix.extend_from_slice(leading_whitespace),
+            WrapIndent::Hanging(width) => {
+                prefix.extend((0..width).map(|_| StyledGrapheme::new(" ", Style::default())));
+            }
+        }
+        prefix.extend_from_slice(&self.marker);
+
+        let width = prefix
+            .iter()
+            .map(|grapheme| grapheme.symbol.cell_width())
+            .fold(0, u16::saturating_add);
+        if width > max_line_width / 2 {
+            prefix.clear();
+            return 0;
+        }
+        width
+    }
 }
 
 /// A state machine that wraps lines on word boundaries.
@@ -31,9 +79,9 @@ pub struct WrappedLine<'lend, 'text> {
 pub struct WordWrapper<'a, O, I>
 where
     // Outer iterator providing the individual lines
-    O: Iterator<Item = (I, Alignment)>,
-    // Inner iterator providing the styled symbols of a line Each line consists of an alignment and
-    // a series of symbols
+    O: Iterator<Item = (I, Alignment, TextDirection)>,
+    // Inner iterator providing the styled symbols of a line Each line consists of an alignment, a
+    // direction and a series of symbols
     I: Iterator<Item = StyledGrapheme<'a>>,
 {
     /// The given, unprocessed lines
@@ -41,19 +89,24 @@ where
     max_line_width: u16,
     wrapped_lines: VecDeque<Vec<StyledGrapheme<'a>>>,
     current_alignment: Alignment,
+    current_direction: TextDirection,
     current_line: Vec<StyledGrapheme<'a>>,
     /// Removes the leading whitespace from lines
     trim: bool,
+    /// Prepended to the continuation lines of a wrapped line
+    continuation: Continuation<'a>,
 
     // These are cached allocations that hold no state across next_line invocations
     pending_word: Vec<StyledGrapheme<'a>>,
     pending_whitespace: VecDeque<StyledGrapheme<'a>>,
     pending_line_pool: Vec<Vec<StyledGrapheme<'a>>>,
+    leading_whitespace: Vec<StyledGrapheme<'a>>,
+    prefix: Vec<StyledGrapheme<'a>>,
 }
 
 impl<'a, O, I> WordWrapper<'a, O, I>
 where
-    O: Iterator<Item = (I, Alignment)>,
+    O: Iterator<Item = (I, Alignment, TextDirection)>,
     I: Iterator<Item = StyledGrapheme<'a>>,
 {
     /// Create a new `WordWrapper` with the given lines and maximum line width.
@@ -63,15 +116,29 @@ where
             max_line_width,
             wrapped_lines: VecDeque::new(),
             current_alignment: Alignment::Left,
+            current_direction: TextDirection::LeftToRight,
             current_line: vec![],
             trim,
+            continuation: Continuation {
+                indent: WrapIndent::None,
+                marker: Vec::new(),
+            },
 
             pending_word: Vec::new(),
             pending_line_pool: Vec::new(),
             pending_whitespace: VecDeque::new(),
+            leading_whitespace: Vec::new(),
+            prefix: Vec::new(),
         }
     }
 
+    /// Set the indentation and marker prepended to the continuation lines of wrapped lines.
+    #[must_use]
+    pub fn continuation(mut self, continuation: Continuation<'a>) -> Self {
+        self.continuation = continuation;
+        self
+    }
+
     /// Split an input line (`line_symbols`) into wrapped lines
     /// and cache them to be emitted later
     fn process_input(&mut self, line_symbols: impl IntoIterator<Item = StyledGrapheme<'a>>) {
@@ -80,37 +147,48 @@ where
         let mut word_width = 0;
         let mut whitespace_width = 0;
         let mut non_whitespace_previous = false;
+        // the continuation prefix is computed when the line wraps for the first time
+        let mut prefix_width = 0;
+        let mut prefix_len = 0;
+        let mut wrapped = false;
+        let mut leading = true;
 
         self.pending_word.clear();
         self.pending_whitespace.clear();
+        self.leading_whitespace.clear();
         pending_line.clear();
 
         for grapheme in line_symbols {
             let is_whitespace = grapheme.is_whitespace();
             let symbol_width = grapheme.symbol.cell_width();
+            let available_width = self.max_line_width - prefix_width;
+
+            leading &= is_whitespace;
+            if leading {
+                self.leading_whitespace.push(grapheme.clone());
+            }
 
             // ignore symbols wider than line limit
-            if symbol_width > self.max_line_width {
+            if symbol_width > available_width {
                 continue;
             }
 
+            let line_start = pending_line.len() == prefix_len;
             let word_found = non_whitespace_previous && is_whitespace;
             // current word would overflow after removing whitespace
-            let trimmed_overflow = pending_line.is_empty()
-                && self.trim
-                && word_width + symbol_width > self.max_line_width;
+            let trimmed_overflow =
+                line_start && self.trim && word_width + symbol_width > available_width;
             // separated whitespace would overflow on its own
-            let whitespace_overflow = pending_line.is_empty()
-                && self.trim
-                && whitespace_width + symbol_width > self.max_line_width;
+            let whitespace_overflow =
+                line_start && self.trim && whitespace_width + symbol_width > available_width;
             // current full word (including whitespace) would overflow
-            let untrimmed_overflow = pending_line.is_empty()
+            let untrimmed_overflow = line_start
                 && !self.trim
-                && word_width + whitespace_width + symbol_width > self.max_line_width;
+                && word_width + whitespace_width + symbol_width > available_width;
 
             // append finished segment to current line
             if word_found || trimmed_overflow || whitespace_overflow || untrimmed_overflow {
-                if !pending_line.is_empty() || !self.trim {
+                if !line_start || !self.trim {
                     pending_line.extend(self.pending_whitespace.drain(..));
                     line_width += whitespace_width;
                 }
@@ -134,7 +212,15 @@ where
                 let mut remaining_width = u16::saturating_sub(self.max_line_width, line_width);
 
                 self.wrapped_lines.push_back(mem::take(&mut pending_line));
-                line_width = 0;
+
+                // start the continuation line with the prefix
+                if !wrapped {
+                    wrapped = true;
+                    prefix_width = self.build_prefix();
+                    prefix_len = self.prefix.len();
+                }
+                pending_line.extend_from_slice(&self.prefix);
+                line_width = prefix_width;
 
                 // remove whitespace up to the end of line
                 while let Some(grapheme) = self.pending_whitespace.front() {
@@ -168,20 +254,21 @@ where
         }
 
         // append remaining text parts
-        if pending_line.is_empty()
+        let line_start = pending_line.len() == prefix_len;
+        if line_start
             && self.pending_word.is_empty()
             && !self.pending_whitespace.is_empty()
             && self.trim
         {
             self.wrapped_lines.push_back(vec![]);
         }
-        if !pending_line.is_empty() || !self.trim {
+        if !line_start || !self.trim {
             pending_line.extend(self.pending_whitespace.drain(..));
         }
         pending_line.append(&mut self.pending_word);
 
         #[expect(clippy::else_if_without_else)]
-        if !pending_line.is_empty() {
+        if pending_line.len() > prefix_len {
             self.wrapped_lines.push_back(pending_line);
         } else if pending_line.capacity() > 0 {
             self.pending_line_pool.push(pending_line);
@@ -191,6 +278,16 @@ where
         }
     }
 
+    /// Builds the continuation prefix from the leading whitespace of the current input line and
+    /// returns its width.
+    fn build_prefix(&mut self) -> u16 {
+        self.continuation.build_prefix(
+            &self.leading_whitespace,
+            self.max_line_width,
+            &mut self.prefix,
+        )
+    }
+
     fn replace_current_line(&mut self, line: Vec<StyledGrapheme<'a>>) {
         let cache = mem::replace(&mut self.current_line, line);
         if cache.capacity() > 0 {
@@ -201,7 +298,7 @@ where
 
 impl<'a, O, I> LineComposer<'a> for WordWrapper<'a, O, I>
 where
-    O: Iterator<Item = (I, Alignment)>,
+    O: Iterator<Item = (I, Alignment, TextDirection)>,
     I: Iterator<Item = StyledGrapheme<'a>>,
 {
     fn next_line<'lend>(&'lend mut self) -> Option<WrappedLine<'lend, 'a>> {
@@ -222,12 +319,199 @@ where
                     graphemes: &self.current_line,
                     width: line_width,
                     alignment: self.current_alignment,
+                    direction: self.current_direction,
                 });
             }
 
             // otherwise, process pending wrapped lines from input
-            let (line_symbols, line_alignment) = self.input_lines.next()?;
+            let (line_symbols, line_alignment, line_direction) = self.input_lines.next()?;
             self.current_alignment = line_alignment;
+            self.current_direction = line_direction;
+            self.process_input(line_symbols);
+        }
+    }
+}
+
+/// A state machine that wraps lines at grapheme boundaries, regardless of words.
+///
+/// This is useful for text that has no whitespace to break on, such as CJK text, long URLs or
+/// hashes.
+#[derive(Debug, Default, Clone)]
+pub struct CharWrapper<'a, O, I>
+where
+    // Outer iterator providing the individual lines
+    O: Iterator<Item = (I, Alignment, TextDirection)>,
+    // Inner iterator providing the styled symbols of a line Each line consists of an alignment, a
+    // direction and a series of symbols
+    I: Iterator<Item = StyledGrapheme<'a>>,
+{
+    /// The given, unprocessed lines
+    input_lines: O,
+    max_line_width: u16,
+    wrapped_lines: VecDeque<Vec<StyledGrapheme<'a>>>,
+    current_alignment: Alignment,
+    current_direction: TextDirection,
+    current_line: Vec<StyledGrapheme<'a>>,
+    /// Removes the leading whitespace from lines
+    trim: bool,
+    /// Prepended to the continuation lines of a wrapped line
+    continuation: Continuation<'a>,
+
+    // These are cached allocations that hold no state across next_line invocations
+    pending_line_pool: Vec<Vec<StyledGrapheme<'a>>>,
+    leading_whitespace: Vec<StyledGrapheme<'a>>,
+    prefix: Vec<StyledGrapheme<'a>>,
+}
+
+impl<'a, O, I> CharWrapper<'a, O, I>
+where
+    O: Iterator<Item = (I, Alignment, TextDirection)>,
+    I: Iterator<Item = StyledGrapheme<'a>>,
+{
+    /// Create a new `CharWrapper` with the given lines and maximum line width.
+    pub const fn new(lines: O, max_line_width: u16, trim: bool) -> Self {
+        Self {
+            input_lines: lines,
+            max_line_width,
+            wrapped_lines: VecDeque::new(),
+            current_alignment: Alignment::Left,
+            current_direction: TextDirection::LeftToRight,
+            current_line: vec![],
+            trim,
+            continuation: Continuation {
+                indent: WrapIndent::None,
+                marker: Vec::new(),
+            },
+
+            pending_line_pool: Vec::new(),
+            leading_whitespace: Vec::new(),
+            prefix: Vec::new(),
+        }
+    }
+
+    /// Set the indentation and marker prepended to the continuation lines of wrapped lines.
+    #[must_use]
+    pub fn continuation(mut self, continuation: Continuation<'a>) -> Self {
+        self.continuation = continuation;
+        self
+    }
+
+    /// Split an input line (`line_symbols`) into wrapped lines
+    /// and cache them to be emitted later
+    fn process_input(&mut self, line_symbols: impl IntoIterator<Item = StyledGrapheme<'a>>) {
+        let mut pending_line = self.pending_line_pool.pop().unwrap_or_default();
+        let mut line_width = 0;
+        // the continuation prefix is computed when the line wraps for the first time
+        let mut prefix_width = 0;
+        let mut prefix_len = 0;
+        let mut wrapped = false;
+        let mut leading = true;
+
+        self.leading_whitespace.clear();
+        pending_line.clear();
+
+        for grapheme in line_symbols {
+            let is_whitespace = grapheme.is_whitespace();
+            let symbol_width = grapheme.symbol.cell_width();
+
+            leading &= is_whitespace;
+            if leading {
+                self.leading_whitespace.push(grapheme.clone());
+            }
+
+            // ignore symbols wider than line limit
+            if symbol_width > self.max_line_width - prefix_width {
+                continue;
+            }
+
+            // add finished wrapped line to remaining lines
+            if line_width + symbol_width > self.max_line_width {
+                self.wrapped_lines.push_back(mem::take(&mut pending_line));
+
+                // start the continuation line with the prefix
+                if !wrapped {
+                    wrapped = true;
+                    prefix_width = self.build_prefix();
+                    prefix_len = self.prefix.len();
+                }
+                pending_line.extend_from_slice(&self.prefix);
+                line_width = prefix_width;
+
+                // ignore symbols wider than the continuation line limit
+                if symbol_width > self.max_line_width - prefix_width {
+                    continue;
+                }
+            }
+
+            // remove whitespace at the start of a line
+            if self.trim && is_whitespace && pending_line.len() == prefix_len {
+                continue;
+            }
+
+            line_width += symbol_width;
+            pending_line.push(grapheme);
+        }
+
+        #[expect(clippy::else_if_without_else)]
+        if pending_line.len() > prefix_len {
+            self.wrapped_lines.push_back(pending_line);
+        } else if pending_line.capacity() > 0 {
+            self.pending_line_pool.push(pending_line);
+        }
+        if self.wrapped_lines.is_empty() {
+            self.wrapped_lines.push_back(vec![]);
+        }
+    }
+
+    /// Builds the continuation prefix from the leading whitespace of the current input line and
+    /// returns its width.
+    fn build_prefix(&mut self) -> u16 {
+        self.continuation.build_prefix(
+            &self.leading_whitespace,
+            self.max_line_width,
+            &mut self.prefix,
+        )
+    }
+
+    fn replace_current_line(&mut self, line: Vec<StyledGrapheme<'a>>) {
+        let cache = mem::replace(&mut self.current_line, line);
+        if cache.capacity() > 0 {
+            self.pending_line_pool.push(cache);
+        }
+    }
+}
+
+impl<'a, O, I> LineComposer<'a> for CharWrapper<'a, O, I>
+where
+    O: Iterator<Item = (I, Alignment, TextDirection)>,
+    I: Iterator<Item = StyledGrapheme<'a>>,
+{
+    fn next_line<'lend>(&'lend mut self) -> Option<WrappedLine<'lend, 'a>> {
+        if self.max_line_width == 0 {
+            return None;
+        }
+
+        loop {
+            // emit next cached line if present
+            if let Some(line) = self.wrapped_lines.pop_front() {
+                let line_width = line
+                    .iter()
+                    .map(|grapheme| grapheme.symbol.cell_width())
+                    .sum();
+
+                self.replace_current_line(line);
+                return Some(WrappedLine {
+                    graphemes: &self.current_line,
+                    width: line_width,
+                    alignment: self.current_alignment,
+                    direction: self.current_direction,
+                });
+            }
+
+            // otherwise, process pending wrapped lines from input
+            let (line_symbols, line_alignment, line_direction) = self.input_lines.next()?;
+            self.current_alignment = line_alignment;
+            self.current_direction = line_direction;
             self.process_input(line_symbols);
         }
     }
@@ -238,9 +522,9 @@ where
 pub struct LineTruncator<'a, O, I>
 where
     // Outer iterator providing the individual lines
-    O: Iterator<Item = (I, Alignment)>,
-    // Inner iterator providing the styled symbols of a line Each line consists of an alignment and
-    // a series of symbols
+    O: Iterator<Item = (I, Alignment, TextDirection)>,
+    // Inner iterator providing the styled symbols of a line Each line consists of an alignment, a
+    // direction and a series of symbols
     I: Iterator<Item = StyledGrapheme<'a>>,
 {
     /// The given, unprocessed lines
@@ -253,7 +537,7 @@ where
 
 impl<'a, O, I> LineTruncator<'a, O, I>
 where
-    O: Iterator<Item = (I, Alignment)>,
+    O: Iterator<Item = (I, Alignment, TextDirection)>,
     I: Iterator<Item = StyledGrapheme<'a>>,
 {
     /// Create a new `LineTruncator` with the given lines and maximum line width.
@@ -274,7 +558,7 @@ where
 
 impl<'a, O, I> LineComposer<'a> for LineTruncator<'a, O, I>
 where
-    O: Iterator<Item = (I, Alignment)>,
+    O: Iterator<Item = (I, Alignment, TextDirection)>,
     I: Iterator<Item = StyledGrapheme<'a>>,
 {
     fn next_line<'lend>(&'lend mut self) -> Option<WrappedLine<'lend, 'a>> {
@@ -288,9 +572,11 @@ where
         let mut lines_exhausted = true;
         let mut horizontal_offset = self.horizontal_offset;
         let mut current_alignment = Alignment::Left;
-        if let Some((current_line, alignment)) = &mut self.input_lines.next() {
+        let mut current_direction = TextDirection::LeftToRight;
+        if let Some((current_line, alignment, direction)) = &mut self.input_lines.next() {
             lines_exhausted = false;
             current_alignment = *alignment;
+            current_direction = *direction;
 
             for StyledGrapheme { symbol, style } in current_line {
                 // Ignore characters wider that the total max width.
@@ -328,6 +614,7 @@ where
                 graphemes: &self.current_line,
                 width: current_line_width,
                 alignment: current_alignment,
+                direction: current_direction,
             })
         }
     }
@@ -363,6 +650,7 @@ mod tests {
     #[derive(Clone, Copy)]
     enum Composer {
         WordWrapper { trim: bool },
+        CharWrapper { trim: bool },
         LineTruncator,
     }
 
@@ -370,6 +658,15 @@ mod tests {
         which: Composer,
         text: impl Into<Text<'a>>,
         text_area_width: u16,
+    ) -> (Vec<String>, Vec<u16>, Vec<Alignment>) {
+        run_composer_with_continuation(which, text, text_area_width, Continuation::default())
+    }
+
+    fn run_composer_with_continuation<'a>(
+        which: Composer,
+        text: impl Into<Text<'a>>,
+        text_area_width: u16,
+        continuation: Continuation<'a>,
     ) -> (Vec<String>, Vec<u16>, Vec<Alignment>) {
         let text = text.into();
         let styled_lines = text.iter().map(|line| {
@@ -377,13 +674,17 @@ mod tests {
                 line.iter()
                     .flat_map(|span| span.styled_graphemes(Style::default())),
                 line.alignment.unwrap_or(Alignment::Left),
+                line.direction(),
             )
         });
 
         let mut composer: Box<dyn LineComposer> = match which {
-            Composer::WordWrapper { trim } => {
-                Box::new(WordWrapper::new(styled_lines, text_area_width, trim))
-            }
+            Composer::WordWrapper { trim } => Box::new(
+                WordWrapper::new(styled_lines, text_area_width, trim).continuation(continuation),
+            ),
+            Composer::CharWrapper { trim } => Box::new(
+                CharWrapper::new(styled_lines, text_area_width, trim).continuation(continuation),
+            ),
             Composer::LineTruncator => Box::new(LineTruncator::new(styled_lines, text_area_width)),
         };
         let mut lines = vec![];
@@ -393,6 +694,7 @@ mod tests {
             graphemes,
             width,
             alignment,
+            ..
         }) = composer.next_line()
         {
             let line = graphemes
@@ -726,4 +1028,175 @@ mod tests {
         let (word_wrapper, _, _) = run_composer(Composer::WordWrapper { trim: true }, line, width);
         assert_eq!(word_wrapper, ["foo", "bar"]);
     }
+    #[test]
+    fn line_composer_char_wrapper() {
+        let width = 10;
+        let text = "Hello wonderful world";
+        let (char_wrapper, widths, _) =
+            run_composer(Composer::CharWrapper { trim: false }, text, width);
+        assert_eq!(char_wrapper, ["Hello wond", "erful worl", "d"]);
+        assert_eq!(widths, [10, 10, 1]);
+    }
+
+    #[test]
+    fn line_composer_char_wrapper_trim() {
+        let width = 5;
+        let text = "  abcd efgh";
+        let (trimmed, _, _) = run_composer(Composer::CharWrapper { trim: true }, text, width);
+        let (untrimmed, _, _) = run_composer(Composer::CharWrapper { trim: false }, text, width);
+        assert_eq!(trimmed, ["abcd ", "efgh"]);
+        assert_eq!(untrimmed, ["  abc", "d efg", "h"]);
+    }
+
+    #[test]
+    fn line_composer_char_wrapper_double_width_chars() {
+        let width = 5;
+        let text = "コンピュータ";
+        let (char_wrapper, widths, _) =
+            run_composer(Composer::CharWrapper { trim: true }, text, width);
+        assert_eq!(char_wrapper, ["コン", "ピュ", "ータ"]);
+        assert_eq!(widths, [4, 4, 4]);
+    }
+
+    #[test]
+    fn line_composer_char_wrapper_empty_lines() {
+        let width = 5;
+        let text = "abc\n\n   \ndef";
+        let (trimmed, _, _) = run_composer(Composer::CharWrapper { trim: true }, text, width);
+        let (untrimmed, _, _) = run_composer(Composer::CharWrapper { trim: false }, text, width);
+        assert_eq!(trimmed, ["abc", "", "", "def"]);
+        assert_eq!(untrimmed, ["abc", "", "   ", "def"]);
+    }
+
+    #[test]
+    fn line_composer_char_wrapper_zero_width() {
+        let (char_wrapper, _, _) = run_composer(Composer::CharWrapper { trim: true }, "abc", 0);
+        assert!(char_wrapper.is_empty());
+    }
+
+    #[test]
+    fn line_composer_continuation_hanging_indent() {
+        let width = 10;
+        let text = "- abcd efgh ijkl";
+        let continuation = Continuation {
+            indent: WrapIndent::Hanging(2),
+            marker: Vec::new(),
+        };
+        let (word_wrapper, widths, _) = run_composer_with_continuation(
+            Composer::WordWrapper { trim: true },
+            text,
+            width,
+            continuation.clone(),
+        );
+        assert_eq!(word_wrapper, ["- abcd", "  efgh", "  ijkl"]);
+        assert_eq!(widths, [6, 6, 6]);
+
+        let (char_wrapper, _, _) = run_composer_with_continuation(
+            Composer::CharWrapper { trim: true },
+            text,
+            width,
+            continuation,
+        );
+        assert_eq!(char_wrapper, ["- abcd efg", "  h ijkl"]);
+    }
+
+    #[test]
+    fn line_composer_continuation_preserve_indent() {
+        let width = 12;
+        let text = "    abcd efgh ijkl\nmnop qrst uvwx";
+        let continuation = Continuation {
+            indent: WrapIndent::Preserve,
+            marker: Vec::new(),
+        };
+        let (word_wrapper, _, _) = run_composer_with_continuation(
+            Composer::WordWrapper { trim: false },
+            text,
+            width,
+            continuation.clone(),
+        );
+        assert_eq!(
+            word_wrapper,
+            ["    abcd", "    efgh", "    ijkl", "mnop qrst", "uvwx"]
+        );
+
+        let (char_wrapper, _, _) = run_composer_with_continuation(
+            Composer::CharWrapper { trim: false },
+            text,
+            width,
+            continuation,
+        );
+        assert_eq!(
+            char_wrapper,
+            ["    abcd efg", "    h ijkl", "mnop qrst uv", "wx"]
+        );
+    }
+
+    #[test]
+    fn line_composer_continuation_marker() {
+        let width = 8;
+        let text = "abcd efgh ijkl";
+        let continuation = Continuation {
+            indent: WrapIndent::Hanging(1),
+            marker: vec![StyledGrapheme::new(">", Style::default())],
+        };
+        let (word_wrapper, widths, _) = run_composer_with_continuation(
+            Composer::WordWrapper { trim: true },
+            text,
+            width,
+            continuation.clone(),
+        );
+        assert_eq!(word_wrapper, ["abcd", " >efgh", " >ijkl"]);
+        assert_eq!(widths, [4, 6, 6]);
+
+        let (char_wrapper, _, _) = run_composer_with_continuation(
+            Composer::CharWrapper { trim: true },
+            text,
+            width,
+            continuation,
+        );
+        assert_eq!(char_wrapper, ["abcd efg", " >h ijkl"]);
+    }
+
+    #[test]
+    fn line_composer_continuation_too_wide_is_dropped() {
+        let width = 6;
+        let text = "abcdef ghijkl";
+        let continuation = Continuation {
+            indent: WrapIndent::Hanging(4),
+            marker: Vec::new(),
+        };
+        let (word_wrapper, _, _) = run_composer_with_continuation(
+            Composer::WordWrapper { trim: true },
+            text,
+            width,
+            continuation.clone(),
+        );
+        assert_eq!(word_wrapper, ["abcdef", "ghijkl"]);
+
+        let (char_wrapper, _, _) = run_composer_with_continuation(
+            Composer::CharWrapper { trim: true },
+            text,
+            width,
+            continuation,
+        );
+        assert_eq!(char_wrapper, ["abcdef", "ghijkl"]);
+    }
+
+    #[test]
+    fn line_composer_continuation_long_word() {
+        let width = 6;
+        let text = "abcdefghijklmn";
+        let continuation = Continuation {
+            indent: WrapIndent::Hanging(2),
+            marker: Vec::new(),
+        };
+        let (word_wrapper, widths, _) = run_composer_with_continuation(
+            Composer::WordWrapper { trim: true },
+            text,
+            width,
+            continuation,
+        );
+        assert_eq!(word_wrapper, ["abcdef", "  ghij", "  klmn"]);
+        assert_eq!(widths, [6, 6, 6]);
+    }
 }
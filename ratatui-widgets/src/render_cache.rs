@@ -0,0 +1,42 @@
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+
+/// What a stateful widget remembers of its last render, such as the positions of its items
+///
+/// This is a cache rather than part of the state: it is equal to any other cache and is neither
+/// hashed nor serialized, so a state compares equal to itself before and after a render, and
+/// after a serialization round trip.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct RenderCache<T>(T);
+
+impl<T> RenderCache<T> {
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> Deref for RenderCache<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for RenderCache<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T> PartialEq for RenderCache<T> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> Eq for RenderCache<T> {}
+
+impl<T> Hash for RenderCache<T> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
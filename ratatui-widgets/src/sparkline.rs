@@ -1,17 +1,22 @@
 //! The [`Sparkline`] widget is used to display a sparkline over one or more lines.
 
+use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
-use core::cmp::min;
+use core::cmp::{Ordering, min};
+use core::iter;
 
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::style::{Color, Modifier, Style, Styled, Theme};
 use ratatui_core::symbols;
+use ratatui_core::symbols::Marker;
+use ratatui_core::text::Line;
 use ratatui_core::widgets::Widget;
 use strum::{Display, EnumString};
 
 use crate::block::{Block, BlockExt};
+use crate::canvas::{Canvas, Painter, Shape};
 
 /// Widget to render a sparkline over one or more lines.
 ///
@@ -39,12 +44,19 @@ use crate::block::{Block, BlockExt};
 /// Absent values and will be rendered with the style set by [`Sparkline::absent_value_style`] and
 /// the symbol set by [`Sparkline::absent_value_symbol`].
 ///
+/// More series can be overlaid on the dataset with [`Sparkline::series`]. Negative values (see
+/// [`SparklineBar::signed`]) are drawn below a midline in the [signed](Sparkline::signed) mode.
+///
 /// # Setter methods
 ///
 /// - [`Sparkline::block`] wraps the sparkline in a [`Block`]
 /// - [`Sparkline::data`] defines the dataset, you'll almost always want to use it
+/// - [`Sparkline::series`] overlays another series on the dataset
 /// - [`Sparkline::max`] sets the maximum value of bars
 /// - [`Sparkline::direction`] sets the render direction
+/// - [`Sparkline::mode`] draws bars or a Braille line
+/// - [`Sparkline::signed`] draws the values above and below a midline
+/// - [`Sparkline::annotations`] prints the min, max or last value next to the sparkline
 ///
 /// # Examples
 ///
@@ -81,6 +93,16 @@ pub struct Sparkline<'a> {
     bar_set: symbols::bar::Set<'a>,
     /// The direction to render the sparkline, either from left to right, or from right to left
     direction: RenderDirection,
+    /// Additional series overlaid on the dataset
+    series: Vec<SparklineSeries>,
+    /// Whether the values are drawn as bars or as a line
+    mode: SparklineMode,
+    /// Whether the values are drawn above and below a midline
+    signed: bool,
+    /// The values printed next to the sparkline
+    annotations: Vec<SparklineAnnotation>,
+    /// Style of the annotations
+    annotation_style: Style,
 }
 
 /// Defines the direction in which sparkline will be rendered.
@@ -96,6 +118,35 @@ pub enum RenderDirection {
     RightToLeft,
 }
 
+/// Defines how the values of a sparkline are drawn.
+///
+/// See [`Sparkline::mode`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SparklineMode {
+    /// Each value is a bar drawn with the [bar set](Sparkline::bar_set) of the sparkline
+    #[default]
+    Bars,
+    /// The values are joined by a line drawn with [Braille](Marker::Braille) patterns, which fit
+    /// two values per cell and four dots per line
+    Line,
+}
+
+/// A value printed next to a sparkline.
+///
+/// The annotations are computed from the values of the [dataset](Sparkline::data), ignoring the
+/// absent values. See [`Sparkline::annotations`].
+#[derive(Debug, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SparklineAnnotation {
+    /// The smallest value, printed as `min <value>`
+    Min,
+    /// The largest value, printed as `max <value>`
+    Max,
+    /// The last value, printed as `last <value>`
+    Last,
+}
+
 impl<'a> Sparkline<'a> {
     /// Wraps the sparkline with the given `block`.
     #[must_use = "method moves the value of self and returns the modified value"]
@@ -243,6 +294,157 @@ impl<'a> Sparkline<'a> {
         self.direction = direction;
         self
     }
+
+    /// Overlays a series on the dataset of the sparkline.
+    ///
+    /// The series are drawn over the [dataset](Sparkline::data) in the order they are added, with
+    /// their own style patched over the style of the sparkline. In the [bars](SparklineMode::Bars)
+    /// mode, the shorter bars of a column are drawn in front of the taller ones so that every
+    /// series remains visible.
+    ///
+    /// A column is drawn with the [absent value symbol](Sparkline::absent_value_symbol) only when
+    /// the values of all the series are absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Stylize;
+    /// use ratatui::widgets::{Sparkline, SparklineSeries};
+    ///
+    /// Sparkline::default()
+    ///     .data([4, 6, 8, 7])
+    ///     .green()
+    ///     .series(SparklineSeries::new([1, 3, 2, 5]).red());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn series(mut self, series: SparklineSeries) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Sets how the values are drawn.
+    ///
+    /// [`SparklineMode::Bars`] by default. In the [`SparklineMode::Line`] mode, absent values
+    /// break the line and only the foreground colors of the styles are used.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn mode(mut self, mode: SparklineMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets whether the values are drawn above and below a midline.
+    ///
+    /// In the signed mode, the positive values are drawn above the middle of the sparkline and the
+    /// negative values (see [`SparklineBar::signed`]) below it, both scaled to the
+    /// [maximum](Sparkline::max). Otherwise, the negative values are drawn as zero.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn signed(mut self, signed: bool) -> Self {
+        self.signed = signed;
+        self
+    }
+
+    /// Sets the values printed next to the sparkline.
+    ///
+    /// The annotations are printed in the given order on the top line of the sparkline, right of
+    /// the values. They are hidden when the sparkline is too narrow to print them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::{Sparkline, SparklineAnnotation};
+    ///
+    /// // renders the values followed by "max 8 last 4"
+    /// Sparkline::default()
+    ///     .data([1, 4, 8, 4])
+    ///     .annotations([SparklineAnnotation::Max, SparklineAnnotation::Last]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn annotations<T>(mut self, annotations: T) -> Self
+    where
+        T: IntoIterator<Item = SparklineAnnotation>,
+    {
+        self.annotations = annotations.into_iter().collect();
+        self
+    }
+
+    /// Sets the style of the annotations.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn annotation_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.annotation_style = style.into();
+        self
+    }
+
+    /// Sets the styles of the sparkline from the `sparkline` and `sparkline.annotation` styles of
+    /// a [`Theme`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style("sparkline");
+        self.annotation_style = theme.style("sparkline.annotation");
+        self
+    }
+}
+
+/// A series of values overlaid on a [`Sparkline`].
+///
+/// See [`Sparkline::series`].
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::style::Stylize;
+/// use ratatui::widgets::{SparklineBar, SparklineSeries};
+///
+/// SparklineSeries::new([SparklineBar::signed(-2), SparklineBar::from(3)]).blue();
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct SparklineSeries {
+    /// The values of the series
+    data: Vec<SparklineBar>,
+    /// The style of the series, patched over the style of the sparkline
+    style: Style,
+}
+
+impl SparklineSeries {
+    /// Creates a new series with the given values.
+    ///
+    /// The values accept the same types as [`Sparkline::data`].
+    pub fn new<T>(data: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<SparklineBar>,
+    {
+        Self {
+            data: data.into_iter().map(Into::into).collect(),
+            style: Style::new(),
+        }
+    }
+
+    /// Sets the style of the series.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl Styled for SparklineSeries {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
 }
 
 /// An bar in a `Sparkline`.
@@ -255,6 +457,8 @@ pub struct SparklineBar {
     ///
     /// If `None`, the bar is absent.
     value: Option<u64>,
+    /// Whether the value is below zero
+    negative: bool,
     /// The style of the bar.
     ///
     /// If `None`, the bar will use the style of the sparkline.
@@ -262,6 +466,25 @@ pub struct SparklineBar {
 }
 
 impl SparklineBar {
+    /// Creates a bar with a signed value.
+    ///
+    /// Negative values are drawn below the midline of a [signed](Sparkline::signed) sparkline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::SparklineBar;
+    ///
+    /// let bar = SparklineBar::signed(-42);
+    /// ```
+    pub const fn signed(value: i64) -> Self {
+        Self {
+            value: Some(value.unsigned_abs()),
+            negative: value < 0,
+            style: None,
+        }
+    }
+
     /// Sets the style of the bar.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -283,7 +506,11 @@ impl SparklineBar {
 
 impl From<Option<u64>> for SparklineBar {
     fn from(value: Option<u64>) -> Self {
-        Self { value, style: None }
+        Self {
+            value,
+            negative: false,
+            style: None,
+        }
     }
 }
 
@@ -291,6 +518,7 @@ impl From<u64> for SparklineBar {
     fn from(value: u64) -> Self {
         Self {
             value: Some(value),
+            negative: false,
             style: None,
         }
     }
@@ -300,6 +528,7 @@ impl From<&u64> for SparklineBar {
     fn from(value: &u64) -> Self {
         Self {
             value: Some(*value),
+            negative: false,
             style: None,
         }
     }
@@ -309,6 +538,7 @@ impl From<&Option<u64>> for SparklineBar {
     fn from(value: &Option<u64>) -> Self {
         Self {
             value: *value,
+            negative: false,
             style: None,
         }
     }
@@ -351,72 +581,254 @@ impl Default for AbsentValueSymbol {
 }
 
 impl Sparkline<'_> {
-    fn render_sparkline(&self, spark_area: Rect, buf: &mut Buffer) {
+    fn render_sparkline(&self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        let spark_area = self.render_annotations(area, buf);
+        match self.mode {
+            SparklineMode::Bars => self.render_bars(spark_area, buf),
+            SparklineMode::Line => self.render_line(spark_area, buf),
+        }
+    }
+
+    /// Returns the dataset and the overlaid series, with their style
+    fn all_series(&self) -> impl Iterator<Item = (&[SparklineBar], Style)> {
+        iter::once((self.data.as_slice(), self.style)).chain(
+            self.series
+                .iter()
+                .map(|series| (series.data.as_slice(), self.style.patch(series.style))),
+        )
+    }
+
+    /// Returns the maximum value, which is the height of the bars in the unsigned mode and the
+    /// height of the bars above the midline in the signed mode
+    fn max_value(&self) -> u64 {
+        self.max.unwrap_or_else(|| {
+            self.all_series()
+                .flat_map(|(data, _)| data)
+                .filter(|bar| self.signed || !bar.negative)
+                .filter_map(|bar| bar.value)
+                .max()
+                .unwrap_or(1)
+        })
+    }
+
+    /// Returns the value of a bar, or zero for negative values in the unsigned mode
+    fn signed_value(&self, bar: &SparklineBar) -> Option<i128> {
+        let value = i128::from(bar.value?);
+        Some(match (bar.negative, self.signed) {
+            (false, _) => value,
+            (true, true) => -value,
+            (true, false) => 0,
+        })
+    }
+
+    /// Returns the x coordinate of the `index`-th value
+    const fn column(&self, area: Rect, index: u16) -> u16 {
+        match self.direction {
+            RenderDirection::LeftToRight => area.left() + index,
+            RenderDirection::RightToLeft => area.right() - index - 1,
+        }
+    }
+
+    fn render_bars(&self, spark_area: Rect, buf: &mut Buffer) {
         if spark_area.is_empty() {
             return;
         }
         // determine the maximum height across all bars
-        let max_height = self
-            .max
-            .unwrap_or_else(|| self.data.iter().filter_map(|s| s.value).max().unwrap_or(1));
+        let max_height = self.max_value();
+
+        // in the signed mode, the midline splits the area between positive and negative values
+        let (upper, lower) = if self.signed {
+            let lower = spark_area.height / 2;
+            (spark_area.height - lower, lower)
+        } else {
+            (spark_area.height, 0)
+        };
+        let midline = spark_area.top() + upper;
 
         // determine the maximum index to render
-        let max_index = min(spark_area.width as usize, self.data.len());
+        let len = self.all_series().map(|(data, _)| data.len()).max();
+        let max_index = min(spark_area.width as usize, len.unwrap_or_default());
 
-        // render each item in the data
-        for (i, item) in self.data.iter().take(max_index).enumerate() {
-            let x = match self.direction {
-                RenderDirection::LeftToRight => spark_area.left() + i as u16,
-                RenderDirection::RightToLeft => spark_area.right() - i as u16 - 1,
-            };
+        for i in 0..max_index {
+            let x = self.column(spark_area, i as u16);
+            let bars = self
+                .all_series()
+                .filter_map(|(data, style)| data.get(i).map(|bar| (bar, style)))
+                .collect::<Vec<_>>();
 
-            // determine the height, symbol and style to use for the item
+            // determine the height and style of the present values
             //
-            // if the item is not absent:
-            // - the height is the value of the item scaled to the height of the spark area
-            // - the symbol is determined by the scaled height
-            // - the style is the style of the item, if one is set
-            //
-            // otherwise:
-            // - the height is the total height of the spark area
-            // - the symbol is the absent value symbol
-            // - the style is the absent value style
-            let (mut height, symbol, style) = match item {
-                SparklineBar {
-                    value: Some(value),
-                    style,
-                } => {
-                    let height = Self::scale_height(*value, max_height, spark_area.height);
-                    (height, None, *style)
+            // the height is the value of the item scaled to the height above the midline, and the
+            // style is the style of the series patched by the style of the item, if one is set.
+            let mut values = bars
+                .iter()
+                .filter_map(|&(bar, style)| {
+                    let value = self.signed_value(bar)?;
+                    let height = Self::scale_height(value.unsigned_abs() as u64, max_height, upper);
+                    let style = style.patch(bar.style.unwrap_or_default());
+                    Some((value < 0, height, style))
+                })
+                .collect::<Vec<_>>();
+
+            // if all the items are absent, the absent value symbol and style are used for the
+            // entire height of the column
+            if values.is_empty() {
+                let style = self.style.patch(self.absent_value_style);
+                for y in spark_area.rows().map(|row| row.y) {
+                    buf[(x, y)]
+                        .set_symbol(&self.absent_value_symbol.0)
+                        .set_style(style);
                 }
-                _ => (
-                    u64::from(spark_area.height) * 8,
-                    Some(self.absent_value_symbol.0.as_str()),
-                    Some(self.absent_value_style),
-                ),
-            };
+                continue;
+            }
 
-            // render the item from top to bottom
-            //
-            // if the symbol is set it will be used for the entire height of the bar, otherwise the
-            // symbol will be determined by the _remaining_ height.
-            //
-            // if the style is set it will be used for the entire height of the bar, otherwise the
-            // sparkline style will be used.
-            for j in (0..spark_area.height).rev() {
-                let symbol = symbol.unwrap_or_else(|| self.symbol_for_height(height));
-                if height > 8 {
-                    height -= 8;
-                } else {
-                    height = 0;
-                }
-                buf[(x, spark_area.top() + j)]
+            // sort the values by height, so that the shorter bars are drawn in front of the
+            // taller ones, with the last series in front on ties
+            values.reverse();
+            values.sort_by_key(|&(_, height, _)| height);
+            let (below, above): (Vec<_>, Vec<_>) =
+                values.into_iter().partition(|&(negative, _, _)| negative);
+            let above = above
+                .into_iter()
+                .map(|(_, height, style)| (height, style))
+                .collect::<Vec<_>>();
+            let below = below
+                .into_iter()
+                .map(|(_, height, style)| (height.min(u64::from(lower) * 8), style))
+                .collect::<Vec<_>>();
+
+            // render the items from the midline, upward then downward
+            for cell in 0..upper {
+                let (symbol, style) = self.bar_cell(&above, cell, false);
+                buf[(x, midline - cell - 1)]
                     .set_symbol(symbol)
-                    .set_style(self.style.patch(style.unwrap_or_default()));
+                    .set_style(style);
+            }
+            for cell in 0..lower {
+                let (symbol, style) = self.bar_cell(&below, cell, true);
+                buf[(x, midline + cell)].set_symbol(symbol).set_style(style);
             }
         }
     }
 
+    /// Returns the symbol and style of a cell of a column, counted from the midline.
+    ///
+    /// The `values` are the heights and styles of the bars of the column, sorted by height. The
+    /// shortest bar reaching the cell is drawn, and the next bar fills the rest of the cell with
+    /// its foreground color as the background color.
+    fn bar_cell(&self, values: &[(u64, Style)], cell: u16, downward: bool) -> (&str, Style) {
+        let low = u64::from(cell) * 8;
+        let Some(index) = values.iter().position(|&(height, _)| height > low) else {
+            let style = values.last().map_or(self.style, |&(_, style)| style);
+            return (self.bar_set.empty, style);
+        };
+        let (height, style) = values[index];
+        let fill = (height - low).min(8);
+        if fill == 8 {
+            return (self.bar_set.full, style);
+        }
+        let style = match values.get(index + 1).and_then(|(_, next)| next.fg) {
+            Some(color) => style.bg(color),
+            None => style,
+        };
+        if downward {
+            // the bar symbols fill cells from the bottom, so cells of bars going down are drawn
+            // with the complementary symbol and reversed colors
+            (
+                self.symbol_for_height(8 - fill),
+                style.add_modifier(Modifier::REVERSED),
+            )
+        } else {
+            (self.symbol_for_height(fill), style)
+        }
+    }
+
+    fn render_line(&self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        let max = i128::from(self.max_value()).max(1);
+        let rows = i128::from(area.height) * 4;
+        let columns = usize::from(area.width) * 2;
+        let traces = self
+            .all_series()
+            .map(|(data, style)| Trace {
+                rows: data
+                    .iter()
+                    .take(columns)
+                    .map(|bar| {
+                        // the rows are counted from the top
+                        let value = self.signed_value(bar)?.clamp(-max, max);
+                        let row = if self.signed {
+                            ((rows - 1) * (max - value) + max) / (2 * max)
+                        } else {
+                            (2 * (rows - 1) * (max - value) + max) / (2 * max)
+                        };
+                        Some(row.clamp(0, rows - 1) as usize)
+                    })
+                    .collect(),
+                columns,
+                direction: self.direction,
+                color: style.fg.unwrap_or(Color::Reset),
+            })
+            .collect::<Vec<_>>();
+
+        Canvas::default()
+            .background_color(self.style.bg.unwrap_or(Color::Reset))
+            .marker(Marker::Braille)
+            .paint(|ctx| {
+                for trace in &traces {
+                    ctx.draw(trace);
+                }
+            })
+            .render(area, buf);
+    }
+
+    /// Prints the annotations on the top line of the area, and returns the area left for the
+    /// values.
+    fn render_annotations(&self, area: Rect, buf: &mut Buffer) -> Rect {
+        let values = self
+            .data
+            .iter()
+            .filter_map(|bar| {
+                let value = i128::from(bar.value?);
+                Some(if bar.negative { -value } else { value })
+            })
+            .collect::<Vec<_>>();
+        let text = self
+            .annotations
+            .iter()
+            .filter_map(|annotation| {
+                let (label, value) = match annotation {
+                    SparklineAnnotation::Min => ("min", values.iter().min()),
+                    SparklineAnnotation::Max => ("max", values.iter().max()),
+                    SparklineAnnotation::Last => ("last", values.last()),
+                };
+                value.map(|value| format!("{label} {value}"))
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        // the annotations are separated from the values by a space
+        let width = text.len() as u16;
+        if text.is_empty() || width >= area.width {
+            return area;
+        }
+        buf.set_line(
+            area.right() - width,
+            area.top(),
+            &Line::styled(text, self.annotation_style),
+            width,
+        );
+        Rect {
+            width: area.width - width - 1,
+            ..area
+        }
+    }
+
     const fn symbol_for_height(&self, height: u64) -> &str {
         match height {
             0 => self.bar_set.empty,
@@ -442,6 +854,43 @@ impl Sparkline<'_> {
     }
 }
 
+/// The dots of a series drawn in the line mode
+struct Trace {
+    /// The row of the dot of each value, counted from the top, or `None` for absent values
+    rows: Vec<Option<usize>>,
+    /// The number of dots on a line
+    columns: usize,
+    direction: RenderDirection,
+    color: Color,
+}
+
+impl Shape for Trace {
+    fn draw(&self, painter: &mut Painter) {
+        let mut previous = None;
+        for (i, row) in self.rows.iter().enumerate() {
+            let Some(row) = *row else {
+                previous = None;
+                continue;
+            };
+            let x = match self.direction {
+                RenderDirection::LeftToRight => i,
+                RenderDirection::RightToLeft => self.columns - i - 1,
+            };
+            // join the previous dot with a vertical run of dots in this column
+            let from = previous.unwrap_or(row);
+            let (top, bottom) = match from.cmp(&row) {
+                Ordering::Less => (from + 1, row),
+                Ordering::Greater => (row, from - 1),
+                Ordering::Equal => (row, row),
+            };
+            for y in top..=bottom {
+                painter.paint(x, y, self.color);
+            }
+            previous = Some(row);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::vec;
@@ -748,4 +1197,102 @@ mod tests {
         // This should not panic, even if the buffer has zero size.
         sparkline.render(buffer.area, &mut buffer);
     }
+
+    #[test]
+    fn it_draws_overlaid_series() {
+        let widget = Sparkline::default()
+            .data([8, 8, 2])
+            .green()
+            .series(SparklineSeries::new([4, 8]).red());
+        let buffer = render(widget, 4);
+        // the shorter bar is in front, and the taller one fills the rest of the cell
+        let mut expected = Buffer::with_lines(["▄█▂x"]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red().on_green());
+        expected.set_style(Rect::new(1, 0, 1, 1), Color::Red);
+        expected.set_style(Rect::new(2, 0, 1, 1), Color::Green);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn it_draws_absent_values_only_when_all_series_are_absent() {
+        let widget = Sparkline::default()
+            .data([None, None])
+            .series(SparklineSeries::new([Some(8), None]))
+            .absent_value_symbol('*');
+        let buffer = render(widget, 3);
+        assert_eq!(buffer, Buffer::with_lines(["█*x"]));
+    }
+
+    #[test]
+    fn it_draws_signed_values_around_the_midline() {
+        let widget = Sparkline::default()
+            .data([
+                SparklineBar::from(8),
+                SparklineBar::from(4),
+                SparklineBar::signed(-4),
+                SparklineBar::signed(-8),
+            ])
+            .signed(true);
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buffer = Buffer::filled(area, Cell::new("x"));
+        widget.render(area, &mut buffer);
+        // the top of the cells below the midline is filled with reversed colors
+        let mut expected = Buffer::with_lines(["█▄  ", "  ▄█"]);
+        expected.set_style(Rect::new(2, 1, 1, 1), Modifier::REVERSED);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn it_draws_negative_values_as_zero_when_unsigned() {
+        let widget = Sparkline::default().data([SparklineBar::signed(-8), SparklineBar::from(8)]);
+        let buffer = render(widget, 2);
+        assert_eq!(buffer, Buffer::with_lines([" █"]));
+    }
+
+    #[test]
+    fn it_draws_a_braille_line() {
+        let widget = Sparkline::default()
+            .data([0, 1, 2, 3, 3, 3])
+            .mode(SparklineMode::Line);
+        let area = Rect::new(0, 0, 3, 1);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+        // two values per cell, joined by vertical runs of dots
+        assert_eq!(buffer, Buffer::with_lines(["⡠⠊⠉"]));
+    }
+
+    #[test]
+    fn it_draws_a_braille_line_right_to_left() {
+        let widget = Sparkline::default()
+            .data([0, 1, 2, 3, 3, 3])
+            .mode(SparklineMode::Line)
+            .direction(RenderDirection::RightToLeft);
+        let area = Rect::new(0, 0, 3, 1);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["⠉⠑⢄"]));
+    }
+
+    #[test]
+    fn it_draws_annotations() {
+        let widget = Sparkline::default()
+            .data([SparklineBar::from(1), SparklineBar::signed(-2), None.into()])
+            .annotations([
+                SparklineAnnotation::Min,
+                SparklineAnnotation::Max,
+                SparklineAnnotation::Last,
+            ]);
+        let buffer = render(widget, 24);
+        // the last annotation is the last present value
+        assert_eq!(buffer, Buffer::with_lines(["█  xmin -2 max 1 last -2"]));
+    }
+
+    #[test]
+    fn it_hides_annotations_when_too_narrow() {
+        let widget = Sparkline::default()
+            .data([8])
+            .annotations([SparklineAnnotation::Max]);
+        let buffer = render(widget, 5);
+        assert_eq!(buffer, Buffer::with_lines(["█xxxx"]));
+    }
 }
@@ -1,27 +1,42 @@
 //! The [`Table`] widget is used to display multiple rows and columns in a grid and allows selecting
 //! one or multiple cells.
 
+use alloc::borrow::Cow;
 use alloc::vec;
 use alloc::vec::Vec;
+use core::ops::Range;
 
 use itertools::Itertools;
 use ratatui_core::buffer::Buffer;
-use ratatui_core::layout::{Constraint, Flex, Layout, Rect};
-use ratatui_core::style::{Style, Styled};
-use ratatui_core::text::Text;
+use ratatui_core::layout::{Constraint, Flex, Layout, Position, Rect};
+use ratatui_core::style::{Modifier, Style, Styled, Theme};
+use ratatui_core::symbols::line;
+use ratatui_core::symbols::merge::MergeStrategy;
+use ratatui_core::text::{Line, Text};
 use ratatui_core::widgets::{StatefulWidget, Widget};
 
 pub use self::cell::Cell;
+pub use self::gridlines::Gridlines;
 pub use self::highlight_spacing::HighlightSpacing;
 pub use self::row::Row;
-pub use self::state::TableState;
+pub use self::selection::Selection;
+pub(crate) use self::selection::SelectionEdit;
+pub use self::source::TableSource;
+pub use self::state::{SortOrder, TableState};
 use crate::block::{Block, BlockExt};
+use crate::borders::Borders;
+use crate::list::SourceRef;
 
 mod cell;
+mod gridlines;
 mod highlight_spacing;
 mod row;
+mod selection;
+mod source;
 mod state;
 
+const DEFAULT_SELECTION_STYLE: Style = Style::new().add_modifier(Modifier::BOLD);
+
 /// A widget to display data in formatted columns.
 ///
 /// A `Table` is a collection of [`Row`]s, each composed of [`Cell`]s:
@@ -53,6 +68,8 @@ mod state;
 /// # Constructor methods
 ///
 /// - [`Table::new`] creates a new [`Table`] with the given rows.
+/// - [`Table::from_source`] creates a new [`Table`] whose rows are created on demand by a
+///   [`TableSource`], to display very large datasets.
 /// - [`Table::default`] creates an empty [`Table`]. You can then add rows using [`Table::rows`].
 ///
 /// # Setter methods
@@ -69,8 +86,11 @@ mod state;
 /// - [`Table::row_highlight_style`] sets the style of the selected row.
 /// - [`Table::column_highlight_style`] sets the style of the selected column.
 /// - [`Table::cell_highlight_style`] sets the style of the selected cell.
+/// - [`Table::selection_style`] sets the style of the multi-selection of rows and cells.
 /// - [`Table::highlight_symbol`] sets the symbol to be displayed in front of the selected row.
 /// - [`Table::highlight_spacing`] sets when to show the highlight spacing.
+/// - [`Table::sticky_columns`] makes the table scroll horizontally, with frozen leading columns.
+/// - [`Table::sort_symbols`] sets the symbols displayed in the header of the sorted column.
 ///
 /// # Example
 ///
@@ -234,6 +254,12 @@ pub struct Table<'a> {
     /// Data to display in each row
     rows: Vec<Row<'a>>,
 
+    /// Source that creates the rows on demand, used instead of `rows` when set
+    source: Option<SourceRef<'a, dyn TableSource + 'a>>,
+
+    /// Whether a cell of `rows` spans several rows
+    row_spans: bool,
+
     /// Optional header
     header: Option<Row<'a>>,
 
@@ -261,6 +287,9 @@ pub struct Table<'a> {
     /// Style used to render the selected cell
     cell_highlight_style: Style,
 
+    /// Style used to render the multi-selection of rows and the selected range of cells
+    selection_style: Style,
+
     /// Symbol in front of the selected row
     highlight_symbol: Text<'a>,
 
@@ -269,12 +298,36 @@ pub struct Table<'a> {
 
     /// Controls how to distribute extra space among the columns
     flex: Flex,
+
+    /// Number of leading columns that stay visible when the table scrolls horizontally, or
+    /// `None` if the table does not scroll horizontally
+    sticky_columns: Option<usize>,
+
+    /// Symbol displayed in the header of the column sorted in ascending order
+    ascending_symbol: &'a str,
+
+    /// Symbol displayed in the header of the column sorted in descending order
+    descending_symbol: &'a str,
+
+    /// Inner lines to draw between the header, rows, columns and footer
+    gridlines: Gridlines,
+
+    /// Symbols used to draw the gridlines
+    gridline_set: line::Set<'a>,
+
+    /// Style used to draw the gridlines
+    gridline_style: Style,
+
+    /// Strategy used to join the gridlines with the borders of the block
+    merge_gridlines: MergeStrategy,
 }
 
 impl Default for Table<'_> {
     fn default() -> Self {
         Self {
             rows: Vec::new(),
+            source: None,
+            row_spans: false,
             header: None,
             footer: None,
             widths: Vec::new(),
@@ -284,9 +337,17 @@ impl Default for Table<'_> {
             row_highlight_style: Style::new(),
             column_highlight_style: Style::new(),
             cell_highlight_style: Style::new(),
+            selection_style: DEFAULT_SELECTION_STYLE,
             highlight_symbol: Text::default(),
             highlight_spacing: HighlightSpacing::default(),
             flex: Flex::Start,
+            sticky_columns: None,
+            ascending_symbol: "▲",
+            descending_symbol: "▼",
+            gridlines: Gridlines::NONE,
+            gridline_set: line::NORMAL,
+            gridline_style: Style::new(),
+            merge_gridlines: MergeStrategy::Exact,
         }
     }
 }
@@ -325,14 +386,55 @@ impl<'a> Table<'a> {
         let widths = widths.into_iter().map(Into::into).collect_vec();
         ensure_percentages_less_than_100(&widths);
 
-        let rows = rows.into_iter().map(Into::into).collect();
+        let rows: Vec<Row> = rows.into_iter().map(Into::into).collect();
         Self {
+            row_spans: has_row_spans(&rows),
             rows,
             widths,
             ..Default::default()
         }
     }
 
+    /// Creates a new [`Table`] whose rows are created on demand by a [`TableSource`]
+    ///
+    /// Only the rows that are visible are created when the table is rendered, and only the
+    /// heights of the rows around the offset and the selection are queried, so the table renders
+    /// in constant time whatever the number of rows.
+    ///
+    /// Setting the [rows](Table::rows) afterwards replaces the source.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::widgets::{Row, Table, TableSource};
+    ///
+    /// #[derive(Debug)]
+    /// struct Numbers;
+    ///
+    /// impl TableSource for Numbers {
+    ///     fn len(&self) -> usize {
+    ///         1_000_000
+    ///     }
+    ///
+    ///     fn row(&self, index: usize) -> Row<'_> {
+    ///         Row::new([index.to_string()])
+    ///     }
+    /// }
+    ///
+    /// let table = Table::from_source(&Numbers, [Constraint::Length(7)]);
+    /// ```
+    pub fn from_source<C>(source: &'a dyn TableSource, widths: C) -> Self
+    where
+        C: IntoIterator,
+        C::Item: Into<Constraint>,
+    {
+        Self {
+            source: Some(SourceRef(source)),
+            ..Self::new(Vec::<Row>::new(), widths)
+        }
+    }
+
     /// Set the rows
     ///
     /// The `rows` parameter accepts any value that can be converted into an iterator of [`Row`]s.
@@ -362,6 +464,8 @@ impl<'a> Table<'a> {
         T: IntoIterator<Item = Row<'a>>,
     {
         self.rows = rows.into_iter().collect();
+        self.row_spans = has_row_spans(&self.rows);
+        self.source = None;
         self
     }
 
@@ -638,6 +742,32 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Set the style of the multi-selection
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style will be applied to the rows of the multi-selection and to the range of selected
+    /// cells of the [`TableState`], under the highlight styles of the selected row, column and
+    /// cell. By default, the selection is bold.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{layout::Constraint, style::{Style, Stylize}, widgets::{Row, Table}};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).selection_style(Style::new().green());
+    /// ```
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selection_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selection_style = style.into();
+        self
+    }
+
     /// Set the symbol to be displayed in front of the selected row
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -720,6 +850,193 @@ impl<'a> Table<'a> {
         self.flex = flex;
         self
     }
+
+    /// Makes the table scroll horizontally, keeping the first `count` columns visible
+    ///
+    /// By default, the columns are squeezed to fit in the width of the table. With this option,
+    /// when the columns are wider than the table, only the columns from the
+    /// [column offset](TableState::column_offset) are displayed, after the `count` sticky
+    /// columns. The offset follows the [selected column](TableState::selected_column), so the
+    /// table scrolls with [`TableState::scroll_right_by`] and [`TableState::select_next_column`].
+    /// Use `0` to scroll all the columns.
+    ///
+    /// The width of a column is given by its [`Length`](Constraint::Length),
+    /// [`Min`](Constraint::Min) or [`Max`](Constraint::Max) constraint, or by its
+    /// [`Percentage`](Constraint::Percentage) or [`Ratio`](Constraint::Ratio) of the width of the
+    /// table. [`Fill`](Constraint::Fill) columns only use the space left by the other columns.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// let rows = [Row::new(["Name", "Jan", "Feb", "Mar", "Apr"])];
+    /// let table = Table::new(rows, [Constraint::Length(10); 5]).sticky_columns(1);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn sticky_columns(mut self, count: usize) -> Self {
+        self.sticky_columns = Some(count);
+        self
+    }
+
+    /// Sets the symbols displayed at the end of the header of the sorted column
+    ///
+    /// The sorted column and the order are given by [`TableState::sort`]. The defaults are `"▲"`
+    /// and `"▼"`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Table;
+    ///
+    /// let table = Table::default().sort_symbols("↑", "↓");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn sort_symbols(mut self, ascending: &'a str, descending: &'a str) -> Self {
+        self.ascending_symbol = ascending;
+        self.descending_symbol = descending;
+        self
+    }
+
+    /// Sets the inner lines drawn by the table
+    ///
+    /// The [`Gridlines`] flags select the line between the header and the rows, the line between
+    /// the rows and the footer, the lines between each row and the lines between each column.
+    /// Defaults to [`Gridlines::NONE`].
+    ///
+    /// The lines between the header, rows and footer take one line of the table each. The lines
+    /// between the columns are drawn in the [`column_spacing`](Self::column_spacing), so they are
+    /// only drawn when the spacing is at least `1`. The lines are not drawn across the cells that
+    /// span several rows or columns.
+    ///
+    /// The lines are joined with each other at their intersections, and with the borders of the
+    /// [`Block`] of the table using the [`merge_gridlines`](Self::merge_gridlines) strategy, so a
+    /// bordered table looks like a spreadsheet.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::widgets::{Block, Gridlines, Row, Table};
+    ///
+    /// let rows = [Row::new(["Foo", "1"]), Row::new(["Bar", "2"])];
+    /// let table = Table::new(rows, [Constraint::Length(5); 2])
+    ///     .header(Row::new(["Name", "Count"]))
+    ///     .block(Block::bordered())
+    ///     .gridlines(Gridlines::HEADER | Gridlines::COLUMNS);
+    /// // ┌─────┬─────┐
+    /// // │Name │Count│
+    /// // ├─────┼─────┤
+    /// // │Foo  │1    │
+    /// // │Bar  │2    │
+    /// // └─────┴─────┘
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn gridlines(mut self, gridlines: Gridlines) -> Self {
+        self.gridlines = gridlines;
+        self
+    }
+
+    /// Sets the symbols used to draw the gridlines
+    ///
+    /// Defaults to [`line::NORMAL`]. The symbol of each intersection is chosen from the set
+    /// depending on the lines that meet there, e.g. [`line::Set::cross`] where two lines cross and
+    /// [`line::Set::vertical_right`] where a row line starts from a column line.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::symbols::line;
+    /// use ratatui::widgets::{Gridlines, Table};
+    ///
+    /// let table = Table::default()
+    ///     .gridlines(Gridlines::ALL)
+    ///     .gridline_symbols(line::DOUBLE);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn gridline_symbols(mut self, symbols: line::Set<'a>) -> Self {
+        self.gridline_set = symbols;
+        self
+    }
+
+    /// Sets the style of the gridlines
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::{Gridlines, Table};
+    ///
+    /// let table = Table::default()
+    ///     .gridlines(Gridlines::ALL)
+    ///     .gridline_style(Style::new().dark_gray());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn gridline_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.gridline_style = style.into();
+        self
+    }
+
+    /// Sets the styles of the table from a [`Theme`].
+    ///
+    /// The styles are replaced by the `table`, `table.row.highlight`, `table.column.highlight`,
+    /// `table.cell.highlight`, `table.selection` and `table.gridline` styles of the theme. The
+    /// selection style is patched on top of the default bold style.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style("table");
+        self.row_highlight_style = theme.style("table.row.highlight");
+        self.column_highlight_style = theme.style("table.column.highlight");
+        self.cell_highlight_style = theme.style("table.cell.highlight");
+        self.selection_style = DEFAULT_SELECTION_STYLE.patch(theme.style("table.selection"));
+        self.gridline_style = theme.style("table.gridline");
+        self
+    }
+
+    /// Sets the [`MergeStrategy`] used to join the gridlines with the borders of the block
+    ///
+    /// The ends of the gridlines are merged with the borders of the [`Block`] they touch, e.g. a
+    /// column line turns the top border `─` into `┬`. Defaults to [`MergeStrategy::Exact`], which
+    /// joins the symbols when a single character represents them and keeps the characters of the
+    /// block titles. With [`MergeStrategy::Replace`], the ends of the gridlines replace the border
+    /// symbols.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::symbols::merge::MergeStrategy;
+    /// use ratatui::widgets::{Block, BorderType, Gridlines, Table};
+    ///
+    /// let table = Table::default()
+    ///     .block(Block::bordered().border_type(BorderType::Double))
+    ///     .gridlines(Gridlines::ALL)
+    ///     .merge_gridlines(MergeStrategy::Fuzzy);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn merge_gridlines(mut self, strategy: MergeStrategy) -> Self {
+        self.merge_gridlines = strategy;
+        self
+    }
 }
 
 impl Widget for Table<'_> {
@@ -754,13 +1071,15 @@ impl StatefulWidget for &Table<'_> {
             return;
         }
 
-        if state.selected.is_some_and(|s| s >= self.rows.len()) {
-            state.select(Some(self.rows.len().saturating_sub(1)));
+        let row_count = self.row_count();
+        if state.selected.is_some_and(|s| s >= row_count) {
+            state.select(Some(row_count.saturating_sub(1)));
         }
 
-        if self.rows.is_empty() {
+        if row_count == 0 {
             state.select(None);
         }
+        state.selection.truncate(row_count);
 
         let column_count = self.column_count();
         if state.selected_column.is_some_and(|s| s >= column_count) {
@@ -769,54 +1088,102 @@ impl StatefulWidget for &Table<'_> {
         if column_count == 0 {
             state.select_column(None);
         }
+        if let Some((row, column)) = state.edit.cell_anchor {
+            state.edit.cell_anchor = (row_count > 0 && column_count > 0)
+                .then(|| (row.min(row_count - 1), column.min(column_count - 1)));
+        }
 
         let selection_width = self.selection_width(state);
-        let column_widths = self.get_column_widths(table_area.width, selection_width, column_count);
-        let (header_area, rows_area, footer_area) = self.layout(table_area);
+        let column_widths =
+            self.column_areas(table_area.width, selection_width, column_count, state);
+        let (header_area, header_line, rows_area, footer_line, footer_area) =
+            self.layout(table_area);
 
-        self.render_header(header_area, buf, &column_widths);
+        self.render_header(header_area, buf, &column_widths, state.sort);
 
-        self.render_rows(rows_area, buf, selection_width, state, &column_widths);
+        let (merged_cells, row_lines) =
+            self.render_rows(rows_area, buf, selection_width, state, &column_widths);
 
         self.render_footer(footer_area, buf, &column_widths);
+
+        let lines = [header_line, footer_line]
+            .into_iter()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.y)
+            .chain(row_lines)
+            .collect_vec();
+        self.render_gridlines(table_area, buf, &column_widths, &lines, &merged_cells);
     }
 }
 
 // private methods for rendering
 impl Table<'_> {
-    /// Splits the table area into a header, rows area and a footer
-    fn layout(&self, area: Rect) -> (Rect, Rect, Rect) {
+    /// Splits the table area into a header, the gridline below it, the rows area, the gridline
+    /// above the footer and the footer
+    fn layout(&self, area: Rect) -> (Rect, Rect, Rect, Rect, Rect) {
         let header_top_margin = self.header.as_ref().map_or(0, |h| h.top_margin);
         let header_height = self.header.as_ref().map_or(0, |h| h.height);
         let header_bottom_margin = self.header.as_ref().map_or(0, |h| h.bottom_margin);
         let footer_top_margin = self.footer.as_ref().map_or(0, |h| h.top_margin);
         let footer_height = self.footer.as_ref().map_or(0, |f| f.height);
         let footer_bottom_margin = self.footer.as_ref().map_or(0, |h| h.bottom_margin);
+        let header_line =
+            u16::from(self.header.is_some() && self.gridlines.contains(Gridlines::HEADER));
+        let footer_line =
+            u16::from(self.footer.is_some() && self.gridlines.contains(Gridlines::FOOTER));
         let layout = Layout::vertical([
             Constraint::Length(header_top_margin),
             Constraint::Length(header_height),
             Constraint::Length(header_bottom_margin),
+            Constraint::Length(header_line),
             Constraint::Min(0),
+            Constraint::Length(footer_line),
             Constraint::Length(footer_top_margin),
             Constraint::Length(footer_height),
             Constraint::Length(footer_bottom_margin),
         ])
         .split(area);
-        let (header_area, rows_area, footer_area) = (layout[1], layout[3], layout[5]);
-        (header_area, rows_area, footer_area)
+        (layout[1], layout[3], layout[4], layout[5], layout[7])
     }
 
     /// Render the header cells, if they are not `None`
     ///
     /// The `x` and `width` fields of each `Rect` in `column_widths` denote the starting
     /// x-coordinate and width of each column in the table.
-    fn render_header(&self, area: Rect, buf: &mut Buffer, column_widths: &[Rect]) {
+    ///
+    /// The symbol of the sort order is rendered at the end of the header of the sorted column.
+    fn render_header(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        column_widths: &[Rect],
+        sort: Option<(usize, SortOrder)>,
+    ) {
         if let Some(ref header) = self.header {
             buf.set_style(area, header.style);
-            for (cell_area, cell) in column_widths.iter().zip(header.cells.iter()) {
+            for (column, (cell_area, cell)) in
+                column_widths.iter().zip(header.cells.iter()).enumerate()
+            {
                 let new_x = area.x + cell_area.x;
                 let area_to_render = Rect::new(new_x, area.y, cell_area.width, area.height);
                 cell.render(area_to_render, buf);
+                if let Some((sorted, order)) = sort
+                    && sorted == column
+                {
+                    let symbol = match order {
+                        SortOrder::Ascending => self.ascending_symbol,
+                        SortOrder::Descending => self.descending_symbol,
+                    };
+                    let symbol = Line::from(symbol);
+                    let width = (symbol.width() as u16).min(area_to_render.width);
+                    let symbol_area = Rect {
+                        x: area_to_render.right() - width,
+                        width,
+                        height: 1,
+                        ..area_to_render
+                    };
+                    symbol.render(symbol_area, buf);
+                }
             }
         }
     }
@@ -836,7 +1203,8 @@ impl Table<'_> {
         }
     }
 
-    /// Render the table rows
+    /// Render the table rows, and return the merged cells and the `y` of the gridlines between
+    /// the rows
     ///
     /// The `x` and `width` fields of each `Rect` in `column_widths` denote the starting
     /// x-coordinate and width of each column in the table.
@@ -847,89 +1215,318 @@ impl Table<'_> {
         selection_width: u16,
         state: &mut TableState,
         columns_widths: &[Rect],
-    ) {
-        if self.rows.is_empty() {
-            return;
+    ) -> (Vec<MergedCell>, Vec<u16>) {
+        if self.row_count() == 0 {
+            state.cache.merged_cells.clear();
+            return (Vec::new(), Vec::new());
         }
 
         let (start_index, end_index) = self.visible_rows(state, area);
         state.offset = start_index;
 
-        let mut y_offset = 0;
+        let rows = (start_index..end_index).map(|i| self.row(i)).collect_vec();
+        let (row_areas, lines) =
+            self.render_row_areas(buf, area, selection_width, state, start_index, &rows);
 
-        let mut selected_row_area = None;
-        for (i, row) in self
-            .rows
+        let merged_cells =
+            self.render_cells(buf, area, columns_widths, start_index, &rows, &row_areas);
+        state.cache.merged_cells = merged_cells
             .iter()
-            .enumerate()
-            .skip(start_index)
-            .take(end_index - start_index)
-        {
+            .map(|merged| (merged.rows.clone(), merged.columns.clone()))
+            .collect();
+
+        // merged cells are highlighted as a whole when any of their rows or columns is
+        let merged_areas = |row: Option<usize>, column: Option<usize>| {
+            merged_cells.iter().filter_map(move |merged| {
+                let in_row = row.is_none_or(|row| merged.rows.contains(&row));
+                let in_column = column.is_none_or(|column| merged.columns.contains(&column));
+                (in_row && in_column).then_some(merged.area)
+            })
+        };
+        let row_area = |index: usize| {
+            index
+                .checked_sub(start_index)
+                .and_then(|index| row_areas.get(index))
+                .copied()
+        };
+        let column_area = |index: usize| {
+            // The selection is clamped by the column count. Since a user can manually specify an
+            // incorrect number of widths, we should use panic free methods.
+            columns_widths.get(index).map(|cell_area| Rect {
+                x: cell_area.x + area.x,
+                width: cell_area.width,
+                ..area
+            })
+        };
+
+        for i in (start_index..end_index).filter(|&i| state.selection.contains(i)) {
+            if let Some(row_area) = row_area(i) {
+                buf.set_style(row_area, self.selection_style);
+            }
+            for merged_area in merged_areas(Some(i), None) {
+                buf.set_style(merged_area, self.selection_style);
+            }
+        }
+        if let Some((rows, columns)) = state.cell_selection() {
+            let first_column = column_area(columns.start);
+            let last_column = column_area(columns.end - 1).or(first_column);
+            if let (Some(first), Some(last)) = (first_column, last_column) {
+                let selected_area = first.union(last);
+                for i in rows.start.max(start_index)..rows.end.min(end_index) {
+                    if let Some(row_area) = row_area(i) {
+                        buf.set_style(row_area.intersection(selected_area), self.selection_style);
+                    }
+                }
+            }
+            for merged in &merged_cells {
+                if ranges_overlap(&merged.rows, &rows) && ranges_overlap(&merged.columns, &columns)
+                {
+                    buf.set_style(merged.area, self.selection_style);
+                }
+            }
+        }
+
+        let selected_row_area = state.selected.and_then(row_area);
+        let selected_column_area = state.selected_column.and_then(column_area);
+        if let Some(row_area) = selected_row_area {
+            buf.set_style(row_area, self.row_highlight_style);
+            for merged_area in merged_areas(state.selected, None) {
+                buf.set_style(merged_area, self.row_highlight_style);
+            }
+        }
+        if let Some(col_area) = selected_column_area {
+            buf.set_style(col_area, self.column_highlight_style);
+            for merged_area in merged_areas(None, state.selected_column) {
+                buf.set_style(merged_area, self.column_highlight_style);
+            }
+        }
+        if let (Some(row_area), Some(col_area)) = (selected_row_area, selected_column_area) {
+            let cell_area = merged_areas(state.selected, state.selected_column)
+                .next()
+                .unwrap_or_else(|| row_area.intersection(col_area));
+            buf.set_style(cell_area, self.cell_highlight_style);
+        }
+        (merged_cells, lines)
+    }
+
+    /// Set the style of the visible rows and render the highlight symbol, and return the areas of
+    /// the rows and the `y` of the gridlines between them
+    fn render_row_areas(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        selection_width: u16,
+        state: &TableState,
+        start_index: usize,
+        rows: &[Cow<Row>],
+    ) -> (Vec<Rect>, Vec<u16>) {
+        let mut row_areas = Vec::with_capacity(rows.len());
+        let mut lines = Vec::new();
+        let mut y_offset = 0;
+        for (i, row) in (start_index..).zip(rows) {
+            let (_, height_with_margin) = self.row_height(i);
+            let line = self.row_line(i);
             let y = area.y + y_offset + row.top_margin;
-            let height = (y + row.height).min(area.bottom()).saturating_sub(y);
+            let row_height = row
+                .height
+                .min(height_with_margin.saturating_sub(row.top_margin + line));
+            let height = (y + row_height).min(area.bottom()).saturating_sub(y);
             let row_area = Rect { y, height, ..area };
             buf.set_style(row_area, row.style);
 
-            let is_selected = state.selected.is_some_and(|index| index == i);
-            if selection_width > 0 && is_selected {
+            if selection_width > 0 && state.selected == Some(i) {
                 self.set_selection_style(buf, selection_width, row_area, row);
             }
-            self.render_row_cells(buf, columns_widths.iter().collect(), &row.cells, row_area);
-            if is_selected {
-                selected_row_area = Some(row_area);
+            row_areas.push(row_area);
+            y_offset += height_with_margin;
+            if line > 0 && area.y + y_offset <= area.bottom() {
+                lines.push(area.y + y_offset - 1);
             }
-            y_offset += row.height_with_margin();
         }
+        (row_areas, lines)
+    }
 
-        let selected_column_area = state.selected_column.and_then(|s| {
-            // The selection is clamped by the column count. Since a user can manually specify an
-            // incorrect number of widths, we should use panic free methods.
-            columns_widths.get(s).map(|cell_area| Rect {
-                x: cell_area.x + area.x,
-                width: cell_area.width,
-                ..area
-            })
-        });
-
-        match (selected_row_area, selected_column_area) {
-            (Some(row_area), Some(col_area)) => {
-                buf.set_style(row_area, self.row_highlight_style);
-                buf.set_style(col_area, self.column_highlight_style);
-                let cell_area = row_area.intersection(col_area);
-                buf.set_style(cell_area, self.cell_highlight_style);
+    /// Render the cells of the visible rows, and of the rows above them that span into the visible
+    /// rows, and return the cells that span several rows or columns.
+    ///
+    /// The cells of each row are placed in the columns that are not covered by the cells spanning
+    /// from the rows above, as in HTML tables. Each cell is rendered across its
+    /// [`Cell::column_span`] columns plus the gaps between them, and its [`Cell::row_span`] rows
+    /// plus the margins between them.
+    fn render_cells(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        column_widths: &[Rect],
+        start_index: usize,
+        rows: &[Cow<Row>],
+        row_areas: &[Rect],
+    ) -> Vec<MergedCell> {
+        let mut merged_cells = Vec::new();
+        let mut covered = Vec::new();
+        let mut render_cell = |cell: &Cell, row: usize, column: usize| {
+            let Some(cell_area) = column_widths.get(column..).and_then(|columns| {
+                Self::get_cell_area(&mut columns.iter(), cell.column_span, self.column_spacing)
+            }) else {
+                return;
+            };
+            let row_span = usize::from(cell.row_span.max(1));
+            // the last visible row of the cell, which is missing when no row is visible
+            let Some(last) = (row + row_span)
+                .min(start_index + row_areas.len())
+                .checked_sub(start_index + 1)
+                .and_then(|last| row_areas.get(last))
+            else {
+                return;
+            };
+            let top = row
+                .checked_sub(start_index)
+                .and_then(|index| row_areas.get(index))
+                .map_or(area.y, |row_area| row_area.y);
+            let bottom = last.bottom();
+            let area_to_render = Rect::new(
+                area.x + cell_area.x,
+                top,
+                cell_area.width,
+                bottom.saturating_sub(top),
+            );
+            cell.render(area_to_render, buf);
+            if row_span > 1 || cell.column_span > 1 {
+                merged_cells.push(MergedCell {
+                    area: area_to_render,
+                    rows: row..row + row_span,
+                    columns: column..column + usize::from(cell.column_span),
+                });
             }
-            (Some(row_area), None) => {
-                buf.set_style(row_area, self.row_highlight_style);
+        };
+
+        // Only the rows stored in the table can be scanned for cells spanning from above
+        if self.row_spans {
+            for (row, cells) in self.rows[..start_index]
+                .iter()
+                .map(|r| &r.cells)
+                .enumerate()
+            {
+                for (cell, column) in place_cells(cells, row, &mut covered) {
+                    if row + usize::from(cell.row_span.max(1)) > start_index {
+                        render_cell(cell, row, column);
+                    }
+                }
             }
-            (None, Some(col_area)) => {
-                buf.set_style(col_area, self.column_highlight_style);
+        }
+        for (row, cells) in (start_index..).zip(rows.iter().map(|r| &r.cells)) {
+            for (cell, column) in place_cells(cells, row, &mut covered) {
+                render_cell(cell, row, column);
             }
-            (None, None) => (),
         }
+        merged_cells
     }
 
-    /// Render cells into the columns of a row
+    /// Render the gridlines on the given lines and between the columns, and join them with the
+    /// borders of the block
     ///
-    /// Render `Cell`s from `cells` into columns specified by `column_widths`, stopping
-    /// if either of these iterators are finished.  Each `Cell` gets rendered across
-    /// [`Cell::get_column_span`] columns plus the gaps between them, if this value is > 1.
-    fn render_row_cells(
+    /// The lines are not drawn across the merged cells. The symbol of each position is chosen
+    /// from the lines going up, down, left and right of it.
+    fn render_gridlines(
         &self,
+        area: Rect,
         buf: &mut Buffer,
-        column_widths: Vec<&Rect>,
-        cells: &Vec<Cell>,
-        row_area: Rect,
+        column_widths: &[Rect],
+        lines: &[u16],
+        merged_cells: &[MergedCell],
     ) {
-        let mut column_widths_iterator = column_widths.into_iter();
-        for current_cell in cells {
-            if let Some(cell_area) = Self::get_cell_area(
-                &mut column_widths_iterator,
-                current_cell.column_span,
-                self.column_spacing,
-            ) {
-                let new_x = row_area.x + cell_area.x;
-                let area_to_render = Rect::new(new_x, row_area.y, cell_area.width, row_area.height);
-                current_cell.render(area_to_render, buf);
+        let columns = if self.gridlines.contains(Gridlines::COLUMNS) {
+            column_widths
+                .iter()
+                .filter(|column| column.width > 0)
+                .tuple_windows()
+                .filter_map(|(left, right)| {
+                    let spacing = right.x.checked_sub(left.right())?;
+                    (spacing > 0).then(|| area.x + left.right() + (spacing - 1) / 2)
+                })
+                .filter(|&x| x < area.right())
+                .collect_vec()
+        } else {
+            Vec::new()
+        };
+        if lines.is_empty() && columns.is_empty() {
+            return;
+        }
+
+        let is_merged = |x: u16, y: u16| {
+            merged_cells
+                .iter()
+                .any(|merged| merged.area.contains(Position::new(x, y)))
+        };
+        let horizontal = |x: u16, y: u16| {
+            lines.contains(&y) && x >= area.left() && x < area.right() && !is_merged(x, y)
+        };
+        let vertical = |x: u16, y: u16| {
+            columns.contains(&x) && y >= area.top() && y < area.bottom() && !is_merged(x, y)
+        };
+        let set = &self.gridline_set;
+        let symbol = |x: u16, y: u16| {
+            let (h, v) = (horizontal(x, y), vertical(x, y));
+            let left = h && (x == area.left() || horizontal(x - 1, y));
+            let right = h && (x + 1 == area.right() || horizontal(x + 1, y));
+            let up = v && (y == area.top() || vertical(x, y - 1));
+            let down = v && (y + 1 == area.bottom() || vertical(x, y + 1));
+            match (up, down, left, right) {
+                (true, true, true, true) => set.cross,
+                (true, true, false, true) => set.vertical_right,
+                (true, true, true, false) => set.vertical_left,
+                (false, true, true, true) => set.horizontal_down,
+                (true, false, true, true) => set.horizontal_up,
+                (false, true, false, true) => set.top_left,
+                (false, true, true, false) => set.top_right,
+                (true, false, false, true) => set.bottom_left,
+                (true, false, true, false) => set.bottom_right,
+                _ if up || down || !h => set.vertical,
+                _ => set.horizontal,
+            }
+        };
+
+        let positions = lines
+            .iter()
+            .flat_map(|&y| area.columns().map(move |column| (column.x, y)))
+            .chain(
+                columns
+                    .iter()
+                    .flat_map(|&x| area.rows().map(move |row| (x, row.y))),
+            );
+        for (x, y) in positions {
+            if (horizontal(x, y) || vertical(x, y))
+                && let Some(cell) = buf.cell_mut((x, y))
+            {
+                cell.set_symbol(symbol(x, y)).set_style(self.gridline_style);
+            }
+        }
+
+        // the ends of the lines are merged with the borders that are next to the table
+        let borders = self
+            .block
+            .as_ref()
+            .map_or(Borders::NONE, Block::adjacent_borders);
+        let mut ends = Vec::new();
+        for &y in lines {
+            if borders.contains(Borders::LEFT) && horizontal(area.left(), y) {
+                ends.push((area.left().wrapping_sub(1), y, set.vertical_right));
+            }
+            if borders.contains(Borders::RIGHT) && horizontal(area.right() - 1, y) {
+                ends.push((area.right(), y, set.vertical_left));
+            }
+        }
+        for &x in &columns {
+            if borders.contains(Borders::TOP) && vertical(x, area.top()) {
+                ends.push((x, area.top().wrapping_sub(1), set.horizontal_down));
+            }
+            if borders.contains(Borders::BOTTOM) && vertical(x, area.bottom() - 1) {
+                ends.push((x, area.bottom(), set.horizontal_up));
+            }
+        }
+        for (x, y, symbol) in ends {
+            if let Some(cell) = buf.cell_mut((x, y)) {
+                cell.merge_symbol(symbol, self.merge_gridlines);
             }
         }
     }
@@ -994,7 +1591,8 @@ impl Table<'_> {
     /// - if there is still space to fill then there's a partial row at the end which should be
     ///   included in the view.
     fn visible_rows(&self, state: &TableState, area: Rect) -> (usize, usize) {
-        let last_row = self.rows.len().saturating_sub(1);
+        let row_count = self.row_count();
+        let last_row = row_count.saturating_sub(1);
         let mut start = state.offset.min(last_row);
 
         if let Some(selected) = state.selected {
@@ -1004,11 +1602,12 @@ impl Table<'_> {
         let mut end = start;
         let mut height = 0;
 
-        for item in self.rows.iter().skip(start) {
-            if height + item.height > area.height {
+        for index in start..row_count {
+            let (row_height, height_with_margin) = self.row_height(index);
+            if height + row_height > area.height {
                 break;
             }
-            height += item.height_with_margin();
+            height += height_with_margin;
             end += 1;
         }
 
@@ -1017,17 +1616,17 @@ impl Table<'_> {
 
             // scroll down until the selected row is visible
             while selected >= end {
-                height = height.saturating_add(self.rows[end].height_with_margin());
+                height = height.saturating_add(self.row_height(end).1);
                 end += 1;
                 while height > area.height {
-                    height = height.saturating_sub(self.rows[start].height_with_margin());
+                    height = height.saturating_sub(self.row_height(start).1);
                     start += 1;
                 }
             }
         }
 
         // Include a partial row if there is space
-        if height < area.height && end < self.rows.len() {
+        if height < area.height && end < row_count {
             end += 1;
         }
 
@@ -1038,33 +1637,135 @@ impl Table<'_> {
     ///
     /// Returns (x, width). When self.widths is empty, it is assumed `.widths()` has not been called
     /// and a default of equal widths is returned.
+    #[cfg(test)]
     fn get_column_widths(
         &self,
         max_width: u16,
         selection_width: u16,
         col_count: usize,
     ) -> Vec<Rect> {
-        let widths = if self.widths.is_empty() {
+        self.column_areas(
+            max_width,
+            selection_width,
+            col_count,
+            &mut TableState::new(),
+        )
+    }
+
+    /// Get the offsets and widths of the columns, taking into account the columns resized and
+    /// hidden in the state, and the horizontal scrolling.
+    ///
+    /// Returns (x, width). Hidden columns, and the columns scrolled out of view, have a width of
+    /// 0. When self.widths is empty, it is assumed `.widths()` has not been called and a default
+    /// of equal widths is returned. The column offset and the rendered widths of the state are
+    /// updated.
+    fn column_areas(
+        &self,
+        max_width: u16,
+        selection_width: u16,
+        col_count: usize,
+        state: &mut TableState,
+    ) -> Vec<Rect> {
+        let mut widths = if self.widths.is_empty() {
             // Divide the space between each column equally
             vec![Constraint::Length(max_width / col_count.max(1) as u16); col_count]
         } else {
             self.widths.clone()
         };
+        for (column, width) in widths.iter_mut().enumerate() {
+            if let Some(length) = state.column_width(column) {
+                *width = Constraint::Length(length);
+            }
+        }
         // this will always allocate a selection area
         let [_selection_area, columns_area] =
             Layout::horizontal([Constraint::Length(selection_width), Constraint::Fill(0)])
                 .areas(Rect::new(0, 0, max_width, 1));
-        let rects = Layout::horizontal(widths)
+
+        let visible = (0..widths.len())
+            .filter(|&column| state.is_column_visible(column))
+            .collect_vec();
+        let displayed = match self.sticky_columns {
+            Some(sticky) => self.scrolled_columns(&visible, &widths, sticky, columns_area, state),
+            None => visible,
+        };
+
+        let rects = Layout::horizontal(displayed.iter().map(|&column| widths[column]))
             .flex(self.flex)
             .spacing(self.column_spacing)
             .split(columns_area);
-        rects
-            .iter()
-            .map(|c| Rect::new(c.x, 0, c.width, 1))
-            .collect()
+
+        // Columns that are not displayed are collapsed at the position of the next displayed column
+        let mut areas = vec![Rect::default(); widths.len()];
+        let mut next_x = columns_area.right();
+        let mut displayed_rects = displayed.iter().zip(rects.iter()).rev().peekable();
+        for column in (0..widths.len()).rev() {
+            if let Some((_, rect)) = displayed_rects.next_if(|(c, _)| **c == column) {
+                areas[column] = Rect::new(rect.x, 0, rect.width, 1);
+                next_x = rect.x;
+            } else {
+                areas[column] = Rect::new(next_x, 0, 0, 1);
+            }
+        }
+        state.cache.column_widths = areas.iter().map(|area| area.width).collect();
+        areas
+    }
+
+    /// Returns the visible columns that are displayed when the table scrolls horizontally
+    ///
+    /// The sticky columns are always displayed, followed by the columns from the column offset of
+    /// the state that fit in the area. The offset is moved to keep the selected column visible.
+    fn scrolled_columns(
+        &self,
+        visible: &[usize],
+        widths: &[Constraint],
+        sticky: usize,
+        area: Rect,
+        state: &mut TableState,
+    ) -> Vec<usize> {
+        let spacing = usize::from(self.column_spacing);
+        let cost =
+            |column: usize| usize::from(preferred_width(widths[column], area.width)) + spacing;
+        let (mut displayed, scrollable): (Vec<usize>, Vec<usize>) =
+            visible.iter().partition(|&&column| column < sticky);
+        // the spacing after the last column is not needed
+        let budget = (usize::from(area.width) + spacing)
+            .saturating_sub(displayed.iter().map(|&column| cost(column)).sum());
+
+        let mut first = scrollable
+            .partition_point(|&column| column < state.column_offset)
+            .min(scrollable.len().saturating_sub(1));
+        if let Some(selected) = state.selected_column
+            && let Some(position) = scrollable.iter().position(|&column| column == selected)
+        {
+            if position < first {
+                first = position;
+            }
+            while first < position
+                && scrollable[first..=position]
+                    .iter()
+                    .map(|&column| cost(column))
+                    .sum::<usize>()
+                    > budget
+            {
+                first += 1;
+            }
+        }
+        state.column_offset = scrollable.get(first).copied().unwrap_or_default();
+
+        let mut used = 0;
+        for (i, &column) in scrollable.iter().enumerate().skip(first) {
+            used += cost(column);
+            if i > first && used > budget {
+                break;
+            }
+            displayed.push(column);
+        }
+        displayed
     }
 
     fn column_count(&self) -> usize {
+        let row_columns = self.source.map_or(0, |source| source.0.column_count());
         self.rows
             .iter()
             .chain(self.footer.iter())
@@ -1072,6 +1773,42 @@ impl Table<'_> {
             .map(|r| r.cells.len())
             .max()
             .unwrap_or_default()
+            .max(row_columns)
+    }
+
+    /// Returns the number of rows, stored in the table or given by its source
+    fn row_count(&self) -> usize {
+        self.source.map_or(self.rows.len(), |source| source.0.len())
+    }
+
+    /// Returns the row at `index`, borrowed from the table or created by its source
+    fn row(&self, index: usize) -> Cow<'_, Row<'_>> {
+        match self.source {
+            Some(source) => Cow::Owned(source.0.row(index)),
+            None => Cow::Borrowed(&self.rows[index]),
+        }
+    }
+
+    /// Returns the height of the row at `index` without and with its margins and the gridline
+    /// below it
+    ///
+    /// The height of a row created by a source is only known with its margins, so both heights
+    /// are the one given by the source.
+    fn row_height(&self, index: usize) -> (u16, u16) {
+        let line = self.row_line(index);
+        if let Some(source) = self.source {
+            let height = source.0.height(index);
+            (height, height.saturating_add(line))
+        } else {
+            let row = &self.rows[index];
+            (row.height, row.height_with_margin().saturating_add(line))
+        }
+    }
+
+    /// Returns the height of the gridline below the row at `index`, `1` if there is one and `0`
+    /// otherwise
+    fn row_line(&self, index: usize) -> u16 {
+        u16::from(self.gridlines.contains(Gridlines::ROWS) && index + 1 < self.row_count())
     }
 
     /// Returns the width of the selection column if a row is selected, or the `highlight_spacing`
@@ -1086,6 +1823,72 @@ impl Table<'_> {
     }
 }
 
+/// A cell that spans several rows or columns, as rendered
+struct MergedCell {
+    area: Rect,
+    rows: Range<usize>,
+    columns: Range<usize>,
+}
+
+/// Places the cells of a row in the columns that are not covered by the cells spanning from the
+/// rows above, and returns each cell with its first column.
+///
+/// `covered` holds, for each column, the index of the first row below the cells spanning over it,
+/// and is updated with the cells of this row.
+fn place_cells<'c, 'a>(
+    cells: &'c [Cell<'a>],
+    row: usize,
+    covered: &mut Vec<usize>,
+) -> Vec<(&'c Cell<'a>, usize)> {
+    let mut column = 0;
+    let mut placed = Vec::with_capacity(cells.len());
+    for cell in cells {
+        while covered.get(column).is_some_and(|&end| end > row) {
+            column += 1;
+        }
+        placed.push((cell, column));
+        let column_span = usize::from(cell.column_span);
+        if cell.row_span > 1 {
+            if covered.len() < column + column_span {
+                covered.resize(column + column_span, 0);
+            }
+            covered[column..column + column_span].fill(row + usize::from(cell.row_span));
+        }
+        column += column_span;
+    }
+    placed
+}
+
+/// Returns `true` if a cell of the rows spans several rows
+fn has_row_spans(rows: &[Row]) -> bool {
+    rows.iter()
+        .flat_map(|row| &row.cells)
+        .any(|cell| cell.row_span > 1)
+}
+
+/// Returns `true` if the two ranges have at least one index in common
+const fn ranges_overlap(a: &Range<usize>, b: &Range<usize>) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+/// Returns the width that a column needs to display its content in an area of the given width.
+///
+/// Fill constraints only use the remaining space, so they do not need any width.
+fn preferred_width(constraint: Constraint, area_width: u16) -> u16 {
+    match constraint {
+        Constraint::Min(width) | Constraint::Max(width) | Constraint::Length(width) => width,
+        Constraint::Percentage(percent) => {
+            (u32::from(area_width) * u32::from(percent.min(100)) / 100) as u16
+        }
+        Constraint::Ratio(numerator, denominator) => {
+            let width =
+                u64::from(area_width) * u64::from(numerator) / u64::from(denominator.max(1));
+            width.min(u64::from(area_width)) as u16
+        }
+        Constraint::Fill(_) => 0,
+    }
+}
+
 fn ensure_percentages_less_than_100(widths: &[Constraint]) {
     for w in widths {
         if let Constraint::Percentage(p) = w {
@@ -1130,7 +1933,7 @@ mod tests {
 
     use ratatui_core::layout::Constraint::*;
     use ratatui_core::style::{Color, Modifier, Style, Stylize};
-    use ratatui_core::text::Line;
+    use ratatui_core::text::{Line, Text, Truncation};
     use rstest::{fixture, rstest};
 
     use super::*;
@@ -1154,6 +1957,17 @@ mod tests {
         assert_eq!(table.flex, Flex::Start);
     }
 
+    #[test]
+    fn theme() {
+        let theme = Theme::new()
+            .with_style("table", Style::new().white())
+            .with_style("table.gridline", Style::new().dark_gray());
+        let table = Table::default().theme(&theme);
+        assert_eq!(table.style, Style::new().white());
+        assert_eq!(table.gridline_style, Style::new().white().dark_gray());
+        assert_eq!(table.selection_style, Style::new().white().bold());
+    }
+
     #[test]
     fn default() {
         let table = Table::default();
@@ -1438,6 +2252,61 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[derive(Debug)]
+        struct Squares;
+
+        impl TableSource for Squares {
+            fn len(&self) -> usize {
+                1_000_000
+            }
+
+            fn row(&self, index: usize) -> Row<'_> {
+                Row::new([index.to_string(), (index * index).to_string()])
+            }
+        }
+
+        #[test]
+        fn render_with_source() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let table = Table::from_source(&Squares, [Constraint::Length(3), Constraint::Fill(1)])
+                .highlight_symbol(">");
+            let mut state = TableState::default().with_selected(Some(999));
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 3), &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                " 997 994009    ",
+                " 998 996004    ",
+                ">999 998001    ",
+            ]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.offset, 997);
+        }
+
+        #[test]
+        fn render_with_source_estimated_height() {
+            #[derive(Debug)]
+            struct Tall;
+
+            impl TableSource for Tall {
+                fn len(&self) -> usize {
+                    usize::MAX
+                }
+
+                fn row(&self, index: usize) -> Row<'_> {
+                    Row::new([alloc::format!("{index}\nmore")]).height(2)
+                }
+
+                fn height(&self, _index: usize) -> u16 {
+                    1
+                }
+            }
+
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+            let table = Table::from_source(&Tall, [Constraint::Length(5)]);
+            Widget::render(table, Rect::new(0, 0, 5, 2), &mut buf);
+            assert_eq!(buf, Buffer::with_lines(["0    ", "1    "]));
+        }
+
         #[rstest]
         #[case(15, 5, vec![
                 Row::new(vec![
@@ -1679,6 +2548,25 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_truncation() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 13, 2));
+            let rows = vec![
+                Row::new(vec![
+                    Line::from("abcdefghij").truncation(Truncation::end()),
+                    Line::from("abcdefghij").truncation(Truncation::start()),
+                ]),
+                Row::new(vec![
+                    Cell::new(Text::from("abcdefghij").truncation(Truncation::middle())),
+                    Cell::new("abcdefghij"),
+                ]),
+            ];
+            let table = Table::new(rows, [Length(6), Length(6)]);
+            Widget::render(table, Rect::new(0, 0, 13, 2), &mut buf);
+            let expected = Buffer::with_lines(["abcde… …fghij", "abc…ij abcdef"]);
+            assert_eq!(buf, expected);
+        }
+
         #[test]
         fn render_with_overflow_does_not_panic() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 20, 3));
@@ -1771,6 +2659,231 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_selection() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 20, 3));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2", "Cell3"]),
+                Row::new(vec!["Cell4", "Cell5", "Cell6"]),
+                Row::new(vec!["Cell7", "Cell8", "Cell9"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 3])
+                .row_highlight_style(Style::new().red())
+                .selection_style(Style::new().green());
+            let mut state = TableState::new()
+                .with_selected(1)
+                .with_selection([0, 1].into_iter().collect());
+            StatefulWidget::render(table, Rect::new(0, 0, 20, 3), &mut buf, &mut state);
+            let expected = Buffer::with_lines([
+                "Cell1 Cell2 Cell3   ".green(),
+                "Cell4 Cell5 Cell6   ".red(),
+                "Cell7 Cell8 Cell9   ".into(),
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_cell_selection() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 20, 3));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2", "Cell3"]),
+                Row::new(vec!["Cell4", "Cell5", "Cell6"]),
+                Row::new(vec!["Cell7", "Cell8", "Cell9"]),
+            ];
+            let table =
+                Table::new(rows, [Constraint::Length(5); 3]).selection_style(Style::new().green());
+            let mut state = TableState::new().with_selected_cell((0, 1));
+            state.extend_cell_selection_to((1, 2));
+            StatefulWidget::render(table, Rect::new(0, 0, 20, 3), &mut buf, &mut state);
+            let expected = Buffer::with_lines::<[Line; 3]>([
+                Line::from(vec!["Cell1 ".into(), "Cell2 Cell3".green(), "   ".into()]),
+                Line::from(vec!["Cell4 ".into(), "Cell5 Cell6".green(), "   ".into()]),
+                Line::from(vec!["Cell7 Cell8 Cell9   ".into()]),
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_row_span() {
+            let rows = vec![
+                Row::new(vec![
+                    Cell::new("Fruit").row_span(2),
+                    "apple".into(),
+                    "1".into(),
+                ]),
+                Row::new(vec!["pear", "2"]),
+                Row::new(vec!["Veg", "leek", "3"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 3]);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 17, 3));
+            Widget::render(table, Rect::new(0, 0, 17, 3), &mut buf);
+            let expected = Buffer::with_lines([
+                "Fruit apple 1    ",
+                "      pear  2    ",
+                "Veg   leek  3    ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_row_span_from_above_offset() {
+            let rows = vec![
+                Row::new(vec![Cell::new("Fruit").row_span(2), "apple".into()]),
+                Row::new(vec!["pear"]),
+                Row::new(vec!["Veg", "leek"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2]);
+            let mut state = TableState::new().with_offset(1);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 2));
+            StatefulWidget::render(table, Rect::new(0, 0, 11, 2), &mut buf, &mut state);
+            let expected = Buffer::with_lines(["Fruit pear ", "Veg   leek "]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_row_span_from_above_offset_without_rows_area() {
+            let rows = vec![
+                Row::new(vec![Cell::new("Fruit").row_span(3), "apple".into()]),
+                Row::new(vec!["pear"]),
+                Row::new(vec!["plum"]),
+            ];
+            let table =
+                Table::new(rows, [Constraint::Length(5); 2]).header(Row::new(["Kind", "Name"]));
+            let mut state = TableState::new().with_offset(2);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+            StatefulWidget::render(table, Rect::new(0, 0, 11, 1), &mut buf, &mut state);
+            let expected = Buffer::with_lines(["Kind  Name "]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_merged_cell_highlight() {
+            let rows = vec![
+                Row::new(vec![Cell::new("Fruit").row_span(2), "apple".into()]),
+                Row::new(vec!["pear"]),
+                Row::new(vec!["Veg", "leek"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .cell_highlight_style(Style::new().green());
+            let mut state = TableState::new().with_selected_cell((1, 0));
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 3));
+            StatefulWidget::render(&table, Rect::new(0, 0, 11, 3), &mut buf, &mut state);
+            let expected = Buffer::with_lines([
+                Line::from(vec!["Fruit".green(), " apple".into()]),
+                Line::from(vec!["     ".green(), " pear ".into()]),
+                Line::from("Veg   leek "),
+            ]);
+            assert_eq!(buf, expected);
+
+            state.select_previous();
+            assert_eq!(state.selected_cell(), Some((0, 0)));
+            state.select_next();
+            assert_eq!(state.selected_cell(), Some((2, 0)));
+        }
+
+        #[test]
+        fn render_with_gridlines_and_block() {
+            let rows = vec![Row::new(["Foo", "1"]), Row::new(["Bar", "2"])];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .header(Row::new(["Name", "Count"]))
+                .block(Block::bordered())
+                .gridlines(Gridlines::HEADER | Gridlines::COLUMNS);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 13, 6));
+            Widget::render(table, Rect::new(0, 0, 13, 6), &mut buf);
+            let expected = Buffer::with_lines([
+                "┌─────┬─────┐",
+                "│Name │Count│",
+                "├─────┼─────┤",
+                "│Foo  │1    │",
+                "│Bar  │2    │",
+                "└─────┴─────┘",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_gridlines_around_merged_cell() {
+            let rows = vec![
+                Row::new(vec![Cell::new("Fruit").row_span(2), "apple".into()]),
+                Row::new(vec!["pear"]),
+                Row::new(vec!["Veg", "leek"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .gridlines(Gridlines::ALL)
+                .gridline_symbols(line::DOUBLE);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 5));
+            Widget::render(table, Rect::new(0, 0, 11, 5), &mut buf);
+            let expected = Buffer::with_lines([
+                "Fruit║apple",
+                "     ╠═════",
+                "     ║pear ",
+                "═════╬═════",
+                "Veg  ║leek ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_footer_gridline() {
+            let rows = vec![Row::new(["Foo", "1"])];
+            let table = Table::new(rows, [Constraint::Length(3); 2])
+                .footer(Row::new(["Sum", "1"]))
+                .gridlines(Gridlines::FOOTER | Gridlines::ROWS);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 7, 4));
+            Widget::render(table, Rect::new(0, 0, 7, 4), &mut buf);
+            let expected = Buffer::with_lines(["Foo 1  ", "       ", "───────", "Sum 1  "]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_sticky_columns() {
+            let header = Row::new(["Name", "Jan", "Feb", "Mar", "Apr"]);
+            let rows = vec![Row::new(["Foo", "1", "2", "3", "4"])];
+            let table = Table::new(rows, [Constraint::Length(5); 5])
+                .header(header)
+                .sticky_columns(1);
+            let mut state = TableState::new().with_selected_column(3);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 17, 2));
+            StatefulWidget::render(&table, Rect::new(0, 0, 17, 2), &mut buf, &mut state);
+            let expected = Buffer::with_lines(["Name  Feb   Mar  ", "Foo   2     3    "]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.column_offset(), 2);
+
+            state.scroll_left_by(2);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 17, 2));
+            StatefulWidget::render(&table, Rect::new(0, 0, 17, 2), &mut buf, &mut state);
+            let expected = Buffer::with_lines(["Name  Jan   Feb  ", "Foo   1     2    "]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.column_offset(), 1);
+        }
+
+        #[test]
+        fn render_with_hidden_and_resized_columns() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 1));
+            let rows = vec![Row::new(["Cell1", "Cell2", "Cell3"])];
+            let table = Table::new(rows, [Constraint::Length(5); 3]);
+            let mut state = TableState::new();
+            state.set_column_visible(1, false);
+            state.set_column_width(2, Some(3));
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 1), &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["Cell1 Cel      "]));
+
+            state.resize_column(0, -2);
+            assert_eq!(state.column_width(0), Some(3));
+        }
+
+        #[rstest]
+        #[case::ascending(SortOrder::Ascending, "Name ▲ Age  ")]
+        #[case::descending(SortOrder::Descending, "Name ▼ Age  ")]
+        fn render_with_sort(#[case] order: SortOrder, #[case] expected: &str) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+            let table = Table::new(Vec::<Row>::new(), [Constraint::Length(6); 2])
+                .header(Row::new(["Name", "Age"]));
+            let mut state = TableState::new().with_sort(0, order);
+            StatefulWidget::render(table, Rect::new(0, 0, 12, 1), &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines([expected]));
+        }
+
         #[test]
         fn render_with_selected_row_and_column() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 20, 4));
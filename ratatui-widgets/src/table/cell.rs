@@ -13,6 +13,9 @@ use ratatui_core::widgets::Widget;
 ///
 /// You can use [`Text::alignment`] when creating a cell to align its content.
 ///
+/// A cell can be merged with its neighbors with [`Cell::column_span`] and [`Cell::row_span`]. The
+/// merged cell is rendered, highlighted and selected as a single unit.
+///
 /// # Examples
 ///
 /// You can create a `Cell` from anything that can be converted to a [`Text`].
@@ -53,6 +56,8 @@ pub struct Cell<'a> {
     style: Style,
     /// The number of columns this cell will extend over
     pub(crate) column_span: u16,
+    /// The number of rows this cell will extend over
+    pub(crate) row_span: u16,
 }
 
 impl<'a> Cell<'a> {
@@ -83,6 +88,7 @@ impl<'a> Cell<'a> {
             content: content.into(),
             style: Style::default(),
             column_span: 1,
+            row_span: 1,
         }
     }
 
@@ -136,6 +142,35 @@ impl<'a> Cell<'a> {
         self
     }
 
+    /// Set the `row_span` of this cell
+    ///
+    /// The cell extends over the same columns of the `row_span - 1` rows below it, including the
+    /// margins between the rows. The cells of these rows are placed in the next columns that are
+    /// not covered, as in HTML tables, so they should not have a cell for the covered columns.
+    ///
+    /// When the table has a [`TableSource`](super::TableSource), the cells spanning from rows above
+    /// the first displayed row are not rendered.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    /// ```rust
+    /// use ratatui::widgets::{Cell, Row};
+    /// let rows = vec![
+    ///     Row::new(vec![Cell::new("Fruits").row_span(2), Cell::new("apple")]),
+    ///     Row::new(vec!["pear"]),
+    ///     Row::new(vec!["Cheese", "brie"]),
+    /// ];
+    /// // "Fruits apple",
+    /// // "       pear ",
+    /// // "Cheese brie ",
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn row_span(mut self, row_span: u16) -> Self {
+        self.row_span = row_span;
+        self
+    }
+
     /// Set the `Style` of this cell
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -191,6 +226,7 @@ where
             content: content.into(),
             style: Style::default(),
             column_span: 1,
+            row_span: 1,
         }
     }
 }
@@ -225,6 +261,13 @@ mod tests {
         assert_eq!(cell.content, Text::from(""));
     }
 
+    #[test]
+    fn row_span() {
+        let cell = Cell::new("").row_span(3);
+        assert_eq!(cell.row_span, 3);
+        assert_eq!(Cell::from("").row_span, 1);
+    }
+
     #[test]
     fn style() {
         let style = Style::default().red().italic();
@@ -0,0 +1,44 @@
+use bitflags::bitflags;
+
+bitflags! {
+    /// Bitflags that can be composed to set the inner lines drawn by a [`Table`]
+    ///
+    /// The lines are drawn with the symbols of [`Table::gridline_symbols`] and joined with each
+    /// other and with the borders of the [`Block`] of the table. See [`Table::gridlines`] for more
+    /// details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Gridlines, Table};
+    ///
+    /// let table = Table::default().gridlines(Gridlines::HEADER | Gridlines::COLUMNS);
+    /// ```
+    ///
+    /// [`Block`]: crate::block::Block
+    /// [`Table`]: crate::table::Table
+    /// [`Table::gridlines`]: crate::table::Table::gridlines
+    /// [`Table::gridline_symbols`]: crate::table::Table::gridline_symbols
+    #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Gridlines: u8 {
+        /// Draw a line between the header and the rows
+        const HEADER  = 0b0001;
+        /// Draw a line between the rows and the footer
+        const FOOTER  = 0b0010;
+        /// Draw a line between each row
+        const ROWS    = 0b0100;
+        /// Draw a line between each column
+        const COLUMNS = 0b1000;
+        /// Draw all the lines
+        const ALL = Self::HEADER.bits()
+            | Self::FOOTER.bits()
+            | Self::ROWS.bits()
+            | Self::COLUMNS.bits();
+    }
+}
+
+impl Gridlines {
+    /// Draw no line (default)
+    pub const NONE: Self = Self::empty();
+}
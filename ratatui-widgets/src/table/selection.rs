@@ -0,0 +1,290 @@
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+use core::ops::Range;
+
+/// A set of selected indices, used for the multi-selection of [`List`] and [`Table`]
+///
+/// The indices are stored as sorted, disjoint ranges, so selecting all the items of a very large
+/// list, or a range of them, takes constant memory.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::Selection;
+///
+/// let mut selection = Selection::new();
+/// selection.insert_range(2..5);
+/// selection.toggle(3);
+/// selection.insert(7);
+/// assert_eq!(selection.iter().collect::<Vec<_>>(), [2, 4, 7]);
+///
+/// selection.invert(8);
+/// assert_eq!(selection.iter().collect::<Vec<_>>(), [0, 1, 3, 5, 6]);
+/// ```
+///
+/// [`List`]: crate::list::List
+/// [`Table`]: crate::table::Table
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Vec<Range<usize>>", into = "Vec<Range<usize>>")
+)]
+pub struct Selection {
+    /// Sorted ranges that neither overlap nor touch each other
+    ranges: Vec<Range<usize>>,
+}
+
+impl Selection {
+    /// Creates an empty selection
+    pub const fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// Returns `true` if no index is selected
+    pub const fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns the number of selected indices
+    pub fn len(&self) -> usize {
+        self.ranges.iter().map(ExactSizeIterator::len).sum()
+    }
+
+    /// Returns `true` if `index` is selected
+    pub fn contains(&self, index: usize) -> bool {
+        let position = self.ranges.partition_point(|range| range.end <= index);
+        self.ranges
+            .get(position)
+            .is_some_and(|range| range.start <= index)
+    }
+
+    /// Selects `index`
+    pub fn insert(&mut self, index: usize) {
+        self.insert_range(index..index.saturating_add(1));
+    }
+
+    /// Deselects `index`
+    pub fn remove(&mut self, index: usize) {
+        self.remove_range(index..index.saturating_add(1));
+    }
+
+    /// Selects `index` if it is not selected, and deselects it otherwise
+    pub fn toggle(&mut self, index: usize) {
+        if self.contains(index) {
+            self.remove(index);
+        } else {
+            self.insert(index);
+        }
+    }
+
+    /// Selects all the indices of `range`
+    pub fn insert_range(&mut self, range: Range<usize>) {
+        if range.is_empty() {
+            return;
+        }
+        let (mut start, mut end) = (range.start, range.end);
+        self.ranges.retain(|other| {
+            if other.end < start || other.start > end {
+                return true;
+            }
+            start = start.min(other.start);
+            end = end.max(other.end);
+            false
+        });
+        let position = self.ranges.partition_point(|other| other.start < start);
+        self.ranges.insert(position, start..end);
+    }
+
+    /// Deselects all the indices of `range`
+    pub fn remove_range(&mut self, range: Range<usize>) {
+        if range.is_empty() {
+            return;
+        }
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+        for other in self.ranges.drain(..) {
+            if other.end <= range.start || other.start >= range.end {
+                ranges.push(other);
+                continue;
+            }
+            if other.start < range.start {
+                ranges.push(other.start..range.start);
+            }
+            if other.end > range.end {
+                ranges.push(range.end..other.end);
+            }
+        }
+        self.ranges = ranges;
+    }
+
+    /// Deselects all the indices
+    pub fn clear(&mut self) {
+        self.ranges.clear();
+    }
+
+    /// Inverts the selection of the indices from `0` to `len`, and deselects the indices after
+    pub fn invert(&mut self, len: usize) {
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+        let mut start = 0;
+        for range in &self.ranges {
+            if range.start >= len {
+                break;
+            }
+            if range.start > start {
+                ranges.push(start..range.start);
+            }
+            start = range.end;
+        }
+        if start < len {
+            ranges.push(start..len);
+        }
+        self.ranges = ranges;
+    }
+
+    /// Deselects the indices that are greater than or equal to `len`
+    pub fn truncate(&mut self, len: usize) {
+        self.remove_range(len..usize::MAX);
+    }
+
+    /// Returns an iterator over the selected indices, in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.ranges.iter().flat_map(Clone::clone)
+    }
+
+    /// Returns the selected indices as sorted, disjoint ranges
+    pub fn ranges(&self) -> &[Range<usize>] {
+        &self.ranges
+    }
+}
+
+impl FromIterator<usize> for Selection {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        let mut selection = Self::new();
+        for index in iter {
+            selection.insert(index);
+        }
+        selection
+    }
+}
+
+impl From<Vec<Range<usize>>> for Selection {
+    /// Creates a selection from ranges that may be unsorted, overlap or touch each other
+    fn from(ranges: Vec<Range<usize>>) -> Self {
+        let mut selection = Self::new();
+        for range in ranges {
+            selection.insert_range(range);
+        }
+        selection
+    }
+}
+
+impl From<Selection> for Vec<Range<usize>> {
+    fn from(selection: Selection) -> Self {
+        selection.ranges
+    }
+}
+
+/// The state of an edit of the multi-selection of a [`ListState`] or [`TableState`]
+///
+/// It only matters while the selection is being edited, so it is always equal to any other edit
+/// and is not hashed or serialized with the state.
+///
+/// [`ListState`]: crate::list::ListState
+/// [`TableState`]: crate::table::TableState
+#[derive(Debug, Default, Clone)]
+pub(crate) struct SelectionEdit {
+    /// The item or row from which the selection is extended
+    pub anchor: Option<usize>,
+    /// The range added by the last extension of the selection
+    pub extension: Option<Range<usize>>,
+    /// The cell from which the cell selection of a table is extended to the selected cell
+    pub cell_anchor: Option<(usize, usize)>,
+}
+
+impl SelectionEdit {
+    pub const fn new() -> Self {
+        Self {
+            anchor: None,
+            extension: None,
+            cell_anchor: None,
+        }
+    }
+}
+
+impl PartialEq for SelectionEdit {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SelectionEdit {}
+
+impl Hash for SelectionEdit {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn indices(selection: &Selection) -> Vec<usize> {
+        selection.iter().collect()
+    }
+
+    #[test]
+    fn insert_merges_ranges() {
+        let mut selection = Selection::new();
+        selection.insert_range(0..2);
+        selection.insert_range(5..7);
+        selection.insert(2);
+        assert_eq!(selection.ranges(), [0..3, 5..7]);
+        selection.insert_range(3..5);
+        assert_eq!(
+            selection.iter().collect::<Vec<_>>(),
+            (0..7).collect::<Vec<_>>()
+        );
+        assert_eq!(selection.len(), 7);
+    }
+
+    #[test]
+    fn remove_splits_ranges() {
+        let mut selection = Selection::new();
+        selection.insert_range(0..10);
+        selection.remove(4);
+        selection.remove_range(7..20);
+        assert_eq!(selection.ranges(), [0..4, 5..7]);
+    }
+
+    #[rstest]
+    #[case::empty(vec![], vec![0, 1, 2, 3])]
+    #[case::all(vec![0, 1, 2, 3], vec![])]
+    #[case::holes(vec![1, 3], vec![0, 2])]
+    #[case::beyond_len(vec![0, 6], vec![1, 2, 3])]
+    fn invert(#[case] selected: Vec<usize>, #[case] expected: Vec<usize>) {
+        let mut selection = selected.into_iter().collect::<Selection>();
+        selection.invert(4);
+        assert_eq!(indices(&selection), expected);
+    }
+
+    #[test]
+    fn toggle() {
+        let mut selection = Selection::new();
+        selection.toggle(3);
+        assert!(selection.contains(3));
+        selection.toggle(3);
+        assert!(!selection.contains(3));
+        assert!(selection.is_empty());
+    }
+
+    #[test]
+    fn truncate() {
+        let mut selection = Selection::new();
+        selection.insert_range(0..usize::MAX);
+        selection.truncate(3);
+        assert_eq!(indices(&selection), [0, 1, 2]);
+    }
+}
@@ -0,0 +1,118 @@
+use core::fmt;
+use core::panic::RefUnwindSafe;
+
+use crate::table::Row;
+
+/// A provider of [`Row`]s that are created on demand
+///
+/// A [`Table`] created with [`Table::from_source`] does not store its rows. When it is rendered,
+/// it asks the source for the number of rows and for the height of the rows around the
+/// [offset](crate::table::TableState::offset) and the selection, and only creates the rows that
+/// are visible. This keeps the cost of a frame independent of the size of the dataset.
+///
+/// The height of a row is computed from the row itself by default, including its margins. When
+/// creating a row is expensive, override [`TableSource::height`] to return a known or an
+/// estimated height: the row is then rendered in an area of that height, and clipped if it is
+/// taller.
+///
+/// A source is borrowed by the table, so it must be [`Sync`] and [`RefUnwindSafe`] to keep the
+/// auto traits of [`Table`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::layout::Constraint;
+/// use ratatui::widgets::{Row, Table, TableSource, TableState};
+///
+/// #[derive(Debug)]
+/// struct Squares;
+///
+/// impl TableSource for Squares {
+///     fn len(&self) -> usize {
+///         10_000_000
+///     }
+///
+///     fn row(&self, index: usize) -> Row<'_> {
+///         Row::new([index.to_string(), (index * index).to_string()])
+///     }
+///
+///     fn height(&self, _index: usize) -> u16 {
+///         1
+///     }
+/// }
+///
+/// # fn ui(frame: &mut Frame) {
+/// let table = Table::from_source(&Squares, [Constraint::Length(10), Constraint::Fill(1)]);
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TableState::default().with_selected(Some(5_000_000));
+///
+/// frame.render_stateful_widget(table, frame.area(), &mut state);
+/// # }
+/// ```
+///
+/// [`Table`]: crate::table::Table
+/// [`Table::from_source`]: crate::table::Table::from_source
+pub trait TableSource: fmt::Debug + Sync + RefUnwindSafe {
+    /// Returns the number of rows
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no rows
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Creates the row at `index`
+    ///
+    /// `index` is always less than [`TableSource::len`].
+    fn row(&self, index: usize) -> Row<'_>;
+
+    /// Returns the height of the row at `index`, including its top and bottom margins
+    ///
+    /// The default implementation creates the row and returns its height.
+    fn height(&self, index: usize) -> u16 {
+        self.row(index).height_with_margin()
+    }
+
+    /// Returns the number of columns of the rows
+    ///
+    /// The default implementation creates the first row and returns its number of cells.
+    fn column_count(&self) -> usize {
+        if self.is_empty() {
+            0
+        } else {
+            self.row(0).cells.len()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Rows(usize);
+
+    impl TableSource for Rows {
+        fn len(&self) -> usize {
+            self.0
+        }
+
+        fn row(&self, _index: usize) -> Row<'_> {
+            Row::new(["a", "b", "c"]).height(2).bottom_margin(1)
+        }
+    }
+
+    #[test]
+    fn default_height_includes_margins() {
+        assert_eq!(Rows(3).height(1), 3);
+    }
+
+    #[test]
+    fn default_column_count() {
+        assert_eq!(Rows(3).column_count(), 3);
+        assert_eq!(Rows(0).column_count(), 0);
+        assert!(Rows(0).is_empty());
+    }
+}
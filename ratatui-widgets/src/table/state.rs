@@ -1,3 +1,11 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use strum::{Display, EnumString};
+
+use crate::render_cache::RenderCache;
+use crate::table::{Selection, SelectionEdit};
+
 /// State of a [`Table`] widget
 ///
 /// This state can be used to scroll through the rows and select one of them. When the table is
@@ -5,15 +13,36 @@
 /// table will be shifted to ensure that the selected row is visible. This will modify the
 /// [`TableState`] object passed to the `Frame::render_stateful_widget` method.
 ///
-/// The state consists of two fields:
+/// The state consists of these fields:
 /// - [`offset`]: the index of the first row to be displayed
 /// - [`selected`]: the index of the selected row, which can be `None` if no row is selected
 /// - [`selected_column`]: the index of the selected column, which can be `None` if no column is
 ///   selected
+/// - [`selection`]: the indices of the rows of the multi-selection
+/// - [`cell_selection`]: the rectangular range of selected cells, from an anchor cell to the
+///   selected cell
+/// - [`column_offset`]: the first column displayed after the sticky columns, when the table
+///   [scrolls horizontally](crate::table::Table::sticky_columns)
+/// - [`column_width`]: the widths of the columns resized by the user
+/// - [`sort`]: the column by which the data is sorted, and the order of the sort
+/// - [`is_column_visible`]: whether a column is displayed
+///
+/// The selected row, column and cell are the cursor of the table. The multi-selection of rows is
+/// independent of it: rows are added to it with [`TableState::toggle_selection`],
+/// [`TableState::extend_selection_to`] and [`TableState::select_all`]. A range of cells is
+/// selected with [`TableState::extend_cell_selection_to`]. Both are highlighted with
+/// [`Table::selection_style`].
 ///
 /// [`offset`]: TableState::offset()
 /// [`selected`]: TableState::selected()
 /// [`selected_column`]: TableState::selected_column()
+/// [`selection`]: TableState::selection()
+/// [`cell_selection`]: TableState::cell_selection()
+/// [`column_offset`]: TableState::column_offset()
+/// [`column_width`]: TableState::column_width()
+/// [`sort`]: TableState::sort()
+/// [`is_column_visible`]: TableState::is_column_visible()
+/// [`Table::selection_style`]: crate::table::Table::selection_style
 ///
 /// See the `table` example and the `recipe` and `traceroute` tabs in the demo2 example in the
 /// [Examples] directory for a more in depth example of the various configuration options and for
@@ -50,12 +79,88 @@
 ///
 /// [`Table`]: super::Table
 /// [`Table::widths`]: crate::table::Table::widths
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableState {
     pub(crate) offset: usize,
     pub(crate) selected: Option<usize>,
     pub(crate) selected_column: Option<usize>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Selection::is_empty")
+    )]
+    pub(crate) selection: Selection,
+    /// The anchors of the multi-selection while it is being edited
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) edit: SelectionEdit,
+    /// The first scrollable column displayed when the table scrolls horizontally
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_zero"))]
+    pub(crate) column_offset: usize,
+    /// The widths of the columns set by the user, overriding the width constraints
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub(crate) column_widths: Vec<Option<u16>>,
+    /// The columns that are not displayed
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Selection::is_empty")
+    )]
+    pub(crate) hidden_columns: Selection,
+    /// The sorted column and the order of the sort
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub(crate) sort: Option<(usize, SortOrder)>,
+    /// The column widths and the merged cells of the last render
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) cache: RenderCache<TableCache>,
+}
+
+/// The layout of the last render of a [`Table`](super::Table)
+#[derive(Debug, Default, Clone)]
+pub(crate) struct TableCache {
+    /// The widths of the columns
+    pub column_widths: Vec<u16>,
+    /// The rows and columns of the merged cells
+    pub merged_cells: Vec<(Range<usize>, Range<usize>)>,
+}
+
+/// Returns `true` if the value is zero, to skip serializing a column offset of zero.
+#[cfg(feature = "serde")]
+#[expect(clippy::trivially_copy_pass_by_ref)] // serde passes the field by reference
+const fn is_zero(value: &usize) -> bool {
+    *value == 0
+}
+
+/// The order in which the rows of a [`Table`] are sorted
+///
+/// The table does not sort its rows: the application sorts its data according to
+/// [`TableState::sort`], and the table displays the [sort
+/// symbol](crate::table::Table::sort_symbols) of the order in the header of the sorted column.
+///
+/// [`Table`]: crate::table::Table
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortOrder {
+    /// From the smallest value to the largest
+    #[default]
+    Ascending,
+    /// From the largest value to the smallest
+    Descending,
+}
+
+impl SortOrder {
+    /// Returns the opposite order
+    #[must_use]
+    pub const fn reversed(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
 }
 
 impl TableState {
@@ -73,6 +178,16 @@ impl TableState {
             offset: 0,
             selected: None,
             selected_column: None,
+            selection: Selection::new(),
+            edit: SelectionEdit::new(),
+            column_offset: 0,
+            column_widths: Vec::new(),
+            hidden_columns: Selection::new(),
+            sort: None,
+            cache: RenderCache::new(TableCache {
+                column_widths: Vec::new(),
+                merged_cells: Vec::new(),
+            }),
         }
     }
 
@@ -322,6 +437,8 @@ impl TableState {
 
     /// Selects the next row or the first one if no row is selected
     ///
+    /// When the selected cell is part of a merged cell, the row after it is selected.
+    ///
     /// Note: until the table is rendered, the number of rows is not known, so the index is set to
     /// `0` and will be corrected when the table is rendered
     ///
@@ -334,12 +451,17 @@ impl TableState {
     /// state.select_next();
     /// ```
     pub fn select_next(&mut self) {
-        let next = self.selected.map_or(0, |i| i.saturating_add(1));
+        let next = self.merged_cell().map_or_else(
+            || self.selected.map_or(0, |i| i.saturating_add(1)),
+            |(rows, _)| rows.end,
+        );
         self.select(Some(next));
     }
 
     /// Selects the next column or the first one if no column is selected
     ///
+    /// When the selected cell is part of a merged cell, the column after it is selected.
+    ///
     /// Note: until the table is rendered, the number of columns is not known, so the index is set
     /// to `0` and will be corrected when the table is rendered
     ///
@@ -351,12 +473,17 @@ impl TableState {
     /// state.select_next_column();
     /// ```
     pub fn select_next_column(&mut self) {
-        let next = self.selected_column.map_or(0, |i| i.saturating_add(1));
+        let next = self.merged_cell().map_or_else(
+            || self.selected_column.map_or(0, |i| i.saturating_add(1)),
+            |(_, columns)| columns.end,
+        );
         self.select_column(Some(next));
     }
 
     /// Selects the previous row or the last one if no item is selected
     ///
+    /// When the selected cell is part of a merged cell, the row before it is selected.
+    ///
     /// Note: until the table is rendered, the number of rows is not known, so the index is set to
     /// `usize::MAX` and will be corrected when the table is rendered
     ///
@@ -369,12 +496,17 @@ impl TableState {
     /// state.select_previous();
     /// ```
     pub fn select_previous(&mut self) {
-        let previous = self.selected.map_or(usize::MAX, |i| i.saturating_sub(1));
+        let previous = self.merged_cell().map_or_else(
+            || self.selected.map_or(usize::MAX, |i| i.saturating_sub(1)),
+            |(rows, _)| rows.start.saturating_sub(1),
+        );
         self.select(Some(previous));
     }
 
     /// Selects the previous column or the last one if no column is selected
     ///
+    /// When the selected cell is part of a merged cell, the column before it is selected.
+    ///
     /// Note: until the table is rendered, the number of columns is not known, so the index is set
     /// to `usize::MAX` and will be corrected when the table is rendered
     ///
@@ -386,12 +518,28 @@ impl TableState {
     /// state.select_previous_column();
     /// ```
     pub fn select_previous_column(&mut self) {
-        let previous = self
-            .selected_column
-            .map_or(usize::MAX, |i| i.saturating_sub(1));
+        let previous = self.merged_cell().map_or_else(
+            || {
+                self.selected_column
+                    .map_or(usize::MAX, |i| i.saturating_sub(1))
+            },
+            |(_, columns)| columns.start.saturating_sub(1),
+        );
         self.select_column(Some(previous));
     }
 
+    /// Returns the rows and columns of the merged cell containing the selected cell, if any
+    ///
+    /// The selection moves over a merged cell as a single unit.
+    fn merged_cell(&self) -> Option<(Range<usize>, Range<usize>)> {
+        let (row, column) = self.selected_cell()?;
+        self.cache
+            .merged_cells
+            .iter()
+            .find(|(rows, columns)| rows.contains(&row) && columns.contains(&column))
+            .cloned()
+    }
+
     /// Selects the first row
     ///
     /// Note: until the table is rendered, the number of rows is not known, so the index is set to
@@ -531,10 +679,447 @@ impl TableState {
         let selected = self.selected_column.unwrap_or_default();
         self.select_column(Some(selected.saturating_sub(amount as usize)));
     }
+
+    /// Sets the multi-selection of rows
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new().with_selection([1, 3].into_iter().collect());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_selection(mut self, selection: Selection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Returns the indices of the rows of the multi-selection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new().with_selected(2);
+    /// state.toggle_selection();
+    /// assert!(state.selection().contains(2));
+    /// ```
+    pub const fn selection(&self) -> &Selection {
+        &self.selection
+    }
+
+    /// Returns a mutable reference to the multi-selection of rows
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// state.selection_mut().insert_range(2..4);
+    /// ```
+    pub const fn selection_mut(&mut self) -> &mut Selection {
+        &mut self.selection
+    }
+
+    /// Adds the selected row to the multi-selection, or removes it if it is already there
+    ///
+    /// The row becomes the anchor from which the selection is
+    /// [extended](TableState::extend_selection_to). Nothing happens if no row is selected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new().with_selected(1);
+    /// state.toggle_selection();
+    /// ```
+    pub fn toggle_selection(&mut self) {
+        if let Some(selected) = self.selected {
+            self.selection.toggle(selected);
+            self.edit.anchor = Some(selected);
+            self.edit.extension = None;
+        }
+    }
+
+    /// Selects the row at `index` and extends the multi-selection to it from the anchor
+    ///
+    /// The rows between the anchor and `index` are added to the multi-selection, replacing the
+    /// range added by the previous extension. The anchor is the last row
+    /// [toggled](TableState::toggle_selection), or the selected row when the extension starts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new().with_selected(2);
+    /// state.extend_selection_to(5);
+    /// state.extend_selection_to(4);
+    /// assert_eq!(state.selection().iter().collect::<Vec<_>>(), [2, 3, 4]);
+    /// ```
+    pub fn extend_selection_to(&mut self, index: usize) {
+        let anchor = *self
+            .edit
+            .anchor
+            .get_or_insert_with(|| self.selected.unwrap_or(index));
+        if let Some(extension) = self.edit.extension.take() {
+            self.selection.remove_range(extension);
+        }
+        let extension = anchor.min(index)..anchor.max(index).saturating_add(1);
+        self.selection.insert_range(extension.clone());
+        self.edit.extension = Some(extension);
+        self.selected = Some(index);
+    }
+
+    /// Adds all the rows to the multi-selection
+    ///
+    /// Note: until the table is rendered, the number of rows is not known, so all the indices are
+    /// selected and the selection will be corrected when the table is rendered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// state.select_all();
+    /// ```
+    pub fn select_all(&mut self) {
+        self.selection.insert_range(0..usize::MAX);
+        self.edit.extension = None;
+    }
+
+    /// Inverts the multi-selection of rows
+    ///
+    /// Note: until the table is rendered, the number of rows is not known, so the selection will
+    /// be corrected when the table is rendered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// state.invert_selection();
+    /// ```
+    pub fn invert_selection(&mut self) {
+        self.selection.invert(usize::MAX);
+        self.edit.extension = None;
+    }
+
+    /// Empties the multi-selection of rows and the cell selection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// state.clear_selection();
+    /// ```
+    pub fn clear_selection(&mut self) {
+        self.selection.clear();
+        self.edit.anchor = None;
+        self.edit.extension = None;
+        self.edit.cell_anchor = None;
+    }
+
+    /// Selects the cell at `indexes` and extends the cell selection to it from the anchor cell
+    ///
+    /// The cell selection is the rectangle between the anchor cell and the selected cell. The
+    /// anchor is the selected cell when the extension starts, and is reset by
+    /// [`TableState::clear_selection`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new().with_selected_cell((1, 1));
+    /// state.extend_cell_selection_to((3, 0));
+    /// assert_eq!(state.cell_selection(), Some((1..4, 0..2)));
+    /// ```
+    pub fn extend_cell_selection_to(&mut self, indexes: (usize, usize)) {
+        if self.edit.cell_anchor.is_none() {
+            self.edit.cell_anchor = Some(self.selected_cell().unwrap_or(indexes));
+        }
+        self.select_cell(Some(indexes));
+    }
+
+    /// Returns the ranges of rows and columns of the cell selection
+    ///
+    /// Returns `None` if the cell selection was not [extended](Self::extend_cell_selection_to) or
+    /// if no cell is selected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new().with_selected_cell((1, 1));
+    /// assert_eq!(state.cell_selection(), None);
+    /// ```
+    pub fn cell_selection(&self) -> Option<(Range<usize>, Range<usize>)> {
+        let (anchor_row, anchor_column) = self.edit.cell_anchor?;
+        let (row, column) = self.selected_cell()?;
+        Some((
+            anchor_row.min(row)..anchor_row.max(row).saturating_add(1),
+            anchor_column.min(column)..anchor_column.max(column).saturating_add(1),
+        ))
+    }
+
+    /// Index of the first column displayed after the sticky columns
+    ///
+    /// The table updates it when it scrolls horizontally to keep the selected column visible.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new();
+    /// assert_eq!(state.column_offset(), 0);
+    /// ```
+    pub const fn column_offset(&self) -> usize {
+        self.column_offset
+    }
+
+    /// Mutable reference to the index of the first column displayed after the sticky columns
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// *state.column_offset_mut() = 3;
+    /// ```
+    pub const fn column_offset_mut(&mut self) -> &mut usize {
+        &mut self.column_offset
+    }
+
+    /// Width of a column set by the user, if any
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// state.set_column_width(1, Some(12));
+    /// assert_eq!(state.column_width(1), Some(12));
+    /// assert_eq!(state.column_width(0), None);
+    /// ```
+    pub fn column_width(&self, column: usize) -> Option<u16> {
+        self.column_widths.get(column).copied().flatten()
+    }
+
+    /// Sets the width of a column, overriding its width constraint
+    ///
+    /// Set to `None` to use the [width constraint](crate::table::Table::widths) of the column
+    /// again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// state.set_column_width(2, Some(20));
+    /// ```
+    pub fn set_column_width(&mut self, column: usize, width: Option<u16>) {
+        if self.column_widths.len() <= column {
+            self.column_widths.resize(column + 1, None);
+        }
+        self.column_widths[column] = width;
+    }
+
+    /// Resizes a column by `delta` cells, starting from its width in the last render
+    ///
+    /// The width of a column is at least one cell. This is usually bound to keys such as `<` and
+    /// `>` for the selected column, or to mouse drags on the borders of the header.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// state.set_column_width(0, Some(10));
+    /// state.resize_column(0, -3);
+    /// assert_eq!(state.column_width(0), Some(7));
+    /// ```
+    pub fn resize_column(&mut self, column: usize, delta: i32) {
+        let width = self
+            .column_width(column)
+            .or_else(|| self.cache.column_widths.get(column).copied())
+            .unwrap_or_default();
+        let width = i32::from(width)
+            .saturating_add(delta)
+            .clamp(1, i32::from(u16::MAX));
+        self.set_column_width(column, Some(width as u16));
+    }
+
+    /// Resets the widths of all the columns to their width constraints
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// state.reset_column_widths();
+    /// ```
+    pub fn reset_column_widths(&mut self) {
+        self.column_widths.clear();
+    }
+
+    /// Returns `true` if the column is displayed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// state.toggle_column_visibility(1);
+    /// assert!(!state.is_column_visible(1));
+    /// ```
+    pub fn is_column_visible(&self, column: usize) -> bool {
+        !self.hidden_columns.contains(column)
+    }
+
+    /// Shows or hides a column
+    ///
+    /// A hidden column takes no space, and its cells are not rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// state.set_column_visible(0, false);
+    /// ```
+    pub fn set_column_visible(&mut self, column: usize, visible: bool) {
+        if visible {
+            self.hidden_columns.remove(column);
+        } else {
+            self.hidden_columns.insert(column);
+        }
+    }
+
+    /// Hides a visible column, or shows a hidden one
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// state.toggle_column_visibility(2);
+    /// ```
+    pub fn toggle_column_visibility(&mut self, column: usize) {
+        self.hidden_columns.toggle(column);
+    }
+
+    /// Sets the sort key: the column by which the data is sorted and the order of the sort
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{SortOrder, TableState};
+    ///
+    /// let state = TableState::new().with_sort(1, SortOrder::Descending);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_sort(mut self, column: usize, order: SortOrder) -> Self {
+        self.sort = Some((column, order));
+        self
+    }
+
+    /// The sort key: the column by which the data is sorted and the order of the sort
+    ///
+    /// Returns `None` if the data is not sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{SortOrder, TableState};
+    ///
+    /// let mut rows = vec![("b", 2), ("a", 3), ("c", 1)];
+    /// let state = TableState::new().with_sort(1, SortOrder::Descending);
+    /// if let Some((column, order)) = state.sort() {
+    ///     rows.sort_by(|a, b| {
+    ///         let ordering = match column {
+    ///             0 => a.0.cmp(b.0),
+    ///             _ => a.1.cmp(&b.1),
+    ///         };
+    ///         match order {
+    ///             SortOrder::Ascending => ordering,
+    ///             SortOrder::Descending => ordering.reverse(),
+    ///         }
+    ///     });
+    /// }
+    /// assert_eq!(rows, [("a", 3), ("b", 2), ("c", 1)]);
+    /// ```
+    pub const fn sort(&self) -> Option<(usize, SortOrder)> {
+        self.sort
+    }
+
+    /// Sets the sort key
+    ///
+    /// Set to `None` if the data is not sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{SortOrder, TableState};
+    ///
+    /// let mut state = TableState::new();
+    /// state.set_sort(Some((0, SortOrder::Ascending)));
+    /// ```
+    pub const fn set_sort(&mut self, sort: Option<(usize, SortOrder)>) {
+        self.sort = sort;
+    }
+
+    /// Sorts by a column, reversing the order if the data is already sorted by this column
+    ///
+    /// This is the usual behavior of a click on a column header. The data is sorted in
+    /// [ascending](SortOrder::Ascending) order when the column changes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{SortOrder, TableState};
+    ///
+    /// let mut state = TableState::new();
+    /// state.toggle_sort(2);
+    /// assert_eq!(state.sort(), Some((2, SortOrder::Ascending)));
+    /// state.toggle_sort(2);
+    /// assert_eq!(state.sort(), Some((2, SortOrder::Descending)));
+    /// ```
+    pub const fn toggle_sort(&mut self, column: usize) {
+        self.sort = match self.sort {
+            Some((sorted, order)) if sorted == column => Some((column, order.reversed())),
+            _ => Some((column, SortOrder::Ascending)),
+        };
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
     use super::*;
 
     #[test]
@@ -742,4 +1327,85 @@ mod tests {
         state.scroll_left_by(20);
         assert_eq!(state.selected_column, Some(80));
     }
+
+    #[test]
+    fn extend_selection() {
+        let mut state = TableState::new().with_selected(3);
+        state.extend_selection_to(5);
+        state.extend_selection_to(4);
+        assert_eq!(state.selected, Some(4));
+        assert_eq!(
+            state.selection.iter().collect::<Vec<_>>(),
+            (3..5).collect::<Vec<_>>()
+        );
+
+        state.toggle_selection();
+        assert_eq!(
+            state.selection.iter().collect::<Vec<_>>(),
+            (3..4).collect::<Vec<_>>()
+        );
+
+        state.clear_selection();
+        assert!(state.selection.is_empty());
+    }
+
+    #[test]
+    fn cell_selection() {
+        let mut state = TableState::new().with_selected_cell((2, 3));
+        assert_eq!(state.cell_selection(), None);
+
+        state.extend_cell_selection_to((0, 4));
+        assert_eq!(state.selected_cell(), Some((0, 4)));
+        assert_eq!(state.cell_selection(), Some((0..3, 3..5)));
+
+        state.select_cell(None);
+        assert_eq!(state.cell_selection(), None);
+
+        state.clear_selection();
+        state.select_cell(Some((1, 1)));
+        assert_eq!(state.cell_selection(), None);
+    }
+
+    #[test]
+    fn toggle_sort() {
+        let mut state = TableState::new();
+        state.toggle_sort(1);
+        assert_eq!(state.sort(), Some((1, SortOrder::Ascending)));
+        state.toggle_sort(1);
+        assert_eq!(state.sort(), Some((1, SortOrder::Descending)));
+        state.toggle_sort(0);
+        assert_eq!(state.sort(), Some((0, SortOrder::Ascending)));
+        state.set_sort(None);
+        assert_eq!(state.sort(), None);
+    }
+
+    #[test]
+    fn resize_column() {
+        let mut state = TableState::new();
+        state.cache.column_widths = vec![4, 8];
+        state.resize_column(1, 2);
+        assert_eq!(state.column_width(1), Some(10));
+        state.resize_column(0, -10);
+        assert_eq!(state.column_width(0), Some(1));
+        state.reset_column_widths();
+        assert_eq!(state.column_width(1), None);
+    }
+
+    #[test]
+    fn column_visibility() {
+        let mut state = TableState::new();
+        assert!(state.is_column_visible(2));
+        state.toggle_column_visibility(2);
+        assert!(!state.is_column_visible(2));
+        state.set_column_visible(2, true);
+        assert!(state.is_column_visible(2));
+    }
+
+    #[test]
+    fn render_caches_are_not_compared() {
+        let mut state = TableState::new();
+        state.cache.column_widths = vec![4, 8];
+        state.cache.merged_cells = vec![(0..2, 1..2)];
+        assert_eq!(state, TableState::new());
+    }
 }
@@ -4,7 +4,7 @@ use alloc::vec::Vec;
 use itertools::Itertools;
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::style::{Style, Styled, Theme};
 use ratatui_core::symbols;
 use ratatui_core::text::{Line, Span};
 use ratatui_core::widgets::Widget;
@@ -240,6 +240,19 @@ impl<'a> Tabs<'a> {
         self
     }
 
+    /// Sets the styles of the tabs from a [`Theme`].
+    ///
+    /// The style and the highlight style are replaced by the `tabs` and `tabs.highlight` styles of
+    /// the theme. The highlight style is patched on top of the default reversed style.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style("tabs");
+        self.highlight_style = DEFAULT_HIGHLIGHT_STYLE.patch(theme.style("tabs.highlight"));
+        self
+    }
+
     /// Sets the style for the highlighted tab.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -510,9 +523,20 @@ mod tests {
     use alloc::{format, vec};
 
     use ratatui_core::style::{Color, Stylize};
+    use ratatui_core::text::Truncation;
 
     use super::*;
 
+    #[test]
+    fn theme() {
+        let theme = Theme::new()
+            .with_style("tabs", Style::new().gray())
+            .with_style("tabs.highlight", Style::new().yellow());
+        let tabs = Tabs::new(["Tab1", "Tab2"]).theme(&theme);
+        assert_eq!(tabs.style, Style::new().gray());
+        assert_eq!(tabs.highlight_style, Style::new().yellow().reversed());
+    }
+
     #[test]
     fn new() {
         let titles = vec!["Tab1", "Tab2", "Tab3", "Tab4"];
@@ -640,6 +664,18 @@ mod tests {
         test_case(tabs, Rect::new(0, 0, 30, 1), &expected);
     }
 
+    #[test]
+    fn render_truncated_title() {
+        let tabs = Tabs::new(vec![
+            Line::from("Tab1"),
+            Line::from("Long title").truncation(Truncation::end()),
+        ]);
+        let mut expected = Buffer::with_lines([" Tab1 │ Long …"]);
+        // first tab selected
+        expected.set_style(Rect::new(1, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 14, 1), &expected);
+    }
+
     #[test]
     fn render_with_block() {
         let tabs =
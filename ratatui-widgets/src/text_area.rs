@@ -0,0 +1,616 @@
+//! The [`TextArea`] widget is used to edit multiple lines of text.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::iter;
+use core::ops::Range;
+
+use ratatui_core::buffer::{Buffer, CellWidth};
+use ratatui_core::layout::{Alignment, Position, Rect};
+use ratatui_core::style::{Modifier, Style, Styled, Theme};
+use ratatui_core::text::{StyledGrapheme, TextDirection};
+use ratatui_core::widgets::{StatefulWidget, Widget};
+use unicode_segmentation::UnicodeSegmentation;
+
+use self::state::display_width;
+pub use self::state::{TextAreaMove, TextAreaState, TextPosition};
+use crate::block::{Block, BlockExt};
+use crate::paragraph::WrapMode;
+use crate::reflow::{CharWrapper, LineComposer, WordWrapper};
+
+mod history;
+mod state;
+
+const DEFAULT_SELECTION_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_SEARCH_STYLE: Style = Style::new().add_modifier(Modifier::UNDERLINED);
+const DEFAULT_LINE_NUMBER_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+
+/// A widget to edit multiple lines of text
+///
+/// The text, the cursor, the selection and the undo history are stored in a [`TextAreaState`],
+/// which also handles keyboard input. The text area scrolls vertically and horizontally to keep
+/// the cursor visible, and the position of the cursor is stored in the state when rendered so that
+/// it can be passed to `Frame::set_cursor_position`.
+///
+/// Long lines can be [wrapped](TextArea::wrap) with the same rules as a [`Paragraph`], in which
+/// case the text area only scrolls vertically. Lines can be prefixed with
+/// [line numbers](TextArea::line_numbers), and the matches of the
+/// [search pattern](TextAreaState::set_search_pattern) of the state are highlighted.
+///
+/// By default the selection is reversed, the matches are underlined and the line numbers are
+/// dimmed. The styles can also be set from the `text_area`, `text_area.selection`,
+/// `text_area.search` and `text_area.line_number` keys of a [`Theme`] with [`TextArea::theme`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::{Block, TextArea, TextAreaState, WrapMode};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TextAreaState::new().with_text("Fix the bug\n\nThe bug was caused by ...");
+///
+/// let text_area = TextArea::new()
+///     .block(Block::bordered().title("Commit message"))
+///     .wrap(WrapMode::Word)
+///     .line_numbers(true);
+///
+/// frame.render_stateful_widget(&text_area, area, &mut state);
+/// if let Some(position) = state.cursor_position() {
+///     frame.set_cursor_position(position);
+/// }
+/// # }
+/// ```
+///
+/// [`Paragraph`]: crate::paragraph::Paragraph
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TextArea<'a> {
+    /// An optional block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Style used as a base style for the widget
+    style: Style,
+    /// Style used to render the selected text
+    selection_style: Style,
+    /// Style used to render the matches of the search pattern
+    search_style: Style,
+    /// Style used to render the line numbers
+    line_number_style: Style,
+    /// Whether line numbers are displayed
+    line_numbers: bool,
+    /// How long lines are wrapped, or `None` to scroll horizontally
+    wrap: Option<WrapMode>,
+}
+
+/// A row of the text area, made of a byte range of one of the lines of the text.
+struct Row {
+    line: usize,
+    range: Range<usize>,
+}
+
+impl Default for TextArea<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> TextArea<'a> {
+    /// Creates a new text area.
+    pub const fn new() -> Self {
+        Self {
+            block: None,
+            style: Style::new(),
+            selection_style: DEFAULT_SELECTION_STYLE,
+            search_style: DEFAULT_SEARCH_STYLE,
+            line_number_style: DEFAULT_LINE_NUMBER_STYLE,
+            line_numbers: false,
+            wrap: None,
+        }
+    }
+
+    /// Wraps the text area with a [`Block`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected text
+    ///
+    /// The selection style is patched on top of the base style.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selection_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selection_style = style.into();
+        self
+    }
+
+    /// Sets the style of the matches of the search pattern
+    ///
+    /// The search style is patched on top of the base style, and below the selection style.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn search_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.search_style = style.into();
+        self
+    }
+
+    /// Sets the style of the line numbers
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn line_number_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.line_number_style = style.into();
+        self
+    }
+
+    /// Sets the styles of the text area from a [`Theme`]
+    ///
+    /// The styles are replaced by the `text_area`, `text_area.selection`, `text_area.search` and
+    /// `text_area.line_number` styles of the theme, patched on top of the default selection,
+    /// search and line number styles.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style("text_area");
+        self.selection_style = DEFAULT_SELECTION_STYLE.patch(theme.style("text_area.selection"));
+        self.search_style = DEFAULT_SEARCH_STYLE.patch(theme.style("text_area.search"));
+        self.line_number_style =
+            DEFAULT_LINE_NUMBER_STYLE.patch(theme.style("text_area.line_number"));
+        self
+    }
+
+    /// Displays the number of each line in a gutter on the left of the text
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Wraps the lines that are longer than the text area
+    ///
+    /// Lines are broken in the same way as a [`Paragraph`] with the given [`WrapMode`], without
+    /// trimming whitespace. Without wrapping, the text area scrolls horizontally to keep the
+    /// cursor visible.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Paragraph`]: crate::paragraph::Paragraph
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn wrap(mut self, mode: WrapMode) -> Self {
+        self.wrap = Some(mode);
+        self
+    }
+
+    /// Splits the lines of the text into rows that fit in `width` columns.
+    fn rows(&self, lines: &[String], width: u16) -> Vec<Row> {
+        let mut rows = Vec::with_capacity(lines.len());
+        for (line, text) in lines.iter().enumerate() {
+            match self.wrap {
+                Some(mode) => rows.extend(
+                    wrapped_ranges(text, width, mode)
+                        .into_iter()
+                        .map(|range| Row { line, range }),
+                ),
+                None => rows.push(Row {
+                    line,
+                    range: 0..text.len(),
+                }),
+            }
+        }
+        rows
+    }
+
+    #[expect(clippy::string_slice)] // Is safe as the rows are split on grapheme boundaries
+    fn render_row(&self, row: &Row, area: Rect, buf: &mut Buffer, state: &TextAreaState) {
+        let text = &state.lines[row.line];
+        let width = usize::from(area.width);
+        let selection = state.selection().unwrap_or_default();
+        let matches: Vec<_> = state.line_matches(row.line).collect();
+
+        let mut column = 0;
+        let graphemes = text[row.range.clone()].grapheme_indices(true);
+        for (index, symbol) in graphemes {
+            let index = row.range.start + index;
+            let start = column;
+            column += usize::from(symbol.cell_width());
+            if start < state.horizontal_offset {
+                continue;
+            }
+            let x = start - state.horizontal_offset;
+            if x >= width {
+                return;
+            }
+            let mut style = Style::new();
+            if matches.iter().any(|range| range.contains(&index)) {
+                style = style.patch(self.search_style);
+            }
+            if selection.contains(&TextPosition::new(row.line, index)) {
+                style = style.patch(self.selection_style);
+            }
+            let column_x = area.x + u16::try_from(x).unwrap_or(u16::MAX);
+            buf.set_stringn(column_x, area.y, symbol, width - x, style);
+        }
+
+        // show a selected line break as a selected cell after the end of the line
+        let line_break = TextPosition::new(row.line, text.len());
+        let x = column.wrapping_sub(state.horizontal_offset);
+        if row.range.end == text.len()
+            && row.line + 1 < state.lines.len()
+            && selection.contains(&line_break)
+            && column >= state.horizontal_offset
+            && x < width
+        {
+            let column_x = area.x + u16::try_from(x).unwrap_or(u16::MAX);
+            buf.set_style(Rect::new(column_x, area.y, 1, 1), self.selection_style);
+        }
+    }
+}
+
+impl Styled for TextArea<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl StatefulWidget for TextArea<'_> {
+    type State = TextAreaState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &TextArea<'_> {
+    type State = TextAreaState;
+
+    #[expect(clippy::string_slice)] // Is safe as the cursor and the rows are on grapheme boundaries
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let area = self.block.inner_if_some(area);
+        state.cursor_position = None;
+        if area.is_empty() {
+            return;
+        }
+
+        // the gutter is dropped when there is no room left for the text
+        let digits = state.lines.len().max(1).ilog10() as usize + 1;
+        let gutter_width = if self.line_numbers {
+            u16::try_from(digits + 1)
+                .ok()
+                .filter(|&gutter_width| gutter_width < area.width)
+                .unwrap_or_default()
+        } else {
+            0
+        };
+        let gutter = Rect {
+            width: gutter_width,
+            ..area
+        };
+        let area = Rect {
+            x: area.x + gutter_width,
+            width: area.width - gutter_width,
+            ..area
+        };
+
+        let rows = self.rows(&state.lines, area.width);
+        let (width, height) = (usize::from(area.width), usize::from(area.height));
+        let columns = if self.wrap.is_some() {
+            width
+        } else {
+            state
+                .lines
+                .iter()
+                .map(|line| display_width(line) + 1)
+                .max()
+                .unwrap_or(1)
+        };
+        state.content_size = (rows.len(), columns);
+        state.viewport_size = (height, width);
+
+        // the last row of the cursor's line that starts at or before the cursor
+        let cursor = state.cursor;
+        let cursor_row = rows
+            .partition_point(|row| (row.line, row.range.start) <= (cursor.line, cursor.index))
+            .saturating_sub(1);
+        let cursor_column = rows.get(cursor_row).map_or(0, |row| {
+            display_width(&state.lines[cursor.line][row.range.start..cursor.index])
+        });
+
+        if state.follow_cursor {
+            state.vertical_offset = state
+                .vertical_offset
+                .min(cursor_row)
+                .max((cursor_row + 1).saturating_sub(height));
+            state.horizontal_offset = state
+                .horizontal_offset
+                .min(cursor_column)
+                .max((cursor_column + 1).saturating_sub(width));
+        }
+        state.vertical_offset = state.vertical_offset.min(rows.len().saturating_sub(height));
+        state.horizontal_offset = state.horizontal_offset.min(columns.saturating_sub(width));
+
+        buf.set_style(gutter, self.line_number_style);
+        let visible_rows = rows.iter().enumerate().skip(state.vertical_offset);
+        for ((index, row), y) in visible_rows.zip(area.rows().map(|row| row.y)) {
+            let is_first_row = index == 0 || rows[index - 1].line != row.line;
+            if gutter_width > 0 && is_first_row {
+                let number = format!("{:>digits$}", row.line + 1);
+                buf.set_string(gutter.x, y, number, self.line_number_style);
+            }
+            self.render_row(
+                row,
+                Rect {
+                    y,
+                    height: 1,
+                    ..area
+                },
+                buf,
+                state,
+            );
+        }
+
+        let x = cursor_column.checked_sub(state.horizontal_offset);
+        let y = cursor_row.checked_sub(state.vertical_offset);
+        if let (Some(x), Some(y)) = (x, y.filter(|&y| y < height)) {
+            let x = u16::try_from(x.min(width - 1)).unwrap_or(u16::MAX);
+            let y = u16::try_from(y).unwrap_or(u16::MAX);
+            state.cursor_position = Some(Position::new(area.x + x, area.y + y));
+        }
+    }
+}
+
+/// Splits a line into the byte ranges of its wrapped rows.
+///
+/// The whitespace that the wrapper drops at the end of a row is not part of any range. The first
+/// range always starts at zero, so that every position in the line falls in or after a range.
+fn wrapped_ranges(text: &str, width: u16, mode: WrapMode) -> Vec<Range<usize>> {
+    let graphemes = text
+        .graphemes(true)
+        .map(|symbol| StyledGrapheme::new(symbol, Style::new()));
+    let lines = iter::once((graphemes, Alignment::Left, TextDirection::LeftToRight));
+    let mut ranges = match mode {
+        WrapMode::Word => composed_ranges(WordWrapper::new(lines, width, false), text),
+        WrapMode::Character => composed_ranges(CharWrapper::new(lines, width, false), text),
+    };
+    match ranges.first_mut() {
+        Some(first) => first.start = 0,
+        None => ranges.push(0..text.len()),
+    }
+    ranges
+}
+
+/// Returns the byte ranges of the rows of a composer that wraps the graphemes of `text`.
+///
+/// The rows are matched against the graphemes of `text` in order, skipping the whitespace that the
+/// composer drops between rows.
+fn composed_ranges<'a>(mut composer: impl LineComposer<'a>, text: &'a str) -> Vec<Range<usize>> {
+    let graphemes = text.grapheme_indices(true).collect::<Vec<_>>();
+    let mut next = 0;
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    while let Some(line) = composer.next_line() {
+        let row = line.graphemes;
+        let is_row_at = |start: usize| {
+            graphemes
+                .get(start..start + row.len())
+                .is_some_and(|source| {
+                    source
+                        .iter()
+                        .map(|(_, g)| *g)
+                        .eq(row.iter().map(|g| g.symbol))
+                })
+        };
+        let mut start = next;
+        while !is_row_at(start)
+            && graphemes
+                .get(start)
+                .is_some_and(|(_, g)| g.trim().is_empty())
+        {
+            start += 1;
+        }
+        let offset = |index: usize| {
+            graphemes
+                .get(index)
+                .map_or(text.len(), |(offset, _)| *offset)
+        };
+        next = (start + row.len()).min(graphemes.len());
+        let range = if row.is_empty() {
+            let previous_end = ranges.last().map_or(0, |range| range.end);
+            previous_end..previous_end
+        } else {
+            offset(start)..offset(next)
+        };
+        ranges.push(range);
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use ratatui_core::style::Stylize;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::scrollbar::ScrollbarState;
+
+    fn render(text_area: &TextArea, state: &mut TextAreaState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        StatefulWidget::render(text_area, buf.area, &mut buf, state);
+        buf
+    }
+
+    #[rstest]
+    #[case::empty("", 4, WrapMode::Word, vec![0..0])]
+    #[case::words("hello world foo", 6, WrapMode::Word, vec![0..5, 6..11, 12..15])]
+    #[case::spaces("ab   cd", 3, WrapMode::Word, vec![0..2, 4..7])]
+    #[case::wide("コンピュータ", 4, WrapMode::Word, vec![0..6, 6..12, 12..18])]
+    #[case::characters("abcdef", 4, WrapMode::Character, vec![0..4, 4..6])]
+    fn wrapped_ranges_of_rows(
+        #[case] text: &str,
+        #[case] width: u16,
+        #[case] mode: WrapMode,
+        #[case] expected: Vec<Range<usize>>,
+    ) {
+        assert_eq!(wrapped_ranges(text, width, mode), expected);
+    }
+
+    #[test]
+    fn render_lines() {
+        let mut state = TextAreaState::new().with_text("hello\nworld");
+        let buf = render(&TextArea::new(), &mut state, 6, 3);
+        assert_eq!(buf, Buffer::with_lines(["hello ", "world ", "      "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(0, 0)));
+    }
+
+    #[rstest]
+    #[case::top(TextPosition::new(0, 0), ["line1", "line2"], (0, 0))]
+    #[case::bottom(TextPosition::new(4, 0), ["line4", "line5"], (0, 1))]
+    #[case::scrolled_right(TextPosition::new(4, 5), ["ine4 ", "ine5 "], (4, 1))]
+    fn render_scrolls_to_cursor(
+        #[case] cursor: TextPosition,
+        #[case] expected: [&str; 2],
+        #[case] position: (u16, u16),
+    ) {
+        let mut state = TextAreaState::new().with_text("line1\nline2\nline3\nline4\nline5");
+        state.set_cursor(cursor);
+        let buf = render(&TextArea::new(), &mut state, 5, 2);
+        assert_eq!(buf, Buffer::with_lines(expected));
+        assert_eq!(state.cursor_position(), Some(position.into()));
+    }
+
+    #[test]
+    fn render_scrolled_away_from_cursor() {
+        let mut state = TextAreaState::new().with_text("a\nb\nc\nd");
+        render(&TextArea::new(), &mut state, 3, 2);
+        state.scroll_down(5);
+        let buf = render(&TextArea::new(), &mut state, 3, 2);
+        assert_eq!(buf, Buffer::with_lines(["c  ", "d  "]));
+        assert_eq!(state.vertical_offset(), 2);
+        assert_eq!(state.cursor_position(), None);
+        assert_eq!(
+            state.scrollbar_state(),
+            ScrollbarState::new(3)
+                .viewport_content_length(2)
+                .position(2)
+        );
+    }
+
+    #[rstest]
+    #[case::word(WrapMode::Word, ["hello ", "world ", "foo   "], (3, 2))]
+    #[case::character(WrapMode::Character, ["hello ", "world ", "foo   "], (3, 2))]
+    fn render_wrapped(
+        #[case] mode: WrapMode,
+        #[case] expected: [&str; 3],
+        #[case] position: (u16, u16),
+    ) {
+        let mut state = TextAreaState::new().with_text("hello world\nfoo");
+        state.move_cursor(TextAreaMove::Bottom);
+        let buf = render(&TextArea::new().wrap(mode), &mut state, 6, 3);
+        assert_eq!(buf, Buffer::with_lines(expected));
+        assert_eq!(state.cursor_position(), Some(position.into()));
+    }
+
+    #[test]
+    fn render_wrapped_cursor_at_row_end() {
+        let mut state = TextAreaState::new().with_text("hello world");
+        state.set_cursor(TextPosition::new(0, 5));
+        render(&TextArea::new().wrap(WrapMode::Word), &mut state, 6, 2);
+        assert_eq!(state.cursor_position(), Some(Position::new(5, 0)));
+        state.move_cursor(TextAreaMove::Right);
+        render(&TextArea::new().wrap(WrapMode::Word), &mut state, 6, 2);
+        assert_eq!(state.cursor_position(), Some(Position::new(0, 1)));
+    }
+
+    #[test]
+    fn render_line_numbers() {
+        let text = (1..=10)
+            .map(|i| format!("{i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut state = TextAreaState::new().with_text(&text);
+        state.move_cursor(TextAreaMove::Bottom);
+        let text_area = TextArea::new()
+            .line_numbers(true)
+            .line_number_style(Style::new().blue());
+        let buf = render(&text_area, &mut state, 6, 2);
+        let mut expected = Buffer::with_lines([" 9 9  ", "10 10 "]);
+        expected.set_style(Rect::new(0, 0, 3, 2), Style::new().blue());
+        assert_eq!(buf, expected);
+        assert_eq!(state.cursor_position(), Some(Position::new(5, 1)));
+    }
+
+    #[test]
+    fn render_selection_and_matches() {
+        let mut state = TextAreaState::new().with_text("abc\nabc");
+        state.set_search_pattern("b");
+        state.set_cursor(TextPosition::new(0, 2));
+        state.select(TextAreaMove::Down);
+        let text_area = TextArea::new()
+            .selection_style(Style::new().red())
+            .search_style(Style::new().on_blue());
+        let buf = render(&text_area, &mut state, 4, 2);
+        let mut expected = Buffer::with_lines(["abc ", "abc "]);
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().on_blue());
+        expected.set_style(Rect::new(1, 1, 1, 1), Style::new().on_blue());
+        // the selection includes the line break
+        expected.set_style(Rect::new(2, 0, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(0, 1, 2, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_in_block() {
+        let mut state = TextAreaState::new().with_text("hi");
+        let buf = render(&TextArea::new().block(Block::bordered()), &mut state, 5, 3);
+        assert_eq!(buf, Buffer::with_lines(["┌───┐", "│hi │", "└───┘"]));
+        assert_eq!(state.cursor_position(), Some(Position::new(1, 1)));
+    }
+
+    #[test]
+    fn render_in_empty_area() {
+        let mut state = TextAreaState::new().with_text("hi");
+        render(&TextArea::new(), &mut state, 0, 0);
+        assert_eq!(state.cursor_position(), None);
+    }
+
+    #[test]
+    fn can_be_stylized() {
+        assert_eq!(
+            TextArea::new().red().on_black().style,
+            Style::new().red().on_black()
+        );
+    }
+}
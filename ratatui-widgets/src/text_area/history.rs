@@ -0,0 +1,130 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::TextPosition;
+
+/// The maximum number of edits kept by [`History`].
+const HISTORY_SIZE: usize = 256;
+
+/// The undo and redo stacks of a [`TextAreaState`](super::TextAreaState).
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct History {
+    undo: Vec<Edit>,
+    redo: Vec<Edit>,
+    /// Whether the next edit may be merged into the last one
+    mergeable: bool,
+}
+
+/// A replacement of `removed` by `inserted` at `start`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Edit {
+    /// The position of the start of the replaced text
+    pub start: TextPosition,
+    /// The text that was removed
+    pub removed: String,
+    /// The text that was inserted
+    pub inserted: String,
+    /// The position of the cursor before the edit
+    pub cursor: TextPosition,
+}
+
+impl Edit {
+    /// The end of the inserted text, i.e. the range to replace to undo the edit.
+    pub fn inserted_end(&self) -> TextPosition {
+        end_of(self.start, &self.inserted)
+    }
+
+    /// The end of the removed text, i.e. the range to replace to redo the edit.
+    pub fn removed_end(&self) -> TextPosition {
+        end_of(self.start, &self.removed)
+    }
+}
+
+impl History {
+    /// Records an edit, merging it into the previous one when both are part of the same run of
+    /// typing or deleting.
+    pub fn push(&mut self, edit: Edit) {
+        self.redo.clear();
+        if self.mergeable
+            && let Some(last) = self.undo.last_mut()
+            && merge(last, &edit)
+        {
+            return;
+        }
+        if self.undo.len() == HISTORY_SIZE {
+            self.undo.remove(0);
+        }
+        self.undo.push(edit);
+        self.mergeable = true;
+    }
+
+    /// Prevents the next edit from being merged into the last one.
+    pub const fn seal(&mut self) {
+        self.mergeable = false;
+    }
+
+    /// Moves the last edit to the redo stack and returns it.
+    pub fn undo(&mut self) -> Option<&Edit> {
+        self.mergeable = false;
+        let edit = self.undo.pop()?;
+        self.redo.push(edit);
+        self.redo.last()
+    }
+
+    /// Moves the last undone edit back to the undo stack and returns it.
+    pub fn redo(&mut self) -> Option<&Edit> {
+        self.mergeable = false;
+        let edit = self.redo.pop()?;
+        self.undo.push(edit);
+        self.undo.last()
+    }
+
+    pub const fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub const fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+}
+
+/// Merges `edit` into `last` if it continues typing or deleting in the same place.
+///
+/// Typing is grouped into words: an insertion is not merged after text that ends with whitespace,
+/// so that undo removes one word at a time.
+fn merge(last: &mut Edit, edit: &Edit) -> bool {
+    let typing = last.removed.is_empty() && edit.removed.is_empty();
+    let deleting = last.inserted.is_empty() && edit.inserted.is_empty();
+    if typing
+        && edit.start == last.inserted_end()
+        && !edit.inserted.contains('\n')
+        && !last.inserted.ends_with(char::is_whitespace)
+    {
+        last.inserted.push_str(&edit.inserted);
+        true
+    } else if deleting && edit.removed_end() == last.start && !edit.removed.contains('\n') {
+        // deleting backward
+        last.removed.insert_str(0, &edit.removed);
+        last.start = edit.start;
+        true
+    } else if deleting && edit.start == last.start && !edit.removed.contains('\n') {
+        // deleting forward
+        last.removed.push_str(&edit.removed);
+        true
+    } else {
+        false
+    }
+}
+
+/// Returns the position after `text` when it is inserted at `start`.
+pub fn end_of(start: TextPosition, text: &str) -> TextPosition {
+    match text.rfind('\n') {
+        Some(newline) => TextPosition::new(
+            start.line + text.matches('\n').count(),
+            text.len() - newline - 1,
+        ),
+        None => TextPosition::new(start.line, start.index + text.len()),
+    }
+}
@@ -0,0 +1,1253 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use ratatui_core::buffer::CellWidth;
+use ratatui_core::input::{KeyCode, KeyEvent};
+use ratatui_core::layout::Position;
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::history::{Edit, History};
+use crate::scrollbar::ScrollbarState;
+
+/// A position in the text of a [`TextAreaState`]
+///
+/// A position is made of the index of a line and a byte index into that line. Positions are
+/// ordered by line and then by index, so a [`Range`] of positions describes a span of text.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextPosition {
+    /// The index of the line
+    pub line: usize,
+    /// The byte index in the line
+    pub index: usize,
+}
+
+impl TextPosition {
+    /// Creates a new position from a line and a byte index in that line.
+    pub const fn new(line: usize, index: usize) -> Self {
+        Self { line, index }
+    }
+}
+
+/// State of the [`TextArea`] widget
+///
+/// The state holds the lines of text being edited, the cursor, the selection, the scroll offsets,
+/// the undo and redo history and the search pattern. The cursor is a [`TextPosition`] whose byte
+/// index always falls on a grapheme boundary.
+///
+/// Keys can be passed to [`TextAreaState::handle_key`], which implements the usual editing keys
+/// as well as the emacs movement shortcuts and undo / redo. All the editing operations are also
+/// available as methods. Consecutive characters that are typed or deleted are undone together, one
+/// word at a time.
+///
+/// When rendered, the state scrolls so that the cursor is visible and stores the position of the
+/// cursor on screen, which can be passed to `Frame::set_cursor_position`. The view can also be
+/// scrolled independently of the cursor, e.g. with the mouse wheel, and the scroll position is
+/// available as a [`ScrollbarState`] to render a [`Scrollbar`] next to the text area.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::input::{KeyCode, KeyEvent, KeyModifiers};
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::{Scrollbar, ScrollbarOrientation, TextArea, TextAreaState};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TextAreaState::new().with_text("Fix the\nbug");
+///
+/// state.handle_key(KeyEvent::from(KeyCode::Enter));
+/// state.handle_key(KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL));
+/// assert_eq!(state.text(), "Fix the\nbug");
+///
+/// frame.render_stateful_widget(TextArea::new(), area, &mut state);
+/// frame.render_stateful_widget(
+///     Scrollbar::new(ScrollbarOrientation::VerticalRight),
+///     area,
+///     &mut state.scrollbar_state(),
+/// );
+/// if let Some(position) = state.cursor_position() {
+///     frame.set_cursor_position(position);
+/// }
+/// # }
+/// ```
+///
+/// [`TextArea`]: super::TextArea
+/// [`Scrollbar`]: crate::scrollbar::Scrollbar
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawTextAreaState")
+)]
+pub struct TextAreaState {
+    pub(crate) lines: Vec<String>,
+    pub(crate) cursor: TextPosition,
+    pub(crate) anchor: Option<TextPosition>,
+    pub(crate) vertical_offset: usize,
+    pub(crate) horizontal_offset: usize,
+    /// Whether the view follows the cursor, or was scrolled away from it
+    pub(crate) follow_cursor: bool,
+    pub(crate) cursor_position: Option<Position>,
+    /// The number of rows and columns of the text, as of the last render
+    pub(crate) content_size: (usize, usize),
+    /// The number of rows and columns that were visible in the last render
+    pub(crate) viewport_size: (usize, usize),
+    pub(crate) search: Option<String>,
+    /// The display column that vertical movements try to keep
+    desired_column: Option<usize>,
+    history: History,
+}
+
+/// The fields of a [`TextAreaState`] as they are deserialized, before they are checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawTextAreaState {
+    lines: Vec<String>,
+    cursor: TextPosition,
+    anchor: Option<TextPosition>,
+    vertical_offset: usize,
+    horizontal_offset: usize,
+    follow_cursor: bool,
+    cursor_position: Option<Position>,
+    content_size: (usize, usize),
+    viewport_size: (usize, usize),
+    search: Option<String>,
+    desired_column: Option<usize>,
+    history: History,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawTextAreaState> for TextAreaState {
+    type Error = &'static str;
+
+    /// Rejects a state without lines, moves the cursor and the anchor into the text and to the
+    /// start of the grapheme they are in, and drops a history that does not match the text.
+    fn try_from(raw: RawTextAreaState) -> Result<Self, Self::Error> {
+        if raw.lines.is_empty() {
+            return Err("a text area state has at least one line");
+        }
+        let mut state = Self {
+            lines: raw.lines,
+            cursor: raw.cursor,
+            anchor: raw.anchor,
+            vertical_offset: raw.vertical_offset,
+            horizontal_offset: raw.horizontal_offset,
+            follow_cursor: raw.follow_cursor,
+            cursor_position: raw.cursor_position,
+            content_size: raw.content_size,
+            viewport_size: raw.viewport_size,
+            search: raw.search,
+            desired_column: raw.desired_column,
+            history: raw.history,
+        };
+        state.cursor = state.clamp(state.cursor);
+        state.anchor = state.anchor.map(|anchor| state.clamp(anchor));
+        if !state.history_matches_text() {
+            state.history = History::default();
+        }
+        Ok(state)
+    }
+}
+
+/// A movement of the cursor of a [`TextAreaState`].
+///
+/// See [`TextAreaState::move_cursor`] and [`TextAreaState::select`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TextAreaMove {
+    /// One grapheme to the left, wrapping to the end of the previous line.
+    Left,
+    /// One grapheme to the right, wrapping to the start of the next line.
+    Right,
+    /// To the start of the current or previous word.
+    WordLeft,
+    /// To the end of the current or next word.
+    WordRight,
+    /// One line up, keeping the column.
+    Up,
+    /// One line down, keeping the column.
+    Down,
+    /// One page up, where a page is the height of the text area when it was last rendered.
+    PageUp,
+    /// One page down, where a page is the height of the text area when it was last rendered.
+    PageDown,
+    /// To the start of the line.
+    LineStart,
+    /// To the end of the line.
+    LineEnd,
+    /// To the start of the text.
+    Top,
+    /// To the end of the text.
+    Bottom,
+}
+
+impl TextAreaMove {
+    const fn is_vertical(self) -> bool {
+        matches!(self, Self::Up | Self::Down | Self::PageUp | Self::PageDown)
+    }
+}
+
+impl Default for TextAreaState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TextAreaState {
+    /// Creates an empty state.
+    pub fn new() -> Self {
+        Self {
+            lines: vec![String::new()],
+            cursor: TextPosition::default(),
+            anchor: None,
+            vertical_offset: 0,
+            horizontal_offset: 0,
+            follow_cursor: true,
+            cursor_position: None,
+            content_size: (0, 0),
+            viewport_size: (0, 0),
+            search: None,
+            desired_column: None,
+            history: History::default(),
+        }
+    }
+
+    /// Sets the text and moves the cursor to the start of it.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TextAreaState;
+    ///
+    /// let state = TextAreaState::new().with_text("hello\nworld");
+    /// assert_eq!(state.lines(), ["hello", "world"]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.set_text(text);
+        self
+    }
+
+    /// The lines of the text
+    ///
+    /// There is always at least one line, which is empty when the text is empty.
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// The text, with the lines joined by `\n`
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Returns `true` if the text is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lines.len() == 1 && self.lines[0].is_empty()
+    }
+
+    /// Replaces the text, moving the cursor to the start of it and clearing the selection and the
+    /// undo history.
+    ///
+    /// `\r\n` line endings are converted to `\n`, and other control characters are removed.
+    pub fn set_text(&mut self, text: &str) {
+        self.lines = sanitize(text).split('\n').map(String::from).collect();
+        self.cursor = TextPosition::default();
+        self.anchor = None;
+        self.vertical_offset = 0;
+        self.horizontal_offset = 0;
+        self.follow_cursor = true;
+        self.desired_column = None;
+        self.history = History::default();
+    }
+
+    /// The position of the cursor in the text
+    pub const fn cursor(&self) -> TextPosition {
+        self.cursor
+    }
+
+    /// Moves the cursor to a position in the text, clearing the selection.
+    ///
+    /// The position is clamped to the text and moved back to the start of the grapheme it is in.
+    pub fn set_cursor(&mut self, position: TextPosition) {
+        self.cursor = self.clamp(position);
+        self.anchor = None;
+        self.moved();
+    }
+
+    /// The range of the selected text, or `None` if nothing is selected
+    pub fn selection(&self) -> Option<Range<TextPosition>> {
+        let anchor = self.anchor?;
+        let range = anchor.min(self.cursor)..anchor.max(self.cursor);
+        (!range.is_empty()).then_some(range)
+    }
+
+    /// The selected text, with the lines joined by `\n`, or `None` if nothing is selected
+    pub fn selected_text(&self) -> Option<String> {
+        self.selection().map(|range| self.text_in(range))
+    }
+
+    /// Selects the whole text, leaving the cursor at the end.
+    pub fn select_all(&mut self) {
+        self.anchor = Some(TextPosition::default());
+        self.cursor = self.end();
+        self.moved();
+    }
+
+    /// The index of the first row that is displayed
+    ///
+    /// When the text is wrapped, a line can take up several rows.
+    pub const fn vertical_offset(&self) -> usize {
+        self.vertical_offset
+    }
+
+    /// The index of the first column that is displayed
+    ///
+    /// This is always zero when the text is wrapped.
+    pub const fn horizontal_offset(&self) -> usize {
+        self.horizontal_offset
+    }
+
+    /// The position of the cursor on screen when the text area was last rendered
+    ///
+    /// Returns `None` if the text area has not been rendered, or if the cursor was scrolled out of
+    /// view. Pass the position to `Frame::set_cursor_position` to show the terminal cursor.
+    pub const fn cursor_position(&self) -> Option<Position> {
+        self.cursor_position
+    }
+
+    /// Scrolls the view up by a number of rows without moving the cursor.
+    ///
+    /// The view follows the cursor again as soon as it is moved or the text is edited.
+    pub const fn scroll_up(&mut self, rows: usize) {
+        self.vertical_offset = self.vertical_offset.saturating_sub(rows);
+        self.follow_cursor = false;
+    }
+
+    /// Scrolls the view down by a number of rows without moving the cursor.
+    ///
+    /// The view follows the cursor again as soon as it is moved or the text is edited.
+    pub fn scroll_down(&mut self, rows: usize) {
+        let max_offset = self.content_size.0.saturating_sub(self.viewport_size.0);
+        self.vertical_offset = self.vertical_offset.saturating_add(rows).min(max_offset);
+        self.follow_cursor = false;
+    }
+
+    /// Scrolls the view left by a number of columns without moving the cursor.
+    pub const fn scroll_left(&mut self, columns: usize) {
+        self.horizontal_offset = self.horizontal_offset.saturating_sub(columns);
+        self.follow_cursor = false;
+    }
+
+    /// Scrolls the view right by a number of columns without moving the cursor.
+    pub fn scroll_right(&mut self, columns: usize) {
+        let max_offset = self.content_size.1.saturating_sub(self.viewport_size.1);
+        self.horizontal_offset = self
+            .horizontal_offset
+            .saturating_add(columns)
+            .min(max_offset);
+        self.follow_cursor = false;
+    }
+
+    /// The state of a vertical [`Scrollbar`] that matches the view of the last render
+    ///
+    /// [`Scrollbar`]: crate::scrollbar::Scrollbar
+    pub const fn scrollbar_state(&self) -> ScrollbarState {
+        let (rows, viewport_rows) = (self.content_size.0, self.viewport_size.0);
+        ScrollbarState::new(rows.saturating_sub(viewport_rows) + 1)
+            .viewport_content_length(viewport_rows)
+            .position(self.vertical_offset)
+    }
+
+    /// The state of a horizontal [`Scrollbar`] that matches the view of the last render
+    ///
+    /// [`Scrollbar`]: crate::scrollbar::Scrollbar
+    pub const fn horizontal_scrollbar_state(&self) -> ScrollbarState {
+        let (columns, viewport_columns) = (self.content_size.1, self.viewport_size.1);
+        ScrollbarState::new(columns.saturating_sub(viewport_columns) + 1)
+            .viewport_content_length(viewport_columns)
+            .position(self.horizontal_offset)
+    }
+
+    /// Moves the cursor, clearing the selection.
+    ///
+    /// When text is selected, moving one grapheme left or right moves the cursor to the start or
+    /// the end of the selection instead.
+    pub fn move_cursor(&mut self, movement: TextAreaMove) {
+        let target = match (self.selection(), movement) {
+            (Some(selection), TextAreaMove::Left) => selection.start,
+            (Some(selection), TextAreaMove::Right) => selection.end,
+            _ => self.target(movement),
+        };
+        self.anchor = None;
+        self.move_to(target, movement);
+    }
+
+    /// Moves the cursor, extending the selection.
+    pub fn select(&mut self, movement: TextAreaMove) {
+        self.anchor.get_or_insert(self.cursor);
+        self.move_to(self.target(movement), movement);
+    }
+
+    /// Inserts a character at the cursor, replacing the selection.
+    ///
+    /// `\n` inserts a line break, other control characters are ignored.
+    pub fn insert_char(&mut self, c: char) {
+        if c == '\n' || !c.is_control() {
+            self.insert_str(c.encode_utf8(&mut [0; 4]));
+        }
+    }
+
+    /// Inserts a line break at the cursor, replacing the selection.
+    pub fn insert_newline(&mut self) {
+        self.insert_str("\n");
+    }
+
+    /// Inserts a string at the cursor, replacing the selection.
+    ///
+    /// `\r\n` line endings are converted to `\n`, and other control characters are removed.
+    pub fn insert_str(&mut self, text: &str) {
+        let range = self.selection().unwrap_or(self.cursor..self.cursor);
+        self.edit(range, &sanitize(text));
+    }
+
+    /// Deletes the selection, or the grapheme before the cursor.
+    pub fn delete_backward(&mut self) {
+        let range = self
+            .selection()
+            .unwrap_or_else(|| self.target(TextAreaMove::Left)..self.cursor);
+        self.edit(range, "");
+    }
+
+    /// Deletes the selection, or the grapheme after the cursor.
+    pub fn delete_forward(&mut self) {
+        let range = self
+            .selection()
+            .unwrap_or_else(|| self.cursor..self.target(TextAreaMove::Right));
+        self.edit(range, "");
+    }
+
+    /// Deletes the selection, or the text from the start of the word before the cursor to the
+    /// cursor.
+    pub fn delete_word_backward(&mut self) {
+        let range = self
+            .selection()
+            .unwrap_or_else(|| self.target(TextAreaMove::WordLeft)..self.cursor);
+        self.edit(range, "");
+    }
+
+    /// Deletes the selection, or the text from the cursor to the end of the word after the
+    /// cursor.
+    pub fn delete_word_forward(&mut self) {
+        let range = self
+            .selection()
+            .unwrap_or_else(|| self.cursor..self.target(TextAreaMove::WordRight));
+        self.edit(range, "");
+    }
+
+    /// Deletes the text from the cursor to the end of the line, or the line break when the cursor
+    /// is at the end of the line.
+    pub fn delete_to_line_end(&mut self) {
+        let end = match self.target(TextAreaMove::LineEnd) {
+            end if end == self.cursor => self.target(TextAreaMove::Right),
+            end => end,
+        };
+        self.edit(self.cursor..end, "");
+    }
+
+    /// Deletes the text from the start of the line to the cursor.
+    pub fn delete_to_line_start(&mut self) {
+        self.edit(self.target(TextAreaMove::LineStart)..self.cursor, "");
+    }
+
+    /// Returns `true` if there is an edit to undo.
+    pub const fn can_undo(&self) -> bool {
+        self.history.can_undo()
+    }
+
+    /// Returns `true` if there is an undone edit to redo.
+    pub const fn can_redo(&self) -> bool {
+        self.history.can_redo()
+    }
+
+    /// Undoes the last edit, returning `false` if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(edit) = self.history.undo().cloned() else {
+            return false;
+        };
+        self.replace(edit.start..edit.inserted_end(), &edit.removed);
+        self.cursor = self.clamp(edit.cursor);
+        self.anchor = None;
+        self.moved();
+        true
+    }
+
+    /// Redoes the last undone edit, returning `false` if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(edit) = self.history.redo().cloned() else {
+            return false;
+        };
+        self.cursor = self.replace(edit.start..edit.removed_end(), &edit.inserted);
+        self.anchor = None;
+        self.moved();
+        true
+    }
+
+    /// The pattern that is searched for and highlighted, if any
+    pub fn search_pattern(&self) -> Option<&str> {
+        self.search.as_deref()
+    }
+
+    /// Sets the pattern to search for with [`Self::search_forward`] and
+    /// [`Self::search_backward`].
+    ///
+    /// The matches of the pattern are highlighted when the text area is rendered. The search is
+    /// case sensitive and does not match across line breaks. An empty pattern clears the search.
+    pub fn set_search_pattern<T: Into<String>>(&mut self, pattern: T) {
+        let pattern = pattern.into();
+        self.search = (!pattern.is_empty()).then_some(pattern);
+    }
+
+    /// Clears the search pattern.
+    pub fn clear_search_pattern(&mut self) {
+        self.search = None;
+    }
+
+    /// Selects the next match of the search pattern after the cursor, wrapping around to the
+    /// start of the text.
+    ///
+    /// Returns `false` if there is no search pattern or it does not match.
+    pub fn search_forward(&mut self) -> bool {
+        let found = self
+            .matches()
+            .find(|range| range.start >= self.cursor)
+            .or_else(|| self.matches().next());
+        self.select_match(found)
+    }
+
+    /// Selects the previous match of the search pattern before the cursor, wrapping around to the
+    /// end of the text.
+    ///
+    /// Returns `false` if there is no search pattern or it does not match.
+    pub fn search_backward(&mut self) -> bool {
+        let from = self
+            .selection()
+            .map_or(self.cursor, |selection| selection.start);
+        let found = self
+            .matches()
+            .filter(|range| range.start < from)
+            .last()
+            .or_else(|| self.matches().last());
+        self.select_match(found)
+    }
+
+    /// Handles a key press, returning `true` if the key was handled.
+    ///
+    /// The following keys are supported, where `C-` is the control key and `M-` is the alt key:
+    ///
+    /// | Key | Action |
+    /// |-----|--------|
+    /// | characters | [insert](Self::insert_char) |
+    /// | `Enter` | [insert a line break](Self::insert_newline) |
+    /// | `Left`, `C-b` / `Right`, `C-f` | move one grapheme |
+    /// | `C-Left`, `M-b` / `C-Right`, `M-f` | move one word |
+    /// | `Up`, `C-p` / `Down`, `C-n` | move one line |
+    /// | `PageUp` / `PageDown` | move one page |
+    /// | `Home`, `C-a` / `End`, `C-e` | move to the start / end of the line |
+    /// | `C-Home` / `C-End` | move to the start / end of the text |
+    /// | `Shift` + any of the above arrow, page, home or end keys | extend the selection |
+    /// | `Backspace`, `C-h` / `Delete`, `C-d` | [delete](Self::delete_backward) a grapheme |
+    /// | `C-Backspace`, `M-Backspace`, `C-w` / `C-Delete`, `M-d` | [delete](Self::delete_word_backward) a word |
+    /// | `C-u` / `C-k` | delete to the [start](Self::delete_to_line_start) / [end](Self::delete_to_line_end) of the line |
+    /// | `C-z` | [undo](Self::undo) |
+    /// | `C-y`, `C-Shift-z` | [redo](Self::redo) |
+    ///
+    /// Other keys, such as `Tab` or `Esc`, are left for the application to handle.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let word = key.is_ctrl() || key.is_alt();
+        let movement = match key.code {
+            KeyCode::Left if word => Some(TextAreaMove::WordLeft),
+            KeyCode::Right if word => Some(TextAreaMove::WordRight),
+            KeyCode::Home if key.is_ctrl() => Some(TextAreaMove::Top),
+            KeyCode::End if key.is_ctrl() => Some(TextAreaMove::Bottom),
+            KeyCode::Left => Some(TextAreaMove::Left),
+            KeyCode::Right => Some(TextAreaMove::Right),
+            KeyCode::Up => Some(TextAreaMove::Up),
+            KeyCode::Down => Some(TextAreaMove::Down),
+            KeyCode::PageUp => Some(TextAreaMove::PageUp),
+            KeyCode::PageDown => Some(TextAreaMove::PageDown),
+            KeyCode::Home => Some(TextAreaMove::LineStart),
+            KeyCode::End => Some(TextAreaMove::LineEnd),
+            _ => None,
+        };
+        if let Some(movement) = movement {
+            if key.is_shift() {
+                self.select(movement);
+            } else {
+                self.move_cursor(movement);
+            }
+            return true;
+        }
+        match key.code {
+            KeyCode::Char(c) if key.is_ctrl() && !key.is_alt() => match c {
+                'a' => self.move_cursor(TextAreaMove::LineStart),
+                'e' => self.move_cursor(TextAreaMove::LineEnd),
+                'b' => self.move_cursor(TextAreaMove::Left),
+                'f' => self.move_cursor(TextAreaMove::Right),
+                'p' => self.move_cursor(TextAreaMove::Up),
+                'n' => self.move_cursor(TextAreaMove::Down),
+                'h' => self.delete_backward(),
+                'd' => self.delete_forward(),
+                'w' => self.delete_word_backward(),
+                'u' => self.delete_to_line_start(),
+                'k' => self.delete_to_line_end(),
+                'z' if key.is_shift() => _ = self.redo(),
+                'Z' | 'y' => _ = self.redo(),
+                'z' => _ = self.undo(),
+                _ => return false,
+            },
+            KeyCode::Char(c) if key.is_alt() && !key.is_ctrl() => match c {
+                'b' => self.move_cursor(TextAreaMove::WordLeft),
+                'f' => self.move_cursor(TextAreaMove::WordRight),
+                'd' => self.delete_word_forward(),
+                _ => return false,
+            },
+            KeyCode::Char(c) if !key.is_ctrl() && !key.is_alt() => self.insert_char(c),
+            KeyCode::Enter => self.insert_newline(),
+            KeyCode::Backspace if word => self.delete_word_backward(),
+            KeyCode::Backspace => self.delete_backward(),
+            KeyCode::Delete if word => self.delete_word_forward(),
+            KeyCode::Delete => self.delete_forward(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// The byte ranges of the matches of the search pattern in a line.
+    pub(crate) fn line_matches(&self, line: usize) -> impl Iterator<Item = Range<usize>> + '_ {
+        let text = self.lines.get(line).map_or("", String::as_str);
+        self.search.as_deref().into_iter().flat_map(move |pattern| {
+            text.match_indices(pattern)
+                .map(|(start, found)| start..start + found.len())
+        })
+    }
+
+    /// The matches of the search pattern in the whole text.
+    fn matches(&self) -> impl Iterator<Item = Range<TextPosition>> + '_ {
+        (0..self.lines.len()).flat_map(move |line| {
+            self.line_matches(line).map(move |range| {
+                TextPosition::new(line, range.start)..TextPosition::new(line, range.end)
+            })
+        })
+    }
+
+    const fn select_match(&mut self, found: Option<Range<TextPosition>>) -> bool {
+        let Some(found) = found else {
+            return false;
+        };
+        self.anchor = Some(found.start);
+        self.cursor = found.end;
+        self.moved();
+        true
+    }
+
+    /// Returns the position of the cursor after a movement.
+    fn target(&self, movement: TextAreaMove) -> TextPosition {
+        let TextPosition { line, index } = self.cursor;
+        let last_line = self.lines.len() - 1;
+        let (before, after) = self.lines[line].split_at(index);
+        let page = self.viewport_size.0.max(1);
+        let target_line = match movement {
+            TextAreaMove::Up => line.checked_sub(1),
+            TextAreaMove::Down => (line < last_line).then_some(line + 1),
+            TextAreaMove::PageUp => line.checked_sub(page).or(Some(0)),
+            TextAreaMove::PageDown => Some((line + page).min(last_line)),
+            TextAreaMove::Left => {
+                return before.graphemes(true).next_back().map_or_else(
+                    || self.previous_line_end(),
+                    |g| TextPosition::new(line, index - g.len()),
+                );
+            }
+            TextAreaMove::Right => {
+                return after.graphemes(true).next().map_or_else(
+                    || self.next_line_start(),
+                    |g| TextPosition::new(line, index + g.len()),
+                );
+            }
+            TextAreaMove::WordLeft => {
+                if before.is_empty() {
+                    return self.previous_line_end();
+                }
+                let mut graphemes = before.grapheme_indices(true).rev().peekable();
+                while graphemes.next_if(|(_, g)| !is_word(g)).is_some() {}
+                while graphemes.next_if(|(_, g)| is_word(g)).is_some() {}
+                let index = graphemes.next().map_or(0, |(i, g)| i + g.len());
+                return TextPosition::new(line, index);
+            }
+            TextAreaMove::WordRight => {
+                if after.is_empty() {
+                    return self.next_line_start();
+                }
+                let mut graphemes = after.grapheme_indices(true).peekable();
+                while graphemes.next_if(|(_, g)| !is_word(g)).is_some() {}
+                while graphemes.next_if(|(_, g)| is_word(g)).is_some() {}
+                let index = graphemes
+                    .next()
+                    .map_or(self.lines[line].len(), |(i, _)| index + i);
+                return TextPosition::new(line, index);
+            }
+            TextAreaMove::LineStart => return TextPosition::new(line, 0),
+            TextAreaMove::LineEnd => return TextPosition::new(line, self.lines[line].len()),
+            TextAreaMove::Top => return TextPosition::default(),
+            TextAreaMove::Bottom => return self.end(),
+        };
+        // moving up from the first line or down from the last line moves to the start or the end
+        let Some(target_line) = target_line else {
+            return if line == 0 {
+                TextPosition::default()
+            } else {
+                self.end()
+            };
+        };
+        let column = self.desired_column.unwrap_or_else(|| display_width(before));
+        TextPosition::new(
+            target_line,
+            index_at_column(&self.lines[target_line], column),
+        )
+    }
+
+    fn previous_line_end(&self) -> TextPosition {
+        match self.cursor.line.checked_sub(1) {
+            Some(line) => TextPosition::new(line, self.lines[line].len()),
+            None => self.cursor,
+        }
+    }
+
+    const fn next_line_start(&self) -> TextPosition {
+        if self.cursor.line + 1 < self.lines.len() {
+            TextPosition::new(self.cursor.line + 1, 0)
+        } else {
+            self.cursor
+        }
+    }
+
+    /// The position at the end of the text.
+    fn end(&self) -> TextPosition {
+        let line = self.lines.len() - 1;
+        TextPosition::new(line, self.lines[line].len())
+    }
+
+    /// Clamps a position to the text and moves it to the start of the grapheme it is in.
+    fn clamp(&self, position: TextPosition) -> TextPosition {
+        let line = position.line.min(self.lines.len() - 1);
+        let text = &self.lines[line];
+        let index = if position.index >= text.len() {
+            text.len()
+        } else {
+            text.grapheme_indices(true)
+                .map(|(i, _)| i)
+                .take_while(|&i| i <= position.index)
+                .last()
+                .unwrap_or(0)
+        };
+        TextPosition::new(line, index)
+    }
+
+    /// Clamps a position to the text and moves it to the start of the character it is in.
+    fn clamp_to_char(&self, position: TextPosition) -> TextPosition {
+        let line = position.line.min(self.lines.len() - 1);
+        let text = &self.lines[line];
+        let mut index = position.index.min(text.len());
+        while !text.is_char_boundary(index) {
+            index -= 1;
+        }
+        TextPosition::new(line, index)
+    }
+
+    /// Whether a range is ordered and its positions are in the text, on character boundaries.
+    #[cfg(feature = "serde")]
+    fn contains_range(&self, range: &Range<TextPosition>) -> bool {
+        let contains = |position: TextPosition| {
+            self.lines
+                .get(position.line)
+                .is_some_and(|line| line.is_char_boundary(position.index))
+        };
+        range.start <= range.end && contains(range.start) && contains(range.end)
+    }
+
+    /// Whether every edit of the history can be undone and redone, i.e. whether each edit
+    /// replaces the text it recorded when the history is replayed.
+    #[cfg(feature = "serde")]
+    fn history_matches_text(&self) -> bool {
+        let mut state = Self {
+            lines: self.lines.clone(),
+            history: self.history.clone(),
+            ..Self::new()
+        };
+        while let Some(edit) = state.history.undo().cloned() {
+            let range = edit.start..edit.inserted_end();
+            if !state.contains_range(&range) || state.text_in(range.clone()) != edit.inserted {
+                return false;
+            }
+            state.replace(range, &edit.removed);
+        }
+        while let Some(edit) = state.history.redo().cloned() {
+            let range = edit.start..edit.removed_end();
+            if !state.contains_range(&range) || state.text_in(range.clone()) != edit.removed {
+                return false;
+            }
+            state.replace(range, &edit.inserted);
+        }
+        true
+    }
+
+    /// Moves the cursor to `target`, remembering the column for vertical movements.
+    #[expect(clippy::string_slice)] // Is safe as the cursor is kept on a grapheme boundary
+    fn move_to(&mut self, target: TextPosition, movement: TextAreaMove) {
+        let desired_column = movement.is_vertical().then(|| {
+            self.desired_column.unwrap_or_else(|| {
+                display_width(&self.lines[self.cursor.line][..self.cursor.index])
+            })
+        });
+        self.cursor = target;
+        self.moved();
+        self.desired_column = desired_column;
+    }
+
+    /// Resets the state that only lasts until the cursor moves.
+    const fn moved(&mut self) {
+        self.desired_column = None;
+        self.follow_cursor = true;
+        self.history.seal();
+    }
+
+    /// Replaces a range of the text, recording the edit in the history, and moves the cursor after
+    /// the inserted text.
+    fn edit(&mut self, range: Range<TextPosition>, text: &str) {
+        self.anchor = None;
+        if range.is_empty() && text.is_empty() {
+            return;
+        }
+        let edit = Edit {
+            start: range.start,
+            removed: self.text_in(range.clone()),
+            inserted: String::from(text),
+            cursor: self.cursor,
+        };
+        self.cursor = self.replace(range, text);
+        self.history.push(edit);
+        self.desired_column = None;
+        self.follow_cursor = true;
+    }
+
+    /// Replaces a range of the text and returns the position after the inserted text.
+    ///
+    /// The range is clamped to the text and to character boundaries.
+    fn replace(&mut self, range: Range<TextPosition>, text: &str) -> TextPosition {
+        let start = self.clamp_to_char(range.start);
+        let end = self.clamp_to_char(range.end).max(start);
+        let tail = self.lines[end.line].split_off(end.index);
+        self.lines.drain(start.line + 1..=end.line);
+        self.lines[start.line].truncate(start.index);
+
+        let mut segments = text.split('\n');
+        self.lines[start.line].push_str(segments.next().unwrap_or_default());
+        let last_line = start.line + text.matches('\n').count();
+        let next_line = start.line + 1;
+        self.lines
+            .splice(next_line..next_line, segments.map(String::from));
+        let end = TextPosition::new(last_line, self.lines[last_line].len());
+        self.lines[last_line].push_str(&tail);
+        end
+    }
+
+    /// The text in a range, with the lines joined by `\n`.
+    #[expect(clippy::string_slice)] // Is safe as positions are kept on grapheme boundaries
+    fn text_in(&self, range: Range<TextPosition>) -> String {
+        let Range { start, end } = range;
+        if start.line == end.line {
+            return String::from(&self.lines[start.line][start.index..end.index]);
+        }
+        let mut text = String::from(&self.lines[start.line][start.index..]);
+        for line in &self.lines[start.line + 1..end.line] {
+            text.push('\n');
+            text.push_str(line);
+        }
+        text.push('\n');
+        text.push_str(&self.lines[end.line][..end.index]);
+        text
+    }
+}
+
+/// Converts `\r\n` line endings to `\n` and removes the other control characters.
+fn sanitize(text: &str) -> String {
+    text.replace("\r\n", "\n")
+        .chars()
+        .filter(|&c| c == '\n' || !c.is_control())
+        .collect()
+}
+
+/// Returns `true` if a grapheme is part of a word for word-wise movement.
+fn is_word(grapheme: &str) -> bool {
+    grapheme
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// The number of columns taken by a string.
+pub(crate) fn display_width(text: &str) -> usize {
+    text.graphemes(true)
+        .map(|g| usize::from(g.cell_width()))
+        .sum()
+}
+
+/// The byte index of the grapheme displayed at a column, or the end of the text.
+fn index_at_column(text: &str, column: usize) -> usize {
+    let mut width = 0;
+    for (index, grapheme) in text.grapheme_indices(true) {
+        width += usize::from(grapheme.cell_width());
+        if width > column {
+            return index;
+        }
+    }
+    text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::input::KeyModifiers;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    fn type_str(state: &mut TextAreaState, text: &str) {
+        for c in text.chars() {
+            state.handle_key(KeyEvent::from(c));
+        }
+    }
+
+    #[test]
+    fn with_text() {
+        let state = TextAreaState::new().with_text("hello\r\nwor\tld\n");
+        assert_eq!(state.lines(), ["hello", "world", ""]);
+        assert_eq!(state.text(), "hello\nworld\n");
+        assert_eq!(state.cursor(), TextPosition::new(0, 0));
+        assert!(!state.is_empty());
+        assert!(TextAreaState::new().is_empty());
+    }
+
+    #[rstest]
+    #[case::inside_emoji(TextPosition::new(1, 2), TextPosition::new(1, 0))]
+    #[case::past_line_end(TextPosition::new(0, 100), TextPosition::new(0, 3))]
+    #[case::past_last_line(TextPosition::new(100, 1), TextPosition::new(1, 0))]
+    fn set_cursor(#[case] position: TextPosition, #[case] expected: TextPosition) {
+        let mut state = TextAreaState::new().with_text("abc\n😀x");
+        state.set_cursor(position);
+        assert_eq!(state.cursor(), expected);
+    }
+
+    #[rstest]
+    #[case::left(TextAreaMove::Left, TextPosition::new(1, 2))]
+    #[case::right(TextAreaMove::Right, TextPosition::new(1, 4))]
+    #[case::word_left(TextAreaMove::WordLeft, TextPosition::new(1, 0))]
+    #[case::word_right(TextAreaMove::WordRight, TextPosition::new(1, 6))]
+    #[case::up(TextAreaMove::Up, TextPosition::new(0, 3))]
+    #[case::down(TextAreaMove::Down, TextPosition::new(2, 2))]
+    #[case::page_up(TextAreaMove::PageUp, TextPosition::new(0, 3))]
+    #[case::page_down(TextAreaMove::PageDown, TextPosition::new(2, 2))]
+    #[case::line_start(TextAreaMove::LineStart, TextPosition::new(1, 0))]
+    #[case::line_end(TextAreaMove::LineEnd, TextPosition::new(1, 11))]
+    #[case::top(TextAreaMove::Top, TextPosition::new(0, 0))]
+    #[case::bottom(TextAreaMove::Bottom, TextPosition::new(2, 2))]
+    fn move_cursor(#[case] movement: TextAreaMove, #[case] expected: TextPosition) {
+        let mut state = TextAreaState::new().with_text("first\nsecond line\nab");
+        state.set_cursor(TextPosition::new(1, 3));
+        state.move_cursor(movement);
+        assert_eq!(state.cursor(), expected);
+    }
+
+    #[test]
+    fn move_across_lines() {
+        let mut state = TextAreaState::new().with_text("ab\ncd");
+        state.set_cursor(TextPosition::new(1, 0));
+        state.move_cursor(TextAreaMove::Left);
+        assert_eq!(state.cursor(), TextPosition::new(0, 2));
+        state.move_cursor(TextAreaMove::Right);
+        assert_eq!(state.cursor(), TextPosition::new(1, 0));
+        state.move_cursor(TextAreaMove::WordLeft);
+        assert_eq!(state.cursor(), TextPosition::new(0, 2));
+        state.move_cursor(TextAreaMove::Up);
+        assert_eq!(state.cursor(), TextPosition::new(0, 0));
+        state.move_cursor(TextAreaMove::Bottom);
+        state.move_cursor(TextAreaMove::Down);
+        assert_eq!(state.cursor(), TextPosition::new(1, 2));
+    }
+
+    #[test]
+    fn vertical_movement_keeps_column() {
+        // the column is kept in cells, so the cursor stays aligned across wide characters
+        let mut state = TextAreaState::new().with_text("abcdef\nab\n日本語");
+        state.set_cursor(TextPosition::new(0, 4));
+        state.move_cursor(TextAreaMove::Down);
+        assert_eq!(state.cursor(), TextPosition::new(1, 2));
+        state.move_cursor(TextAreaMove::Down);
+        assert_eq!(state.cursor(), TextPosition::new(2, 6));
+        state.move_cursor(TextAreaMove::Up);
+        state.move_cursor(TextAreaMove::Up);
+        assert_eq!(state.cursor(), TextPosition::new(0, 4));
+    }
+
+    #[test]
+    fn select() {
+        let mut state = TextAreaState::new().with_text("hello\nworld");
+        state.set_cursor(TextPosition::new(0, 3));
+        state.select(TextAreaMove::Down);
+        assert_eq!(
+            state.selection(),
+            Some(TextPosition::new(0, 3)..TextPosition::new(1, 3))
+        );
+        assert_eq!(state.selected_text().as_deref(), Some("lo\nwor"));
+
+        state.move_cursor(TextAreaMove::Left);
+        assert_eq!(state.cursor(), TextPosition::new(0, 3));
+        assert_eq!(state.selection(), None);
+
+        state.select_all();
+        assert_eq!(state.selected_text().as_deref(), Some("hello\nworld"));
+        state.insert_char('x');
+        assert_eq!(state.text(), "x");
+    }
+
+    #[test]
+    fn insert() {
+        let mut state = TextAreaState::new().with_text("ad");
+        state.set_cursor(TextPosition::new(0, 1));
+        state.insert_str("b\r\nc\u{7}");
+        assert_eq!(state.lines(), ["ab", "cd"]);
+        assert_eq!(state.cursor(), TextPosition::new(1, 1));
+        state.insert_newline();
+        assert_eq!(state.lines(), ["ab", "c", "d"]);
+        assert_eq!(state.cursor(), TextPosition::new(2, 0));
+        state.insert_char('\t');
+        assert_eq!(state.lines(), ["ab", "c", "d"]);
+    }
+
+    #[test]
+    fn delete() {
+        let mut state = TextAreaState::new().with_text("ab\ncd");
+        state.set_cursor(TextPosition::new(1, 0));
+        state.delete_backward();
+        assert_eq!(state.lines(), ["abcd"]);
+        assert_eq!(state.cursor(), TextPosition::new(0, 2));
+        state.delete_forward();
+        assert_eq!(state.lines(), ["abd"]);
+        state.move_cursor(TextAreaMove::LineEnd);
+        state.delete_forward();
+        assert_eq!(state.lines(), ["abd"]);
+    }
+
+    #[test]
+    fn delete_words_and_lines() {
+        let mut state = TextAreaState::new().with_text("foo bar baz\nqux");
+        state.set_cursor(TextPosition::new(0, 7));
+        state.delete_word_backward();
+        assert_eq!(state.lines(), ["foo  baz", "qux"]);
+        state.delete_word_forward();
+        assert_eq!(state.lines(), ["foo ", "qux"]);
+        state.delete_to_line_end();
+        assert_eq!(state.lines(), ["foo qux"]);
+        state.delete_to_line_end();
+        assert_eq!(state.lines(), ["foo "]);
+        state.delete_to_line_start();
+        assert_eq!(state.lines(), [""]);
+    }
+
+    #[test]
+    fn undo_redo() {
+        let mut state = TextAreaState::new();
+        type_str(&mut state, "hello world");
+        state.handle_key(KeyEvent::from(KeyCode::Enter));
+        type_str(&mut state, "again");
+        assert_eq!(state.text(), "hello world\nagain");
+        assert!(!state.can_redo());
+
+        // typing is undone one word at a time
+        assert!(state.undo());
+        assert_eq!(state.text(), "hello world\n");
+        assert!(state.undo());
+        assert_eq!(state.text(), "hello world");
+        assert!(state.undo());
+        assert_eq!(state.text(), "hello ");
+        assert_eq!(state.cursor(), TextPosition::new(0, 6));
+        assert!(state.undo());
+        assert_eq!(state.text(), "");
+        assert!(!state.undo());
+        assert!(!state.can_undo());
+
+        assert!(state.redo());
+        assert!(state.redo());
+        assert_eq!(state.text(), "hello world");
+        assert_eq!(state.cursor(), TextPosition::new(0, 11));
+
+        // a new edit clears the redo history
+        state.insert_char('!');
+        assert!(!state.redo());
+        assert_eq!(state.text(), "hello world!");
+    }
+
+    #[test]
+    fn undo_groups_deletions() {
+        let mut state = TextAreaState::new().with_text("hello world");
+        state.move_cursor(TextAreaMove::LineEnd);
+        for _ in 0..3 {
+            state.delete_backward();
+        }
+        state.move_cursor(TextAreaMove::LineStart);
+        state.delete_forward();
+        state.delete_forward();
+        assert_eq!(state.text(), "llo wo");
+
+        assert!(state.undo());
+        assert_eq!(state.text(), "hello wo");
+        assert_eq!(state.cursor(), TextPosition::new(0, 0));
+        assert!(state.undo());
+        assert_eq!(state.text(), "hello world");
+        assert_eq!(state.cursor(), TextPosition::new(0, 11));
+    }
+
+    #[test]
+    fn undo_replaced_selection() {
+        let mut state = TextAreaState::new().with_text("one\ntwo\nthree");
+        state.set_cursor(TextPosition::new(0, 1));
+        state.select(TextAreaMove::Down);
+        state.insert_str("X\nY");
+        assert_eq!(state.text(), "oX\nYwo\nthree");
+        assert!(state.undo());
+        assert_eq!(state.text(), "one\ntwo\nthree");
+        assert!(state.redo());
+        assert_eq!(state.text(), "oX\nYwo\nthree");
+        assert_eq!(state.cursor(), TextPosition::new(1, 1));
+    }
+
+    #[test]
+    fn undo_clamps_edits_outside_the_text() {
+        let mut state = TextAreaState::new().with_text("é");
+        state.history.push(Edit {
+            start: TextPosition::new(0, 1),
+            removed: String::from("x"),
+            inserted: String::new(),
+            cursor: TextPosition::new(5, 9),
+        });
+        assert!(state.undo());
+        assert_eq!(state.text(), "xé");
+        assert_eq!(state.cursor(), TextPosition::new(0, 3));
+    }
+
+    #[test]
+    fn search() {
+        let mut state = TextAreaState::new().with_text("foo bar\nbar foo\nfoo");
+        assert!(!state.search_forward());
+
+        state.set_search_pattern("foo");
+        assert_eq!(state.search_pattern(), Some("foo"));
+        assert!(state.search_forward());
+        assert_eq!(
+            state.selection(),
+            Some(TextPosition::new(0, 0)..TextPosition::new(0, 3))
+        );
+        assert!(state.search_forward());
+        assert_eq!(
+            state.selection(),
+            Some(TextPosition::new(1, 4)..TextPosition::new(1, 7))
+        );
+        assert!(state.search_forward());
+        assert!(state.search_forward());
+        assert_eq!(state.selected_text().as_deref(), Some("foo"));
+        assert_eq!(state.cursor(), TextPosition::new(0, 3));
+
+        assert!(state.search_backward());
+        assert_eq!(state.cursor(), TextPosition::new(2, 3));
+        assert!(state.search_backward());
+        assert_eq!(state.cursor(), TextPosition::new(1, 7));
+
+        state.set_search_pattern("baz");
+        assert!(!state.search_forward());
+        state.set_search_pattern("");
+        assert_eq!(state.search_pattern(), None);
+    }
+
+    #[test]
+    fn scroll() {
+        let mut state = TextAreaState::new().with_text("a\nb\nc\nd\ne");
+        state.content_size = (5, 2);
+        state.viewport_size = (2, 1);
+        state.scroll_down(10);
+        assert_eq!(state.vertical_offset(), 3);
+        assert!(!state.follow_cursor);
+        assert_eq!(
+            state.scrollbar_state(),
+            ScrollbarState::new(4)
+                .viewport_content_length(2)
+                .position(3)
+        );
+        state.scroll_up(1);
+        assert_eq!(state.vertical_offset(), 2);
+        state.scroll_right(10);
+        assert_eq!(state.horizontal_offset(), 1);
+        state.scroll_left(10);
+        assert_eq!(state.horizontal_offset(), 0);
+
+        state.move_cursor(TextAreaMove::Down);
+        assert!(state.follow_cursor);
+    }
+
+    #[rstest]
+    #[case::ctrl_a(ctrl('a'), TextPosition::new(0, 0))]
+    #[case::ctrl_e(ctrl('e'), TextPosition::new(0, 5))]
+    #[case::ctrl_n(ctrl('n'), TextPosition::new(1, 2))]
+    #[case::ctrl_home(
+        KeyEvent::new(KeyCode::Home, KeyModifiers::CONTROL),
+        TextPosition::new(0, 0)
+    )]
+    #[case::ctrl_end(
+        KeyEvent::new(KeyCode::End, KeyModifiers::CONTROL),
+        TextPosition::new(1, 5)
+    )]
+    fn handle_key_moves(#[case] key: KeyEvent, #[case] expected: TextPosition) {
+        let mut state = TextAreaState::new().with_text("hello\nworld");
+        state.set_cursor(TextPosition::new(0, 2));
+        assert!(state.handle_key(key));
+        assert_eq!(state.cursor(), expected);
+    }
+
+    #[test]
+    fn handle_key_edits() {
+        let mut state = TextAreaState::new().with_text("hello");
+        state.move_cursor(TextAreaMove::LineEnd);
+        assert!(state.handle_key(KeyEvent::new(KeyCode::Home, KeyModifiers::SHIFT)));
+        assert_eq!(state.selected_text().as_deref(), Some("hello"));
+        assert!(state.handle_key(KeyEvent::from('x')));
+        assert!(state.handle_key(ctrl('z')));
+        assert_eq!(state.text(), "hello");
+        assert!(state.handle_key(ctrl('y')));
+        assert_eq!(state.text(), "x");
+        assert!(state.handle_key(ctrl('z')));
+        assert!(state.handle_key(KeyEvent::new(
+            KeyCode::Char('z'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT
+        )));
+        assert_eq!(state.text(), "x");
+        assert!(!state.handle_key(KeyEvent::from(KeyCode::Tab)));
+        assert!(!state.handle_key(KeyEvent::from(KeyCode::Esc)));
+    }
+}
@@ -0,0 +1,424 @@
+//! The [`TextInput`] widget is used to edit a single line of text.
+
+use alloc::borrow::Cow;
+
+use ratatui_core::buffer::{Buffer, CellWidth};
+use ratatui_core::layout::{Position, Rect};
+use ratatui_core::style::{Modifier, Style, Styled, Theme};
+use ratatui_core::text::{Line, Masked};
+use ratatui_core::widgets::{StatefulWidget, Widget};
+use unicode_segmentation::UnicodeSegmentation;
+
+pub use self::state::{CursorMove, TextInputState};
+use crate::block::{Block, BlockExt};
+
+const DEFAULT_SELECTION_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_PLACEHOLDER_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+
+mod state;
+
+/// A widget to edit a single line of text
+///
+/// The text, the cursor and the selection are stored in a [`TextInputState`], which also handles
+/// keyboard input. The input scrolls horizontally to keep the cursor visible, and the position of
+/// the cursor is stored in the state when rendered so that it can be passed to
+/// `Frame::set_cursor_position`.
+///
+/// A placeholder is shown when the value is empty, and the value can be [masked](TextInput::mask)
+/// to hide passwords.
+///
+/// By default the selection is reversed and the placeholder is dimmed. The styles can also be set
+/// from the `text_input`, `text_input.selection` and `text_input.placeholder` keys of a [`Theme`]
+/// with [`TextInput::theme`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::{Block, TextInput, TextInputState};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TextInputState::new();
+///
+/// let input = TextInput::new()
+///     .block(Block::bordered().title("Password"))
+///     .placeholder("Enter your password")
+///     .mask('*');
+///
+/// frame.render_stateful_widget(&input, area, &mut state);
+/// if let Some(position) = state.cursor_position() {
+///     frame.set_cursor_position(position);
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TextInput<'a> {
+    /// An optional block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Style used as a base style for the widget
+    style: Style,
+    /// Style used to render the selected text
+    selection_style: Style,
+    /// Text displayed when the value is empty
+    placeholder: Option<Line<'a>>,
+    /// Style used to render the placeholder
+    placeholder_style: Style,
+    /// Character displayed instead of each character of the value
+    mask: Option<char>,
+}
+
+impl Default for TextInput<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> TextInput<'a> {
+    /// Creates a new text input.
+    pub const fn new() -> Self {
+        Self {
+            block: None,
+            style: Style::new(),
+            selection_style: DEFAULT_SELECTION_STYLE,
+            placeholder: None,
+            placeholder_style: DEFAULT_PLACEHOLDER_STYLE,
+            mask: None,
+        }
+    }
+
+    /// Wraps the input with a [`Block`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected text
+    ///
+    /// The selection style is patched on top of the base style.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selection_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selection_style = style.into();
+        self
+    }
+
+    /// Sets the text displayed when the value is empty
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder<T: Into<Line<'a>>>(mut self, placeholder: T) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the style of the placeholder
+    ///
+    /// The placeholder style is patched on top of the base style.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.placeholder_style = style.into();
+        self
+    }
+
+    /// Sets the styles of the input from a [`Theme`]
+    ///
+    /// The base, selection and placeholder styles are replaced by the `text_input`,
+    /// `text_input.selection` and `text_input.placeholder` styles of the theme, patched on top of
+    /// the default selection and placeholder styles.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style("text_input");
+        self.selection_style = DEFAULT_SELECTION_STYLE.patch(theme.style("text_input.selection"));
+        self.placeholder_style =
+            DEFAULT_PLACEHOLDER_STYLE.patch(theme.style("text_input.placeholder"));
+        self
+    }
+
+    /// Masks the value, displaying `mask_char` for each of its characters
+    ///
+    /// The value is masked in the same way as [`Masked`], which is useful for password fields.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn mask(mut self, mask_char: char) -> Self {
+        self.mask = Some(mask_char);
+        self
+    }
+}
+
+impl Styled for TextInput<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Widget for TextInput<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &TextInput<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = TextInputState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for TextInput<'_> {
+    type State = TextInputState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &TextInput<'_> {
+    type State = TextInputState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let area = self.block.inner_if_some(area);
+        state.cursor_position = None;
+        if area.is_empty() {
+            return;
+        }
+
+        if state.value.is_empty() {
+            state.offset = 0;
+            if let Some(placeholder) = &self.placeholder {
+                let placeholder = placeholder.clone().patch_style(self.placeholder_style);
+                buf.set_line(area.x, area.y, &placeholder, area.width);
+            }
+            state.cursor_position = Some(area.as_position());
+            return;
+        }
+
+        let masked = self
+            .mask
+            .map(|mask_char| Masked::new(&state.value, mask_char));
+        let graphemes = DisplayGraphemes::new(&state.value, masked.as_ref());
+        let cursor_column: usize = graphemes
+            .clone()
+            .take_while(|(range, _)| range.start < state.cursor)
+            .map(|(_, symbol)| usize::from(symbol.cell_width()))
+            .sum();
+        let text_width: usize = graphemes
+            .clone()
+            .map(|(_, symbol)| usize::from(symbol.cell_width()))
+            .sum();
+
+        // keep the cursor visible, and keep the text filling the area when it is scrolled
+        let width = usize::from(area.width);
+        state.offset = state
+            .offset
+            .min(cursor_column)
+            .max((cursor_column + 1).saturating_sub(width))
+            .min((text_width + 1).saturating_sub(width));
+
+        let selection = state.selection().unwrap_or_default();
+        let mut column = 0;
+        for (range, symbol) in graphemes {
+            let start = column;
+            column += usize::from(symbol.cell_width());
+            if start < state.offset {
+                continue;
+            }
+            let x = start - state.offset;
+            if x >= width {
+                break;
+            }
+            let style = if selection.contains(&range.start) {
+                self.selection_style
+            } else {
+                Style::new()
+            };
+            let column_x = area.x + u16::try_from(x).unwrap_or(u16::MAX);
+            buf.set_stringn(column_x, area.y, symbol, width - x, style);
+        }
+
+        let cursor_x = (cursor_column - state.offset).min(width - 1);
+        let cursor_x = u16::try_from(cursor_x).unwrap_or(u16::MAX);
+        state.cursor_position = Some(Position::new(area.x + cursor_x, area.y));
+    }
+}
+
+/// The graphemes displayed for a value, with their byte ranges in the value.
+///
+/// When the value is masked, each character of the value is displayed as the mask character.
+#[derive(Clone)]
+enum DisplayGraphemes<'a> {
+    Plain(unicode_segmentation::GraphemeIndices<'a>),
+    Masked {
+        value: core::str::CharIndices<'a>,
+        mask: Cow<'a, str>,
+    },
+}
+
+impl<'a> DisplayGraphemes<'a> {
+    fn new(value: &'a str, masked: Option<&Masked<'a>>) -> Self {
+        masked.map_or_else(
+            || Self::Plain(value.grapheme_indices(true)),
+            |masked| Self::Masked {
+                value: value.char_indices(),
+                mask: Cow::Owned(masked.mask_char().into()),
+            },
+        )
+    }
+}
+
+impl<'a> Iterator for DisplayGraphemes<'a> {
+    type Item = (core::ops::Range<usize>, Cow<'a, str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Plain(graphemes) => graphemes
+                .next()
+                .map(|(i, g)| (i..i + g.len(), Cow::Borrowed(g))),
+            Self::Masked { value, mask } => value
+                .next()
+                .map(|(i, c)| (i..i + c.len_utf8(), mask.clone())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Stylize;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn render(input: &TextInput, state: &mut TextInputState, width: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+        StatefulWidget::render(input, buf.area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn render_placeholder() {
+        let input = TextInput::new().placeholder("Name");
+        let mut state = TextInputState::new();
+        let buf = render(&input, &mut state, 6);
+        let mut expected = Buffer::with_lines(["Name  "]);
+        expected.set_style(Rect::new(0, 0, 4, 1), Style::new().dim());
+        assert_eq!(buf, expected);
+        assert_eq!(state.cursor_position(), Some(Position::new(0, 0)));
+    }
+
+    #[rstest]
+    #[case::fits("hello", 5, "hello ", 5)]
+    #[case::scrolled_to_end("hello world", 11, "world ", 5)]
+    #[case::scrolled_to_start("hello world", 0, "hello ", 0)]
+    #[case::wide_graphemes("あいう", 9, " いう ", 5)]
+    fn render_scrolls_to_cursor(
+        #[case] value: &str,
+        #[case] cursor: usize,
+        #[case] expected: &str,
+        #[case] cursor_x: u16,
+    ) {
+        let input = TextInput::new();
+        let mut state = TextInputState::new().with_value(value);
+        state.set_cursor(cursor);
+        let buf = render(&input, &mut state, 6);
+        assert_eq!(buf, Buffer::with_lines([expected]));
+        assert_eq!(state.cursor_position(), Some(Position::new(cursor_x, 0)));
+    }
+
+    #[test]
+    fn render_keeps_offset_while_cursor_is_visible() {
+        let input = TextInput::new();
+        let mut state = TextInputState::new().with_value("hello world");
+        render(&input, &mut state, 6);
+        assert_eq!(state.offset(), 6);
+
+        state.move_cursor(CursorMove::WordLeft);
+        let buf = render(&input, &mut state, 6);
+        assert_eq!(buf, Buffer::with_lines(["world "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(0, 0)));
+
+        state.move_cursor(CursorMove::Left);
+        let buf = render(&input, &mut state, 6);
+        assert_eq!(buf, Buffer::with_lines([" world"]));
+    }
+
+    #[test]
+    fn render_masked() {
+        let input = TextInput::new().mask('*');
+        let mut state = TextInputState::new().with_value("pässword");
+        let buf = render(&input, &mut state, 10);
+        assert_eq!(buf, Buffer::with_lines(["********  "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(8, 0)));
+    }
+
+    #[test]
+    fn render_selection() {
+        let input = TextInput::new().selection_style(Style::new().red());
+        let mut state = TextInputState::new().with_value("hello");
+        state.select(CursorMove::Left);
+        state.select(CursorMove::Left);
+        let buf = render(&input, &mut state, 6);
+        let mut expected = Buffer::with_lines(["hello "]);
+        expected.set_style(Rect::new(3, 0, 2, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_in_block() {
+        let input = TextInput::new().block(Block::bordered());
+        let mut state = TextInputState::new().with_value("hi");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 3));
+        StatefulWidget::render(&input, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["┌────┐", "│hi  │", "└────┘"]));
+        assert_eq!(state.cursor_position(), Some(Position::new(3, 1)));
+    }
+
+    #[test]
+    fn render_in_empty_area() {
+        let input = TextInput::new();
+        let mut state = TextInputState::new().with_value("hi");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 0, 1));
+        StatefulWidget::render(&input, buf.area, &mut buf, &mut state);
+        assert_eq!(state.cursor_position(), None);
+    }
+
+    #[test]
+    fn can_be_stylized() {
+        assert_eq!(
+            TextInput::new().red().on_black().style,
+            Style::new().red().on_black()
+        );
+    }
+}
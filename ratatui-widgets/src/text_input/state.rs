@@ -0,0 +1,676 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use ratatui_core::input::{KeyCode, KeyEvent};
+use ratatui_core::layout::Position;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The maximum number of killed strings kept by [`TextInputState`].
+const KILL_RING_SIZE: usize = 16;
+
+/// State of the [`TextInput`] widget
+///
+/// The state holds the text being edited, the position of the cursor, the selection, the
+/// horizontal scroll offset and a kill ring of deleted text. The cursor and the selection are byte
+/// indices into the value that always fall on grapheme boundaries, so that the cursor never splits
+/// a grapheme such as an emoji or a letter with combining marks.
+///
+/// Keys can be passed to [`TextInputState::handle_key`], which implements the usual editing keys
+/// as well as the readline / emacs shortcuts. The [`KeyEvent`] of a backend can be converted with
+/// the `FromCrossterm`, `FromTermion`, `FromTermwiz` or `FromTermina` traits of the backend
+/// crates. All the editing operations are also available as methods.
+///
+/// When rendered, the state scrolls so that the cursor is visible and stores the position of the
+/// cursor on screen, which can be passed to `Frame::set_cursor_position`.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::input::{KeyCode, KeyEvent, KeyModifiers};
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::{TextInput, TextInputState};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TextInputState::new().with_value("hello world");
+///
+/// state.handle_key(KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL));
+/// assert_eq!(state.value(), "hello ");
+///
+/// frame.render_stateful_widget(TextInput::new(), area, &mut state);
+/// if let Some(position) = state.cursor_position() {
+///     frame.set_cursor_position(position);
+/// }
+/// # }
+/// ```
+///
+/// [`TextInput`]: super::TextInput
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "RawTextInputState")
+)]
+pub struct TextInputState {
+    pub(crate) value: String,
+    pub(crate) cursor: usize,
+    pub(crate) anchor: Option<usize>,
+    pub(crate) offset: usize,
+    pub(crate) cursor_position: Option<Position>,
+    kill_ring: Vec<String>,
+    yank: Option<Yank>,
+}
+
+/// The text inserted by the last yank, which [`TextInputState::yank_pop`] replaces.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Yank {
+    range: Range<usize>,
+    index: usize,
+}
+
+/// The fields of a [`TextInputState`] as they are deserialized, before the indices are checked
+/// against the value.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawTextInputState {
+    value: String,
+    cursor: usize,
+    anchor: Option<usize>,
+    offset: usize,
+    cursor_position: Option<Position>,
+    kill_ring: Vec<String>,
+    yank: Option<Yank>,
+}
+
+#[cfg(feature = "serde")]
+impl From<RawTextInputState> for TextInputState {
+    /// Moves the cursor and the anchor to the start of the grapheme they are in, and forgets a
+    /// yank whose range or kill ring entry does not exist, so that a state deserialized from
+    /// hand-edited data cannot split a character.
+    fn from(raw: RawTextInputState) -> Self {
+        let mut state = Self {
+            value: raw.value,
+            offset: raw.offset,
+            cursor_position: raw.cursor_position,
+            kill_ring: raw.kill_ring,
+            ..Self::new()
+        };
+        state.cursor = state.grapheme_start(raw.cursor);
+        state.anchor = raw.anchor.map(|anchor| state.grapheme_start(anchor));
+        state.yank = raw.yank.filter(|yank| {
+            yank.index < state.kill_ring.len() && state.value.get(yank.range.clone()).is_some()
+        });
+        state
+    }
+}
+
+/// A movement of the cursor of a [`TextInputState`].
+///
+/// See [`TextInputState::move_cursor`] and [`TextInputState::select`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum CursorMove {
+    /// One grapheme to the left.
+    Left,
+    /// One grapheme to the right.
+    Right,
+    /// To the start of the current or previous word.
+    WordLeft,
+    /// To the end of the current or next word.
+    WordRight,
+    /// To the start of the text.
+    Home,
+    /// To the end of the text.
+    End,
+}
+
+impl TextInputState {
+    /// Creates an empty state.
+    pub const fn new() -> Self {
+        Self {
+            value: String::new(),
+            cursor: 0,
+            anchor: None,
+            offset: 0,
+            cursor_position: None,
+            kill_ring: Vec::new(),
+            yank: None,
+        }
+    }
+
+    /// Sets the value and moves the cursor to the end of it.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TextInputState;
+    ///
+    /// let state = TextInputState::new().with_value("hello");
+    /// assert_eq!(state.cursor(), 5);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_value<T: Into<String>>(mut self, value: T) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    /// The text being edited
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Replaces the value, moving the cursor to the end of it and clearing the selection.
+    ///
+    /// Control characters such as newlines are removed, as the input is a single line.
+    pub fn set_value<T: Into<String>>(&mut self, value: T) {
+        let mut value = value.into();
+        value.retain(|c| !c.is_control());
+        self.cursor = value.len();
+        self.value = value;
+        self.anchor = None;
+        self.yank = None;
+    }
+
+    /// Clears the value, returning the previous value.
+    pub fn take_value(&mut self) -> String {
+        let value = core::mem::take(&mut self.value);
+        self.set_value(String::new());
+        value
+    }
+
+    /// The byte index of the cursor in the value
+    pub const fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Moves the cursor to a byte index in the value, clearing the selection.
+    ///
+    /// The index is clamped to the length of the value and moved back to the start of the
+    /// grapheme it is in.
+    pub fn set_cursor(&mut self, index: usize) {
+        self.cursor = self.grapheme_start(index);
+        self.anchor = None;
+        self.yank = None;
+    }
+
+    /// The byte range of the selected text, or `None` if nothing is selected
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.anchor?;
+        let range = anchor.min(self.cursor)..anchor.max(self.cursor);
+        (!range.is_empty()).then_some(range)
+    }
+
+    /// The selected text, or `None` if nothing is selected
+    pub fn selected_text(&self) -> Option<&str> {
+        self.value.get(self.selection()?)
+    }
+
+    /// Selects the whole value, leaving the cursor at the end.
+    pub const fn select_all(&mut self) {
+        self.anchor = Some(0);
+        self.cursor = self.value.len();
+    }
+
+    /// The index of the first column of the value that is displayed
+    ///
+    /// The offset is updated when the input is rendered to keep the cursor visible.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The position of the cursor on screen when the input was last rendered
+    ///
+    /// Returns `None` if the input has not been rendered or was rendered in an empty area. Pass
+    /// the position to `Frame::set_cursor_position` to show the terminal cursor in the input.
+    pub const fn cursor_position(&self) -> Option<Position> {
+        self.cursor_position
+    }
+
+    /// The killed text that can be yanked, from the oldest to the most recent
+    pub fn kill_ring(&self) -> &[String] {
+        &self.kill_ring
+    }
+
+    /// Moves the cursor, clearing the selection.
+    ///
+    /// When text is selected, moving one grapheme left or right moves the cursor to the start or
+    /// the end of the selection instead.
+    pub fn move_cursor(&mut self, movement: CursorMove) {
+        self.cursor = match (self.selection(), movement) {
+            (Some(selection), CursorMove::Left) => selection.start,
+            (Some(selection), CursorMove::Right) => selection.end,
+            _ => self.target(movement),
+        };
+        self.anchor = None;
+        self.yank = None;
+    }
+
+    /// Moves the cursor, extending the selection.
+    pub fn select(&mut self, movement: CursorMove) {
+        self.anchor.get_or_insert(self.cursor);
+        self.cursor = self.target(movement);
+        self.yank = None;
+    }
+
+    /// Inserts a character at the cursor, replacing the selection.
+    ///
+    /// Control characters are ignored.
+    pub fn insert_char(&mut self, c: char) {
+        if !c.is_control() {
+            self.insert_str(c.encode_utf8(&mut [0; 4]));
+        }
+    }
+
+    /// Inserts a string at the cursor, replacing the selection.
+    ///
+    /// Control characters such as newlines are removed, as the input is a single line.
+    pub fn insert_str(&mut self, text: &str) {
+        let text: String = text.chars().filter(|c| !c.is_control()).collect();
+        let range = self.selection().unwrap_or(self.cursor..self.cursor);
+        self.replace(range, &text);
+    }
+
+    /// Deletes the selection, or the grapheme before the cursor.
+    pub fn delete_backward(&mut self) {
+        let range = self
+            .selection()
+            .unwrap_or_else(|| self.target(CursorMove::Left)..self.cursor);
+        self.replace(range, "");
+    }
+
+    /// Deletes the selection, or the grapheme after the cursor.
+    pub fn delete_forward(&mut self) {
+        let range = self
+            .selection()
+            .unwrap_or_else(|| self.cursor..self.target(CursorMove::Right));
+        self.replace(range, "");
+    }
+
+    /// Kills the selection, or the text from the start of the word before the cursor to the
+    /// cursor.
+    pub fn delete_word_backward(&mut self) {
+        let range = self
+            .selection()
+            .unwrap_or_else(|| self.target(CursorMove::WordLeft)..self.cursor);
+        self.kill(range);
+    }
+
+    /// Kills the selection, or the text from the cursor to the end of the word after the cursor.
+    pub fn delete_word_forward(&mut self) {
+        let range = self
+            .selection()
+            .unwrap_or_else(|| self.cursor..self.target(CursorMove::WordRight));
+        self.kill(range);
+    }
+
+    /// Kills the text from the cursor to the end of the value.
+    pub fn kill_to_end(&mut self) {
+        self.kill(self.cursor..self.value.len());
+    }
+
+    /// Kills the text from the start of the value to the cursor.
+    pub fn kill_to_start(&mut self) {
+        self.kill(0..self.cursor);
+    }
+
+    /// Inserts the most recently killed text at the cursor, replacing the selection.
+    pub fn yank(&mut self) {
+        let Some(index) = self.kill_ring.len().checked_sub(1) else {
+            return;
+        };
+        let range = self.selection().unwrap_or(self.cursor..self.cursor);
+        self.yank_at(range, index);
+    }
+
+    /// Replaces the text inserted by the last yank with the previous entry of the kill ring.
+    ///
+    /// Does nothing unless the last operation was a yank or a yank pop.
+    pub fn yank_pop(&mut self) {
+        let Some(yank) = self.yank.take() else {
+            return;
+        };
+        let index = yank
+            .index
+            .checked_sub(1)
+            .unwrap_or_else(|| self.kill_ring.len().saturating_sub(1));
+        self.yank_at(yank.range, index);
+    }
+
+    /// Handles a key press, returning `true` if the key changed the state.
+    ///
+    /// The following keys are supported, where `C-` is the control key and `M-` is the alt key:
+    ///
+    /// | Key | Action |
+    /// |-----|--------|
+    /// | characters | [insert](Self::insert_char) |
+    /// | `Left`, `C-b` / `Right`, `C-f` | move one grapheme |
+    /// | `C-Left`, `M-b` / `C-Right`, `M-f` | move one word |
+    /// | `Home`, `C-a` / `End`, `C-e` | move to the start / end |
+    /// | `Shift` + any of the above arrow, home or end keys | extend the selection |
+    /// | `Backspace`, `C-h` / `Delete`, `C-d` | [delete](Self::delete_backward) a grapheme |
+    /// | `C-Backspace`, `M-Backspace`, `C-w` / `C-Delete`, `M-d` | [kill](Self::delete_word_backward) a word |
+    /// | `C-u` / `C-k` | kill to the [start](Self::kill_to_start) / [end](Self::kill_to_end) |
+    /// | `C-y` / `M-y` | [yank](Self::yank) / [yank pop](Self::yank_pop) |
+    ///
+    /// Other keys, such as `Enter` or `Esc`, are left for the application to handle.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let word = key.is_ctrl() || key.is_alt();
+        let movement = match key.code {
+            KeyCode::Left if word => Some(CursorMove::WordLeft),
+            KeyCode::Right if word => Some(CursorMove::WordRight),
+            KeyCode::Left => Some(CursorMove::Left),
+            KeyCode::Right => Some(CursorMove::Right),
+            KeyCode::Home => Some(CursorMove::Home),
+            KeyCode::End => Some(CursorMove::End),
+            _ => None,
+        };
+        if let Some(movement) = movement {
+            if key.is_shift() {
+                self.select(movement);
+            } else {
+                self.move_cursor(movement);
+            }
+            return true;
+        }
+        match key.code {
+            KeyCode::Char(c) if key.is_ctrl() && !key.is_alt() => match c {
+                'a' => self.move_cursor(CursorMove::Home),
+                'e' => self.move_cursor(CursorMove::End),
+                'b' => self.move_cursor(CursorMove::Left),
+                'f' => self.move_cursor(CursorMove::Right),
+                'h' => self.delete_backward(),
+                'd' => self.delete_forward(),
+                'w' => self.delete_word_backward(),
+                'u' => self.kill_to_start(),
+                'k' => self.kill_to_end(),
+                'y' => self.yank(),
+                _ => return false,
+            },
+            KeyCode::Char(c) if key.is_alt() && !key.is_ctrl() => match c {
+                'b' => self.move_cursor(CursorMove::WordLeft),
+                'f' => self.move_cursor(CursorMove::WordRight),
+                'd' => self.delete_word_forward(),
+                'y' => self.yank_pop(),
+                _ => return false,
+            },
+            KeyCode::Char(c) if !key.is_ctrl() && !key.is_alt() => self.insert_char(c),
+            KeyCode::Backspace if word => self.delete_word_backward(),
+            KeyCode::Backspace => self.delete_backward(),
+            KeyCode::Delete if word => self.delete_word_forward(),
+            KeyCode::Delete => self.delete_forward(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Returns the position of the cursor after a movement.
+    fn target(&self, movement: CursorMove) -> usize {
+        let (before, after) = self.value.split_at(self.cursor);
+        match movement {
+            CursorMove::Left => before
+                .graphemes(true)
+                .next_back()
+                .map_or(0, |g| self.cursor - g.len()),
+            CursorMove::Right => after
+                .graphemes(true)
+                .next()
+                .map_or(self.cursor, |g| self.cursor + g.len()),
+            CursorMove::WordLeft => {
+                let mut graphemes = before.grapheme_indices(true).rev().peekable();
+                while graphemes.next_if(|(_, g)| !is_word(g)).is_some() {}
+                while graphemes.next_if(|(_, g)| is_word(g)).is_some() {}
+                graphemes.next().map_or(0, |(i, g)| i + g.len())
+            }
+            CursorMove::WordRight => {
+                let mut graphemes = after.grapheme_indices(true).peekable();
+                while graphemes.next_if(|(_, g)| !is_word(g)).is_some() {}
+                while graphemes.next_if(|(_, g)| is_word(g)).is_some() {}
+                graphemes
+                    .next()
+                    .map_or(self.value.len(), |(i, _)| self.cursor + i)
+            }
+            CursorMove::Home => 0,
+            CursorMove::End => self.value.len(),
+        }
+    }
+
+    /// Returns the start of the grapheme containing the byte index.
+    fn grapheme_start(&self, index: usize) -> usize {
+        if index >= self.value.len() {
+            return self.value.len();
+        }
+        self.value
+            .grapheme_indices(true)
+            .map(|(i, _)| i)
+            .take_while(|&i| i <= index)
+            .last()
+            .unwrap_or(index)
+    }
+
+    /// Replaces a range of the value with text and moves the cursor after the text.
+    fn replace(&mut self, range: Range<usize>, text: &str) {
+        self.cursor = range.start + text.len();
+        self.value.replace_range(range, text);
+        self.anchor = None;
+        self.yank = None;
+    }
+
+    /// Removes a range of the value and adds it to the kill ring.
+    fn kill(&mut self, range: Range<usize>) {
+        let Some(killed) = self
+            .value
+            .get(range.clone())
+            .filter(|text| !text.is_empty())
+        else {
+            return;
+        };
+        if self.kill_ring.len() == KILL_RING_SIZE {
+            self.kill_ring.remove(0);
+        }
+        self.kill_ring.push(String::from(killed));
+        self.replace(range, "");
+    }
+
+    /// Replaces a range with an entry of the kill ring, remembering it for [`Self::yank_pop`].
+    fn yank_at(&mut self, range: Range<usize>, index: usize) {
+        let Some(text) = self.kill_ring.get(index).cloned() else {
+            return;
+        };
+        let start = range.start;
+        self.replace(range, &text);
+        self.yank = Some(Yank {
+            range: start..start + text.len(),
+            index,
+        });
+    }
+}
+
+/// Returns `true` if a grapheme is part of a word for word-wise movement.
+fn is_word(grapheme: &str) -> bool {
+    grapheme
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::input::KeyModifiers;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    fn alt(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::ALT)
+    }
+
+    #[test]
+    fn with_value() {
+        let state = TextInputState::new().with_value("hello\nworld");
+        assert_eq!(state.value(), "helloworld");
+        assert_eq!(state.cursor(), 10);
+        assert_eq!(state.selection(), None);
+    }
+
+    #[test]
+    fn take_value() {
+        let mut state = TextInputState::new().with_value("hello");
+        assert_eq!(state.take_value(), "hello");
+        assert_eq!(state.value(), "");
+        assert_eq!(state.cursor(), 0);
+    }
+
+    #[rstest]
+    #[case::start(0, 0)]
+    #[case::inside_emoji(2, 0)]
+    #[case::after_emoji(4, 4)]
+    #[case::inside_combining(6, 4)]
+    #[case::past_end(100, 7)]
+    fn set_cursor(#[case] index: usize, #[case] expected: usize) {
+        // 😀 is 4 bytes, e + combining acute accent is 3 bytes
+        let mut state = TextInputState::new().with_value("😀e\u{301}");
+        state.set_cursor(index);
+        assert_eq!(state.cursor(), expected);
+    }
+
+    #[rstest]
+    #[case::left(CursorMove::Left, 8)]
+    #[case::right(CursorMove::Right, 13)]
+    #[case::word_left(CursorMove::WordLeft, 7)]
+    #[case::word_right(CursorMove::WordRight, 15)]
+    #[case::home(CursorMove::Home, 0)]
+    #[case::end(CursorMove::End, 21)]
+    fn move_cursor(#[case] movement: CursorMove, #[case] expected: usize) {
+        // the cursor is after the 😀, which is 4 bytes and not part of a word
+        let mut state = TextInputState::new().with_value("hello, w😀rld again");
+        state.set_cursor(12);
+        state.move_cursor(movement);
+        assert_eq!(state.cursor(), expected);
+    }
+
+    #[test]
+    fn word_movement_skips_punctuation() {
+        let mut state = TextInputState::new().with_value("foo -- bar");
+        state.move_cursor(CursorMove::WordLeft);
+        assert_eq!(state.cursor(), 7);
+        state.move_cursor(CursorMove::WordLeft);
+        assert_eq!(state.cursor(), 0);
+        state.move_cursor(CursorMove::WordRight);
+        assert_eq!(state.cursor(), 3);
+        state.move_cursor(CursorMove::WordRight);
+        assert_eq!(state.cursor(), 10);
+    }
+
+    #[test]
+    fn select() {
+        let mut state = TextInputState::new().with_value("hello world");
+        state.select(CursorMove::WordLeft);
+        assert_eq!(state.selection(), Some(6..11));
+        assert_eq!(state.selected_text(), Some("world"));
+
+        state.move_cursor(CursorMove::Left);
+        assert_eq!(state.cursor(), 6);
+        assert_eq!(state.selection(), None);
+
+        state.select_all();
+        assert_eq!(state.selected_text(), Some("hello world"));
+        state.insert_char('x');
+        assert_eq!(state.value(), "x");
+    }
+
+    #[test]
+    fn insert() {
+        let mut state = TextInputState::new().with_value("ac");
+        state.move_cursor(CursorMove::Left);
+        state.insert_char('b');
+        assert_eq!(state.value(), "abc");
+        assert_eq!(state.cursor(), 2);
+        state.insert_str("\tß\n");
+        assert_eq!(state.value(), "abßc");
+        assert_eq!(state.cursor(), 4);
+    }
+
+    #[test]
+    fn delete_graphemes() {
+        let mut state = TextInputState::new().with_value("ae\u{301}😀b");
+        state.set_cursor(8);
+        state.delete_backward();
+        assert_eq!(state.value(), "ae\u{301}b");
+        state.move_cursor(CursorMove::Home);
+        state.delete_forward();
+        assert_eq!(state.value(), "e\u{301}b");
+        state.delete_forward();
+        assert_eq!(state.value(), "b");
+    }
+
+    #[test]
+    fn kill_and_yank() {
+        let mut state = TextInputState::new().with_value("one two three");
+        state.delete_word_backward();
+        assert_eq!(state.value(), "one two ");
+        state.move_cursor(CursorMove::Home);
+        state.kill_to_end();
+        assert_eq!(state.value(), "");
+        assert_eq!(state.kill_ring(), ["three", "one two "]);
+
+        state.yank();
+        assert_eq!(state.value(), "one two ");
+        state.yank_pop();
+        assert_eq!(state.value(), "three");
+        state.yank_pop();
+        assert_eq!(state.value(), "one two ");
+
+        // yank pop only works directly after a yank
+        state.move_cursor(CursorMove::Home);
+        state.yank_pop();
+        assert_eq!(state.value(), "one two ");
+    }
+
+    #[test]
+    fn kill_ring_is_bounded() {
+        let mut state = TextInputState::new();
+        for i in 0..20 {
+            state.insert_char(char::from_digit(i % 10, 10).unwrap());
+            state.kill_to_start();
+        }
+        assert_eq!(state.kill_ring().len(), KILL_RING_SIZE);
+        assert_eq!(state.kill_ring().last().map(String::as_str), Some("9"));
+    }
+
+    #[test]
+    fn handle_key() {
+        let mut state = TextInputState::new();
+        for c in "hello world".chars() {
+            assert!(state.handle_key(KeyEvent::from(c)));
+        }
+        assert!(state.handle_key(ctrl('w')));
+        assert_eq!(state.value(), "hello ");
+        assert!(state.handle_key(ctrl('a')));
+        assert!(state.handle_key(alt('d')));
+        assert_eq!(state.value(), " ");
+        assert!(state.handle_key(ctrl('y')));
+        assert_eq!(state.value(), "hello ");
+        assert!(state.handle_key(alt('y')));
+        assert_eq!(state.value(), "world ");
+
+        assert!(state.handle_key(KeyEvent::new(
+            KeyCode::Left,
+            KeyModifiers::SHIFT | KeyModifiers::CONTROL
+        )));
+        assert_eq!(state.selected_text(), Some("world"));
+        assert!(state.handle_key(KeyEvent::from(KeyCode::Backspace)));
+        assert_eq!(state.value(), " ");
+
+        assert!(!state.handle_key(KeyEvent::from(KeyCode::Enter)));
+        assert!(!state.handle_key(ctrl('c')));
+    }
+}
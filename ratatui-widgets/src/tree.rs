@@ -0,0 +1,296 @@
+//! The [`Tree`] widget is used to display hierarchical data, such as a file system, and allows
+//! opening, closing and selecting nodes.
+
+use alloc::vec::Vec;
+
+use ratatui_core::style::{Style, Styled, Theme};
+use ratatui_core::symbols::line;
+use ratatui_core::text::Line;
+
+pub use self::item::TreeItem;
+pub use self::state::TreeState;
+use crate::block::Block;
+use crate::table::HighlightSpacing;
+
+mod item;
+mod rendering;
+mod state;
+
+/// A widget to display a tree of items among which one can be selected
+///
+/// A tree is a list of root [`TreeItem`]s, each of which can have children. The children of a node
+/// are only displayed when the node is open. Like a [`List`], a `Tree` is a [`StatefulWidget`]
+/// that works with a [`TreeState`], which stores the selected node, the opened nodes and the
+/// scroll offset, and provides keyboard navigation.
+///
+/// Each row starts with the highlight symbol column, followed by the guide lines that connect the
+/// nodes to their parents, the open or closed symbol of expandable nodes and the content of the
+/// item.
+///
+/// [`List`]: crate::list::List
+/// [`StatefulWidget`]: ratatui_core::widgets::StatefulWidget
+///
+/// # Fluent setters
+///
+/// - [`Tree::highlight_style`] sets the style of the selected node.
+/// - [`Tree::highlight_symbol`] sets the symbol to be displayed in front of the selected node.
+/// - [`Tree::guides`] sets the symbols of the guide lines, or hides them.
+/// - [`Tree::guide_style`] sets the style of the guide lines.
+/// - [`Tree::open_symbol`] and [`Tree::closed_symbol`] set the symbols of expandable nodes.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Style, Stylize};
+/// use ratatui::symbols::line;
+/// use ratatui::widgets::{Block, Tree, TreeItem, TreeState};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let items = [
+///     TreeItem::new("src").children([
+///         TreeItem::new("widgets").children(["list.rs", "tree.rs"]),
+///         TreeItem::new("lib.rs"),
+///     ]),
+///     TreeItem::new("Cargo.toml"),
+/// ];
+/// let tree = Tree::new(items)
+///     .block(Block::bordered().title("Files"))
+///     .highlight_style(Style::new().reversed())
+///     .highlight_symbol(">>")
+///     .guides(Some(line::ROUNDED));
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TreeState::default().with_selected(Some(vec![0, 0, 1]));
+///
+/// frame.render_stateful_widget(tree, area, &mut state);
+/// //   ▼ src
+/// //   ├─ ▼ widgets
+/// //   │  ├─   list.rs
+/// // >>│  ╰─   tree.rs
+/// //   ╰─   lib.rs
+/// //     Cargo.toml
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Tree<'a> {
+    /// An optional block to wrap the widget in
+    pub(crate) block: Option<Block<'a>>,
+    /// The root items of the tree
+    pub(crate) items: Vec<TreeItem<'a>>,
+    /// Style used as a base style for the widget
+    pub(crate) style: Style,
+    /// Style used to render the selected node
+    pub(crate) highlight_style: Style,
+    /// Symbol in front of the selected node (Shift all nodes to the right)
+    pub(crate) highlight_symbol: Option<Line<'a>>,
+    /// Decides when to allocate spacing for the selection symbol
+    pub(crate) highlight_spacing: HighlightSpacing,
+    /// How many rows to try to keep visible before and after the selected node
+    pub(crate) scroll_padding: usize,
+    /// Symbols used to draw the guide lines, or `None` to indent without guides
+    pub(crate) guides: Option<line::Set<'a>>,
+    /// Style used to render the guide lines
+    pub(crate) guide_style: Style,
+    /// Symbol in front of open nodes
+    pub(crate) open_symbol: &'a str,
+    /// Symbol in front of closed nodes
+    pub(crate) closed_symbol: &'a str,
+}
+
+impl Default for Tree<'_> {
+    fn default() -> Self {
+        Self::new(Vec::<TreeItem>::new())
+    }
+}
+
+impl<'a> Tree<'a> {
+    /// Creates a new tree from its root items
+    ///
+    /// `items` accepts any iterator of items convertible to [`TreeItem`], such as `&str` or
+    /// [`Line`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Tree, TreeItem};
+    ///
+    /// let tree = Tree::new([TreeItem::new("root").children(["child"])]);
+    /// ```
+    pub fn new<T>(items: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<TreeItem<'a>>,
+    {
+        Self {
+            block: None,
+            items: items.into_iter().map(Into::into).collect(),
+            style: Style::new(),
+            highlight_style: Style::new(),
+            highlight_symbol: None,
+            highlight_spacing: HighlightSpacing::default(),
+            scroll_padding: 0,
+            guides: Some(line::NORMAL),
+            guide_style: Style::new(),
+            open_symbol: "▼ ",
+            closed_symbol: "▶ ",
+        }
+    }
+
+    /// Set the root items
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn items<T>(mut self, items: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<TreeItem<'a>>,
+    {
+        self.items = items.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Wraps the tree with a custom [`Block`] widget.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the symbol to be displayed in front of the selected node
+    ///
+    /// By default there are no highlight symbol.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_symbol<L: Into<Line<'a>>>(mut self, highlight_symbol: L) -> Self {
+        self.highlight_symbol = Some(highlight_symbol.into());
+        self
+    }
+
+    /// Set the style of the selected node
+    ///
+    /// The style is applied to the whole row, on top of the base style and the style of the item.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Set when to show the highlight spacing
+    ///
+    /// See [`HighlightSpacing`] about which variant affects spacing in which way
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn highlight_spacing(mut self, value: HighlightSpacing) -> Self {
+        self.highlight_spacing = value;
+        self
+    }
+
+    /// Sets the number of rows around the selected node that should be visible
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn scroll_padding(mut self, padding: usize) -> Self {
+        self.scroll_padding = padding;
+        self
+    }
+
+    /// Sets the symbols used to draw the guide lines between nodes and their parents
+    ///
+    /// The `vertical`, `vertical_right`, `bottom_left` and `horizontal` symbols of the set are
+    /// used, e.g. `│`, `├`, `└` and `─` for [`line::NORMAL`], which is the default. With `None`,
+    /// the children are indented without guide lines.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn guides(mut self, guides: Option<line::Set<'a>>) -> Self {
+        self.guides = guides;
+        self
+    }
+
+    /// Sets the style of the guide lines
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn guide_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.guide_style = style.into();
+        self
+    }
+
+    /// Sets the styles of the tree from a [`Theme`]
+    ///
+    /// The style, highlight style and guide style are replaced by the `tree`, `tree.highlight` and
+    /// `tree.guide` styles of the theme.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style("tree");
+        self.highlight_style = theme.style("tree.highlight");
+        self.guide_style = theme.style("tree.guide");
+        self
+    }
+
+    /// Sets the symbol displayed in front of open nodes, `"▼ "` by default
+    ///
+    /// Nodes that cannot be expanded are indented by the width of the closed symbol.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn open_symbol(mut self, symbol: &'a str) -> Self {
+        self.open_symbol = symbol;
+        self
+    }
+
+    /// Sets the symbol displayed in front of closed nodes, `"▶ "` by default
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn closed_symbol(mut self, symbol: &'a str) -> Self {
+        self.closed_symbol = symbol;
+        self
+    }
+}
+
+impl Styled for Tree<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl<'a, Item> FromIterator<Item> for Tree<'a>
+where
+    Item: Into<TreeItem<'a>>,
+{
+    fn from_iter<Iter: IntoIterator<Item = Item>>(iter: Iter) -> Self {
+        Self::new(iter)
+    }
+}
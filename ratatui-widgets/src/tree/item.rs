@@ -0,0 +1,150 @@
+use alloc::vec::Vec;
+
+use ratatui_core::style::{Style, Styled};
+use ratatui_core::text::Line;
+
+/// A node of a [`Tree`](super::Tree)
+///
+/// A tree item is a single [`Line`] of content with an optional list of children. The style of
+/// the item is applied to the whole row of the tree, under the style of the content.
+///
+/// Children can be loaded lazily: an item marked as [expandable](TreeItem::expandable) can be
+/// opened even though it has no children yet. The application can then check
+/// [`TreeState::is_open`](super::TreeState::is_open) when it builds the tree and load the
+/// children of the opened items only.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::style::Stylize;
+/// use ratatui::widgets::TreeItem;
+///
+/// let item = TreeItem::new("src".blue()).children([
+///     TreeItem::new("lib.rs"),
+///     TreeItem::new("widgets").expandable(true),
+/// ]);
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct TreeItem<'a> {
+    pub(crate) content: Line<'a>,
+    pub(crate) style: Style,
+    pub(crate) children: Vec<Self>,
+    pub(crate) expandable: bool,
+}
+
+impl<'a> TreeItem<'a> {
+    /// Creates a new item without children.
+    ///
+    /// `content` accepts any type that is convertible to [`Line`] (e.g. `&str`, `String`, [`Span`]
+    /// or [`Line`]).
+    ///
+    /// [`Span`]: ratatui_core::text::Span
+    pub fn new<T: Into<Line<'a>>>(content: T) -> Self {
+        Self {
+            content: content.into(),
+            style: Style::default(),
+            children: Vec::new(),
+            expandable: false,
+        }
+    }
+
+    /// Sets the children of the item
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn children<I>(mut self, children: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Self>,
+    {
+        self.children = children.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Marks the item as having children that are not loaded yet
+    ///
+    /// An expandable item is displayed with the open or closed symbol and can be opened even
+    /// when it has no children. Items with children are always expandable.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn expandable(mut self, expandable: bool) -> Self {
+        self.expandable = expandable;
+        self
+    }
+
+    /// Sets the style of the item
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns `true` if the item has children or is marked as [expandable](Self::expandable).
+    pub const fn is_expandable(&self) -> bool {
+        self.expandable || !self.children.is_empty()
+    }
+}
+
+impl<'a, T> From<T> for TreeItem<'a>
+where
+    T: Into<Line<'a>>,
+{
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Styled for TreeItem<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let item = TreeItem::new("root");
+        assert_eq!(item.content, Line::from("root"));
+        assert!(item.children.is_empty());
+        assert!(!item.is_expandable());
+    }
+
+    #[test]
+    fn children() {
+        let item = TreeItem::new("root").children(["a", "b"]);
+        assert_eq!(item.children, [TreeItem::new("a"), TreeItem::new("b")]);
+        assert!(item.is_expandable());
+    }
+
+    #[test]
+    fn expandable() {
+        assert!(TreeItem::new("lazy").expandable(true).is_expandable());
+    }
+
+    #[test]
+    fn can_be_stylized() {
+        assert_eq!(
+            TreeItem::new("root").red().on_black().style,
+            Style::new().red().on_black()
+        );
+    }
+}
@@ -0,0 +1,360 @@
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::Style;
+use ratatui_core::text::Line;
+use ratatui_core::widgets::{StatefulWidget, Widget};
+use unicode_width::UnicodeWidthStr;
+
+use super::state::VisibleNode;
+use crate::block::BlockExt;
+use crate::tree::{Tree, TreeItem, TreeState};
+
+/// A displayed node of the tree.
+struct Row<'t, 'a> {
+    path: Vec<usize>,
+    item: &'t TreeItem<'a>,
+    /// Whether the node and each of its ancestors is the last of its siblings, from the root
+    last: Vec<bool>,
+}
+
+impl Widget for Tree<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Tree<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = TreeState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for Tree<'_> {
+    type State = TreeState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &Tree<'_> {
+    type State = TreeState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let tree_area = self.block.inner_if_some(area);
+
+        let mut rows = Vec::new();
+        flatten(
+            &self.items,
+            &state.opened,
+            &mut Vec::new(),
+            &mut Vec::new(),
+            &mut rows,
+        );
+        state.cache.visible = rows
+            .iter()
+            .map(|row| VisibleNode {
+                path: row.path.clone(),
+                expandable: row.item.is_expandable(),
+            })
+            .collect();
+        state.cache.viewport_height = usize::from(tree_area.height);
+
+        if tree_area.is_empty() {
+            return;
+        }
+        if rows.is_empty() {
+            state.select(None);
+            return;
+        }
+
+        let selected = state
+            .selected
+            .as_deref()
+            .map(|selected| selected_index(&rows, selected));
+        state.selected = selected.map(|index| rows[index].path.clone());
+
+        let height = usize::from(tree_area.height);
+        state.offset = self.offset(selected, state.offset, rows.len(), height);
+
+        let default_highlight_symbol = Line::default();
+        let highlight_symbol = self
+            .highlight_symbol
+            .as_ref()
+            .unwrap_or(&default_highlight_symbol);
+        let highlight_symbol_width = highlight_symbol.width() as u16;
+        let selection_spacing = self.highlight_spacing.should_add(selected.is_some());
+
+        for (index, row) in rows.iter().enumerate().skip(state.offset).take(height) {
+            let y = tree_area.y + (index - state.offset) as u16;
+            let row_area = Rect::new(tree_area.x, y, tree_area.width, 1);
+            buf.set_style(row_area, row.item.style);
+            let is_selected = selected == Some(index);
+
+            let mut x = tree_area.x;
+            if selection_spacing {
+                if is_selected {
+                    buf.set_line(x, y, highlight_symbol, highlight_symbol_width);
+                }
+                x = x.saturating_add(highlight_symbol_width);
+            }
+            x = self.render_guides(row, x, row_area, buf);
+            let marker = match (row.item.is_expandable(), state.is_open(&row.path)) {
+                (true, true) => self.open_symbol,
+                (true, false) => self.closed_symbol,
+                (false, _) => "",
+            };
+            let marker_width = self.open_symbol.width().max(self.closed_symbol.width());
+            let remaining = usize::from(row_area.right().saturating_sub(x));
+            buf.set_stringn(x, y, marker, remaining, Style::new());
+            x = x.saturating_add(marker_width.min(remaining) as u16);
+            buf.set_line(x, y, &row.item.content, row_area.right().saturating_sub(x));
+
+            if is_selected {
+                buf.set_style(row_area, self.highlight_style);
+            }
+        }
+    }
+}
+
+impl Tree<'_> {
+    /// Returns the offset that keeps the selected row and its scroll padding visible.
+    fn offset(&self, selected: Option<usize>, offset: usize, len: usize, height: usize) -> usize {
+        let offset = match selected {
+            Some(selected) => {
+                let padding = self.scroll_padding.min(height.saturating_sub(1) / 2);
+                offset
+                    .min(selected.saturating_sub(padding))
+                    .max((selected + padding + 1).saturating_sub(height))
+            }
+            None => offset,
+        };
+        offset.min(len.saturating_sub(height))
+    }
+
+    /// Draws the guide lines of a row and returns the column after them.
+    fn render_guides(&self, row: &Row, mut x: u16, row_area: Rect, buf: &mut Buffer) -> u16 {
+        let depth = row.last.len() - 1;
+        for (level, &last) in row.last.iter().enumerate().skip(1) {
+            let Some(guides) = self.guides else {
+                x = x.saturating_add(2).min(row_area.right());
+                continue;
+            };
+            let (first, second) = match (level == depth, last) {
+                (false, false) => (guides.vertical, " "),
+                (false, true) => (" ", " "),
+                (true, false) => (guides.vertical_right, guides.horizontal),
+                (true, true) => (guides.bottom_left, guides.horizontal),
+            };
+            for symbol in [first, second, " "] {
+                let remaining = usize::from(row_area.right().saturating_sub(x));
+                (x, _) = buf.set_stringn(x, row_area.y, symbol, remaining, self.guide_style);
+            }
+        }
+        x
+    }
+}
+
+/// Collects the displayed nodes in order, descending into the opened nodes.
+fn flatten<'t, 'a>(
+    items: &'t [TreeItem<'a>],
+    opened: &BTreeSet<Vec<usize>>,
+    path: &mut Vec<usize>,
+    last: &mut Vec<bool>,
+    rows: &mut Vec<Row<'t, 'a>>,
+) {
+    for (index, item) in items.iter().enumerate() {
+        path.push(index);
+        last.push(index + 1 == items.len());
+        rows.push(Row {
+            path: path.clone(),
+            item,
+            last: last.clone(),
+        });
+        if opened.contains(path) {
+            flatten(&item.children, opened, path, last, rows);
+        }
+        path.pop();
+        last.pop();
+    }
+}
+
+/// Returns the index of the selected row, or of its deepest displayed ancestor if it is not
+/// displayed, or of the last row if none of its ancestors are displayed.
+fn selected_index(rows: &[Row], selected: &[usize]) -> usize {
+    (1..=selected.len())
+        .rev()
+        .find_map(|depth| rows.iter().position(|row| row.path == selected[..depth]))
+        .unwrap_or(rows.len() - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use ratatui_core::style::Stylize;
+    use ratatui_core::symbols::line;
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::block::Block;
+    use crate::table::HighlightSpacing;
+
+    #[fixture]
+    fn items() -> Vec<TreeItem<'static>> {
+        vec![
+            TreeItem::new("src").children([
+                TreeItem::new("widgets").children(["list", "tree"]),
+                TreeItem::new("lib"),
+            ]),
+            TreeItem::new("docs").expandable(true),
+            TreeItem::new("README"),
+        ]
+    }
+
+    fn render(tree: &Tree, state: &mut TreeState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        StatefulWidget::render(tree, buf.area, &mut buf, state);
+        buf
+    }
+
+    #[rstest]
+    fn render_closed(items: Vec<TreeItem<'static>>) {
+        let buf = render(&Tree::new(items), &mut TreeState::default(), 10, 4);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["▶ src     ", "▶ docs    ", "  README  ", "          "])
+        );
+    }
+
+    #[rstest]
+    fn render_open(items: Vec<TreeItem<'static>>) {
+        let mut state = TreeState::default();
+        state.open(vec![0]);
+        state.open(vec![0, 0]);
+        state.open(vec![1]);
+        let buf = render(&Tree::new(items), &mut state, 16, 7);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "▼ src           ",
+                "├─ ▼ widgets    ",
+                "│  ├─   list    ",
+                "│  └─   tree    ",
+                "└─   lib        ",
+                "▼ docs          ",
+                "  README        ",
+            ])
+        );
+    }
+
+    #[rstest]
+    fn render_without_guides(items: Vec<TreeItem<'static>>) {
+        let mut state = TreeState::default().with_selected(Some(vec![0, 0, 1]));
+        let tree = Tree::new(items)
+            .guides(None)
+            .open_symbol("- ")
+            .closed_symbol("+ ");
+        let buf = render(&tree, &mut state, 12, 4);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "- src       ",
+                "  - widgets ",
+                "      list  ",
+                "      tree  "
+            ])
+        );
+    }
+
+    #[rstest]
+    fn render_selected(items: Vec<TreeItem<'static>>) {
+        let mut state = TreeState::default().with_selected(Some(vec![0, 1]));
+        let tree = Tree::new(items)
+            .guides(Some(line::ROUNDED))
+            .guide_style(Style::new().blue())
+            .highlight_symbol(">")
+            .highlight_style(Style::new().red());
+        let buf = render(&tree, &mut state, 12, 4);
+        let mut expected = Buffer::with_lines([
+            " ▼ src      ",
+            " ├─ ▶ widget",
+            ">╰─   lib   ",
+            " ▶ docs     ",
+        ]);
+        expected.set_style(Rect::new(1, 1, 3, 2), Style::new().blue());
+        expected.set_style(Rect::new(0, 2, 12, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    fn render_scrolls_to_selected(items: Vec<TreeItem<'static>>) {
+        let mut state = TreeState::default().with_selected(Some(vec![2]));
+        state.open(vec![0]);
+        let tree = Tree::new(items).highlight_spacing(HighlightSpacing::Never);
+        let buf = render(&tree, &mut state, 10, 2);
+        assert_eq!(buf, Buffer::with_lines(["▶ docs    ", "  README  "]));
+        assert_eq!(state.offset(), 3);
+
+        state.select(Some(vec![0]));
+        let tree = tree.scroll_padding(1);
+        let buf = render(&tree, &mut state, 10, 3);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["▼ src     ", "├─ ▶ widge", "└─   lib  "])
+        );
+        assert_eq!(state.offset(), 0);
+    }
+
+    #[rstest]
+    #[case::missing_child(vec![0, 5], Some(vec![0]))]
+    #[case::closed_ancestor(vec![1, 0], Some(vec![1]))]
+    #[case::missing_root(vec![9], Some(vec![2]))]
+    fn render_fixes_selection(
+        items: Vec<TreeItem<'static>>,
+        #[case] selected: Vec<usize>,
+        #[case] expected: Option<Vec<usize>>,
+    ) {
+        let mut state = TreeState {
+            selected: Some(selected),
+            ..TreeState::default()
+        };
+        render(&Tree::new(items), &mut state, 10, 5);
+        assert_eq!(state.selected, expected);
+    }
+
+    #[rstest]
+    fn render_updates_navigation(items: Vec<TreeItem<'static>>) {
+        let mut state = TreeState::default();
+        state.open(vec![0]);
+        let tree = Tree::new(items);
+        render(&tree, &mut state, 10, 5);
+        state.select_last();
+        assert_eq!(state.selected(), Some([2].as_slice()));
+        state.select_previous();
+        state.select_previous();
+        assert_eq!(state.selected(), Some([0, 1].as_slice()));
+    }
+
+    #[test]
+    fn render_empty() {
+        let mut state = TreeState::default().with_selected(Some(vec![0]));
+        let buf = render(&Tree::default().block(Block::bordered()), &mut state, 4, 3);
+        assert_eq!(buf, Buffer::with_lines(["┌──┐", "│  │", "└──┘"]));
+        assert_eq!(state.selected(), None);
+    }
+
+    #[test]
+    fn can_be_stylized() {
+        assert_eq!(
+            Tree::default().red().on_black().style,
+            Style::new().red().on_black()
+        );
+    }
+}
@@ -0,0 +1,464 @@
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+use ratatui_core::input::{KeyCode, KeyEvent};
+
+use crate::render_cache::RenderCache;
+
+/// State of the [`Tree`] widget
+///
+/// This state can be used to scroll through the tree, select a node and open or close nodes.
+/// Nodes are identified by their path: the index of the node among its siblings, preceded by the
+/// indices of its ancestors. For example, `[1, 0]` is the first child of the second root item.
+///
+/// The state consists of:
+/// - [`offset`]: the index of the first displayed row
+/// - [`selected`]: the path of the selected node, which can be `None` if no node is selected
+/// - [`opened`]: the paths of the opened nodes, whose children are displayed
+///
+/// When the tree is rendered, the state remembers the displayed nodes so that the navigation
+/// methods, such as [`TreeState::select_next`] or [`TreeState::select_parent`], and
+/// [`TreeState::handle_key`] can follow the structure of the tree.
+///
+/// [`offset`]: TreeState::offset()
+/// [`selected`]: TreeState::selected()
+/// [`opened`]: TreeState::opened()
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::{Tree, TreeItem, TreeState};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let items = [TreeItem::new("src").children(["lib.rs", "main.rs"])];
+/// let tree = Tree::new(items);
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TreeState::default();
+///
+/// state.select(Some(vec![0, 1])); // select main.rs, opening src
+///
+/// frame.render_stateful_widget(tree, area, &mut state);
+/// # }
+/// ```
+///
+/// [`Tree`]: super::Tree
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TreeState {
+    pub(crate) offset: usize,
+    pub(crate) selected: Option<Vec<usize>>,
+    pub(crate) opened: BTreeSet<Vec<usize>>,
+    /// The nodes and the height of the last render
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) cache: RenderCache<TreeCache>,
+}
+
+/// The layout of the last render of a [`Tree`](super::Tree)
+#[derive(Debug, Default, Clone)]
+pub(crate) struct TreeCache {
+    /// The nodes displayed, in order
+    pub visible: Vec<VisibleNode>,
+    /// The number of rows displayed
+    pub viewport_height: usize,
+}
+
+/// A node displayed by the last render of a [`Tree`](super::Tree).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub(crate) struct VisibleNode {
+    pub path: Vec<usize>,
+    pub expandable: bool,
+}
+
+impl TreeState {
+    /// Sets the index of the first row to be displayed
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the path of the selected node, opening its ancestors
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TreeState;
+    ///
+    /// let state = TreeState::default().with_selected(Some(vec![0, 2]));
+    /// assert!(state.is_open(&[0]));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_selected(mut self, selected: Option<Vec<usize>>) -> Self {
+        self.select(selected);
+        self
+    }
+
+    /// Index of the first row to be displayed
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Mutable reference to the index of the first row to be displayed
+    pub const fn offset_mut(&mut self) -> &mut usize {
+        &mut self.offset
+    }
+
+    /// The path of the selected node, or `None` if no node is selected
+    pub fn selected(&self) -> Option<&[usize]> {
+        self.selected.as_deref()
+    }
+
+    /// Selects a node by its path, or deselects with `None`.
+    ///
+    /// The ancestors of the node are opened so that it is displayed. If the path does not exist
+    /// when the tree is rendered, its deepest existing ancestor is selected instead.
+    pub fn select(&mut self, path: Option<Vec<usize>>) {
+        if let Some(path) = &path {
+            for depth in 1..path.len() {
+                self.opened.insert(path[..depth].to_vec());
+            }
+        }
+        self.selected = path;
+    }
+
+    /// The paths of the opened nodes
+    pub const fn opened(&self) -> &BTreeSet<Vec<usize>> {
+        &self.opened
+    }
+
+    /// Returns `true` if the node at `path` is open.
+    pub fn is_open(&self, path: &[usize]) -> bool {
+        self.opened.contains(path)
+    }
+
+    /// Opens the node at `path`, returning `true` if it was closed.
+    pub fn open(&mut self, path: Vec<usize>) -> bool {
+        self.opened.insert(path)
+    }
+
+    /// Closes the node at `path`, returning `true` if it was open.
+    ///
+    /// The opened descendants of the node stay open, and are displayed again when the node is
+    /// reopened.
+    pub fn close(&mut self, path: &[usize]) -> bool {
+        self.opened.remove(path)
+    }
+
+    /// Opens the node at `path` if it is closed, or closes it if it is open.
+    pub fn toggle(&mut self, path: Vec<usize>) {
+        if !self.close(&path) {
+            self.open(path);
+        }
+    }
+
+    /// Opens or closes the selected node.
+    pub fn toggle_selected(&mut self) {
+        if let Some(path) = self.selected.clone() {
+            self.toggle(path);
+        }
+    }
+
+    /// Closes all the nodes.
+    pub fn close_all(&mut self) {
+        self.opened.clear();
+    }
+
+    /// Selects the first displayed node of the tree.
+    ///
+    /// This does nothing before the tree is rendered.
+    pub fn select_first(&mut self) {
+        if let Some(node) = self.cache.visible.first() {
+            self.selected = Some(node.path.clone());
+        }
+    }
+
+    /// Selects the last displayed node of the tree.
+    ///
+    /// This does nothing before the tree is rendered.
+    pub fn select_last(&mut self) {
+        if let Some(node) = self.cache.visible.last() {
+            self.selected = Some(node.path.clone());
+        }
+    }
+
+    /// Selects the displayed node after the selected one, or the first node if none is selected.
+    pub fn select_next(&mut self) {
+        self.select_relative(1);
+    }
+
+    /// Selects the displayed node before the selected one, or the first node if none is selected.
+    pub fn select_previous(&mut self) {
+        self.select_relative(-1);
+    }
+
+    /// Selects the parent of the selected node.
+    pub fn select_parent(&mut self) {
+        if let Some(path) = self.selected.as_mut().filter(|path| path.len() > 1) {
+            path.pop();
+        }
+    }
+
+    /// Opens the selected node and selects its first child.
+    ///
+    /// When the children of the node are loaded lazily, the node is opened and stays selected
+    /// until its children are displayed.
+    pub fn select_first_child(&mut self) {
+        let Some(node) = self.selected_node() else {
+            return;
+        };
+        if !node.expandable {
+            return;
+        }
+        let path = node.path.clone();
+        let mut child = path.clone();
+        child.push(0);
+        if self.cache.visible.iter().any(|node| node.path == child) {
+            self.selected = Some(child);
+        }
+        self.open(path);
+    }
+
+    /// Selects the next sibling of the selected node, if any.
+    pub fn select_next_sibling(&mut self) {
+        let Some(mut path) = self.selected.clone() else {
+            return;
+        };
+        if let Some(index) = path.last_mut() {
+            *index += 1;
+        }
+        if self.cache.visible.iter().any(|node| node.path == path) {
+            self.selected = Some(path);
+        }
+    }
+
+    /// Selects the previous sibling of the selected node, if any.
+    pub fn select_previous_sibling(&mut self) {
+        if let Some(index) = self.selected.as_mut().and_then(|path| path.last_mut()) {
+            *index = index.saturating_sub(1);
+        }
+    }
+
+    /// Handles a key press, returning `true` if the key was handled.
+    ///
+    /// | Key | Action |
+    /// |-----|--------|
+    /// | `Up` / `Down` | select the [previous](Self::select_previous) / [next](Self::select_next) node |
+    /// | `PageUp` / `PageDown` | move the selection by a page |
+    /// | `Home` / `End` | select the [first](Self::select_first) / [last](Self::select_last) node |
+    /// | `Left` | close the selected node, or select its [parent](Self::select_parent) |
+    /// | `Right` | open the selected node, or select its [first child](Self::select_first_child) |
+    /// | `Enter`, `Space` | [toggle](Self::toggle_selected) the selected node |
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let page = isize::try_from(self.cache.viewport_height.max(1)).unwrap_or(isize::MAX);
+        match key.code {
+            KeyCode::Up => self.select_previous(),
+            KeyCode::Down => self.select_next(),
+            KeyCode::PageUp => self.select_relative(-page),
+            KeyCode::PageDown => self.select_relative(page),
+            KeyCode::Home => self.select_first(),
+            KeyCode::End => self.select_last(),
+            KeyCode::Left => {
+                let closed = self.selected.clone().is_some_and(|path| self.close(&path));
+                if !closed {
+                    self.select_parent();
+                }
+            }
+            KeyCode::Right => {
+                let opened = self
+                    .selected_node()
+                    .filter(|node| node.expandable)
+                    .map(|node| node.path.clone())
+                    .is_some_and(|path| self.open(path));
+                if !opened {
+                    self.select_first_child();
+                }
+            }
+            KeyCode::Enter | KeyCode::Char(' ') => self.toggle_selected(),
+            _ => return false,
+        }
+        true
+    }
+
+    fn selected_node(&self) -> Option<&VisibleNode> {
+        let selected = self.selected.as_ref()?;
+        self.cache
+            .visible
+            .iter()
+            .find(|node| &node.path == selected)
+    }
+
+    /// Moves the selection by a number of displayed nodes, stopping at the first and last nodes.
+    fn select_relative(&mut self, delta: isize) {
+        let index = self.selected.as_ref().and_then(|selected| {
+            self.cache
+                .visible
+                .iter()
+                .position(|node| &node.path == selected)
+        });
+        let (Some(index), Some(last)) = (index, self.cache.visible.len().checked_sub(1)) else {
+            self.select_first();
+            return;
+        };
+        let index = index.saturating_add_signed(delta).min(last);
+        self.selected = Some(self.cache.visible[index].path.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    /// A state as rendered for a tree with the structure:
+    ///
+    /// ```text
+    /// 0
+    /// ├─ 0.0
+    /// │  └─ 0.0.0 (closed)
+    /// └─ 0.1
+    /// 1 (lazy)
+    /// ```
+    fn rendered_state(selected: &[usize]) -> TreeState {
+        let node = |path: &[usize], expandable| VisibleNode {
+            path: path.to_vec(),
+            expandable,
+        };
+        TreeState {
+            selected: Some(selected.to_vec()),
+            opened: BTreeSet::from([vec![0]]),
+            cache: RenderCache::new(TreeCache {
+                visible: vec![
+                    node(&[0], true),
+                    node(&[0, 0], true),
+                    node(&[0, 1], false),
+                    node(&[1], true),
+                ],
+                viewport_height: 2,
+            }),
+            ..TreeState::default()
+        }
+    }
+
+    #[test]
+    fn select_opens_ancestors() {
+        let mut state = TreeState::default();
+        state.select(Some(vec![1, 2, 3]));
+        assert_eq!(state.selected(), Some([1, 2, 3].as_slice()));
+        assert!(state.is_open(&[1]));
+        assert!(state.is_open(&[1, 2]));
+        assert!(!state.is_open(&[1, 2, 3]));
+        state.select(None);
+        assert_eq!(state.selected(), None);
+    }
+
+    #[test]
+    fn open_close() {
+        let mut state = TreeState::default();
+        assert!(state.open(vec![0]));
+        assert!(!state.open(vec![0]));
+        state.toggle(vec![1]);
+        assert_eq!(state.opened(), &BTreeSet::from([vec![0], vec![1]]));
+        assert!(state.close(&[0]));
+        assert!(!state.close(&[0]));
+        state.close_all();
+        assert!(state.opened().is_empty());
+    }
+
+    #[test]
+    fn select_next_previous() {
+        let mut state = rendered_state(&[0, 1]);
+        state.select_next();
+        assert_eq!(state.selected(), Some([1].as_slice()));
+        state.select_next();
+        assert_eq!(state.selected(), Some([1].as_slice()));
+        state.select_previous();
+        state.select_previous();
+        assert_eq!(state.selected(), Some([0, 0].as_slice()));
+
+        state.select(None);
+        state.select_next();
+        assert_eq!(state.selected(), Some([0].as_slice()));
+        state.select_last();
+        assert_eq!(state.selected(), Some([1].as_slice()));
+    }
+
+    #[test]
+    fn select_first_last_before_render() {
+        let mut state = TreeState::default();
+        state.select_first();
+        state.select_last();
+        assert_eq!(state.selected(), None);
+
+        let mut state = rendered_state(&[0, 1]);
+        state.select_first();
+        assert_eq!(state.selected(), Some([0].as_slice()));
+    }
+
+    #[test]
+    fn render_caches_are_not_compared() {
+        let state = rendered_state(&[0]);
+        let expected = TreeState {
+            selected: Some(vec![0]),
+            opened: BTreeSet::from([vec![0]]),
+            ..TreeState::default()
+        };
+        assert_eq!(state, expected);
+    }
+
+    #[test]
+    fn select_parent_and_child() {
+        let mut state = rendered_state(&[0, 1]);
+        state.select_parent();
+        assert_eq!(state.selected(), Some([0].as_slice()));
+        state.select_parent();
+        assert_eq!(state.selected(), Some([0].as_slice()));
+
+        state.select_first_child();
+        assert_eq!(state.selected(), Some([0, 0].as_slice()));
+        // the children of [0, 0] are not displayed yet, so it is only opened
+        state.select_first_child();
+        assert_eq!(state.selected(), Some([0, 0].as_slice()));
+        assert!(state.is_open(&[0, 0]));
+    }
+
+    #[test]
+    fn select_siblings() {
+        let mut state = rendered_state(&[0, 0]);
+        state.select_next_sibling();
+        assert_eq!(state.selected(), Some([0, 1].as_slice()));
+        state.select_next_sibling();
+        assert_eq!(state.selected(), Some([0, 1].as_slice()));
+        state.select_previous_sibling();
+        assert_eq!(state.selected(), Some([0, 0].as_slice()));
+        state.select_previous_sibling();
+        assert_eq!(state.selected(), Some([0, 0].as_slice()));
+    }
+
+    #[test]
+    fn handle_key() {
+        let mut state = rendered_state(&[0]);
+        assert!(state.handle_key(KeyEvent::from(KeyCode::PageDown)));
+        assert_eq!(state.selected(), Some([0, 1].as_slice()));
+        assert!(state.handle_key(KeyEvent::from(KeyCode::Left)));
+        assert_eq!(state.selected(), Some([0].as_slice()));
+        assert!(state.handle_key(KeyEvent::from(KeyCode::Left)));
+        assert!(!state.is_open(&[0]));
+        assert!(state.handle_key(KeyEvent::from(KeyCode::Right)));
+        assert!(state.is_open(&[0]));
+        assert!(state.handle_key(KeyEvent::from(KeyCode::Right)));
+        assert_eq!(state.selected(), Some([0, 0].as_slice()));
+        assert!(state.handle_key(KeyEvent::from(KeyCode::End)));
+        assert!(state.handle_key(KeyEvent::from(' ')));
+        assert!(state.is_open(&[1]));
+        assert!(!state.handle_key(KeyEvent::from(KeyCode::Esc)));
+    }
+}
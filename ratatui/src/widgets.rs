@@ -51,13 +51,20 @@
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
 //! - [`Fill`]: paints every cell in its area with a single repeated symbol and style.
 //! - [`Gauge`]: displays progress percentage using block characters.
+//! - [`Heatmap`]: displays a grid of values as colors.
 //! - [`LineGauge`]: display progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
+//! - [`Menu`]: displays a menu bar or a context menu with nested submenus.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
+//! - [`PieChart`]: displays the proportions of values as the slices of a pie or a donut.
+//! - [`Popup`]: displays a dialog with a title, a body and buttons over other widgets.
 //! - [`Scrollbar`]: displays a scrollbar.
-//! - [`Sparkline`]: display a single data set as a sparkline.
+//! - [`Sparkline`]: display one or more data sets as a sparkline.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`TextArea`]: edits multiple lines of text.
+//! - [`TextInput`]: edits a single line of text.
+//! - [`Tree`]: displays hierarchical items and allows opening, closing and selecting them.
 //! - [`RatatuiLogo`]: displays the Ratatui logo.
 //! - [`RatatuiMascot`]: displays the Ratatui mascot.
 //!
@@ -665,7 +672,7 @@
 //! [no-std concept guide]: https://ratatui.rs/concepts/no-std/
 
 pub use ratatui_core::widgets::{StatefulWidget, Widget};
-pub use ratatui_widgets::barchart::{Bar, BarChart, BarGroup};
+pub use ratatui_widgets::barchart::{Bar, BarChart, BarGroup, BarSegment};
 pub use ratatui_widgets::block::{
     Block, BlockExt, CellEffect, Dimmed, Padding, Shadow, TitlePosition, dimmed,
 };
@@ -673,20 +680,34 @@ pub use ratatui_widgets::borders::{BorderType, Borders};
 #[cfg(feature = "widget-calendar")]
 pub use ratatui_widgets::calendar;
 pub use ratatui_widgets::canvas;
-pub use ratatui_widgets::chart::{Axis, Chart, Dataset, GraphType, LegendPosition};
+pub use ratatui_widgets::chart::{
+    Axis, AxisScale, BoxPlot, Candle, Chart, ChartState, Dataset, ErrorBar, GraphType,
+    LegendPosition, YAxis,
+};
 pub use ratatui_widgets::clear::Clear;
 pub use ratatui_widgets::fill::Fill;
 pub use ratatui_widgets::gauge::{Gauge, LineGauge};
-pub use ratatui_widgets::list::{List, ListDirection, ListItem, ListState};
+pub use ratatui_widgets::heatmap::{ColorScale, Heatmap};
+pub use ratatui_widgets::list::{List, ListDirection, ListItem, ListSource, ListState};
 pub use ratatui_widgets::logo::{RatatuiLogo, Size as RatatuiLogoSize};
 pub use ratatui_widgets::mascot::{MascotEyeColor, RatatuiMascot};
-pub use ratatui_widgets::paragraph::{Paragraph, Wrap};
+pub use ratatui_widgets::menu::{Menu, MenuItem, MenuState};
+pub use ratatui_widgets::paragraph::{Paragraph, Wrap, WrapIndent, WrapMode};
+pub use ratatui_widgets::piechart::{PieChart, Slice};
+pub use ratatui_widgets::popup::{Popup, PopupPosition, PopupState};
 pub use ratatui_widgets::scrollbar::{
     ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState,
 };
-pub use ratatui_widgets::sparkline::{RenderDirection, Sparkline, SparklineBar};
-pub use ratatui_widgets::table::{Cell, HighlightSpacing, Row, Table, TableState};
+pub use ratatui_widgets::sparkline::{
+    RenderDirection, Sparkline, SparklineAnnotation, SparklineBar, SparklineMode, SparklineSeries,
+};
+pub use ratatui_widgets::table::{
+    Cell, Gridlines, HighlightSpacing, Row, Selection, SortOrder, Table, TableSource, TableState,
+};
 pub use ratatui_widgets::tabs::Tabs;
+pub use ratatui_widgets::text_area::{TextArea, TextAreaMove, TextAreaState, TextPosition};
+pub use ratatui_widgets::text_input::{CursorMove, TextInput, TextInputState};
+pub use ratatui_widgets::tree::{Tree, TreeItem, TreeState};
 #[instability::unstable(feature = "widget-ref")]
 pub use {stateful_widget_ref::StatefulWidgetRef, widget_ref::WidgetRef};
 
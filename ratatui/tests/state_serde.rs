@@ -19,8 +19,9 @@ use ratatui::backend::TestBackend;
 use ratatui::layout::{Constraint, Direction, Layout};
 use ratatui::text::Line;
 use ratatui::widgets::{
-    Block, Borders, List, ListState, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table,
-    TableState,
+    Block, Borders, CursorMove, List, ListState, Row, Scrollbar, ScrollbarOrientation,
+    ScrollbarState, Selection, Table, TableState, TextAreaMove, TextAreaState, TextInputState,
+    TextPosition,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -227,3 +228,105 @@ fn table_state_backwards_compatibility() {
     let new_state: TableState = serde_json::from_str(NEW_TABLE_DESERIALIZE).unwrap();
     assert_eq!(old_state, new_state);
 }
+
+// The indices of a text input state are moved to grapheme boundaries when it is deserialized.
+const TEXT_INPUT_INVALID_INDICES: &str = r#"{
+    "value": "é",
+    "cursor": 1,
+    "anchor": 1,
+    "offset": 0,
+    "cursor_position": null,
+    "kill_ring": ["x"],
+    "yank": { "range": { "start": 1, "end": 2 }, "index": 0 }
+}"#;
+
+#[test]
+fn text_input_state_deserialize_invalid_indices() {
+    let mut state: TextInputState = serde_json::from_str(TEXT_INPUT_INVALID_INDICES).unwrap();
+    assert_eq!(state.cursor(), 0);
+    assert_eq!(state.selection(), None);
+    state.yank_pop();
+    assert_eq!(state.value(), "é");
+    state.move_cursor(CursorMove::Right);
+    assert_eq!(state.cursor(), 2);
+}
+
+#[test]
+fn text_area_state_deserialize_invalid_state() {
+    let mut json = serde_json::to_value(TextAreaState::new()).unwrap();
+    json["lines"] = serde_json::json!([]);
+    assert!(serde_json::from_value::<TextAreaState>(json.clone()).is_err());
+
+    json["lines"] = serde_json::json!(["é"]);
+    json["cursor"] = serde_json::json!({ "line": 3, "index": 1 });
+    let state: TextAreaState = serde_json::from_value(json).unwrap();
+    assert_eq!(state.cursor(), TextPosition::new(0, 0));
+}
+
+#[test]
+fn list_state_selection_round_trip() {
+    let mut state = ListState::default().with_selected(Some(2));
+    state.toggle_selection();
+    state.extend_selection_to(4);
+    let json = serde_json::to_string(&state).unwrap();
+    let deserialized: ListState = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.selected(), Some(4));
+    assert_eq!(deserialized.selection(), state.selection());
+    assert_eq!(deserialized, state);
+}
+
+#[test]
+fn table_state_selection_round_trip() {
+    let mut state = TableState::default().with_selected(Some(1));
+    state.toggle_selection();
+    state.extend_selection_to(3);
+    let json = serde_json::to_string(&state).unwrap();
+    let deserialized: TableState = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, state);
+}
+
+#[test]
+fn selection_deserialize_normalizes_ranges() {
+    let json = serde_json::json!([
+        { "start": 6, "end": 8 },
+        { "start": 0, "end": 2 },
+        { "start": 1, "end": 4 },
+        { "start": 4, "end": 5 },
+        { "start": 9, "end": 9 },
+    ]);
+    let selection: Selection = serde_json::from_value(json).unwrap();
+    assert_eq!(selection.ranges(), [0..5, 6..8]);
+    assert_eq!(selection.len(), 7);
+}
+
+#[test]
+fn text_area_state_deserialize_invalid_history() {
+    let mut json = serde_json::to_value(TextAreaState::new()).unwrap();
+    json["lines"] = serde_json::json!(["é"]);
+    for start in [
+        serde_json::json!({ "line": 5, "index": 0 }),
+        serde_json::json!({ "line": 0, "index": 1 }),
+        serde_json::json!({ "line": 0, "index": 9 }),
+    ] {
+        json["history"]["undo"] = serde_json::json!([{
+            "start": start,
+            "removed": "",
+            "inserted": "x",
+            "cursor": { "line": 0, "index": 0 },
+        }]);
+        let mut state: TextAreaState = serde_json::from_value(json.clone()).unwrap();
+        assert!(!state.undo());
+        assert_eq!(state.text(), "é");
+    }
+}
+
+#[test]
+fn text_area_state_history_round_trip() {
+    let mut state = TextAreaState::new().with_text("é");
+    state.move_cursor(TextAreaMove::LineEnd);
+    state.insert_char('x');
+    let json = serde_json::to_string(&state).unwrap();
+    let mut deserialized: TextAreaState = serde_json::from_str(&json).unwrap();
+    assert!(deserialized.undo());
+    assert_eq!(deserialized.text(), "é");
+}